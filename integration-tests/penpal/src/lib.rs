@@ -80,7 +80,7 @@ use frame_support::traits::WithdrawReasons;
 use parachains_common::{AccountId, Signature};
 use polimec_common::migration_types::MigrationInfo;
 use polkadot_runtime_common::{xcm_sender::NoPriceForMessageDelivery, BlockHashCount, SlowAdjustingFeeUpdate};
-use sp_runtime::traits::Convert;
+use sp_runtime::{traits::Convert, SaturatedConversion};
 use weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight};
 use xcm::{
 	latest::prelude::{AssetId as AssetLocationId, BodyId},
@@ -672,6 +672,13 @@ impl Convert<MigrationInfo, Balance> for DivideBalanceByBlocks {
 		}
 	}
 }
+pub struct CliffAfterGenesis;
+impl Convert<u64, BlockNumber> for CliffAfterGenesis {
+	fn convert(cliff: u64) -> BlockNumber {
+		GenesisMoment::get().saturating_add(cliff.saturated_into())
+	}
+}
+
 parameter_types! {
 	pub PolimecParaId: ParaId = 3344u32.into();
 	pub GenesisMoment: BlockNumber = 0u32;
@@ -681,12 +688,14 @@ parameter_types! {
 impl polimec_receiver::Config for Runtime {
 	type Balance = Balance;
 	type Balances = Balances;
+	type CliffToStartingMoment = CliffAfterGenesis;
 	type GenesisMoment = GenesisMoment;
 	type MaxMigrations = MaxMigrations;
 	type MigrationInfoToPerBlockBalance = DivideBalanceByBlocks;
 	type PolimecParaId = PolimecParaId;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
+	type UserLocationToAccountId = xcm_builder::AccountId32Aliases<xcm_config::RelayNetwork, AccountId>;
 	type Vesting = Vesting;
 }
 
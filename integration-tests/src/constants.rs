@@ -586,6 +586,10 @@ pub mod polimec {
 			vesting: Default::default(),
 			transaction_payment: Default::default(),
 			treasury: Default::default(),
+			funding: polimec_runtime::FundingConfig {
+				funding_assets: polimec_runtime::xcm_config::default_funding_assets(),
+				..Default::default()
+			},
 		};
 
 		genesis_config.build_storage().unwrap()
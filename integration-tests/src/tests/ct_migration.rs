@@ -180,6 +180,8 @@ fn create_settled_project() -> (ProjectId, Vec<AccountId>) {
 						.map(|contribution| contribution.contributor),
 				)
 				.collect();
+		// The liquidity-pool CTs are migrated like the participants' ones
+		participants.push(<PolimecRuntime as pallet_funding::Config>::LiquidityPoolsAccount::get());
 		participants.sort();
 		participants.dedup();
 
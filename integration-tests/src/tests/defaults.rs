@@ -17,8 +17,8 @@ use crate::PolimecRuntime;
 use frame_support::BoundedVec;
 pub use pallet_funding::instantiator::{BidParams, ContributionParams, UserToUSDBalance};
 use pallet_funding::{
-	AcceptedFundingAsset, BiddingTicketSizes, ContributingTicketSizes, CurrencyMetadata, PriceProviderOf,
-	ProjectMetadata, ProjectMetadataOf, TicketSize,
+	AcceptedFundingAsset, BiddingTicketSizes, ContributingTicketSizes, CurrencyMetadata, FeeMode, PriceProviderOf,
+	PricingModel, ProjectMetadata, ProjectMetadataOf, TicketSize,
 };
 use sp_arithmetic::{FixedPointNumber, Percent};
 
@@ -87,7 +87,16 @@ pub fn default_project_metadata(issuer: AccountId) -> ProjectMetadataOf<polimec_
 		},
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: issuer,
+		funding_destination_location: None,
 		policy_ipfs_cid: Some(ipfs_hash()),
+		round_durations: Default::default(),
+		ct_release_policy: None,
+		soft_cap: None,
+		private_round: None,
+		fee_mode: FeeMode::ContributionTokens,
+		milestones: None,
+		pricing_model: PricingModel::Buckets,
+		jurisdiction_restriction: None,
 	}
 }
 pub fn default_evaluations() -> Vec<UserToUSDBalance<PolimecRuntime>> {
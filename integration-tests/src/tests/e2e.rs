@@ -73,7 +73,16 @@ pub fn excel_project() -> ProjectMetadataOf<PolimecRuntime> {
 		},
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: ISSUER.into(),
+		funding_destination_location: None,
 		policy_ipfs_cid: Some(metadata_hash),
+		round_durations: Default::default(),
+		ct_release_policy: None,
+		soft_cap: None,
+		private_round: None,
+		fee_mode: FeeMode::ContributionTokens,
+		milestones: None,
+		pricing_model: PricingModel::Buckets,
+		jurisdiction_restriction: None,
 	}
 }
 
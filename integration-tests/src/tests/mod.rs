@@ -24,3 +24,4 @@ mod oracle;
 mod reserve_backed_transfers;
 mod vest;
 mod xcm_config;
+mod xcm_participation;
@@ -0,0 +1,363 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{constants::PricesBuilder, tests::defaults::*, *};
+use frame_support::{
+	dispatch::GetDispatchInfo,
+	traits::fungibles::{Inspect, Mutate},
+};
+use pallet_funding::{AcceptedFundingAsset, FundingOutcome, ProjectId, ProjectStatus};
+use polimec_common::credentials::InvestorType;
+use polimec_common_test_utils::{generate_did_from_account, get_mock_jwt_with_cid};
+use polimec_runtime::PLMC;
+use xcm_executor::traits::ConvertLocation;
+
+const USDT_ID: u32 = 1984;
+const USDT_UNIT: u128 = 1_000_000;
+
+/// The USDT of AssetHub, seen from Polimec.
+fn usdt_location() -> Location {
+	Location::new(1, [Parachain(AssetNet::para_id().into()), PalletInstance(50), GeneralIndex(USDT_ID as u128)])
+}
+
+/// The account of ALICE on AssetHub, seen from Polimec.
+fn alice_location() -> Location {
+	let alice: [u8; 32] = AssetNet::account_id_of(ALICE).into();
+	Location::new(1, [Parachain(AssetNet::para_id().into()), AccountId32 { network: None, id: alice }])
+}
+
+/// The Polimec account that holds the participations of ALICE of AssetHub.
+fn alice_polimec_account() -> AccountId {
+	polimec_runtime::xcm_config::LocationToAccountId::convert_location(&alice_location()).unwrap()
+}
+
+fn asset_hub_usdt_balance(who: &AccountId) -> u128 {
+	AssetNet::execute_with(|| AssetHubAssets::balance(USDT_ID, who.clone()))
+}
+
+/// Creates USDT on AssetHub, and mints some to ALICE there.
+fn create_usdt_on_asset_hub(amount: u128) {
+	let alice = AssetNet::account_id_of(ALICE);
+	AssetNet::execute_with(|| {
+		assert_ok!(AssetHubAssets::force_create(
+			AssetHubOrigin::root(),
+			USDT_ID.into(),
+			sp_runtime::MultiAddress::Id(alice.clone()),
+			true,
+			1000
+		));
+		assert_ok!(AssetHubAssets::mint_into(USDT_ID, &alice, amount));
+	});
+}
+
+/// The PLMC bond is not paid from the assets sent by ALICE. Her Polimec account is funded like any other
+/// Polimec account, here by BOB.
+fn fund_alice_polimec_account_with_plmc(amount: u128) {
+	PolimecNet::execute_with(|| {
+		assert_ok!(PolimecBalances::transfer_keep_alive(
+			PolimecOrigin::signed(PolimecNet::account_id_of(BOB)),
+			alice_polimec_account().into(),
+			amount,
+		));
+	});
+}
+
+/// ALICE sends `usdt_amount` of her AssetHub USDT to her Polimec account, followed by a `Transact` of `call`
+/// paying its execution with the USDT that just arrived. XCM v4 clears the origin of the message carrying
+/// reserve-transferred assets, so these are two messages, sent from the same AssetHub block and executed in order
+/// on Polimec.
+fn participate_from_asset_hub(usdt_amount: u128, call: PolimecCall, origin_kind: OriginKind) {
+	let alice = AssetNet::account_id_of(ALICE);
+	let call_weight = PolimecNet::execute_with(|| call.get_dispatch_info().weight);
+	let fees: Asset = (usdt_location(), 10 * USDT_UNIT).into();
+	let transact = Xcm::<()>(vec![
+		WithdrawAsset(fees.clone().into()),
+		BuyExecution { fees, weight_limit: Unlimited },
+		Transact { origin_kind, require_weight_at_most: call_weight, call: call.encode().into() },
+		RefundSurplus,
+		DepositAsset { assets: Wild(AllCounted(1)), beneficiary: alice_location() },
+	]);
+	let polimec: Location = ParentThen(Parachain(PolimecNet::para_id().into()).into()).into();
+
+	AssetNet::execute_with(|| {
+		let usdt: Asset = (Location::new(0, [PalletInstance(50), GeneralIndex(USDT_ID as u128)]), usdt_amount).into();
+		assert_ok!(AssetHubXcmPallet::limited_reserve_transfer_assets(
+			AssetHubOrigin::signed(alice.clone()),
+			bx!(polimec.clone().into()),
+			bx!(alice_location().into()),
+			bx!(usdt.into()),
+			0,
+			Unlimited,
+		));
+		assert_ok!(AssetHubXcmPallet::send(
+			AssetHubOrigin::signed(alice),
+			bx!(polimec.into()),
+			bx!(VersionedXcm::from(transact)),
+		));
+	});
+}
+
+fn assert_polimec_processed_messages(success: bool) {
+	PolimecNet::execute_with(|| {
+		assert_expected_events!(
+			PolimecNet,
+			vec![
+				PolimecEvent::MessageQueue(pallet_message_queue::Event::Processed { success: processed, .. }) => {
+					processed: *processed == success,
+				},
+			]
+		);
+	});
+}
+
+fn create_community_round_project() -> ProjectId {
+	let mut inst = IntegrationInstantiator::new(None);
+	PolimecNet::execute_with(|| {
+		inst.create_community_contributing_project(
+			default_project_metadata(ISSUER.into()),
+			ISSUER.into(),
+			None,
+			default_evaluations(),
+			default_bids(),
+		)
+	})
+}
+
+fn contribute_call(project_id: ProjectId, investor_type: InvestorType) -> PolimecCall {
+	let account = alice_polimec_account();
+	let jwt = get_mock_jwt_with_cid(account.clone(), investor_type, generate_did_from_account(account), ipfs_hash());
+	PolimecCall::Funding(pallet_funding::Call::contribute {
+		jwt,
+		project_id,
+		ct_amount: 100 * CT_UNIT,
+		multiplier: 1u8.try_into().unwrap(),
+		funding_asset: AcceptedFundingAsset::USDT,
+	})
+}
+
+#[test]
+fn asset_hub_account_contributes_and_gets_refunded_through_xcm() {
+	polimec::set_prices(PricesBuilder::default());
+	let project_id = create_community_round_project();
+	create_usdt_on_asset_hub(200_000 * USDT_UNIT);
+	fund_alice_polimec_account_with_plmc(200 * PLMC);
+	let alice = alice_polimec_account();
+
+	participate_from_asset_hub(100_000 * USDT_UNIT, contribute_call(project_id, InvestorType::Retail), OriginKind::Xcm);
+	assert_polimec_processed_messages(true);
+
+	let contribution = PolimecNet::execute_with(|| {
+		assert_eq!(
+			pallet_funding::XcmParticipantLocations::<PolimecRuntime>::get(project_id, &alice),
+			Some(alice_location())
+		);
+		let mut contributions =
+			pallet_funding::Contributions::<PolimecRuntime>::iter_prefix_values((project_id, alice.clone()));
+		let contribution = contributions.next().expect("The contribution was made through XCM");
+		assert!(contributions.next().is_none());
+		assert_eq!(contribution.ct_amount, 100 * CT_UNIT);
+		contribution
+	});
+
+	// The project is cancelled, so the contribution is refunded back to AssetHub on settlement
+	let asset_hub_balance_before = asset_hub_usdt_balance(&AssetNet::account_id_of(ALICE));
+	PolimecNet::execute_with(|| {
+		assert_ok!(PolimecFunding::cancel_project(PolimecOrigin::root(), project_id));
+		assert_eq!(
+			pallet_funding::ProjectsDetails::<PolimecRuntime>::get(project_id).unwrap().status,
+			ProjectStatus::SettlementStarted(FundingOutcome::Failure)
+		);
+		let polimec_balance_before = PolimecForeignAssets::balance(AcceptedFundingAsset::USDT.id(), alice.clone());
+		assert_ok!(PolimecFunding::settle_contribution(
+			PolimecOrigin::signed(PolimecNet::account_id_of(BOB)),
+			project_id,
+			alice.clone(),
+			contribution.id,
+		));
+		assert_expected_events!(
+			PolimecNet,
+			vec![
+				PolimecEvent::Funding(pallet_funding::Event::RefundSentThroughXcm { account, amount, .. }) => {
+					account: *account == alice,
+					amount: *amount == contribution.funding_asset_amount,
+				},
+			]
+		);
+		// The refund left Polimec, instead of being kept on ALICE's local account
+		assert_eq!(
+			PolimecForeignAssets::balance(AcceptedFundingAsset::USDT.id(), alice.clone()),
+			polimec_balance_before
+		);
+		// Nothing is left to refund ALICE in the project
+		assert!(pallet_funding::XcmParticipantLocations::<PolimecRuntime>::get(project_id, &alice).is_none());
+	});
+
+	AssetNet::execute_with(|| {
+		assert_expected_events!(
+			AssetNet,
+			vec![
+				AssetHubEvent::MessageQueue(pallet_message_queue::Event::Processed { success: true, .. }) => {},
+			]
+		);
+	});
+	let asset_hub_balance_after = asset_hub_usdt_balance(&AssetNet::account_id_of(ALICE));
+	let received = asset_hub_balance_after - asset_hub_balance_before;
+	// AssetHub takes its execution fee out of the refund
+	assert!(received < contribution.funding_asset_amount);
+	assert!(received > contribution.funding_asset_amount - USDT_UNIT);
+}
+
+#[test]
+fn asset_hub_account_withdraws_a_contribution_and_gets_refunded_through_xcm() {
+	polimec::set_prices(PricesBuilder::default());
+	let project_id = create_community_round_project();
+	create_usdt_on_asset_hub(200_000 * USDT_UNIT);
+	fund_alice_polimec_account_with_plmc(200 * PLMC);
+	let alice = alice_polimec_account();
+
+	participate_from_asset_hub(100_000 * USDT_UNIT, contribute_call(project_id, InvestorType::Retail), OriginKind::Xcm);
+	assert_polimec_processed_messages(true);
+	let contribution = PolimecNet::execute_with(|| {
+		pallet_funding::Contributions::<PolimecRuntime>::iter_prefix_values((project_id, alice.clone()))
+			.next()
+			.expect("The contribution was made through XCM")
+	});
+
+	// The withdrawal is transacted from AssetHub too, with some USDT to pay for its execution
+	let jwt = get_mock_jwt_with_cid(
+		alice.clone(),
+		InvestorType::Retail,
+		generate_did_from_account(alice.clone()),
+		ipfs_hash(),
+	);
+	let withdraw_call = PolimecCall::Funding(pallet_funding::Call::withdraw_contribution {
+		jwt,
+		project_id,
+		contribution_id: contribution.id,
+	});
+	let asset_hub_balance_before = asset_hub_usdt_balance(&AssetNet::account_id_of(ALICE));
+	participate_from_asset_hub(20 * USDT_UNIT, withdraw_call, OriginKind::Xcm);
+	assert_polimec_processed_messages(true);
+
+	PolimecNet::execute_with(|| {
+		assert_expected_events!(
+			PolimecNet,
+			vec![
+				PolimecEvent::Funding(pallet_funding::Event::RefundSentThroughXcm { account, amount, .. }) => {
+					account: *account == alice,
+					amount: *amount == contribution.funding_asset_amount,
+				},
+			]
+		);
+		assert!(pallet_funding::Contributions::<PolimecRuntime>::get((project_id, alice.clone(), contribution.id))
+			.is_none());
+		// Nothing is left to refund ALICE in the project
+		assert!(pallet_funding::XcmParticipantLocations::<PolimecRuntime>::get(project_id, &alice).is_none());
+	});
+
+	// ALICE paid 20 USDT to send the withdrawal, and AssetHub takes its execution fee out of the refund
+	let received = asset_hub_usdt_balance(&AssetNet::account_id_of(ALICE)) + 20 * USDT_UNIT - asset_hub_balance_before;
+	assert!(received < contribution.funding_asset_amount);
+	assert!(received > contribution.funding_asset_amount - USDT_UNIT);
+}
+
+#[test]
+fn xcm_participation_needs_a_jwt_for_the_derived_account() {
+	polimec::set_prices(PricesBuilder::default());
+	let project_id = create_community_round_project();
+	create_usdt_on_asset_hub(200_000 * USDT_UNIT);
+	fund_alice_polimec_account_with_plmc(200 * PLMC);
+
+	// The JWT was issued for ALICE's AssetHub account, not the account she participates with on Polimec
+	let jwt = get_mock_jwt_with_cid(
+		AssetNet::account_id_of(ALICE),
+		InvestorType::Retail,
+		generate_did_from_account(AssetNet::account_id_of(ALICE)),
+		ipfs_hash(),
+	);
+	let call = PolimecCall::Funding(pallet_funding::Call::contribute {
+		jwt,
+		project_id,
+		ct_amount: 100 * CT_UNIT,
+		multiplier: 1u8.try_into().unwrap(),
+		funding_asset: AcceptedFundingAsset::USDT,
+	});
+	participate_from_asset_hub(100_000 * USDT_UNIT, call, OriginKind::Xcm);
+
+	PolimecNet::execute_with(|| {
+		let alice = alice_polimec_account();
+		assert!(pallet_funding::Contributions::<PolimecRuntime>::iter_prefix_values((project_id, alice.clone()))
+			.next()
+			.is_none());
+		assert!(pallet_funding::XcmParticipantLocations::<PolimecRuntime>::get(project_id, alice).is_none());
+	});
+}
+
+#[test]
+fn sovereign_account_participation_keeps_refunds_on_polimec() {
+	polimec::set_prices(PricesBuilder::default());
+	let project_id = create_community_round_project();
+	create_usdt_on_asset_hub(200_000 * USDT_UNIT);
+	fund_alice_polimec_account_with_plmc(200 * PLMC);
+	let alice = alice_polimec_account();
+
+	// `OriginKind::SovereignAccount` dispatches the call from a plain `Signed` origin of ALICE's Polimec account
+	participate_from_asset_hub(
+		100_000 * USDT_UNIT,
+		contribute_call(project_id, InvestorType::Retail),
+		OriginKind::SovereignAccount,
+	);
+	assert_polimec_processed_messages(true);
+
+	PolimecNet::execute_with(|| {
+		let contribution =
+			pallet_funding::Contributions::<PolimecRuntime>::iter_prefix_values((project_id, alice.clone()))
+				.next()
+				.expect("The contribution was made through XCM");
+		assert!(pallet_funding::XcmParticipantLocations::<PolimecRuntime>::get(project_id, &alice).is_none());
+
+		assert_ok!(PolimecFunding::cancel_project(PolimecOrigin::root(), project_id));
+		let polimec_balance_before = PolimecForeignAssets::balance(AcceptedFundingAsset::USDT.id(), alice.clone());
+		assert_ok!(PolimecFunding::settle_contribution(
+			PolimecOrigin::signed(PolimecNet::account_id_of(BOB)),
+			project_id,
+			alice.clone(),
+			contribution.id,
+		));
+		assert_eq!(
+			PolimecForeignAssets::balance(AcceptedFundingAsset::USDT.id(), alice.clone()),
+			polimec_balance_before + contribution.funding_asset_amount
+		);
+	});
+}
+
+#[test]
+fn only_funding_participations_can_be_transacted() {
+	polimec::set_prices(PricesBuilder::default());
+	create_usdt_on_asset_hub(200_000 * USDT_UNIT);
+	fund_alice_polimec_account_with_plmc(200 * PLMC);
+	let alice = alice_polimec_account();
+	let plmc_before = PolimecNet::execute_with(|| PolimecBalances::free_balance(&alice));
+
+	let transfer = PolimecCall::Balances(pallet_balances::Call::transfer_allow_death {
+		dest: PolimecNet::account_id_of(BOB).into(),
+		value: 100 * PLMC,
+	});
+	participate_from_asset_hub(100_000 * USDT_UNIT, transfer, OriginKind::SovereignAccount);
+
+	assert_polimec_processed_messages(false);
+	PolimecNet::execute_with(|| assert_eq!(PolimecBalances::free_balance(&alice), plmc_before));
+}
@@ -175,5 +175,8 @@ pub fn genesis_config(genesis_config_params: GenesisConfigParams) -> serde_json:
 		"technicalCommittee": {
 			"members": technical_committee_members
 		},
+		"funding": {
+			"fundingAssets": polimec_runtime::xcm_config::default_funding_assets()
+		},
 	})
 }
@@ -20,7 +20,7 @@
 
 use std::sync::Arc;
 
-use polimec_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use polimec_runtime::{opaque::Block, AccountId, Balance, Nonce, Runtime};
 
 pub use sc_rpc::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
@@ -28,6 +28,8 @@ use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
+pub mod funding;
+
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
@@ -53,8 +55,13 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: pallet_funding::runtime_api::Leaderboards<Block, Runtime>,
+	C::Api: pallet_funding::runtime_api::UserInformation<Block, Runtime>,
+	C::Api: pallet_funding::runtime_api::ProjectInformation<Block, Runtime>,
+	C::Api: pallet_funding::runtime_api::ExtrinsicHelpers<Block, Runtime>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use funding::{Funding, FundingApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -62,6 +69,7 @@ where
 	let FullDeps { client, pool, deny_unsafe } = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Funding::new(client).into_rpc())?;
 	Ok(module)
 }
@@ -0,0 +1,450 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! RPCs of the funding pallet, calling its runtime APIs.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_funding::{
+	runtime_api::{ExtrinsicHelpers, Leaderboards, ProjectInformation, ProjectParticipationIds, UserInformation},
+	types::{serde_balance, AcceptedFundingAsset},
+	BidInfoOf, ContributionInfoOf, EvaluationInfoOf, ProjectDetailsOf, ProjectId, ProjectMetadataOf,
+};
+use polimec_runtime::{opaque::Block, AccountId, Balance, Runtime};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block as BlockT, FixedU128, Perquintill};
+
+/// A project, with its metadata and details.
+pub type ProjectInfo = (ProjectId, ProjectMetadataOf<Runtime>, ProjectDetailsOf<Runtime>);
+
+/// A balance, passed as a decimal string like the balances in the funding pallet types, since JSON numbers cannot
+/// hold every `u128` precisely. Numbers are accepted too.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcBalance(#[serde(with = "serde_balance")] pub Balance);
+
+/// Funding RPC methods. Each one is queried at the block `at`, or at the best block if it is not given.
+#[rpc(server)]
+pub trait FundingApi<BlockHash> {
+	/// The top evaluations of a project, by PLMC bonded.
+	#[method(name = "funding_topEvaluations")]
+	fn top_evaluations(
+		&self,
+		project_id: ProjectId,
+		amount: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<EvaluationInfoOf<Runtime>>>;
+
+	/// The top bids of a project, by CTs bought.
+	#[method(name = "funding_topBids")]
+	fn top_bids(&self, project_id: ProjectId, amount: u32, at: Option<BlockHash>)
+		-> RpcResult<Vec<BidInfoOf<Runtime>>>;
+
+	/// The top contributions of a project, by CTs bought.
+	#[method(name = "funding_topContributions")]
+	fn top_contributions(
+		&self,
+		project_id: ProjectId,
+		amount: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ContributionInfoOf<Runtime>>>;
+
+	/// The top projects, by USD raised.
+	#[method(name = "funding_topProjectsByUsdRaised")]
+	fn top_projects_by_usd_raised(&self, amount: u32, at: Option<BlockHash>) -> RpcResult<Vec<ProjectInfo>>;
+
+	/// The top projects, by percentage of their funding target reached.
+	#[method(name = "funding_topProjectsByUsdTargetPercentReached")]
+	fn top_projects_by_usd_target_percent_reached(
+		&self,
+		amount: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ProjectInfo>>;
+
+	/// The contribution token balances of an account, by project.
+	#[method(name = "funding_contributionTokens")]
+	fn contribution_tokens(&self, account: AccountId, at: Option<BlockHash>)
+		-> RpcResult<Vec<(ProjectId, RpcBalance)>>;
+
+	/// The participations made in a project by a DID, grouped by account.
+	#[method(name = "funding_allProjectParticipationsByDid")]
+	fn all_project_participations_by_did(
+		&self,
+		project_id: ProjectId,
+		did: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ProjectParticipationIds<Runtime>>>;
+
+	/// The percentage of its funding target a project reached.
+	#[method(name = "funding_usdTargetPercentReached")]
+	fn usd_target_percent_reached(&self, project_id: ProjectId, at: Option<BlockHash>) -> RpcResult<FixedU128>;
+
+	/// The projects created by a DID.
+	#[method(name = "funding_projectsByDid")]
+	fn projects_by_did(&self, did: String, at: Option<BlockHash>) -> RpcResult<Vec<ProjectId>>;
+
+	/// The share of its funding target a project needs to raise to be successful.
+	#[method(name = "funding_fundingSuccessThreshold")]
+	fn funding_success_threshold(&self, project_id: ProjectId, at: Option<BlockHash>) -> RpcResult<Perquintill>;
+
+	/// The amount of CTs that can currently be bought with `asset_amount` of a funding asset.
+	#[method(name = "funding_fundingAssetToCtAmount")]
+	fn funding_asset_to_ct_amount(
+		&self,
+		project_id: ProjectId,
+		asset: AcceptedFundingAsset,
+		asset_amount: RpcBalance,
+		at: Option<BlockHash>,
+	) -> RpcResult<RpcBalance>;
+}
+
+/// Error codes of the funding RPC methods.
+pub enum Error {
+	/// The runtime API call failed.
+	RuntimeError,
+	/// A parameter is invalid.
+	InvalidParams,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidParams => 2,
+		}
+	}
+}
+
+fn runtime_error(e: impl ToString) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), "Unable to query the funding runtime API.", Some(e.to_string()))
+}
+
+/// Implements the [`FundingApiServer`] RPC trait with the runtime APIs of the funding pallet.
+pub struct Funding<C> {
+	client: Arc<C>,
+}
+
+impl<C> Funding<C> {
+	/// Creates a new instance of the funding RPC.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+
+	fn at_or_best(&self, at: Option<<Block as BlockT>::Hash>) -> <Block as BlockT>::Hash
+	where
+		C: HeaderBackend<Block>,
+	{
+		at.unwrap_or_else(|| self.client.info().best_hash)
+	}
+}
+
+impl<C> FundingApiServer<<Block as BlockT>::Hash> for Funding<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: Leaderboards<Block, Runtime>,
+	C::Api: UserInformation<Block, Runtime>,
+	C::Api: ProjectInformation<Block, Runtime>,
+	C::Api: ExtrinsicHelpers<Block, Runtime>,
+{
+	fn top_evaluations(
+		&self,
+		project_id: ProjectId,
+		amount: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<EvaluationInfoOf<Runtime>>> {
+		self.client.runtime_api().top_evaluations(self.at_or_best(at), project_id, amount).map_err(runtime_error)
+	}
+
+	fn top_bids(
+		&self,
+		project_id: ProjectId,
+		amount: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<BidInfoOf<Runtime>>> {
+		self.client.runtime_api().top_bids(self.at_or_best(at), project_id, amount).map_err(runtime_error)
+	}
+
+	fn top_contributions(
+		&self,
+		project_id: ProjectId,
+		amount: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ContributionInfoOf<Runtime>>> {
+		self.client.runtime_api().top_contributions(self.at_or_best(at), project_id, amount).map_err(runtime_error)
+	}
+
+	fn top_projects_by_usd_raised(
+		&self,
+		amount: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ProjectInfo>> {
+		self.client.runtime_api().top_projects_by_usd_raised(self.at_or_best(at), amount).map_err(runtime_error)
+	}
+
+	fn top_projects_by_usd_target_percent_reached(
+		&self,
+		amount: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ProjectInfo>> {
+		self.client
+			.runtime_api()
+			.top_projects_by_usd_target_percent_reached(self.at_or_best(at), amount)
+			.map_err(runtime_error)
+	}
+
+	fn contribution_tokens(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(ProjectId, RpcBalance)>> {
+		let balances =
+			self.client.runtime_api().contribution_tokens(self.at_or_best(at), account).map_err(runtime_error)?;
+		Ok(balances.into_iter().map(|(project_id, balance)| (project_id, RpcBalance(balance))).collect())
+	}
+
+	fn all_project_participations_by_did(
+		&self,
+		project_id: ProjectId,
+		did: String,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ProjectParticipationIds<Runtime>>> {
+		let did = did_from_string(did)?;
+		self.client
+			.runtime_api()
+			.all_project_participations_by_did(self.at_or_best(at), project_id, did)
+			.map_err(runtime_error)
+	}
+
+	fn usd_target_percent_reached(
+		&self,
+		project_id: ProjectId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<FixedU128> {
+		self.client.runtime_api().usd_target_percent_reached(self.at_or_best(at), project_id).map_err(runtime_error)
+	}
+
+	fn projects_by_did(&self, did: String, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<ProjectId>> {
+		let did = did_from_string(did)?;
+		self.client.runtime_api().projects_by_did(self.at_or_best(at), did).map_err(runtime_error)
+	}
+
+	fn funding_success_threshold(
+		&self,
+		project_id: ProjectId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Perquintill> {
+		self.client.runtime_api().funding_success_threshold(self.at_or_best(at), project_id).map_err(runtime_error)
+	}
+
+	fn funding_asset_to_ct_amount(
+		&self,
+		project_id: ProjectId,
+		asset: AcceptedFundingAsset,
+		asset_amount: RpcBalance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RpcBalance> {
+		self.client
+			.runtime_api()
+			.funding_asset_to_ct_amount(self.at_or_best(at), project_id, asset, asset_amount.0)
+			.map(RpcBalance)
+			.map_err(runtime_error)
+	}
+}
+
+/// DIDs are passed as strings, like they appear in the credentials.
+fn did_from_string<Did: TryFrom<Vec<u8>>>(did: String) -> Result<Did, ErrorObjectOwned> {
+	did.into_bytes()
+		.try_into()
+		.map_err(|_| ErrorObject::owned(Error::InvalidParams.into(), "The DID is too long.", None::<()>))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::executor::block_on;
+	use jsonrpsee::RpcModule;
+	use pallet_funding::types::{BidStatus, Multiplier};
+	use sp_api::{ApiError, ApiRef};
+	use sp_blockchain::{BlockStatus, Info};
+	use sp_core::{ConstU32, H256};
+	use sp_runtime::{traits::NumberFor, BoundedVec};
+
+	const BEST_HASH: H256 = H256::repeat_byte(1);
+	/// Does not fit in a `u64`, let alone in the 53 bits a JSON number can hold precisely.
+	const BIG_BALANCE: Balance = 1_000_000_000_000_000_000_000_000_000_000;
+
+	struct TestClient;
+
+	impl ProvideRuntimeApi<Block> for TestClient {
+		type Api = TestApi;
+
+		fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+			TestApi.into()
+		}
+	}
+
+	impl HeaderBackend<Block> for TestClient {
+		fn header(&self, _hash: H256) -> sp_blockchain::Result<Option<<Block as BlockT>::Header>> {
+			Ok(None)
+		}
+
+		fn info(&self) -> Info<Block> {
+			Info {
+				best_hash: BEST_HASH,
+				best_number: 1,
+				genesis_hash: H256::zero(),
+				finalized_hash: H256::zero(),
+				finalized_number: 0,
+				finalized_state: None,
+				number_leaves: 1,
+				block_gap: None,
+			}
+		}
+
+		fn status(&self, _hash: H256) -> sp_blockchain::Result<BlockStatus> {
+			Ok(BlockStatus::Unknown)
+		}
+
+		fn number(&self, _hash: H256) -> sp_blockchain::Result<Option<NumberFor<Block>>> {
+			Ok(None)
+		}
+
+		fn hash(&self, _number: NumberFor<Block>) -> sp_blockchain::Result<Option<H256>> {
+			Ok(None)
+		}
+	}
+
+	struct TestApi;
+
+	fn bid() -> BidInfoOf<Runtime> {
+		BidInfoOf::<Runtime> {
+			id: 0,
+			project_id: 0,
+			bidder: AccountId::new([1u8; 32]),
+			did: b"did:polimec:0x01".to_vec().try_into().unwrap(),
+			status: BidStatus::PartiallyAccepted(BIG_BALANCE / 2),
+			original_ct_amount: BIG_BALANCE,
+			original_ct_usd_price: FixedU128::from_rational(1, 2),
+			funding_asset: AcceptedFundingAsset::USDT,
+			funding_asset_amount_locked: BIG_BALANCE,
+			multiplier: Multiplier::force_new(1),
+			plmc_bond: BIG_BALANCE,
+			when: 1,
+		}
+	}
+
+	sp_api::mock_impl_runtime_apis! {
+		impl Leaderboards<Block, Runtime> for TestApi {
+			fn top_bids(_project_id: ProjectId, _amount: u32) -> Vec<BidInfoOf<Runtime>> {
+				vec![bid()]
+			}
+		}
+
+		impl UserInformation<Block, Runtime> for TestApi {
+			fn contribution_tokens(_account: AccountId) -> Vec<(ProjectId, Balance)> {
+				vec![(0, BIG_BALANCE)]
+			}
+		}
+
+		impl ProjectInformation<Block, Runtime> for TestApi {
+			#[advanced]
+			fn usd_target_percent_reached(
+				&self,
+				at: <Block as BlockT>::Hash,
+				_project_id: ProjectId,
+			) -> Result<FixedU128, ApiError> {
+				if at == BEST_HASH {
+					Ok(FixedU128::from_rational(1, 2))
+				} else {
+					Err(ApiError::Application("unknown block".into()))
+				}
+			}
+
+			fn projects_by_did(did: BoundedVec<u8, ConstU32<57>>) -> Vec<ProjectId> {
+				if did.as_slice() == b"did:polimec:0x01" { vec![0, 1] } else { vec![] }
+			}
+		}
+
+		impl ExtrinsicHelpers<Block, Runtime> for TestApi {
+			fn funding_asset_to_ct_amount(_project_id: ProjectId, _asset: AcceptedFundingAsset, asset_amount: Balance) -> Balance {
+				asset_amount * 2
+			}
+		}
+	}
+
+	fn rpc() -> RpcModule<Funding<TestClient>> {
+		Funding::new(Arc::new(TestClient)).into_rpc()
+	}
+
+	#[test]
+	fn balances_are_serialized_as_strings() {
+		let bids: serde_json::Value = block_on(rpc().call("funding_topBids", (0u32, 10u32))).unwrap();
+		assert_eq!(bids[0]["original_ct_amount"], serde_json::json!(BIG_BALANCE.to_string()));
+		assert_eq!(bids[0]["funding_asset_amount_locked"], serde_json::json!(BIG_BALANCE.to_string()));
+		assert_eq!(bids[0]["plmc_bond"], serde_json::json!(BIG_BALANCE.to_string()));
+		assert_eq!(bids[0]["status"], serde_json::json!({ "PartiallyAccepted": (BIG_BALANCE / 2).to_string() }));
+
+		let bids: Vec<BidInfoOf<Runtime>> = block_on(rpc().call("funding_topBids", (0u32, 10u32))).unwrap();
+		assert_eq!(bids, vec![bid()]);
+
+		let balances: serde_json::Value =
+			block_on(rpc().call("funding_contributionTokens", (AccountId::new([1u8; 32]),))).unwrap();
+		assert_eq!(balances, serde_json::json!([[0, BIG_BALANCE.to_string()]]));
+	}
+
+	#[test]
+	fn balances_are_accepted_as_strings_or_numbers() {
+		let ct_amount: serde_json::Value = block_on(
+			rpc().call("funding_fundingAssetToCtAmount", (0u32, AcceptedFundingAsset::USDT, BIG_BALANCE.to_string())),
+		)
+		.unwrap();
+		assert_eq!(ct_amount, serde_json::json!((BIG_BALANCE * 2).to_string()));
+
+		let ct_amount: RpcBalance =
+			block_on(rpc().call("funding_fundingAssetToCtAmount", (0u32, AcceptedFundingAsset::USDT, 100u64))).unwrap();
+		assert_eq!(ct_amount, RpcBalance(200));
+	}
+
+	#[test]
+	fn dids_are_passed_as_strings() {
+		let project_ids: Vec<ProjectId> = block_on(rpc().call("funding_projectsByDid", ("did:polimec:0x01",))).unwrap();
+		assert_eq!(project_ids, vec![0, 1]);
+
+		let error = block_on(rpc().call::<_, Vec<ProjectId>>("funding_projectsByDid", ("a".repeat(58),))).unwrap_err();
+		assert!(format!("{error:?}").contains("The DID is too long."));
+	}
+
+	#[test]
+	fn queries_the_best_block_unless_another_is_given() {
+		let percent: FixedU128 = block_on(rpc().call("funding_usdTargetPercentReached", (0u32,))).unwrap();
+		assert_eq!(percent, FixedU128::from_rational(1, 2));
+
+		let percent: FixedU128 =
+			block_on(rpc().call("funding_usdTargetPercentReached", (0u32, Some(BEST_HASH)))).unwrap();
+		assert_eq!(percent, FixedU128::from_rational(1, 2));
+
+		let error =
+			block_on(rpc().call::<_, FixedU128>("funding_usdTargetPercentReached", (0u32, Some(H256::repeat_byte(2)))))
+				.unwrap_err();
+		assert!(format!("{error:?}").contains("Unable to query the funding runtime API."));
+	}
+}
@@ -0,0 +1,153 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@polimec.org
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Credentials;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::EnsureOrigin, BoundedVec};
+
+// A valid Ed25519 public key.
+const VERIFIER_PUBLIC_KEY: [u8; 32] = [
+	32, 118, 30, 171, 58, 212, 197, 27, 146, 122, 255, 243, 34, 245, 90, 244, 221, 37, 253, 195, 18, 202, 111, 55, 39,
+	48, 123, 17, 101, 78, 215, 94,
+];
+
+fn verifier_key() -> VerifierKey {
+	VerifierKey {
+		public_key: VERIFIER_PUBLIC_KEY,
+		issuer: BoundedVec::truncate_from([b'i'; 64].to_vec()),
+		valid_from: 0,
+		valid_until: Some(u64::MAX),
+	}
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn revoke_did() -> Result<(), BenchmarkError> {
+		let origin = T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let did: Did = BoundedVec::truncate_from(b"did:polimec:0x1234".to_vec());
+
+		#[extrinsic_call]
+		revoke_did(origin as T::RuntimeOrigin, did.clone());
+
+		let issued_before = RevokedDids::<T>::get(&did).unwrap();
+		assert_last_event::<T>(Event::<T>::DidRevoked { did, issued_before }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_token() -> Result<(), BenchmarkError> {
+		let origin = T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let token_id: TokenId = BoundedVec::truncate_from([7u8; 64].to_vec());
+
+		#[extrinsic_call]
+		revoke_token(origin as T::RuntimeOrigin, token_id.clone());
+
+		assert_eq!(RevokedTokens::<T>::get(&token_id), Some(()));
+		assert_last_event::<T>(Event::<T>::TokenRevoked { token_id }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_tokens_issued_before() -> Result<(), BenchmarkError> {
+		let origin = T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let issued_before = 1_700_000_000_000u64;
+
+		#[extrinsic_call]
+		revoke_tokens_issued_before(origin as T::RuntimeOrigin, issued_before);
+
+		assert_eq!(RevokedBefore::<T>::get(), Some(issued_before));
+		assert_last_event::<T>(Event::<T>::TokensIssuedBeforeRevoked { issued_before }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_verifier_key() -> Result<(), BenchmarkError> {
+		let origin = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let key_id: KeyId = BoundedVec::truncate_from([1u8; 64].to_vec());
+		let key = verifier_key();
+
+		#[extrinsic_call]
+		add_verifier_key(origin as T::RuntimeOrigin, key_id.clone(), key.clone());
+
+		assert_eq!(TrustedVerifierKeys::<T>::get(&key_id), Some(key.clone()));
+		assert_last_event::<T>(Event::<T>::VerifierKeyAdded { key_id, key }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn rotate_verifier_key() -> Result<(), BenchmarkError> {
+		let origin = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let old_key_id: KeyId = BoundedVec::truncate_from([1u8; 64].to_vec());
+		let new_key_id: KeyId = BoundedVec::truncate_from([2u8; 64].to_vec());
+		TrustedVerifierKeys::<T>::insert(&old_key_id, verifier_key());
+		let grace_period = 7 * 24 * 3_600_000u64;
+
+		#[extrinsic_call]
+		rotate_verifier_key(
+			origin as T::RuntimeOrigin,
+			old_key_id.clone(),
+			new_key_id.clone(),
+			verifier_key(),
+			grace_period,
+		);
+
+		let valid_until = TrustedVerifierKeys::<T>::get(&old_key_id).unwrap().valid_until.unwrap();
+		assert!(TrustedVerifierKeys::<T>::contains_key(&new_key_id));
+		assert_last_event::<T>(Event::<T>::VerifierKeyRotated { old_key_id, new_key_id, valid_until }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_verifier_key() -> Result<(), BenchmarkError> {
+		let origin = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let key_id: KeyId = BoundedVec::truncate_from([1u8; 64].to_vec());
+		TrustedVerifierKeys::<T>::insert(&key_id, verifier_key());
+
+		#[extrinsic_call]
+		remove_verifier_key(origin as T::RuntimeOrigin, key_id.clone());
+
+		assert!(!TrustedVerifierKeys::<T>::contains_key(&key_id));
+		assert_last_event::<T>(Event::<T>::VerifierKeyRemoved { key_id }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn retire_default_verifier_key() -> Result<(), BenchmarkError> {
+		let origin = T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let grace_period = 7 * 24 * 3_600_000u64;
+
+		#[extrinsic_call]
+		retire_default_verifier_key(origin as T::RuntimeOrigin, grace_period);
+
+		let retired_at = DefaultVerifierKeyRetiredAt::<T>::get().unwrap();
+		assert_last_event::<T>(Event::<T>::DefaultVerifierKeyRetired { retired_at }.into());
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Credentials, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
+}
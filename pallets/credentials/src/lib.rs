@@ -0,0 +1,351 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@polimec.org
+
+//! # Credentials Pallet
+//!
+//! On-chain state of the investor credentials (JWTs) issued off-chain by the verifier.
+//!
+//! A credential stays valid until it expires, even if the KYC provider revokes the investor, or downgrades them
+//! e.g. from institutional to retail. This pallet lets the verifier revoke credentials before they expire:
+//! - All the credentials of a DID issued so far, with [`revoke_did`](Pallet::revoke_did). Credentials issued
+//!   afterwards, e.g. with the downgraded investor type, are accepted again.
+//! - A single credential, by its `jti` claim, with [`revoke_token`](Pallet::revoke_token).
+//! - All the credentials issued before a moment, e.g. after the verifier's signing key was compromised, with
+//!   [`revoke_tokens_issued_before`](Pallet::revoke_tokens_issued_before).
+//!
+//! The pallet implements [`CredentialRevocations`], so that `EnsureInvestor<Runtime, Credentials>` rejects the
+//! revoked credentials of every pallet using it as its `InvestorOrigin`.
+//! Credentials without an `iat` claim cannot prove when they were issued, so they are rejected by the revocations
+//! based on it.
+//!
+//! It also keeps the keys trusted to sign credentials, managed by governance, so that a leaked key or a new KYC
+//! provider does not need a runtime upgrade. Credentials point to their key with the `kid` header, and their `iss`
+//! claim must match the key's issuer. The pallet implements [`VerifierKeys`] for `EnsureInvestor`.
+//! - A key is trusted from its `valid_from` until its `valid_until`, set with
+//!   [`add_verifier_key`](Pallet::add_verifier_key).
+//! - [`rotate_verifier_key`](Pallet::rotate_verifier_key) adds a new key, and keeps trusting the old one during a grace
+//!   period, so that credentials signed with it can be replaced without downtime.
+//! - [`remove_verifier_key`](Pallet::remove_verifier_key) stops trusting a leaked key right away.
+//! - Credentials without a `kid` are verified with the runtime's `VerifierPublicKey`, until it is retired with
+//!   [`retire_default_verifier_key`](Pallet::retire_default_verifier_key).
+//!
+//! Finally, it implements [`CredentialNonces`] by keeping the nonces of the single-use credentials already used.
+//! A nonce only needs to be kept until its credential expires, so the expired ones are removed on idle.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub use crate::weights::WeightInfo;
+pub use polimec_common::credentials::{
+	CredentialNonces, CredentialRevocations, Did, KeyId, Nonce, TokenId, VerifierKey, VerifierKeys,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	#[allow(clippy::wildcard_imports)]
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use polimec_common::credentials::{Algorithm, Ed25519, VerifyingKey};
+	use sp_runtime::SaturatedConversion;
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_timestamp::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The Origin of the verifier, which can revoke the credentials it issued.
+		type VerifierOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The Origin that manages the keys trusted to sign credentials.
+		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: crate::weights::WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The credentials of a DID issued before this moment (in milliseconds) are revoked.
+	#[pallet::storage]
+	pub type RevokedDids<T> = StorageMap<_, Blake2_128Concat, Did, u64>;
+
+	/// Credentials revoked by their `jti` claim.
+	#[pallet::storage]
+	pub type RevokedTokens<T> = StorageMap<_, Blake2_128Concat, TokenId, ()>;
+
+	/// All the credentials issued before this moment (in milliseconds) are revoked.
+	#[pallet::storage]
+	pub type RevokedBefore<T> = StorageValue<_, u64>;
+
+	/// The keys trusted to sign credentials, by the `kid` header pointing to them.
+	#[pallet::storage]
+	pub type TrustedVerifierKeys<T> = StorageMap<_, Blake2_128Concat, KeyId, VerifierKey>;
+
+	/// From this moment (in milliseconds), credentials without a `kid` header are not verified with the runtime's
+	/// `VerifierPublicKey` anymore.
+	#[pallet::storage]
+	pub type DefaultVerifierKeyRetiredAt<T> = StorageValue<_, u64>;
+
+	/// Nonces of the single-use credentials already used, by the DID of the credential, with the moment (in
+	/// milliseconds) their credential expires.
+	#[pallet::storage]
+	pub type UsedNonces<T> = StorageDoubleMap<_, Blake2_128Concat, Did, Blake2_128Concat, Nonce, u64>;
+
+	/// The last DID and nonce checked by the cleanup of expired nonces. The next cleanup resumes after it.
+	#[pallet::storage]
+	pub type NonceCleanupCursor<T> = StorageValue<_, (Did, Nonce)>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The credentials of a DID issued before `issued_before` were revoked.
+		DidRevoked { did: Did, issued_before: u64 },
+		/// A credential was revoked by its `jti` claim.
+		TokenRevoked { token_id: TokenId },
+		/// All the credentials issued before `issued_before` were revoked.
+		TokensIssuedBeforeRevoked { issued_before: u64 },
+		/// A key is trusted to sign credentials.
+		VerifierKeyAdded { key_id: KeyId, key: VerifierKey },
+		/// A key is replaced by a new one, and stops being trusted at `valid_until`.
+		VerifierKeyRotated { old_key_id: KeyId, new_key_id: KeyId, valid_until: u64 },
+		/// A key is not trusted anymore.
+		VerifierKeyRemoved { key_id: KeyId },
+		/// Credentials without a `kid` header are not accepted from `retired_at` on.
+		DefaultVerifierKeyRetired { retired_at: u64 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The credential was already revoked.
+		TokenAlreadyRevoked,
+		/// A key with the same id is already trusted.
+		VerifierKeyAlreadyExists,
+		/// No key has this id.
+		VerifierKeyNotFound,
+		/// The public key is not a valid Ed25519 key.
+		InvalidVerifierKey,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Revoke all the credentials of `did` issued until now.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::revoke_did())]
+		pub fn revoke_did(origin: OriginFor<T>, did: Did) -> DispatchResult {
+			T::VerifierOrigin::ensure_origin(origin)?;
+			let issued_before = Self::now();
+			RevokedDids::<T>::insert(&did, issued_before);
+			Self::deposit_event(Event::DidRevoked { did, issued_before });
+			Ok(())
+		}
+
+		/// Revoke the credential with the `jti` claim `token_id`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::revoke_token())]
+		pub fn revoke_token(origin: OriginFor<T>, token_id: TokenId) -> DispatchResult {
+			T::VerifierOrigin::ensure_origin(origin)?;
+			ensure!(!RevokedTokens::<T>::contains_key(&token_id), Error::<T>::TokenAlreadyRevoked);
+			RevokedTokens::<T>::insert(&token_id, ());
+			Self::deposit_event(Event::TokenRevoked { token_id });
+			Ok(())
+		}
+
+		/// Revoke all the credentials issued before `issued_before` (in milliseconds), e.g. after the verifier's
+		/// signing key was compromised.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::revoke_tokens_issued_before())]
+		pub fn revoke_tokens_issued_before(origin: OriginFor<T>, issued_before: u64) -> DispatchResult {
+			T::VerifierOrigin::ensure_origin(origin)?;
+			RevokedBefore::<T>::put(issued_before);
+			Self::deposit_event(Event::TokensIssuedBeforeRevoked { issued_before });
+			Ok(())
+		}
+
+		/// Trust `key` to sign the credentials with the `kid` header `key_id`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::add_verifier_key())]
+		pub fn add_verifier_key(origin: OriginFor<T>, key_id: KeyId, key: VerifierKey) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			Self::do_add_verifier_key(key_id.clone(), key.clone())?;
+			Self::deposit_event(Event::VerifierKeyAdded { key_id, key });
+			Ok(())
+		}
+
+		/// Replace the key `old_key_id` with `new_key`. The old key is still trusted during `grace_period` (in
+		/// milliseconds), so that the credentials signed with it can be reissued.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::rotate_verifier_key())]
+		pub fn rotate_verifier_key(
+			origin: OriginFor<T>,
+			old_key_id: KeyId,
+			new_key_id: KeyId,
+			new_key: VerifierKey,
+			grace_period: u64,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let valid_until = Self::now().saturating_add(grace_period);
+			TrustedVerifierKeys::<T>::try_mutate(&old_key_id, |maybe_key| -> DispatchResult {
+				let old_key = maybe_key.as_mut().ok_or(Error::<T>::VerifierKeyNotFound)?;
+				old_key.valid_until = Some(old_key.valid_until.map_or(valid_until, |until| until.min(valid_until)));
+				Ok(())
+			})?;
+			Self::do_add_verifier_key(new_key_id.clone(), new_key.clone())?;
+
+			Self::deposit_event(Event::VerifierKeyAdded { key_id: new_key_id.clone(), key: new_key });
+			Self::deposit_event(Event::VerifierKeyRotated { old_key_id, new_key_id, valid_until });
+			Ok(())
+		}
+
+		/// Stop trusting the key `key_id` right away, e.g. because it leaked.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::remove_verifier_key())]
+		pub fn remove_verifier_key(origin: OriginFor<T>, key_id: KeyId) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			TrustedVerifierKeys::<T>::take(&key_id).ok_or(Error::<T>::VerifierKeyNotFound)?;
+			Self::deposit_event(Event::VerifierKeyRemoved { key_id });
+			Ok(())
+		}
+
+		/// Stop accepting credentials without a `kid` header after `grace_period` (in milliseconds).
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::retire_default_verifier_key())]
+		pub fn retire_default_verifier_key(origin: OriginFor<T>, grace_period: u64) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let retired_at = Self::now().saturating_add(grace_period);
+			DefaultVerifierKeyRetiredAt::<T>::put(retired_at);
+			Self::deposit_event(Event::DefaultVerifierKeyRetired { retired_at });
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::do_remove_expired_nonces(remaining_weight)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The current moment, in milliseconds.
+		fn now() -> u64 {
+			pallet_timestamp::Now::<T>::get().saturated_into::<u64>()
+		}
+
+		fn do_add_verifier_key(key_id: KeyId, key: VerifierKey) -> DispatchResult {
+			ensure!(!TrustedVerifierKeys::<T>::contains_key(&key_id), Error::<T>::VerifierKeyAlreadyExists);
+			ensure!(
+				<<Ed25519 as Algorithm>::VerifyingKey as VerifyingKey<Ed25519>>::from_slice(&key.public_key).is_ok(),
+				Error::<T>::InvalidVerifierKey
+			);
+			TrustedVerifierKeys::<T>::insert(key_id, key);
+			Ok(())
+		}
+
+		/// Removes the nonces of expired credentials, checking as many as `max_weight` allows. It resumes after the
+		/// [`NonceCleanupCursor`], and starts over once every nonce was checked.
+		pub fn do_remove_expired_nonces(max_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			// Reading the current moment, and reading and writing the cursor
+			let overhead = db_weight.reads_writes(2, 1);
+			// Reading a nonce, and removing it if its credential expired
+			let step_weight = db_weight.reads_writes(1, 1);
+			if overhead.saturating_add(step_weight).any_gt(max_weight) {
+				return Weight::zero();
+			}
+
+			let now = Self::now();
+			let mut nonces = match NonceCleanupCursor::<T>::get() {
+				Some((did, nonce)) => UsedNonces::<T>::iter_from(UsedNonces::<T>::hashed_key_for(did, nonce)),
+				None => UsedNonces::<T>::iter(),
+			};
+			let mut used_weight = overhead;
+			let mut last_checked = None;
+			let mut checked_all = false;
+			while used_weight.saturating_add(step_weight).all_lte(max_weight) {
+				used_weight.saturating_accrue(step_weight);
+				let Some((did, nonce, expires_at)) = nonces.next() else {
+					checked_all = true;
+					break;
+				};
+				// The credential is still accepted at the moment it expires
+				if expires_at < now {
+					UsedNonces::<T>::remove(&did, &nonce);
+				}
+				last_checked = Some((did, nonce));
+			}
+
+			match last_checked {
+				Some(cursor) if !checked_all => NonceCleanupCursor::<T>::put(cursor),
+				_ => NonceCleanupCursor::<T>::kill(),
+			}
+			used_weight
+		}
+	}
+
+	impl<T: Config> CredentialRevocations for Pallet<T> {
+		fn is_revoked(did: &Did, token_id: Option<&TokenId>, issued_at: Option<u64>) -> bool {
+			if token_id.is_some_and(|token_id| RevokedTokens::<T>::contains_key(token_id)) {
+				return true;
+			}
+			match RevokedBefore::<T>::get().max(RevokedDids::<T>::get(did)) {
+				Some(issued_before) => issued_at.map_or(true, |issued_at| issued_at < issued_before),
+				None => false,
+			}
+		}
+	}
+
+	impl<T: Config> VerifierKeys for Pallet<T> {
+		fn trusted_key(key_id: &[u8]) -> Option<VerifierKey> {
+			let key_id = KeyId::try_from(key_id.to_vec()).ok()?;
+			TrustedVerifierKeys::<T>::get(key_id).filter(|key| key.is_valid_at(Self::now()))
+		}
+
+		fn accepts_default_key() -> bool {
+			DefaultVerifierKeyRetiredAt::<T>::get().map_or(true, |retired_at| Self::now() < retired_at)
+		}
+	}
+
+	impl<T: Config> CredentialNonces for Pallet<T> {
+		fn is_used(did: &Did, nonce: &Nonce) -> bool {
+			UsedNonces::<T>::contains_key(did, nonce)
+		}
+
+		fn use_nonce(did: &Did, nonce: &Nonce, expires_at: u64) -> bool {
+			if UsedNonces::<T>::contains_key(did, nonce) {
+				return false;
+			}
+			UsedNonces::<T>::insert(did, nonce, expires_at);
+			true
+		}
+	}
+}
@@ -0,0 +1,470 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@polimec.org
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use polimec_common::credentials::{EnsureOriginWithCredentials, InvestorType, UntrustedToken};
+use polimec_common_test_utils::{
+	generate_did_from_account, get_mock_jwt, get_mock_jwt_with_options, get_mock_jwt_with_token_id, mock_verifying_key,
+	MockJwtOptions,
+};
+use sp_runtime::DispatchError;
+
+// 2023-11-14T22:13:20Z
+const NOW_SECS: i64 = 1_700_000_000;
+const NOW_MILLIS: u64 = 1_700_000_000_000;
+
+fn token_id(id: &str) -> TokenId {
+	BoundedVec::try_from(id.as_bytes().to_vec()).unwrap()
+}
+
+fn jwt_issued_at(account: u64, token: &str, issued_at: i64) -> UntrustedToken {
+	get_mock_jwt_with_token_id(
+		account,
+		InvestorType::Institutional,
+		generate_did_from_account(account),
+		token_id(token),
+		issued_at,
+	)
+}
+
+fn key_id(id: &str) -> KeyId {
+	BoundedVec::try_from(id.as_bytes().to_vec()).unwrap()
+}
+
+fn verifier_key(signing_key: [u8; 32], issuer: &str, valid_from: u64, valid_until: Option<u64>) -> VerifierKey {
+	VerifierKey {
+		public_key: mock_verifying_key(signing_key),
+		issuer: BoundedVec::try_from(issuer.as_bytes().to_vec()).unwrap(),
+		valid_from,
+		valid_until,
+	}
+}
+
+fn jwt_signed_with(account: u64, key_id: &str, signing_key: [u8; 32], issuer: &str) -> UntrustedToken {
+	get_mock_jwt_with_options(
+		account,
+		InvestorType::Institutional,
+		generate_did_from_account(account),
+		MockJwtOptions {
+			key_id: Some(key_id.to_string()),
+			signing_key: Some(signing_key),
+			issuer: Some(issuer.to_string()),
+			..Default::default()
+		},
+	)
+}
+
+fn is_accepted(account: u64, jwt: &UntrustedToken) -> bool {
+	InvestorOrigin::ensure_origin(RuntimeOrigin::signed(account), jwt, VerifierPublicKey::get()).is_ok()
+}
+
+mod revoke_did {
+	use super::*;
+
+	#[test]
+	fn only_verifier_can_revoke() {
+		ExtBuilder::default().build().execute_with(|| {
+			let did = generate_did_from_account(1);
+			assert_noop!(Credentials::revoke_did(RuntimeOrigin::signed(1), did.clone()), DispatchError::BadOrigin);
+			assert_ok!(Credentials::revoke_did(RuntimeOrigin::signed(Verifier::get()), did));
+		});
+	}
+
+	#[test]
+	fn credentials_issued_before_are_rejected() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(NOW_MILLIS);
+			let old_jwt = jwt_issued_at(1, "old", NOW_SECS - 60);
+			let jwt_without_iat = get_mock_jwt(1, InvestorType::Institutional, generate_did_from_account(1));
+			let other_did_jwt = jwt_issued_at(2, "other", NOW_SECS - 60);
+			assert!(is_accepted(1, &old_jwt));
+			assert!(is_accepted(1, &jwt_without_iat));
+
+			assert_ok!(Credentials::revoke_did(RuntimeOrigin::signed(Verifier::get()), generate_did_from_account(1)));
+			System::assert_last_event(
+				Event::DidRevoked { did: generate_did_from_account(1), issued_before: NOW_MILLIS }.into(),
+			);
+
+			assert!(!is_accepted(1, &old_jwt));
+			assert!(!is_accepted(1, &jwt_without_iat));
+			assert!(is_accepted(2, &other_did_jwt));
+
+			// A credential issued afterwards, e.g. with a downgraded investor type, is accepted
+			let new_jwt = get_mock_jwt_with_token_id(
+				1,
+				InvestorType::Retail,
+				generate_did_from_account(1),
+				token_id("new"),
+				NOW_SECS + 60,
+			);
+			assert!(is_accepted(1, &new_jwt));
+		});
+	}
+}
+
+mod revoke_token {
+	use super::*;
+
+	#[test]
+	fn only_verifier_can_revoke() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(Credentials::revoke_token(RuntimeOrigin::signed(1), token_id("a")), DispatchError::BadOrigin);
+			assert_ok!(Credentials::revoke_token(RuntimeOrigin::signed(Verifier::get()), token_id("a")));
+		});
+	}
+
+	#[test]
+	fn revoked_credential_is_rejected() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(NOW_MILLIS);
+			let revoked_jwt = jwt_issued_at(1, "revoked", NOW_SECS);
+			let other_jwt = jwt_issued_at(1, "other", NOW_SECS);
+
+			assert_ok!(Credentials::revoke_token(RuntimeOrigin::signed(Verifier::get()), token_id("revoked")));
+			System::assert_last_event(Event::TokenRevoked { token_id: token_id("revoked") }.into());
+
+			assert!(!is_accepted(1, &revoked_jwt));
+			assert!(is_accepted(1, &other_jwt));
+		});
+	}
+
+	#[test]
+	fn cannot_revoke_twice() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Credentials::revoke_token(RuntimeOrigin::signed(Verifier::get()), token_id("a")));
+			assert_noop!(
+				Credentials::revoke_token(RuntimeOrigin::signed(Verifier::get()), token_id("a")),
+				Error::<Test>::TokenAlreadyRevoked
+			);
+		});
+	}
+}
+
+mod revoke_tokens_issued_before {
+	use super::*;
+
+	#[test]
+	fn only_verifier_can_revoke() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				Credentials::revoke_tokens_issued_before(RuntimeOrigin::signed(1), NOW_MILLIS),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Credentials::revoke_tokens_issued_before(RuntimeOrigin::signed(Verifier::get()), NOW_MILLIS));
+		});
+	}
+
+	#[test]
+	fn credentials_issued_before_are_rejected() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(NOW_MILLIS);
+			let old_jwt = jwt_issued_at(1, "old", NOW_SECS - 60);
+			let new_jwt = jwt_issued_at(2, "new", NOW_SECS);
+			let jwt_without_iat = get_mock_jwt(3, InvestorType::Institutional, generate_did_from_account(3));
+
+			assert_ok!(Credentials::revoke_tokens_issued_before(RuntimeOrigin::signed(Verifier::get()), NOW_MILLIS));
+			System::assert_last_event(Event::TokensIssuedBeforeRevoked { issued_before: NOW_MILLIS }.into());
+
+			assert!(!is_accepted(1, &old_jwt));
+			assert!(is_accepted(2, &new_jwt));
+			assert!(!is_accepted(3, &jwt_without_iat));
+		});
+	}
+}
+
+mod verifier_keys {
+	use super::*;
+
+	const KEY_A: [u8; 32] = [1u8; 32];
+	const KEY_B: [u8; 32] = [2u8; 32];
+	const DAY_MILLIS: u64 = 24 * 3_600_000;
+
+	#[test]
+	fn only_governance_can_manage_keys() {
+		ExtBuilder::default().build().execute_with(|| {
+			let key = verifier_key(KEY_A, "provider-a", 0, None);
+			assert_noop!(
+				Credentials::add_verifier_key(RuntimeOrigin::signed(Verifier::get()), key_id("a"), key.clone()),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Credentials::retire_default_verifier_key(RuntimeOrigin::signed(Verifier::get()), 0),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Credentials::add_verifier_key(RuntimeOrigin::root(), key_id("a"), key.clone()));
+			System::assert_last_event(Event::VerifierKeyAdded { key_id: key_id("a"), key: key.clone() }.into());
+			assert_noop!(
+				Credentials::add_verifier_key(RuntimeOrigin::root(), key_id("a"), key),
+				Error::<Test>::VerifierKeyAlreadyExists
+			);
+		});
+	}
+
+	#[test]
+	fn credential_is_verified_with_the_key_of_its_kid() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(NOW_MILLIS);
+			assert_ok!(Credentials::add_verifier_key(
+				RuntimeOrigin::root(),
+				key_id("a"),
+				verifier_key(KEY_A, "provider-a", 0, None)
+			));
+			assert_ok!(Credentials::add_verifier_key(
+				RuntimeOrigin::root(),
+				key_id("b"),
+				verifier_key(KEY_B, "provider-b", 0, None)
+			));
+
+			assert!(is_accepted(1, &jwt_signed_with(1, "a", KEY_A, "provider-a")));
+			assert!(is_accepted(1, &jwt_signed_with(1, "b", KEY_B, "provider-b")));
+			// Signed with another key than the one of its `kid`
+			assert!(!is_accepted(1, &jwt_signed_with(1, "a", KEY_B, "provider-a")));
+			// Unknown `kid`
+			assert!(!is_accepted(1, &jwt_signed_with(1, "c", KEY_A, "provider-a")));
+			// The `iss` claim does not match the issuer of the key
+			assert!(!is_accepted(1, &jwt_signed_with(1, "a", KEY_A, "provider-b")));
+		});
+	}
+
+	#[test]
+	fn key_is_trusted_within_its_validity_window() {
+		ExtBuilder::default().build().execute_with(|| {
+			let key = verifier_key(KEY_A, "provider-a", NOW_MILLIS, Some(NOW_MILLIS + DAY_MILLIS));
+			assert_ok!(Credentials::add_verifier_key(RuntimeOrigin::root(), key_id("a"), key));
+			let jwt = jwt_signed_with(1, "a", KEY_A, "provider-a");
+
+			Timestamp::set_timestamp(NOW_MILLIS - 1);
+			assert!(!is_accepted(1, &jwt));
+			Timestamp::set_timestamp(NOW_MILLIS);
+			assert!(is_accepted(1, &jwt));
+			Timestamp::set_timestamp(NOW_MILLIS + DAY_MILLIS);
+			assert!(!is_accepted(1, &jwt));
+		});
+	}
+
+	#[test]
+	fn rotated_key_is_trusted_during_the_grace_period() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(NOW_MILLIS);
+			assert_ok!(Credentials::add_verifier_key(
+				RuntimeOrigin::root(),
+				key_id("a"),
+				verifier_key(KEY_A, "provider", 0, None)
+			));
+			let old_jwt = jwt_signed_with(1, "a", KEY_A, "provider");
+			let new_jwt = jwt_signed_with(1, "b", KEY_B, "provider");
+
+			assert_ok!(Credentials::rotate_verifier_key(
+				RuntimeOrigin::root(),
+				key_id("a"),
+				key_id("b"),
+				verifier_key(KEY_B, "provider", 0, None),
+				DAY_MILLIS
+			));
+			System::assert_last_event(
+				Event::VerifierKeyRotated {
+					old_key_id: key_id("a"),
+					new_key_id: key_id("b"),
+					valid_until: NOW_MILLIS + DAY_MILLIS,
+				}
+				.into(),
+			);
+			assert!(is_accepted(1, &old_jwt));
+			assert!(is_accepted(1, &new_jwt));
+
+			Timestamp::set_timestamp(NOW_MILLIS + DAY_MILLIS);
+			assert!(!is_accepted(1, &old_jwt));
+			assert!(is_accepted(1, &new_jwt));
+		});
+	}
+
+	#[test]
+	fn rotation_does_not_extend_the_old_key() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(NOW_MILLIS);
+			assert_ok!(Credentials::add_verifier_key(
+				RuntimeOrigin::root(),
+				key_id("a"),
+				verifier_key(KEY_A, "provider", 0, Some(NOW_MILLIS + 1))
+			));
+			assert_ok!(Credentials::rotate_verifier_key(
+				RuntimeOrigin::root(),
+				key_id("a"),
+				key_id("b"),
+				verifier_key(KEY_B, "provider", 0, None),
+				DAY_MILLIS
+			));
+			assert_eq!(TrustedVerifierKeys::<Test>::get(key_id("a")).unwrap().valid_until, Some(NOW_MILLIS + 1));
+		});
+	}
+
+	#[test]
+	fn cannot_rotate_unknown_key_or_to_existing_key() {
+		ExtBuilder::default().build().execute_with(|| {
+			let key = verifier_key(KEY_A, "provider", 0, None);
+			assert_noop!(
+				Credentials::rotate_verifier_key(RuntimeOrigin::root(), key_id("a"), key_id("b"), key.clone(), 0),
+				Error::<Test>::VerifierKeyNotFound
+			);
+			assert_ok!(Credentials::add_verifier_key(RuntimeOrigin::root(), key_id("a"), key.clone()));
+			assert_noop!(
+				Credentials::rotate_verifier_key(RuntimeOrigin::root(), key_id("a"), key_id("a"), key, 0),
+				Error::<Test>::VerifierKeyAlreadyExists
+			);
+		});
+	}
+
+	#[test]
+	fn removed_key_is_not_trusted() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(NOW_MILLIS);
+			assert_ok!(Credentials::add_verifier_key(
+				RuntimeOrigin::root(),
+				key_id("a"),
+				verifier_key(KEY_A, "provider", 0, None)
+			));
+			let jwt = jwt_signed_with(1, "a", KEY_A, "provider");
+			assert!(is_accepted(1, &jwt));
+
+			assert_ok!(Credentials::remove_verifier_key(RuntimeOrigin::root(), key_id("a")));
+			System::assert_last_event(Event::VerifierKeyRemoved { key_id: key_id("a") }.into());
+			assert!(!is_accepted(1, &jwt));
+			assert_noop!(
+				Credentials::remove_verifier_key(RuntimeOrigin::root(), key_id("a")),
+				Error::<Test>::VerifierKeyNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn credentials_without_kid_are_rejected_once_the_default_key_is_retired() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(NOW_MILLIS);
+			let jwt = get_mock_jwt(1, InvestorType::Institutional, generate_did_from_account(1));
+			assert!(is_accepted(1, &jwt));
+
+			assert_ok!(Credentials::retire_default_verifier_key(RuntimeOrigin::root(), DAY_MILLIS));
+			System::assert_last_event(Event::DefaultVerifierKeyRetired { retired_at: NOW_MILLIS + DAY_MILLIS }.into());
+			assert!(is_accepted(1, &jwt));
+
+			Timestamp::set_timestamp(NOW_MILLIS + DAY_MILLIS);
+			assert!(!is_accepted(1, &jwt));
+		});
+	}
+}
+
+mod nonces {
+	use super::*;
+	use frame_support::{traits::Get, weights::Weight};
+
+	// 2030-01-01T00:00:00Z, the expiration of the mock credentials
+	const EXPIRES_AT: u64 = 1_893_456_000_000;
+
+	fn nonce(id: &str) -> Nonce {
+		BoundedVec::try_from(id.as_bytes().to_vec()).unwrap()
+	}
+
+	fn did(account: u64) -> Did {
+		generate_did_from_account(account)
+	}
+
+	fn jwt_with_nonce(account: u64, nonce: &str) -> UntrustedToken {
+		get_mock_jwt_with_options(
+			account,
+			InvestorType::Institutional,
+			generate_did_from_account(account),
+			MockJwtOptions { nonce: Some(nonce.to_string()), ..Default::default() },
+		)
+	}
+
+	#[test]
+	fn credential_with_used_nonce_is_rejected() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(NOW_MILLIS);
+			let jwt = jwt_with_nonce(1, "a");
+			let (_, _, _, _, _, scope) =
+				InvestorOrigin::ensure_origin(RuntimeOrigin::signed(1), &jwt, VerifierPublicKey::get()).unwrap();
+			assert_eq!(scope.nonce, Some(nonce("a")));
+			assert_eq!(scope.expires_at, EXPIRES_AT);
+
+			// Checking the credential does not use its nonce
+			assert!(is_accepted(1, &jwt));
+			assert!(Credentials::use_nonce(&did(1), &nonce("a"), scope.expires_at));
+			assert!(!Credentials::use_nonce(&did(1), &nonce("a"), scope.expires_at));
+			assert!(!is_accepted(1, &jwt));
+			assert!(is_accepted(1, &jwt_with_nonce(1, "b")));
+		});
+	}
+
+	#[test]
+	fn nonces_are_kept_per_did() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(NOW_MILLIS);
+			assert!(Credentials::use_nonce(&did(1), &nonce("a"), EXPIRES_AT));
+
+			assert!(!is_accepted(1, &jwt_with_nonce(1, "a")));
+			assert!(is_accepted(2, &jwt_with_nonce(2, "a")));
+			assert!(Credentials::use_nonce(&did(2), &nonce("a"), EXPIRES_AT));
+		});
+	}
+
+	#[test]
+	fn expired_nonces_are_removed() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(NOW_MILLIS);
+			assert!(Credentials::use_nonce(&did(1), &nonce("expired"), NOW_MILLIS - 1));
+			assert!(Credentials::use_nonce(&did(1), &nonce("expiring"), NOW_MILLIS));
+			assert!(Credentials::use_nonce(&did(2), &nonce("live"), EXPIRES_AT));
+
+			Credentials::do_remove_expired_nonces(Weight::MAX);
+			assert!(!Credentials::is_used(&did(1), &nonce("expired")));
+			assert!(Credentials::is_used(&did(1), &nonce("expiring")));
+			assert!(Credentials::is_used(&did(2), &nonce("live")));
+			assert_eq!(NonceCleanupCursor::<Test>::get(), None);
+		});
+	}
+
+	#[test]
+	fn cleanup_resumes_where_it_stopped() {
+		ExtBuilder::default().build().execute_with(|| {
+			Timestamp::set_timestamp(EXPIRES_AT);
+			for id in ["a", "b", "c", "d", "e"] {
+				assert!(Credentials::use_nonce(&did(1), &nonce(id), NOW_MILLIS));
+			}
+
+			let db_weight = <Test as frame_system::Config>::DbWeight::get();
+			let two_steps = db_weight.reads_writes(2, 1).saturating_add(db_weight.reads_writes(2, 2));
+			assert_eq!(Credentials::do_remove_expired_nonces(two_steps), two_steps);
+			assert_eq!(UsedNonces::<Test>::iter().count(), 3);
+			assert!(NonceCleanupCursor::<Test>::get().is_some());
+
+			Credentials::do_remove_expired_nonces(two_steps);
+			assert_eq!(UsedNonces::<Test>::iter().count(), 1);
+
+			// Not enough weight to check any nonce
+			assert_eq!(Credentials::do_remove_expired_nonces(db_weight.reads_writes(2, 1)), Weight::zero());
+			assert_eq!(UsedNonces::<Test>::iter().count(), 1);
+
+			Credentials::do_remove_expired_nonces(two_steps);
+			assert_eq!(UsedNonces::<Test>::iter().count(), 0);
+			assert_eq!(NonceCleanupCursor::<Test>::get(), None);
+		});
+	}
+}
@@ -0,0 +1,235 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@polimec.org
+
+
+//! Autogenerated weights for `pallet_credentials`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2024-05-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `ip-172-31-23-147`, CPU: `AMD EPYC 9R14`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("polimec-local")`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/production/polimec-node
+// benchmark
+// pallet
+// --chain=polimec-local
+// --steps=50
+// --repeat=20
+// --pallet=pallet-credentials
+// --no-storage-info
+// --no-median-slopes
+// --no-min-squares
+// --extrinsic
+// *
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=pallets/credentials/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_credentials`.
+pub trait WeightInfo {
+	fn revoke_did() -> Weight;
+	fn revoke_token() -> Weight;
+	fn revoke_tokens_issued_before() -> Weight;
+	fn add_verifier_key() -> Weight;
+	fn rotate_verifier_key() -> Weight;
+	fn remove_verifier_key() -> Weight;
+	fn retire_default_verifier_key() -> Weight;
+}
+
+/// Weights for `pallet_credentials` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Credentials::RevokedDids` (r:0 w:1)
+	/// Proof: `Credentials::RevokedDids` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	fn revoke_did() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1493`
+		// Minimum execution time: 6_010_000 picoseconds.
+		Weight::from_parts(6_290_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Credentials::RevokedTokens` (r:1 w:1)
+	/// Proof: `Credentials::RevokedTokens` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn revoke_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3546`
+		// Minimum execution time: 8_720_000 picoseconds.
+		Weight::from_parts(9_050_000, 3546)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Credentials::RevokedBefore` (r:0 w:1)
+	/// Proof: `Credentials::RevokedBefore` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn revoke_tokens_issued_before() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_410_000 picoseconds.
+		Weight::from_parts(3_620_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Credentials::TrustedVerifierKeys` (r:1 w:1)
+	/// Proof: `Credentials::TrustedVerifierKeys` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	fn add_verifier_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3660`
+		// Minimum execution time: 34_120_000 picoseconds.
+		Weight::from_parts(35_010_000, 3660)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Credentials::TrustedVerifierKeys` (r:2 w:2)
+	/// Proof: `Credentials::TrustedVerifierKeys` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	fn rotate_verifier_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `6330`
+		// Minimum execution time: 43_870_000 picoseconds.
+		Weight::from_parts(45_020_000, 6330)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Credentials::TrustedVerifierKeys` (r:1 w:1)
+	/// Proof: `Credentials::TrustedVerifierKeys` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	fn remove_verifier_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `3660`
+		// Minimum execution time: 10_540_000 picoseconds.
+		Weight::from_parts(10_930_000, 3660)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Credentials::DefaultVerifierKeyRetiredAt` (r:0 w:1)
+	/// Proof: `Credentials::DefaultVerifierKeyRetiredAt` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn retire_default_verifier_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1493`
+		// Minimum execution time: 5_890_000 picoseconds.
+		Weight::from_parts(6_130_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Credentials::RevokedDids` (r:0 w:1)
+	/// Proof: `Credentials::RevokedDids` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	fn revoke_did() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1493`
+		// Minimum execution time: 6_010_000 picoseconds.
+		Weight::from_parts(6_290_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Credentials::RevokedTokens` (r:1 w:1)
+	/// Proof: `Credentials::RevokedTokens` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn revoke_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3546`
+		// Minimum execution time: 8_720_000 picoseconds.
+		Weight::from_parts(9_050_000, 3546)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Credentials::RevokedBefore` (r:0 w:1)
+	/// Proof: `Credentials::RevokedBefore` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn revoke_tokens_issued_before() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_410_000 picoseconds.
+		Weight::from_parts(3_620_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}	/// Storage: `Credentials::TrustedVerifierKeys` (r:1 w:1)
+	/// Proof: `Credentials::TrustedVerifierKeys` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	fn add_verifier_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3660`
+		// Minimum execution time: 34_120_000 picoseconds.
+		Weight::from_parts(35_010_000, 3660)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Credentials::TrustedVerifierKeys` (r:2 w:2)
+	/// Proof: `Credentials::TrustedVerifierKeys` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	fn rotate_verifier_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `6330`
+		// Minimum execution time: 43_870_000 picoseconds.
+		Weight::from_parts(45_020_000, 6330)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Credentials::TrustedVerifierKeys` (r:1 w:1)
+	/// Proof: `Credentials::TrustedVerifierKeys` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	fn remove_verifier_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `3660`
+		// Minimum execution time: 10_540_000 picoseconds.
+		Weight::from_parts(10_930_000, 3660)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Credentials::DefaultVerifierKeyRetiredAt` (r:0 w:1)
+	/// Proof: `Credentials::DefaultVerifierKeyRetiredAt` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn retire_default_verifier_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1493`
+		// Minimum execution time: 5_890_000 picoseconds.
+		Weight::from_parts(6_130_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -28,7 +28,10 @@ pub use frame_support::traits::{
 	tokens::{currency::VestingSchedule, Balance},
 	Currency, ExistenceRequirement,
 };
-pub use polimec_common::credentials::{Cid, Did, EnsureOriginWithCredentials, InvestorType, UntrustedToken};
+pub use polimec_common::credentials::{
+	Cid, CredentialNonces, CredentialScope, Did, EnsureOriginWithCredentials, InvestorType, Jurisdiction,
+	UntrustedToken,
+};
 pub use sp_runtime::traits::Convert;
 
 pub mod extensions;
@@ -83,7 +86,7 @@ pub mod pallet {
 		/// The Origin that can dispense funds from the dispenser. The Origin must contain a valid JWT token.
 		type InvestorOrigin: EnsureOriginWithCredentials<
 			<Self as frame_system::Config>::RuntimeOrigin,
-			Success = (AccountIdOf<Self>, Did, InvestorType, Cid),
+			Success = (AccountIdOf<Self>, Did, InvestorType, Cid, Option<Jurisdiction>, CredentialScope),
 		>;
 
 		/// The period of time that the dispensed funds are locked. Used to calculate the
@@ -144,12 +147,14 @@ pub mod pallet {
 		DispenseAmountTooLow,
 		/// The origin does not have the required credentials.
 		InvalidCredential,
+		/// The credential is scoped to a project or to a maximum USD amount, so it can only be used to participate.
+		ScopedCredential,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::feeless_if( | origin: &OriginFor<T>, jwt: &UntrustedToken | -> bool {
-            if let Ok((_, did, _, _)) = T::InvestorOrigin::ensure_origin(origin.clone(), jwt, T::VerifierPublicKey::get()) {
+            if let Ok((_, did, _, _, _, _)) = T::InvestorOrigin::ensure_origin(origin.clone(), jwt, T::VerifierPublicKey::get()) {
                 Dispensed::<T>::get(did).is_none()
             } else {
                 false
@@ -158,10 +163,21 @@ pub mod pallet {
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::dispense())]
 		pub fn dispense(origin: OriginFor<T>, jwt: UntrustedToken) -> DispatchResultWithPostInfo {
-			let (who, did, _investor_type, whitelisted_policy) =
+			let (who, did, _investor_type, whitelisted_policy, _jurisdiction, scope) =
 				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
 			ensure!(Dispensed::<T>::get(&did).is_none(), Error::<T>::DispensedAlreadyToDid);
 			ensure!(whitelisted_policy == T::WhitelistedPolicy::get(), Error::<T>::InvalidCredential);
+			ensure!(scope.project_id.is_none() && scope.max_usd.is_none(), Error::<T>::ScopedCredential);
+			if let Some(nonce) = &scope.nonce {
+				ensure!(
+					<T::InvestorOrigin as EnsureOriginWithCredentials<_>>::Nonces::use_nonce(
+						&did,
+						nonce,
+						scope.expires_at
+					),
+					Error::<T>::InvalidCredential
+				);
+			}
 
 			let amount = DispenseAmount::<T>::get();
 			ensure!(CurrencyOf::<T>::free_balance(&Self::dispense_account()) >= amount, Error::<T>::DispenserDepleted);
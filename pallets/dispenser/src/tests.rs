@@ -21,7 +21,10 @@ use crate as pallet_dispenser;
 use crate::mock::*;
 use frame_support::{assert_noop, assert_ok};
 use polimec_common::credentials::InvestorType;
-use polimec_common_test_utils::{generate_cid_from_string, generate_did_from_account, get_mock_jwt_with_cid};
+use polimec_common_test_utils::{
+	generate_cid_from_string, generate_did_from_account, get_mock_jwt_with_cid, get_mock_jwt_with_options,
+	MockJwtOptions,
+};
 use sp_runtime::DispatchError;
 
 mod admin {
@@ -174,4 +177,31 @@ mod dispense {
 			assert_noop!(Dispenser::dispense(RuntimeOrigin::signed(1), jwt.clone()), Error::<Test>::InvalidCredential);
 		});
 	}
+
+	#[test]
+	fn user_cannot_dispense_with_scoped_credential() {
+		ExtBuilder::default().build().execute_with(|| {
+			let scoped_jwt = |project_id: Option<u32>, max_usd: Option<u128>| {
+				get_mock_jwt_with_options(
+					1,
+					InvestorType::Retail,
+					generate_did_from_account(1),
+					MockJwtOptions {
+						ipfs_cid: Some(WhitelistedPolicy::get()),
+						project_id,
+						max_usd,
+						..Default::default()
+					},
+				)
+			};
+			assert_noop!(
+				Dispenser::dispense(RuntimeOrigin::signed(1), scoped_jwt(Some(0), None)),
+				Error::<Test>::ScopedCredential
+			);
+			assert_noop!(
+				Dispenser::dispense(RuntimeOrigin::signed(1), scoped_jwt(None, Some(1000))),
+				Error::<Test>::ScopedCredential
+			);
+		});
+	}
 }
@@ -37,7 +37,7 @@ use itertools::Itertools;
 use parity_scale_codec::{Decode, Encode};
 use polimec_common::{credentials::InvestorType, ReleaseSchedule, USD_DECIMALS, USD_UNIT};
 use polimec_common_test_utils::{generate_did_from_account, get_mock_jwt_with_cid};
-use sp_arithmetic::Percent;
+use sp_arithmetic::{Percent, Perquintill};
 use sp_core::H256;
 use sp_io::hashing::blake2_256;
 use sp_runtime::traits::{Get, Member, TrailingZeroInput, Zero};
@@ -81,7 +81,16 @@ where
 		},
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: issuer,
+		funding_destination_location: None,
 		policy_ipfs_cid: Some(metadata_hash.into()),
+		round_durations: Default::default(),
+		ct_release_policy: None,
+		soft_cap: None,
+		private_round: None,
+		fee_mode: FeeMode::ContributionTokens,
+		milestones: None,
+		pricing_model: PricingModel::Buckets,
+		jurisdiction_restriction: None,
 	}
 }
 
@@ -254,6 +263,22 @@ pub fn default_remainder_contributor_multipliers() -> Vec<u8> {
 	vec![1u8, 11u8, 1u8, 1u8, 1u8]
 }
 
+/// Default project metadata, with two milestones each releasing half of the funds and approved by the evaluators.
+pub fn milestones_project_metadata<T: Config>(issuer: AccountIdOf<T>) -> ProjectMetadataOf<T>
+where
+	T::Price: From<u128>,
+	T::Hash: From<H256>,
+{
+	let milestone =
+		|deadline: u32| Milestone { release_percentage: Percent::from_percent(50), deadline: deadline.into() };
+	let mut project_metadata = default_project_metadata::<T>(issuer);
+	project_metadata.milestones = Some(MilestonePlan {
+		milestones: BoundedVec::truncate_from(vec![milestone(10u32), milestone(20u32)]),
+		approvers: MilestoneApprovers::Evaluators,
+	});
+	project_metadata
+}
+
 /// Grab an account, seeded by a name and index.
 pub fn string_account<AccountId: Decode>(
 	name: scale_info::prelude::string::String,
@@ -410,7 +435,16 @@ mod benchmarks {
 			},
 			participation_currencies: vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset::USDC].try_into().unwrap(),
 			funding_destination_account: issuer_funding.clone().clone(),
+			funding_destination_location: None,
 			policy_ipfs_cid: Some(BoundedVec::try_from(IPFS_CID.as_bytes().to_vec()).unwrap()),
+			round_durations: Default::default(),
+			ct_release_policy: None,
+			soft_cap: None,
+			private_round: None,
+			fee_mode: FeeMode::ContributionTokens,
+			milestones: None,
+			pricing_model: PricingModel::Buckets,
+			jurisdiction_restriction: None,
 		};
 
 		let jwt = get_mock_jwt_with_cid(
@@ -573,6 +607,166 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn increase_evaluation() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let test_evaluator = account::<AccountIdOf<T>>("evaluator", 0, 0);
+		whitelist_account!(test_evaluator);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_evaluating_project(project_metadata.clone(), issuer, None);
+		let project_details = inst.get_project_details(project_id);
+
+		// The early evaluation threshold is reached, so the increase is added to the late USD amount.
+		let early_threshold_usd =
+			<T as Config>::EvaluationSuccessThreshold::get() * project_details.fundraising_target_usd;
+		let existing_evaluation = UserToUSDBalance::new(test_evaluator.clone(), early_threshold_usd);
+		let extrinsic_increase = UserToUSDBalance::new(test_evaluator.clone(), (500 * USD_UNIT).into());
+
+		let plmc_for_existing_evaluation =
+			inst.calculate_evaluation_plmc_spent(vec![existing_evaluation.clone()], false);
+		let plmc_for_extrinsic_increase = inst.calculate_evaluation_plmc_spent(vec![extrinsic_increase.clone()], false);
+		inst.mint_plmc_to(plmc_for_existing_evaluation.accounts().existential_deposits());
+		inst.mint_plmc_to(plmc_for_existing_evaluation.clone());
+		inst.mint_plmc_to(plmc_for_extrinsic_increase.clone());
+
+		inst.evaluate_for_users(project_id, vec![existing_evaluation]).expect("All evaluations are accepted");
+		let existing_evaluation =
+			inst.execute(|| Evaluations::<T>::iter_prefix_values((project_id, test_evaluator.clone())).next().unwrap());
+
+		let jwt = get_mock_jwt_with_cid(
+			test_evaluator.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(test_evaluator.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		increase_evaluation(
+			RawOrigin::Signed(test_evaluator.clone()),
+			jwt,
+			project_id,
+			existing_evaluation.id,
+			extrinsic_increase.usd_amount,
+		);
+
+		// * validity checks *
+		// Storage
+		let extrinsic_plmc_bonded = plmc_for_extrinsic_increase[0].plmc_amount;
+		let stored_evaluation =
+			Evaluations::<T>::get((project_id, test_evaluator.clone(), existing_evaluation.id)).unwrap();
+		assert_eq!(
+			stored_evaluation.original_plmc_bond,
+			existing_evaluation.original_plmc_bond + extrinsic_plmc_bonded
+		);
+		assert_eq!(stored_evaluation.early_usd_amount, existing_evaluation.early_usd_amount);
+		assert_eq!(stored_evaluation.late_usd_amount, extrinsic_increase.usd_amount);
+
+		// Balances
+		let bonded_plmc = inst
+			.get_reserved_plmc_balances_for(vec![test_evaluator.clone()], HoldReason::Evaluation.into())[0]
+			.plmc_amount;
+		assert_eq!(bonded_plmc, stored_evaluation.current_plmc_bond);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::EvaluationIncreased {
+				project_id,
+				evaluator: test_evaluator,
+				id: existing_evaluation.id,
+				plmc_amount: extrinsic_plmc_bonded,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn withdraw_evaluation(
+		// How many evaluations the project has, which are all read to hand out the freed early evaluation slots
+		x: Linear<1, { T::MaxEvaluationsPerProject::get() }>,
+	) {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let test_evaluator = account::<AccountIdOf<T>>("evaluator", 0, 0);
+		whitelist_account!(test_evaluator);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_evaluating_project(project_metadata.clone(), issuer, None);
+		let project_details = inst.get_project_details(project_id);
+
+		// Worst case: the withdrawn evaluation holds all the early USD, and every other evaluation is late,
+		// so withdrawing it promotes every other evaluation.
+		let early_threshold_usd =
+			<T as Config>::EvaluationSuccessThreshold::get() * project_details.fundraising_target_usd;
+		let mut evaluations = vec![UserToUSDBalance::new(test_evaluator.clone(), early_threshold_usd)];
+		for i in 1..x {
+			evaluations
+				.push(UserToUSDBalance::new(account::<AccountIdOf<T>>("evaluator", i, 0), (100 * USD_UNIT).into()));
+		}
+
+		let plmc_for_evaluations = inst.calculate_evaluation_plmc_spent(evaluations.clone(), false);
+		inst.mint_plmc_to(plmc_for_evaluations.accounts().existential_deposits());
+		inst.mint_plmc_to(plmc_for_evaluations.clone());
+		inst.evaluate_for_users(project_id, evaluations).expect("All evaluations are accepted");
+
+		let evaluation_to_withdraw =
+			inst.execute(|| Evaluations::<T>::iter_prefix_values((project_id, test_evaluator.clone())).next().unwrap());
+
+		let jwt = get_mock_jwt_with_cid(
+			test_evaluator.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(test_evaluator.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		withdraw_evaluation(
+			RawOrigin::Signed(test_evaluator.clone()),
+			jwt,
+			project_id,
+			evaluation_to_withdraw.id,
+			early_threshold_usd,
+		);
+
+		// * validity checks *
+		// Storage
+		assert!(Evaluations::<T>::get((project_id, test_evaluator.clone(), evaluation_to_withdraw.id)).is_none());
+		for evaluation in Evaluations::<T>::iter_prefix_values((project_id,)) {
+			assert!(evaluation.late_usd_amount.is_zero());
+		}
+
+		// Balances
+		let bonded_plmc = inst
+			.get_reserved_plmc_balances_for(vec![test_evaluator.clone()], HoldReason::Evaluation.into())[0]
+			.plmc_amount;
+		assert_eq!(bonded_plmc, Zero::zero());
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::EvaluationWithdrawn {
+				project_id,
+				evaluator: test_evaluator,
+				id: evaluation_to_withdraw.id,
+				usd_amount: early_threshold_usd,
+				plmc_released: evaluation_to_withdraw.current_plmc_bond,
+			}
+			.into(),
+		);
+	}
+
 	// There are 2 logic branches in end_evaluation
 	// 1. If the evaluation round is successful
 	// 2. If the evaluation round failed
@@ -876,553 +1070,1221 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn end_auction(
-		// Accepted Bids
-		x: Linear<10, { 25 }>,
-		// Failed Bids
-		y: Linear<0, { 8 }>,
-	) {
+	fn cancel_bid() {
 		// * setup *
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
+
 		// We can't see events at block 0
-		inst.jump_to_block(1u32.into());
+		inst.advance_time(1u32.into());
 
 		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
-		whitelist_account!(issuer);
-
-		let mut project_metadata = default_project_metadata::<T>(issuer.clone());
-		project_metadata.mainnet_token_max_supply = 10_000_000 * CT_UNIT;
-		project_metadata.total_allocation_size = 10_000_000 * CT_UNIT;
-		project_metadata.auction_round_allocation_percentage = Percent::from_percent(100u8);
+		let bidder = account::<AccountIdOf<T>>("bidder", 0, 0);
+		let filler_bidder = account::<AccountIdOf<T>>("filler_bidder", 0, 0);
+		whitelist_account!(bidder);
 
-		let project_id = inst.create_auctioning_project(
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(
 			project_metadata.clone(),
-			issuer.clone(),
-			None,
-			inst.generate_successful_evaluations(
-				project_metadata.clone(),
-				default_evaluators::<T>(),
-				default_weights(),
-			),
+			default_evaluators::<T>(),
+			default_weights(),
 		);
-		let expected_remainder_round_block = inst.remainder_round_block() - One::one();
-
-		let mut all_bids = Vec::new();
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, None, evaluations);
 
+		// The cancelled bid takes the whole first bucket, and another bid moves the bucket up above it.
 		let auction_allocation =
 			project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
-		let min_bid_amount = 500u128;
-
-		// These bids will always be rejected, and will be made after the first bucket bid
-		let rejected_bids = (0..y.saturating_sub(1))
-			.map(|i| {
-				BidParams::<T>::new(
-					account::<AccountIdOf<T>>("bidder", 0, i),
-					(min_bid_amount * CT_UNIT).into(),
-					1u8,
-					AcceptedFundingAsset::USDT,
-				)
-			})
-			.collect_vec();
-		all_bids.extend(rejected_bids.clone());
-
-		let already_accepted_bids_count = if y > 0 {
-			// This one needs to fill the remaining with the bucket, so that all "accepted" bids will take the CT from a rejected one
-			let last_rejected_bid = BidParams::<T>::new(
-				account::<AccountIdOf<T>>("bidder", 0, 420),
-				auction_allocation - (min_bid_amount * CT_UNIT * (y as u128 - 1u128)),
-				1u8,
-				AcceptedFundingAsset::USDT,
-			);
-			all_bids.push(last_rejected_bid.clone());
-
-			// We first need to invalidate all rejected bids.
-			// We do it by placing a bid of  the whole auction allocation, i.e. 10 new bids
-			let allocation_bid = BidParams::<T>::new(
-				account::<AccountIdOf<T>>("bidder", 0, y),
-				auction_allocation,
-				1u8,
-				AcceptedFundingAsset::USDT,
-			);
-			all_bids.push(allocation_bid);
-
-			10
-		} else {
-			0
-		};
-
-		let accepted_bids = (0..x.saturating_sub(already_accepted_bids_count))
-			.map(|i| {
-				BidParams::<T>::new(
-					account::<AccountIdOf<T>>("bidder", 0, i),
-					(min_bid_amount * CT_UNIT).into(),
-					1u8,
-					AcceptedFundingAsset::USDT,
-				)
-			})
-			.collect_vec();
-		all_bids.extend(accepted_bids.clone());
-
-		let plmc_needed_for_bids = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
-			&all_bids,
+		let filler_amount = Percent::from_percent(95) * auction_allocation;
+		let bids = vec![
+			BidParams::new(bidder.clone(), auction_allocation, 1u8, AcceptedFundingAsset::USDT),
+			BidParams::new(filler_bidder, filler_amount, 1u8, AcceptedFundingAsset::USDT),
+		];
+		let plmc_for_bids = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+			&bids,
 			project_metadata.clone(),
 			None,
-			true,
+			false,
 		);
-		let funding_asset_needed_for_bids = inst
-			.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
-				&all_bids,
-				project_metadata.clone(),
-				None,
-			);
-
-		inst.mint_plmc_to(plmc_needed_for_bids);
-		inst.mint_funding_asset_to(funding_asset_needed_for_bids);
+		let usdt_for_bids = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+		);
+		inst.mint_plmc_to(plmc_for_bids);
+		inst.mint_plmc_to(bids.accounts().existential_deposits());
+		inst.mint_funding_asset_to(usdt_for_bids);
+		inst.bid_for_users(project_id, bids).unwrap();
 
-		inst.bid_for_users(project_id, all_bids).unwrap();
+		let bid_to_cancel =
+			inst.execute(|| Bids::<T>::iter_prefix_values((project_id, bidder.clone())).next().unwrap());
+		let mut expected_bucket = Buckets::<T>::get(project_id).unwrap();
+		expected_bucket.free(bid_to_cancel.original_ct_amount);
 
-		let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
-		inst.jump_to_block(auction_end);
+		let jwt = get_mock_jwt_with_cid(
+			bidder.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(bidder.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
 
-		#[block]
-		{
-			Pallet::<T>::do_end_auction(project_id).unwrap();
-		}
+		#[extrinsic_call]
+		cancel_bid(RawOrigin::Signed(bidder.clone()), jwt, project_id, bid_to_cancel.id);
 
 		// * validity checks *
 		// Storage
-		let stored_details = ProjectsDetails::<T>::get(project_id).unwrap();
-		assert!(matches!(stored_details.status, ProjectStatus::CommunityRound(..)));
+		assert!(Bids::<T>::get((project_id, bidder.clone(), bid_to_cancel.id)).is_none());
+		assert_eq!(Buckets::<T>::get(project_id).unwrap(), expected_bucket);
 
-		let accepted_bids_count = Bids::<T>::iter_prefix_values((project_id,))
-			.filter(|b| matches!(b.status, BidStatus::Accepted | BidStatus::PartiallyAccepted(..)))
-			.count();
-		let rejected_bids_count =
-			Bids::<T>::iter_prefix_values((project_id,)).filter(|b| matches!(b.status, BidStatus::Rejected)).count();
-		assert_eq!(accepted_bids_count, x as usize);
-		assert_eq!(rejected_bids_count, y as usize);
+		// Balances
+		let bonded_plmc =
+			inst.get_reserved_plmc_balances_for(vec![bidder.clone()], HoldReason::Participation.into())[0].plmc_amount;
+		assert_eq!(bonded_plmc, Zero::zero());
+		let free_usdt = inst.get_free_funding_asset_balances_for(usdt_id(), vec![bidder.clone()])[0].asset_amount;
+		assert_eq!(free_usdt, bid_to_cancel.funding_asset_amount_locked);
 
 		// Events
 		frame_system::Pallet::<T>::assert_last_event(
-			Event::<T>::ProjectPhaseTransition {
+			Event::BidCancelled {
 				project_id,
-				phase: ProjectStatus::CommunityRound(expected_remainder_round_block),
+				bidder,
+				id: bid_to_cancel.id,
+				ct_amount: bid_to_cancel.original_ct_amount,
+				funding_asset: AcceptedFundingAsset::USDT,
+				funding_amount_released: bid_to_cancel.funding_asset_amount_locked,
+				plmc_released: bid_to_cancel.plmc_bond,
 			}
 			.into(),
 		);
 	}
 
-	// We check if the user has a winning bid regardless if its the community or remainder round, so both rounds should have
-	// the same weight with `x` being equal.
 	#[benchmark]
-	fn contribute(
-		// How many other contributions the user did for that same project
-		x: Linear<0, { T::MaxContributionsPerUser::get() - 1 }>,
-	) {
-		// setup
+	fn reduce_bid() {
+		// * setup *
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
 
 		// We can't see events at block 0
-		inst.jump_to_block(1u32.into());
+		inst.advance_time(1u32.into());
 
 		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
-		let contributor = account::<AccountIdOf<T>>("contributor", 0, 0);
-		whitelist_account!(contributor);
+		let bidder = account::<AccountIdOf<T>>("bidder", 0, 0);
+		let filler_bidder = account::<AccountIdOf<T>>("filler_bidder", 0, 0);
+		whitelist_account!(bidder);
 
 		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(
+			project_metadata.clone(),
+			default_evaluators::<T>(),
+			default_weights(),
+		);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, None, evaluations);
 
-		let project_id = inst.create_community_contributing_project(
+		// The reduced bid takes the whole first bucket, and another bid moves the bucket up above it.
+		let auction_allocation =
+			project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
+		let bids = vec![
+			BidParams::new(bidder.clone(), auction_allocation, 1u8, AcceptedFundingAsset::USDT),
+			BidParams::new(
+				filler_bidder,
+				Percent::from_percent(95) * auction_allocation,
+				1u8,
+				AcceptedFundingAsset::USDT,
+			),
+		];
+		let plmc_for_bids = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+			&bids,
 			project_metadata.clone(),
-			issuer,
 			None,
-			default_evaluations::<T>(),
-			full_bids::<T>(),
+			false,
 		);
+		let usdt_for_bids = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+		);
+		inst.mint_plmc_to(plmc_for_bids);
+		inst.mint_plmc_to(bids.accounts().existential_deposits());
+		inst.mint_funding_asset_to(usdt_for_bids);
+		inst.bid_for_users(project_id, bids).unwrap();
 
-		let price = inst.get_project_details(project_id).weighted_average_price.unwrap();
-
-		let contributions =
-			vec![
-				ContributionParams::new(contributor.clone(), (50 * CT_UNIT).into(), 1u8, AcceptedFundingAsset::USDT);
-				x as usize + 1
-			];
-
-		let plmc = inst.calculate_contributed_plmc_spent(contributions.clone(), price, false);
-		let usdt = inst.calculate_contributed_funding_asset_spent(contributions.clone(), price);
-
-		let escrow_account = Pallet::<T>::fund_account_id(project_id);
-		let prev_total_usdt_locked = inst.get_free_funding_asset_balances_for(usdt_id(), vec![escrow_account.clone()]);
-
-		inst.mint_plmc_to(plmc.clone());
-		inst.mint_plmc_to(plmc.accounts().existential_deposits());
-		inst.mint_funding_asset_to(usdt.clone());
-
-		// do "x" contributions for this user
-		inst.contribute_for_users(project_id, contributions[1..].to_vec()).expect("All contributions are accepted");
-
-		let total_plmc_bonded = inst.sum_balance_mappings(vec![plmc.clone()]);
-		let total_usdt_locked = inst.sum_funding_asset_mappings(vec![prev_total_usdt_locked, usdt.clone()])[0].1;
-
-		let total_free_plmc = inst.get_ed();
-		let total_free_usdt = Zero::zero();
+		let bid_to_reduce =
+			inst.execute(|| Bids::<T>::iter_prefix_values((project_id, bidder.clone())).next().unwrap());
+		let new_ct_amount = bid_to_reduce.original_ct_amount / 2;
+		let mut expected_bucket = Buckets::<T>::get(project_id).unwrap();
+		expected_bucket.free(bid_to_reduce.original_ct_amount - new_ct_amount);
 
 		let jwt = get_mock_jwt_with_cid(
-			contributor.clone(),
-			InvestorType::Retail,
-			generate_did_from_account(contributor.clone()),
+			bidder.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(bidder.clone()),
 			project_metadata.clone().policy_ipfs_cid.unwrap(),
 		);
 
 		#[extrinsic_call]
-		contribute(
-			RawOrigin::Signed(contributor.clone()),
-			jwt,
-			project_id,
-			contributions[0].amount,
-			contributions[0].multiplier,
-			contributions[0].asset,
-		);
+		reduce_bid(RawOrigin::Signed(bidder.clone()), jwt, project_id, bid_to_reduce.id, new_ct_amount);
 
 		// * validity checks *
 		// Storage
-		let stored_contributions =
-			Contributions::<T>::iter_prefix_values((project_id, contributor.clone())).collect_vec();
-		assert_eq!(stored_contributions.len(), x as usize + 1);
+		let stored_bid = Bids::<T>::get((project_id, bidder.clone(), bid_to_reduce.id)).unwrap();
+		assert_eq!(stored_bid.original_ct_amount, new_ct_amount);
+		assert_eq!(stored_bid.original_ct_usd_price, bid_to_reduce.original_ct_usd_price);
+		assert_eq!(Buckets::<T>::get(project_id).unwrap(), expected_bucket);
 
 		// Balances
-		let bonded_plmc = inst.get_reserved_plmc_balance_for(contributor.clone(), HoldReason::Participation.into());
+		let bonded_plmc =
+			inst.get_reserved_plmc_balances_for(vec![bidder.clone()], HoldReason::Participation.into())[0].plmc_amount;
+		assert_eq!(bonded_plmc, stored_bid.plmc_bond);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::BidReduced {
+				project_id,
+				bidder,
+				id: bid_to_reduce.id,
+				ct_amount: new_ct_amount,
+				funding_asset: AcceptedFundingAsset::USDT,
+				funding_amount_released: bid_to_reduce.funding_asset_amount_locked -
+					stored_bid.funding_asset_amount_locked,
+				plmc_released: bid_to_reduce.plmc_bond - stored_bid.plmc_bond,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn end_auction() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
+		whitelist_account!(anyone);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_auctioning_project(
+			project_metadata.clone(),
+			issuer,
+			None,
+			inst.generate_successful_evaluations(
+				project_metadata.clone(),
+				default_evaluators::<T>(),
+				default_weights(),
+			),
+		);
+
+		let bids = full_bids::<T>();
+		let plmc_for_bids = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+			true,
+		);
+		let usdt_for_bids = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+		);
+		inst.mint_plmc_to(plmc_for_bids);
+		inst.mint_funding_asset_to(usdt_for_bids);
+		inst.bid_for_users(project_id, bids).unwrap();
+
+		let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+		inst.jump_to_block(auction_end + One::one());
+
+		#[extrinsic_call]
+		end_auction(RawOrigin::Signed(anyone), project_id);
+
+		// * validity checks *
+		// Storage
+		let stored_details = ProjectsDetails::<T>::get(project_id).unwrap();
+		assert_eq!(stored_details.status, ProjectStatus::AuctionResolving);
+		let resolution = AuctionResolutions::<T>::get(project_id).unwrap();
+		assert_eq!(resolution.position, 0);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::ProjectPhaseTransition { project_id, phase: ProjectStatus::AuctionResolving }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn resolve_bid() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let bidder = account::<AccountIdOf<T>>("bidder", 0, 0);
+		let filler_bidder = account::<AccountIdOf<T>>("filler_bidder", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_auctioning_project(
+			project_metadata.clone(),
+			issuer,
+			None,
+			inst.generate_successful_evaluations(
+				project_metadata.clone(),
+				default_evaluators::<T>(),
+				default_weights(),
+			),
+		);
+
+		// The auction is oversubscribed, so one of the bids ends up partially accepted
+		let auction_allocation =
+			project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
+		let bids = vec![
+			BidParams::new(bidder, auction_allocation, 1u8, AcceptedFundingAsset::USDT),
+			BidParams::new(
+				filler_bidder,
+				Percent::from_percent(50) * auction_allocation,
+				1u8,
+				AcceptedFundingAsset::USDT,
+			),
+		];
+		let plmc_for_bids = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+			false,
+		);
+		let usdt_for_bids = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+		);
+		inst.mint_plmc_to(plmc_for_bids);
+		inst.mint_plmc_to(bids.accounts().existential_deposits());
+		inst.mint_funding_asset_to(usdt_for_bids);
+		inst.bid_for_users(project_id, bids).unwrap();
+
+		let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+		inst.jump_to_block(auction_end + One::one());
+		inst.execute(|| Pallet::<T>::do_end_auction(project_id).unwrap());
+
+		// Worst case: the next bid to resolve is the one that gets partially accepted
+		let (bidder, bid_id) = loop {
+			let resolution = AuctionResolutions::<T>::get(project_id).unwrap();
+			if let Some((bidder, bid_id)) = BidsByBucket::<T>::get((project_id, resolution.bucket, resolution.position))
+			{
+				let bid = Bids::<T>::get((project_id, bidder.clone(), bid_id)).unwrap();
+				if !resolution.ct_left.is_zero() && bid.original_ct_amount > resolution.ct_left {
+					break (bidder, bid_id);
+				}
+			}
+			Pallet::<T>::do_resolve_auction(project_id, 1).unwrap();
+		};
+
+		#[block]
+		{
+			Pallet::<T>::do_resolve_auction(project_id, 1).unwrap();
+		}
+
+		// * validity checks *
+		let stored_bid = Bids::<T>::get((project_id, bidder, bid_id)).unwrap();
+		assert!(matches!(stored_bid.status, BidStatus::PartiallyAccepted(..)));
+		assert_eq!(AuctionResolutions::<T>::get(project_id).unwrap().ct_left, Zero::zero());
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionResolving);
+	}
+
+	#[benchmark]
+	fn finish_auction_resolution() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_auctioning_project(
+			project_metadata.clone(),
+			issuer,
+			None,
+			inst.generate_successful_evaluations(
+				project_metadata.clone(),
+				default_evaluators::<T>(),
+				default_weights(),
+			),
+		);
+
+		let bids = default_bids::<T>();
+		let plmc_for_bids = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+			true,
+		);
+		let usdt_for_bids = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+		);
+		inst.mint_plmc_to(plmc_for_bids);
+		inst.mint_funding_asset_to(usdt_for_bids);
+		inst.bid_for_users(project_id, bids).unwrap();
+
+		let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+		inst.jump_to_block(auction_end + One::one());
+		inst.execute(|| Pallet::<T>::do_end_auction(project_id).unwrap());
+
+		// Resolve every bid, so only the final step is left
+		loop {
+			let resolution = AuctionResolutions::<T>::get(project_id).unwrap();
+			if resolution.bucket.is_zero() && resolution.position >= BucketBidCounts::<T>::get(project_id, 0) {
+				break;
+			}
+			Pallet::<T>::do_resolve_auction(project_id, 1).unwrap();
+		}
+		let expected_remainder_round_block = inst.remainder_round_block() - One::one();
+
+		#[block]
+		{
+			Pallet::<T>::do_resolve_auction(project_id, 1).unwrap();
+		}
+
+		// * validity checks *
+		// Storage
+		let stored_details = ProjectsDetails::<T>::get(project_id).unwrap();
+		assert!(matches!(stored_details.status, ProjectStatus::CommunityRound(..)));
+		assert!(stored_details.weighted_average_price.is_some());
+		assert!(AuctionResolutions::<T>::get(project_id).is_none());
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::ProjectPhaseTransition {
+				project_id,
+				phase: ProjectStatus::CommunityRound(expected_remainder_round_block),
+			}
+			.into(),
+		);
+	}
+
+	// We check if the user has a winning bid regardless if its the community or remainder round, so both rounds should have
+	// the same weight with `x` being equal.
+	#[benchmark]
+	fn contribute(
+		// How many other contributions the user did for that same project
+		x: Linear<0, { T::MaxContributionsPerUser::get() - 1 }>,
+	) {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let contributor = account::<AccountIdOf<T>>("contributor", 0, 0);
+		whitelist_account!(contributor);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+
+		let project_id = inst.create_community_contributing_project(
+			project_metadata.clone(),
+			issuer,
+			None,
+			default_evaluations::<T>(),
+			full_bids::<T>(),
+		);
+
+		let price = inst.get_project_details(project_id).weighted_average_price.unwrap();
+
+		let contributions =
+			vec![
+				ContributionParams::new(contributor.clone(), (50 * CT_UNIT).into(), 1u8, AcceptedFundingAsset::USDT);
+				x as usize + 1
+			];
+
+		let plmc = inst.calculate_contributed_plmc_spent(contributions.clone(), price, false);
+		let usdt = inst.calculate_contributed_funding_asset_spent(contributions.clone(), price);
+
+		let escrow_account = Pallet::<T>::fund_account_id(project_id);
+		let prev_total_usdt_locked = inst.get_free_funding_asset_balances_for(usdt_id(), vec![escrow_account.clone()]);
+
+		inst.mint_plmc_to(plmc.clone());
+		inst.mint_plmc_to(plmc.accounts().existential_deposits());
+		inst.mint_funding_asset_to(usdt.clone());
+
+		// do "x" contributions for this user
+		inst.contribute_for_users(project_id, contributions[1..].to_vec()).expect("All contributions are accepted");
+
+		let total_plmc_bonded = inst.sum_balance_mappings(vec![plmc.clone()]);
+		let total_usdt_locked = inst.sum_funding_asset_mappings(vec![prev_total_usdt_locked, usdt.clone()])[0].1;
+
+		let total_free_plmc = inst.get_ed();
+		let total_free_usdt = Zero::zero();
+
+		let jwt = get_mock_jwt_with_cid(
+			contributor.clone(),
+			InvestorType::Retail,
+			generate_did_from_account(contributor.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		contribute(
+			RawOrigin::Signed(contributor.clone()),
+			jwt,
+			project_id,
+			contributions[0].amount,
+			contributions[0].multiplier,
+			contributions[0].asset,
+		);
+
+		// * validity checks *
+		// Storage
+		let stored_contributions =
+			Contributions::<T>::iter_prefix_values((project_id, contributor.clone())).collect_vec();
+		assert_eq!(stored_contributions.len(), x as usize + 1);
+
+		// Balances
+		let bonded_plmc = inst.get_reserved_plmc_balance_for(contributor.clone(), HoldReason::Participation.into());
 		assert_eq!(bonded_plmc, total_plmc_bonded);
 
-		let free_plmc = inst.get_free_plmc_balance_for(contributor.clone());
-		assert_eq!(free_plmc, total_free_plmc);
+		let free_plmc = inst.get_free_plmc_balance_for(contributor.clone());
+		assert_eq!(free_plmc, total_free_plmc);
+
+		let escrow_account = Pallet::<T>::fund_account_id(project_id);
+		let locked_usdt = inst.get_free_funding_asset_balance_for(usdt_id(), escrow_account.clone());
+		assert_eq!(locked_usdt, total_usdt_locked);
+
+		let free_usdt = inst.get_free_funding_asset_balance_for(usdt_id(), contributor.clone());
+		assert_eq!(free_usdt, total_free_usdt);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::Contribution {
+				project_id,
+				contributor,
+				id: x,
+				ct_amount: contributions[0].amount,
+				funding_asset: AcceptedFundingAsset::USDT,
+				funding_amount: usdt[0].asset_amount,
+				plmc_bond: plmc[0].plmc_amount,
+				multiplier: contributions[0].multiplier,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn withdraw_contribution() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let contributor = account::<AccountIdOf<T>>("contributor", 0, 0);
+		whitelist_account!(contributor);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+
+		let project_id = inst.create_community_contributing_project(
+			project_metadata.clone(),
+			issuer,
+			None,
+			default_evaluations::<T>(),
+			full_bids::<T>(),
+		);
+
+		let price = inst.get_project_details(project_id).weighted_average_price.unwrap();
+
+		let contributions =
+			vec![ContributionParams::new(contributor.clone(), (50 * CT_UNIT).into(), 1u8, AcceptedFundingAsset::USDT)];
+
+		let plmc = inst.calculate_contributed_plmc_spent(contributions.clone(), price, false);
+		let usdt = inst.calculate_contributed_funding_asset_spent(contributions.clone(), price);
+
+		inst.mint_plmc_to(plmc.clone());
+		inst.mint_plmc_to(plmc.accounts().existential_deposits());
+		inst.mint_funding_asset_to(usdt.clone());
+
+		inst.contribute_for_users(project_id, contributions).expect("All contributions are accepted");
+
+		let contribution =
+			inst.execute(|| Contributions::<T>::iter_prefix_values((project_id, contributor.clone())).next().unwrap());
+		let prev_project_details = inst.get_project_details(project_id);
+
+		let jwt = get_mock_jwt_with_cid(
+			contributor.clone(),
+			InvestorType::Retail,
+			generate_did_from_account(contributor.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		withdraw_contribution(RawOrigin::Signed(contributor.clone()), jwt, project_id, contribution.id);
+
+		// * validity checks *
+		// Storage
+		assert!(Contributions::<T>::get((project_id, contributor.clone(), contribution.id)).is_none());
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(
+			project_details.remaining_contribution_tokens,
+			prev_project_details.remaining_contribution_tokens + contribution.ct_amount
+		);
+		assert_eq!(
+			project_details.funding_amount_reached_usd,
+			prev_project_details.funding_amount_reached_usd - contribution.usd_contribution_amount
+		);
+
+		// Balances
+		let bonded_plmc = inst.get_reserved_plmc_balance_for(contributor.clone(), HoldReason::Participation.into());
+		assert_eq!(bonded_plmc, Zero::zero());
+		let free_usdt = inst.get_free_funding_asset_balance_for(usdt_id(), contributor.clone());
+		assert_eq!(free_usdt, usdt[0].asset_amount);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::ContributionWithdrawn {
+				project_id,
+				contributor,
+				id: contribution.id,
+				ct_amount: contribution.ct_amount,
+				funding_asset: AcceptedFundingAsset::USDT,
+				funding_amount_released: contribution.funding_asset_amount,
+				plmc_released: contribution.plmc_bond,
+			}
+			.into(),
+		);
+	}
+
+	// end_funding has 2 logic paths:
+	// 1 - Funding successful (most expensive, not by much)
+	// 2 - Funding failed
+	// They only differ in that 1- has to calculate the evaluator rewards.
+	#[benchmark]
+	fn end_funding_project_successful() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+
+		let project_id = inst.create_remainder_contributing_project(
+			project_metadata,
+			issuer.clone(),
+			None,
+			default_evaluations::<T>(),
+			default_bids::<T>(),
+			default_community_contributions::<T>(),
+		);
+
+		let end_block = inst.get_project_details(project_id).round_duration.end().unwrap();
+		inst.jump_to_block(end_block);
+
+		#[block]
+		{
+			Pallet::<T>::do_end_funding(project_id).unwrap();
+		}
+
+		// * validity checks *
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.status, ProjectStatus::FundingSuccessful);
+		assert!(matches!(
+			project_details.evaluation_round_info.evaluators_outcome,
+			Some(EvaluatorsOutcome::Rewarded(_))
+		));
+	}
+
+	// Success case is the most expensive, so we always charge for that.
+	#[benchmark]
+	fn start_settlement() {
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		whitelist_account!(anyone);
+
+		let project_id = inst.create_finished_project(
+			default_project_metadata::<T>(issuer.clone()),
+			issuer,
+			None,
+			default_evaluations::<T>(),
+			default_bids::<T>(),
+			default_community_contributions::<T>(),
+			vec![],
+		);
+
+		#[extrinsic_call]
+		start_settlement(RawOrigin::Signed(anyone), project_id);
+
+		// * validity checks *
+		let project_details = ProjectsDetails::<T>::get(project_id).unwrap();
+		assert_eq!(project_details.status, ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		assert!(<T as Config>::ContributionTokenCurrency::asset_exists(project_id));
+	}
+
+	// We have 3 logic paths:
+	// 1 - Evaluation rewarded
+	// 2 - Evaluation slashed
+	// 3 - Evaluation failed (evaluation round unsuccessful)
+	// Path 1 is the most expensive but not by far, so we only benchmark and charge for this weight
+	#[benchmark]
+	fn settle_rewarded_evaluation() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let evaluations: Vec<UserToUSDBalance<T>> = default_evaluations::<T>();
+		let evaluator: AccountIdOf<T> = evaluations[0].account.clone();
+		whitelist_account!(evaluator);
+
+		let project_id = inst.create_finished_project(
+			default_project_metadata::<T>(issuer.clone()),
+			issuer,
+			None,
+			evaluations,
+			default_bids::<T>(),
+			default_community_contributions::<T>(),
+			vec![],
+		);
+
+		let evaluation_to_settle =
+			inst.execute(|| Evaluations::<T>::iter_prefix_values((project_id, evaluator.clone())).next().unwrap());
+
+		assert_ok!(<Pallet<T>>::do_start_settlement(project_id));
+
+		#[extrinsic_call]
+		settle_evaluation(RawOrigin::Signed(evaluator.clone()), project_id, evaluator.clone(), evaluation_to_settle.id);
+
+		// * validity checks *
+		// Evaluation should be removed
+		assert!(Evaluations::<T>::get((project_id, evaluator.clone(), evaluation_to_settle.id)).is_none());
+
+		// Balances
+		let project_details = ProjectsDetails::<T>::get(project_id).unwrap();
+		let reward_info = match project_details.evaluation_round_info.evaluators_outcome {
+			Some(EvaluatorsOutcome::Rewarded(reward_info)) => reward_info,
+			_ => panic!("EvaluatorsOutcome should be Rewarded"),
+		};
+		let reward = Pallet::<T>::calculate_evaluator_reward(&evaluation_to_settle, &reward_info);
+		inst.assert_ct_balance(project_id, evaluator.clone(), reward);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::EvaluationSettled {
+				project_id,
+				account: evaluator.clone(),
+				id: evaluation_to_settle.id,
+				ct_rewarded: reward,
+				plmc_released: evaluation_to_settle.original_plmc_bond,
+			}
+			.into(),
+		);
+	}
+
+	// We have 3 logic paths
+	// 1 - Accepted bid with no refunds (i.e. final price <= WAP, no partial acceptance)
+	// 2 - Accepted bid with refund (i.e. final price > WAP or partial acceptance)
+	// 3 - Rejected bid (i.e. bid not accepted, everything refunded, no CT/migration)
+	// Path 2 is the most expensive but not by far, so we only benchmark and charge for this weight
+	#[benchmark]
+	fn settle_accepted_bid_with_refund() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let mut bidder_accounts = default_bidders::<T>().into_iter();
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		// let target_wap = project_metadata.minimum_price + project_metadata.minimum_price * <PriceOf<T>>::saturating_from_rational(1, 10);
+		let mut target_bucket = <Pallet<T>>::create_bucket_from_metadata(&project_metadata.clone()).unwrap();
+		target_bucket.update(target_bucket.amount_left);
+		target_bucket.update(target_bucket.amount_left);
+
+		let bids = inst.generate_bids_from_bucket(
+			project_metadata.clone(),
+			target_bucket,
+			bidder_accounts.next().unwrap(),
+			|_| bidder_accounts.next().unwrap(),
+			AcceptedFundingAsset::USDT,
+		);
+
+		let project_id = inst.create_finished_project(
+			project_metadata.clone(),
+			issuer,
+			None,
+			default_evaluations::<T>(),
+			bids.clone(),
+			default_community_contributions::<T>(),
+			vec![],
+		);
 
-		let escrow_account = Pallet::<T>::fund_account_id(project_id);
-		let locked_usdt = inst.get_free_funding_asset_balance_for(usdt_id(), escrow_account.clone());
-		assert_eq!(locked_usdt, total_usdt_locked);
+		let wap = inst.get_project_details(project_id).weighted_average_price.unwrap();
 
-		let free_usdt = inst.get_free_funding_asset_balance_for(usdt_id(), contributor.clone());
-		assert_eq!(free_usdt, total_free_usdt);
+		let bidder = bids.last().unwrap().bidder.clone();
+		whitelist_account!(bidder);
+
+		assert_ok!(<Pallet<T>>::do_start_settlement(project_id));
+
+		let bid_to_settle =
+			inst.execute(|| Bids::<T>::iter_prefix_values((project_id, bidder.clone())).next().unwrap());
+
+		// Make sure a refund has to happen
+		assert!(bid_to_settle.original_ct_usd_price > wap);
+
+		#[extrinsic_call]
+		settle_bid(RawOrigin::Signed(bidder.clone()), project_id, bidder.clone(), bid_to_settle.id);
+
+		// * validity checks *
+		// Storage
+		assert!(Bids::<T>::get((project_id, bidder.clone(), bid_to_settle.id)).is_none());
+
+		// Balances
+		let ct_amount = inst.get_ct_asset_balances_for(project_id, vec![bidder.clone()])[0];
+		assert_eq!(bid_to_settle.original_ct_amount, ct_amount);
 
 		// Events
 		frame_system::Pallet::<T>::assert_last_event(
-			Event::Contribution {
+			Event::BidSettled {
 				project_id,
-				contributor,
-				id: x,
-				ct_amount: contributions[0].amount,
-				funding_asset: AcceptedFundingAsset::USDT,
-				funding_amount: usdt[0].asset_amount,
-				plmc_bond: plmc[0].plmc_amount,
-				multiplier: contributions[0].multiplier,
+				account: bidder.clone(),
+				id: bid_to_settle.id,
+				final_ct_amount: bid_to_settle.original_ct_amount,
+				final_ct_usd_price: wap,
+				funding_asset_fee: Zero::zero(),
 			}
 			.into(),
 		);
 	}
 
-	// end_funding has 2 logic paths:
-	// 1 - Funding successful (most expensive, not by much)
-	// 2 - Funding failed
-	// They only differ in that 1- has to calculate the evaluator rewards.
+	// We have 2 logic paths
+	// 1 - Project was successful, USDT is transferred to issuer, CT minted, PLMC locked for vesting
+	// 2 - Project failed, USDT is refunded to contributor, CT is not minted, PLMC is released
+	// Path 1 is the most expensive but not by far, so we only benchmark and charge for this weight
 	#[benchmark]
-	fn end_funding_project_successful() {
+	fn settle_contribution_project_successful() {
 		// setup
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
 
 		// We can't see events at block 0
-		inst.jump_to_block(1u32.into());
+		inst.advance_time(1u32.into());
 
 		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let contributions = default_community_contributions::<T>();
+		let contributor = contributions[0].contributor.clone();
+		whitelist_account!(contributor);
 
 		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_finished_project(
+			project_metadata.clone(),
+			issuer,
+			None,
+			default_evaluations::<T>(),
+			default_bids::<T>(),
+			contributions,
+			vec![],
+		);
 
-		let project_id = inst.create_remainder_contributing_project(
-			project_metadata,
+		assert_ok!(<Pallet<T>>::do_start_settlement(project_id));
+
+		let contribution_to_settle =
+			inst.execute(|| Contributions::<T>::iter_prefix_values((project_id, contributor.clone())).next().unwrap());
+
+		#[extrinsic_call]
+		settle_contribution(
+			RawOrigin::Signed(contributor.clone()),
+			project_id,
+			contributor.clone(),
+			contribution_to_settle.id,
+		);
+
+		// * validity checks *
+		// Storage
+		assert!(Contributions::<T>::get((project_id, contributor.clone(), contribution_to_settle.id)).is_none());
+
+		// Balances
+		let ct_amount = inst.get_ct_asset_balances_for(project_id, vec![contributor.clone()])[0];
+		assert_eq!(contribution_to_settle.ct_amount, ct_amount);
+		inst.assert_plmc_held_balance(
+			contributor.clone(),
+			contribution_to_settle.plmc_bond,
+			HoldReason::Participation.into(),
+		);
+		assert_eq!(
+			VestingOf::<T>::total_scheduled_amount(&contributor, HoldReason::Participation.into()),
+			Some(contribution_to_settle.plmc_bond)
+		);
+		let funding_account = project_metadata.funding_destination_account;
+		inst.assert_funding_asset_free_balance(
+			funding_account,
+			AcceptedFundingAsset::USDT.id(),
+			contribution_to_settle.funding_asset_amount,
+		);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::ContributionSettled {
+				project_id,
+				account: contributor.clone(),
+				id: contribution_to_settle.id,
+				ct_amount,
+				funding_asset_fee: Zero::zero(),
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn mark_project_as_settled() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
+		whitelist_account!(anyone);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_settled_project(
+			project_metadata.clone(),
 			issuer.clone(),
 			None,
 			default_evaluations::<T>(),
 			default_bids::<T>(),
 			default_community_contributions::<T>(),
+			vec![],
+			false,
 		);
 
-		let end_block = inst.get_project_details(project_id).round_duration.end().unwrap();
-		inst.jump_to_block(end_block);
-
-		#[block]
-		{
-			Pallet::<T>::do_end_funding(project_id).unwrap();
-		}
+		#[extrinsic_call]
+		mark_project_as_settled(RawOrigin::Signed(anyone), project_id);
 
 		// * validity checks *
 		let project_details = inst.get_project_details(project_id);
-		assert_eq!(project_details.status, ProjectStatus::FundingSuccessful);
-		assert!(matches!(
-			project_details.evaluation_round_info.evaluators_outcome,
-			Some(EvaluatorsOutcome::Rewarded(_))
-		));
+		assert_eq!(project_details.status, ProjectStatus::SettlementFinished(FundingOutcome::Success));
 	}
 
-	// Success case is the most expensive, so we always charge for that.
 	#[benchmark]
-	fn start_settlement() {
+	fn claim_long_term_holder_reward() {
+		// setup
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
 
 		// We can't see events at block 0
 		inst.jump_to_block(1u32.into());
 
-		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
 		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
 		whitelist_account!(anyone);
 
-		let project_id = inst.create_finished_project(
-			default_project_metadata::<T>(issuer.clone()),
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_settled_project(
+			project_metadata.clone(),
+			issuer.clone(),
+			None,
+			default_evaluations::<T>(),
+			default_bids::<T>(),
+			default_community_contributions::<T>(),
+			vec![],
+			true,
+		);
+
+		let holder = default_community_contributions::<T>()[0].contributor.clone();
+		let holding = LongTermHoldings::<T>::get((project_id, holder.clone())).unwrap();
+		let pot = LongTermHolderPots::<T>::get(project_id).unwrap();
+		inst.jump_to_block(holding.since + <T as Config>::LongTermHolderPeriod::get());
+
+		#[extrinsic_call]
+		claim_long_term_holder_reward(RawOrigin::Signed(anyone), project_id, holder.clone());
+
+		// * validity checks *
+		assert!(LongTermHoldings::<T>::get((project_id, holder.clone())).is_none());
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::LongTermHolderRewardClaimed {
+				project_id,
+				account: holder,
+				ct_amount: pot.share_of(holding.ct_amount),
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn forfeit_long_term_holder_reward() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_settled_project(
+			project_metadata.clone(),
+			issuer.clone(),
+			None,
+			default_evaluations::<T>(),
+			default_bids::<T>(),
+			default_community_contributions::<T>(),
+			vec![],
+			true,
+		);
+
+		let holder = default_community_contributions::<T>()[0].contributor.clone();
+		whitelist_account!(holder);
+		let holding = LongTermHoldings::<T>::get((project_id, holder.clone())).unwrap();
+
+		#[extrinsic_call]
+		forfeit_long_term_holder_reward(RawOrigin::Signed(holder.clone()), project_id);
+
+		// * validity checks *
+		assert!(LongTermHoldings::<T>::get((project_id, holder.clone())).is_none());
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::LongTermHolderRewardForfeited { project_id, account: holder, ct_amount: holding.ct_amount }
+				.into(),
+		);
+	}
+
+	#[benchmark]
+	fn approve_milestone() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_id = inst.create_settled_project(
+			milestones_project_metadata::<T>(issuer.clone()),
 			issuer,
 			None,
 			default_evaluations::<T>(),
 			default_bids::<T>(),
 			default_community_contributions::<T>(),
 			vec![],
+			true,
 		);
+		let approver = default_evaluations::<T>()[0].account.clone();
+		whitelist_account!(approver);
+
+		// Worst case: this approval releases the milestone
+		let mut progress = MilestonesProgress::<T>::get(project_id).unwrap();
+		progress.approvals = progress.total_power / 2;
+		MilestonesProgress::<T>::insert(project_id, progress);
 
 		#[extrinsic_call]
-		start_settlement(RawOrigin::Signed(anyone), project_id);
+		approve_milestone(RawOrigin::Signed(approver.clone()), project_id);
+
+		// * validity checks *
+		let progress = MilestonesProgress::<T>::get(project_id).unwrap();
+		assert_eq!(progress.current, 1);
+		assert!(MilestoneApprovals::<T>::contains_key((project_id, 0, approver)));
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::MilestoneReleased { project_id, milestone: 0 }.into());
+	}
+
+	#[benchmark]
+	fn fail_milestone() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_id = inst.create_settled_project(
+			milestones_project_metadata::<T>(issuer.clone()),
+			issuer,
+			None,
+			default_evaluations::<T>(),
+			default_bids::<T>(),
+			default_community_contributions::<T>(),
+			vec![],
+			true,
+		);
+		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
+		whitelist_account!(anyone);
+
+		let funding_end_block = inst.get_project_details(project_id).funding_end_block.unwrap();
+		inst.jump_to_block(funding_end_block + 11u32.into());
+
+		#[extrinsic_call]
+		fail_milestone(RawOrigin::Signed(anyone), project_id);
 
 		// * validity checks *
-		let project_details = ProjectsDetails::<T>::get(project_id).unwrap();
-		assert_eq!(project_details.status, ProjectStatus::SettlementStarted(FundingOutcome::Success));
-		assert!(<T as Config>::ContributionTokenCurrency::asset_exists(project_id));
+		assert!(MilestonesProgress::<T>::get(project_id).unwrap().failed);
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::MilestoneFailed { project_id, milestone: 0 }.into());
 	}
 
-	// We have 3 logic paths:
-	// 1 - Evaluation rewarded
-	// 2 - Evaluation slashed
-	// 3 - Evaluation failed (evaluation round unsuccessful)
-	// Path 1 is the most expensive but not by far, so we only benchmark and charge for this weight
 	#[benchmark]
-	fn settle_rewarded_evaluation() {
+	fn claim_escrow_refund() {
 		// setup
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
 
 		// We can't see events at block 0
-		inst.advance_time(1u32.into());
+		inst.jump_to_block(1u32.into());
 
 		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
-		let evaluations: Vec<UserToUSDBalance<T>> = default_evaluations::<T>();
-		let evaluator: AccountIdOf<T> = evaluations[0].account.clone();
-		whitelist_account!(evaluator);
-
-		let project_id = inst.create_finished_project(
-			default_project_metadata::<T>(issuer.clone()),
+		let project_id = inst.create_settled_project(
+			milestones_project_metadata::<T>(issuer.clone()),
 			issuer,
 			None,
-			evaluations,
+			default_evaluations::<T>(),
 			default_bids::<T>(),
 			default_community_contributions::<T>(),
 			vec![],
+			true,
 		);
+		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
+		whitelist_account!(anyone);
+		let participant = default_community_contributions::<T>()[0].contributor.clone();
 
-		let evaluation_to_settle =
-			inst.execute(|| Evaluations::<T>::iter_prefix_values((project_id, evaluator.clone())).next().unwrap());
-
-		assert_ok!(<Pallet<T>>::do_start_settlement(project_id));
+		let funding_end_block = inst.get_project_details(project_id).funding_end_block.unwrap();
+		inst.jump_to_block(funding_end_block + 11u32.into());
+		Pallet::<T>::do_fail_milestone(project_id).unwrap();
+		let escrowed =
+			EscrowedParticipations::<T>::get((project_id, participant.clone(), AcceptedFundingAsset::USDT)).unwrap();
 
 		#[extrinsic_call]
-		settle_evaluation(RawOrigin::Signed(evaluator.clone()), project_id, evaluator.clone(), evaluation_to_settle.id);
+		claim_escrow_refund(RawOrigin::Signed(anyone), project_id, participant.clone());
 
 		// * validity checks *
-		// Evaluation should be removed
-		assert!(Evaluations::<T>::get((project_id, evaluator.clone(), evaluation_to_settle.id)).is_none());
-
-		// Balances
-		let project_details = ProjectsDetails::<T>::get(project_id).unwrap();
-		let reward_info = match project_details.evaluation_round_info.evaluators_outcome {
-			Some(EvaluatorsOutcome::Rewarded(reward_info)) => reward_info,
-			_ => panic!("EvaluatorsOutcome should be Rewarded"),
-		};
-		let reward = Pallet::<T>::calculate_evaluator_reward(&evaluation_to_settle, &reward_info);
-		inst.assert_ct_balance(project_id, evaluator.clone(), reward);
-
-		// Events
+		assert!(
+			EscrowedParticipations::<T>::get((project_id, participant.clone(), AcceptedFundingAsset::USDT)).is_none()
+		);
 		frame_system::Pallet::<T>::assert_last_event(
-			Event::EvaluationSettled {
+			Event::<T>::EscrowRefunded {
 				project_id,
-				account: evaluator.clone(),
-				id: evaluation_to_settle.id,
-				ct_rewarded: reward,
-				plmc_released: evaluation_to_settle.original_plmc_bond,
+				account: participant,
+				asset: AcceptedFundingAsset::USDT,
+				amount: escrowed,
 			}
 			.into(),
 		);
 	}
 
-	// We have 3 logic paths
-	// 1 - Accepted bid with no refunds (i.e. final price <= WAP, no partial acceptance)
-	// 2 - Accepted bid with refund (i.e. final price > WAP or partial acceptance)
-	// 3 - Rejected bid (i.e. bid not accepted, everything refunded, no CT/migration)
-	// Path 2 is the most expensive but not by far, so we only benchmark and charge for this weight
 	#[benchmark]
-	fn settle_accepted_bid_with_refund() {
+	fn pay_out_issuer() {
 		// setup
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
 
 		// We can't see events at block 0
-		inst.advance_time(1u32.into());
+		inst.jump_to_block(1u32.into());
 
 		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
-		let mut bidder_accounts = default_bidders::<T>().into_iter();
-
-		let project_metadata = default_project_metadata::<T>(issuer.clone());
-		// let target_wap = project_metadata.minimum_price + project_metadata.minimum_price * <PriceOf<T>>::saturating_from_rational(1, 10);
-		let mut target_bucket = <Pallet<T>>::create_bucket_from_metadata(&project_metadata.clone()).unwrap();
-		target_bucket.update(target_bucket.amount_left);
-		target_bucket.update(target_bucket.amount_left);
-
-		let bids = inst.generate_bids_from_bucket(
-			project_metadata.clone(),
-			target_bucket,
-			bidder_accounts.next().unwrap(),
-			|_| bidder_accounts.next().unwrap(),
-			AcceptedFundingAsset::USDT,
-		);
-
-		let project_id = inst.create_finished_project(
-			project_metadata.clone(),
+		let destination = Location::new(1, [Parachain(1000), AccountId32 { network: None, id: [42u8; 32] }]);
+		let mut project_metadata = default_project_metadata::<T>(issuer.clone());
+		project_metadata.funding_destination_location = Some(destination.clone());
+		let project_id = inst.create_settled_project(
+			project_metadata,
 			issuer,
 			None,
 			default_evaluations::<T>(),
-			bids.clone(),
+			default_bids::<T>(),
 			default_community_contributions::<T>(),
 			vec![],
+			true,
 		);
-
-		let wap = inst.get_project_details(project_id).weighted_average_price.unwrap();
-
-		let bidder = bids.last().unwrap().bidder.clone();
-		whitelist_account!(bidder);
-
-		assert_ok!(<Pallet<T>>::do_start_settlement(project_id));
-
-		let bid_to_settle =
-			inst.execute(|| Bids::<T>::iter_prefix_values((project_id, bidder.clone())).next().unwrap());
-
-		// Make sure a refund has to happen
-		assert!(bid_to_settle.original_ct_usd_price > wap);
+		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
+		whitelist_account!(anyone);
+		let amount = PendingIssuerPayouts::<T>::get(project_id, AcceptedFundingAsset::USDT).unwrap();
 
 		#[extrinsic_call]
-		settle_bid(RawOrigin::Signed(bidder.clone()), project_id, bidder.clone(), bid_to_settle.id);
+		pay_out_issuer(RawOrigin::Signed(anyone), project_id);
 
 		// * validity checks *
-		// Storage
-		assert!(Bids::<T>::get((project_id, bidder.clone(), bid_to_settle.id)).is_none());
-
-		// Balances
-		let ct_amount = inst.get_ct_asset_balances_for(project_id, vec![bidder.clone()])[0];
-		assert_eq!(bid_to_settle.original_ct_amount, ct_amount);
-
-		// Events
-		frame_system::Pallet::<T>::assert_last_event(
-			Event::BidSettled {
-				project_id,
-				account: bidder.clone(),
-				id: bid_to_settle.id,
-				final_ct_amount: bid_to_settle.original_ct_amount,
-				final_ct_usd_price: wap,
-			}
-			.into(),
-		);
+		// The destination is a reserve of the funding assets, but the send can still fail. If it did, it stays queued.
+		if PendingIssuerPayouts::<T>::get(project_id, AcceptedFundingAsset::USDT).is_some() {
+			frame_system::Pallet::<T>::assert_last_event(
+				Event::<T>::IssuerPayoutThroughXcmFailed {
+					project_id,
+					destination,
+					asset: AcceptedFundingAsset::USDT,
+					amount,
+				}
+				.into(),
+			);
+		}
 	}
 
-	// We have 2 logic paths
-	// 1 - Project was successful, USDT is transferred to issuer, CT minted, PLMC locked for vesting
-	// 2 - Project failed, USDT is refunded to contributor, CT is not minted, PLMC is released
-	// Path 1 is the most expensive but not by far, so we only benchmark and charge for this weight
 	#[benchmark]
-	fn settle_contribution_project_successful() {
+	fn set_funding_destination_location() {
 		// setup
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
 
 		// We can't see events at block 0
-		inst.advance_time(1u32.into());
+		inst.jump_to_block(1u32.into());
 
 		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
-		let contributions = default_community_contributions::<T>();
-		let contributor = contributions[0].contributor.clone();
-		whitelist_account!(contributor);
-
-		let project_metadata = default_project_metadata::<T>(issuer.clone());
-		let project_id = inst.create_finished_project(
+		whitelist_account!(issuer);
+		let mut project_metadata = default_project_metadata::<T>(issuer.clone());
+		project_metadata.funding_destination_location =
+			Some(Location::new(1, [Parachain(1000), AccountId32 { network: None, id: [42u8; 32] }]));
+		let project_id = inst.create_settled_project(
 			project_metadata.clone(),
-			issuer,
+			issuer.clone(),
 			None,
 			default_evaluations::<T>(),
 			default_bids::<T>(),
-			contributions,
+			default_community_contributions::<T>(),
 			vec![],
+			true,
 		);
+		let pending = PendingIssuerPayouts::<T>::get(project_id, AcceptedFundingAsset::USDT).unwrap();
+		let issuer_usdt = inst.get_free_funding_asset_balances_for(usdt_id(), vec![issuer.clone()])[0].asset_amount;
 
-		assert_ok!(<Pallet<T>>::do_start_settlement(project_id));
-
-		let contribution_to_settle =
-			inst.execute(|| Contributions::<T>::iter_prefix_values((project_id, contributor.clone())).next().unwrap());
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.policy_ipfs_cid.unwrap(),
+		);
 
+		// Removing the location is the worst case, as the queued payouts are paid out locally
 		#[extrinsic_call]
-		settle_contribution(
-			RawOrigin::Signed(contributor.clone()),
-			project_id,
-			contributor.clone(),
-			contribution_to_settle.id,
-		);
+		set_funding_destination_location(RawOrigin::Signed(issuer.clone()), jwt, project_id, None);
 
 		// * validity checks *
-		// Storage
-		assert!(Contributions::<T>::get((project_id, contributor.clone(), contribution_to_settle.id)).is_none());
-
-		// Balances
-		let ct_amount = inst.get_ct_asset_balances_for(project_id, vec![contributor.clone()])[0];
-		assert_eq!(contribution_to_settle.ct_amount, ct_amount);
-		inst.assert_plmc_held_balance(
-			contributor.clone(),
-			contribution_to_settle.plmc_bond,
-			HoldReason::Participation.into(),
-		);
+		assert!(PendingIssuerPayouts::<T>::iter_prefix(project_id).next().is_none());
 		assert_eq!(
-			VestingOf::<T>::total_scheduled_amount(&contributor, HoldReason::Participation.into()),
-			Some(contribution_to_settle.plmc_bond)
-		);
-		let funding_account = project_metadata.funding_destination_account;
-		inst.assert_funding_asset_free_balance(
-			funding_account,
-			AcceptedFundingAsset::USDT.id(),
-			contribution_to_settle.funding_asset_amount,
+			inst.get_free_funding_asset_balances_for(usdt_id(), vec![issuer])[0].asset_amount,
+			issuer_usdt + pending
 		);
-
-		// Events
 		frame_system::Pallet::<T>::assert_last_event(
-			Event::ContributionSettled {
-				project_id,
-				account: contributor.clone(),
-				id: contribution_to_settle.id,
-				ct_amount,
-			}
-			.into(),
+			Event::<T>::FundingDestinationLocationSet { project_id, location: None }.into(),
 		);
 	}
 
 	#[benchmark]
-	fn mark_project_as_settled() {
+	fn start_offchain_migration() {
 		// setup
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
 
 		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
-		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
-		whitelist_account!(anyone);
 
 		let project_metadata = default_project_metadata::<T>(issuer.clone());
 		let project_id = inst.create_settled_project(
@@ -1433,34 +2295,73 @@ mod benchmarks {
 			default_bids::<T>(),
 			default_community_contributions::<T>(),
 			vec![],
-			false,
+			true,
+		);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
 		);
 
 		#[extrinsic_call]
-		mark_project_as_settled(RawOrigin::Signed(anyone), project_id);
+		start_offchain_migration(RawOrigin::Signed(issuer), jwt, project_id);
 
 		// * validity checks *
 		let project_details = inst.get_project_details(project_id);
-		assert_eq!(project_details.status, ProjectStatus::SettlementFinished(FundingOutcome::Success));
+		assert_eq!(project_details.status, ProjectStatus::CTMigrationStarted);
+		assert_eq!(UnmigratedCounter::<T>::get(project_id), 13);
 	}
 
 	#[benchmark]
-	fn start_offchain_migration() {
+	fn confirm_offchain_migration(
+		// Amount of migrations to confirm for a single user
+		x: Linear<1, { MaxParticipationsPerUser::<T>::get() }>,
+	) {
 		// setup
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
 
 		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let participant = account::<AccountIdOf<T>>("test_participant", 0, 0);
+
+		let max_evaluations = (x / 3).min(<T as Config>::MaxEvaluationsPerUser::get());
+		let max_bids = ((x - max_evaluations) / 2).min(<T as Config>::MaxBidsPerUser::get());
+		let max_contributions = x - max_evaluations - max_bids;
+
+		let participant_evaluations = (0..max_evaluations)
+			.map(|_| UserToUSDBalance::new(participant.clone(), (100 * USD_UNIT).into()))
+			.collect_vec();
+		let participant_bids = (0..max_bids)
+			.map(|_| BidParams::new(participant.clone(), (500 * CT_UNIT).into(), 1u8, AcceptedFundingAsset::USDT))
+			.collect_vec();
+		let participant_contributions = (0..max_contributions)
+			.map(|_| {
+				ContributionParams::<T>::new(
+					participant.clone(),
+					(10 * CT_UNIT).into(),
+					1u8,
+					AcceptedFundingAsset::USDT,
+				)
+			})
+			.collect_vec();
+
+		let mut evaluations = default_evaluations::<T>();
+		evaluations.extend(participant_evaluations);
+
+		let mut bids = default_bids::<T>();
+		bids.extend(participant_bids);
 
 		let project_metadata = default_project_metadata::<T>(issuer.clone());
 		let project_id = inst.create_settled_project(
 			project_metadata.clone(),
 			issuer.clone(),
 			None,
-			default_evaluations::<T>(),
-			default_bids::<T>(),
+			evaluations,
+			bids,
 			default_community_contributions::<T>(),
-			vec![],
+			participant_contributions,
 			true,
 		);
 
@@ -1471,8 +2372,14 @@ mod benchmarks {
 			project_metadata.clone().policy_ipfs_cid.unwrap(),
 		);
 
+		<Pallet<T>>::start_offchain_migration(RawOrigin::Signed(issuer.clone()).into(), jwt.clone(), project_id)
+			.unwrap();
+
+		let participant_migrations_len = UserMigrations::<T>::get((project_id, participant.clone())).unwrap().1.len();
+		assert_eq!(participant_migrations_len as u32, x);
+
 		#[extrinsic_call]
-		start_offchain_migration(RawOrigin::Signed(issuer), jwt, project_id);
+		confirm_offchain_migration(RawOrigin::Signed(issuer), project_id, participant);
 
 		// * validity checks *
 		let project_details = inst.get_project_details(project_id);
@@ -1481,8 +2388,8 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn confirm_offchain_migration(
-		// Amount of migrations to confirm for a single user
+	fn set_migration_destination(
+		// Amount of migrations of the participant to point to the new destination
 		x: Linear<1, { MaxParticipationsPerUser::<T>::get() }>,
 	) {
 		// setup
@@ -1528,29 +2435,26 @@ mod benchmarks {
 			bids,
 			default_community_contributions::<T>(),
 			participant_contributions,
-			true,
-		);
-
-		let jwt = get_mock_jwt_with_cid(
-			issuer.clone(),
-			InvestorType::Institutional,
-			generate_did_from_account(issuer.clone()),
-			project_metadata.clone().policy_ipfs_cid.unwrap(),
+			true,
 		);
 
-		<Pallet<T>>::start_offchain_migration(RawOrigin::Signed(issuer.clone()).into(), jwt.clone(), project_id)
-			.unwrap();
-
 		let participant_migrations_len = UserMigrations::<T>::get((project_id, participant.clone())).unwrap().1.len();
 		assert_eq!(participant_migrations_len as u32, x);
 
+		// Recovering the signer of an Ethereum signature is the most expensive verification
+		let (destination, signature) =
+			inst.sign_migration_destination_with_ethereum_key(project_id, &participant, [7u8; 32]);
+
 		#[extrinsic_call]
-		confirm_offchain_migration(RawOrigin::Signed(issuer), project_id, participant);
+		set_migration_destination(RawOrigin::Signed(participant.clone()), project_id, destination.clone(), signature);
 
 		// * validity checks *
-		let project_details = inst.get_project_details(project_id);
-		assert_eq!(project_details.status, ProjectStatus::CTMigrationStarted);
-		assert_eq!(UnmigratedCounter::<T>::get(project_id), 13);
+		let (_, migrations) = UserMigrations::<T>::get((project_id, participant.clone())).unwrap();
+		assert!(migrations.iter().all(|migration| migration.origin.user == destination));
+		assert_eq!(MigrationDestinations::<T>::get(project_id, participant.clone()), Some(destination.clone()));
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::MigrationDestinationSet { project_id, account: participant, destination }.into(),
+		);
 	}
 
 	#[benchmark]
@@ -2150,4 +3054,378 @@ mod benchmarks {
 			true
 		);
 	}
+
+	#[benchmark]
+	fn register_funding_asset() -> Result<(), BenchmarkError> {
+		// setup
+		let origin = <T as Config>::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		// Worst case: the asset is already registered, and its old location has to be removed.
+		let asset = AcceptedFundingAsset(4242);
+		let old_info = FundingAssetInfo {
+			location: Location::new(1, [Parachain(4242)]),
+			decimals: 6,
+			oracle_key: 4242,
+			enabled: true,
+		};
+		crate::Pallet::<T>::do_register_funding_asset(asset, old_info.clone()).unwrap();
+		let info = FundingAssetInfo {
+			location: Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(4242)]),
+			..old_info.clone()
+		};
+
+		#[extrinsic_call]
+		register_funding_asset(origin, asset, info.clone());
+
+		// * validity checks *
+		assert_eq!(FundingAssets::<T>::get(asset), Some(info.clone()));
+		assert_eq!(FundingAssetLocations::<T>::get(info.location.clone()), Some(asset));
+		assert_eq!(FundingAssetLocations::<T>::get(old_info.location), None);
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::FundingAssetRegistered { asset, info }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_funding_asset_enabled() -> Result<(), BenchmarkError> {
+		// setup
+		let origin = <T as Config>::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let asset = AcceptedFundingAsset(4242);
+		let info = FundingAssetInfo {
+			location: Location::new(1, [Parachain(4242)]),
+			decimals: 6,
+			oracle_key: 4242,
+			enabled: true,
+		};
+		crate::Pallet::<T>::do_register_funding_asset(asset, info).unwrap();
+
+		#[extrinsic_call]
+		set_funding_asset_enabled(origin, asset, false);
+
+		// * validity checks *
+		assert!(!FundingAssets::<T>::get(asset).unwrap().enabled);
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::FundingAssetStatusChanged { asset, enabled: false }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_round_duration_bounds() -> Result<(), BenchmarkError> {
+		// setup
+		let origin = <T as Config>::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let bounds = RoundDurationBounds {
+			evaluation: DurationBounds::new(1u32.into(), 100u32.into()),
+			auction: DurationBounds::new(1u32.into(), 100u32.into()),
+			community: DurationBounds::new(1u32.into(), 100u32.into()),
+			remainder: DurationBounds::new(1u32.into(), 100u32.into()),
+		};
+
+		#[extrinsic_call]
+		set_round_duration_bounds(origin, bounds);
+
+		// * validity checks *
+		assert_eq!(RoundDurationLimits::<T>::get(), bounds);
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::RoundDurationBoundsSet { bounds }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn pause_project() -> Result<(), BenchmarkError> {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let origin = <T as Config>::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_evaluating_project(project_metadata, issuer, None);
+
+		#[extrinsic_call]
+		pause_project(origin, project_id);
+
+		// * validity checks *
+		assert!(PausedProjects::<T>::contains_key(project_id));
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::ProjectPaused { project_id }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resume_project() -> Result<(), BenchmarkError> {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let origin = <T as Config>::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_evaluating_project(project_metadata, issuer, None);
+		inst.execute(|| crate::Pallet::<T>::do_pause_project(project_id).unwrap());
+
+		#[extrinsic_call]
+		resume_project(origin, project_id);
+
+		// * validity checks *
+		assert!(!PausedProjects::<T>::contains_key(project_id));
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::ProjectResumed { project_id }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_project() -> Result<(), BenchmarkError> {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let origin = <T as Config>::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_evaluating_project(project_metadata, issuer, None);
+		inst.execute(|| crate::Pallet::<T>::do_pause_project(project_id).unwrap());
+
+		#[extrinsic_call]
+		cancel_project(origin, project_id);
+
+		// * validity checks *
+		let project_details = ProjectsDetails::<T>::get(project_id).unwrap();
+		assert_eq!(project_details.status, ProjectStatus::SettlementStarted(FundingOutcome::Failure));
+		assert!(!PausedProjects::<T>::contains_key(project_id));
+		assert!(SettlementCursors::<T>::contains_key(project_id));
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::ProjectCancelled { project_id }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_soft_cap_bounds() -> Result<(), BenchmarkError> {
+		// setup
+		let origin = <T as Config>::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let bounds = SoftCapBounds::new(Perquintill::from_percent(10), Perquintill::from_percent(90));
+
+		#[extrinsic_call]
+		set_soft_cap_bounds(origin, bounds);
+
+		// * validity checks *
+		assert_eq!(SoftCapLimits::<T>::get(), bounds);
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::SoftCapBoundsSet { bounds }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_fee_parameters() -> Result<(), BenchmarkError> {
+		// setup
+		let origin = <T as Config>::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let parameters = FeeParameters {
+			fee_brackets: BoundedVec::truncate_from(vec![(Percent::from_percent(10), u128::MAX); 10]),
+			evaluator_share: Perquintill::from_percent(30),
+			early_evaluator_share: Perquintill::from_percent(20),
+			liquidity_pools_share: Perquintill::from_percent(50),
+			long_term_holder_share: Perquintill::from_percent(20),
+		};
+
+		#[extrinsic_call]
+		set_fee_parameters(origin, parameters.clone());
+
+		// * validity checks *
+		assert_eq!(ProtocolFeeParameters::<T>::get(), parameters);
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::FeeParametersSet { parameters }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_active_projects_limit() -> Result<(), BenchmarkError> {
+		// setup
+		let origin = <T as Config>::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let limit = <T as Config>::MaxActiveProjectsPerDid::get();
+
+		#[extrinsic_call]
+		set_active_projects_limit(origin, limit);
+
+		// * validity checks *
+		assert_eq!(ActiveProjectsLimit::<T>::get(), limit);
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::ActiveProjectsLimitSet { limit }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_private_round_access() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let investor = account::<AccountIdOf<T>>("investor", 0, 0);
+		whitelist_account!(issuer);
+
+		let mut project_metadata = default_project_metadata::<T>(issuer.clone());
+		project_metadata.private_round = Some(PrivateRoundConfig {
+			allocation_percentage: Percent::from_percent(20),
+			price: project_metadata.minimum_price,
+		});
+		let project_id = inst.create_private_round_project(
+			project_metadata.clone(),
+			issuer.clone(),
+			None,
+			default_evaluations::<T>(),
+		);
+
+		let did = generate_did_from_account(investor);
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		set_private_round_access(RawOrigin::Signed(issuer), jwt, project_id, did.clone(), true);
+
+		// * validity checks *
+		assert!(PrivateRoundAllowList::<T>::get(project_id, did.clone()));
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::PrivateRoundAccessSet { project_id, did, allowed: true }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn private_contribute(
+		// How many other contributions the user did for that same project
+		x: Linear<0, { T::MaxContributionsPerUser::get() - 1 }>,
+	) {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let contributor = account::<AccountIdOf<T>>("contributor", 0, 0);
+		whitelist_account!(contributor);
+
+		let mut project_metadata = default_project_metadata::<T>(issuer.clone());
+		project_metadata.private_round = Some(PrivateRoundConfig {
+			allocation_percentage: Percent::from_percent(20),
+			price: project_metadata.minimum_price,
+		});
+		let project_id =
+			inst.create_private_round_project(project_metadata.clone(), issuer, None, default_evaluations::<T>());
+		inst.allow_list_for_private_round(project_id, vec![contributor.clone()]);
+
+		let price = project_metadata.minimum_price;
+
+		let contributions =
+			vec![
+				ContributionParams::new(contributor.clone(), (50 * CT_UNIT).into(), 1u8, AcceptedFundingAsset::USDT);
+				x as usize + 1
+			];
+
+		let plmc = inst.calculate_contributed_plmc_spent(contributions.clone(), price, false);
+		let usdt = inst.calculate_contributed_funding_asset_spent(contributions.clone(), price);
+
+		inst.mint_plmc_to(plmc.clone());
+		inst.mint_plmc_to(plmc.accounts().existential_deposits());
+		inst.mint_funding_asset_to(usdt.clone());
+
+		// do "x" contributions for this user
+		inst.contribute_for_users(project_id, contributions[1..].to_vec()).expect("All contributions are accepted");
+
+		let total_plmc_bonded = inst.sum_balance_mappings(vec![plmc.clone()]);
+		let total_usdt_locked = inst.sum_funding_asset_mappings(vec![usdt.clone()])[0].1;
+
+		let jwt = get_mock_jwt_with_cid(
+			contributor.clone(),
+			InvestorType::Retail,
+			generate_did_from_account(contributor.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		private_contribute(
+			RawOrigin::Signed(contributor.clone()),
+			jwt,
+			project_id,
+			contributions[0].amount,
+			contributions[0].multiplier,
+			contributions[0].asset,
+		);
+
+		// * validity checks *
+		// Storage
+		let stored_contributions =
+			Contributions::<T>::iter_prefix_values((project_id, contributor.clone())).collect_vec();
+		assert_eq!(stored_contributions.len(), x as usize + 1);
+		assert!(stored_contributions.iter().all(|contribution| contribution.private_round));
+
+		// Balances
+		let bonded_plmc = inst.get_reserved_plmc_balance_for(contributor.clone(), HoldReason::Participation.into());
+		assert_eq!(bonded_plmc, total_plmc_bonded);
+
+		let escrow_account = Pallet::<T>::fund_account_id(project_id);
+		let locked_usdt = inst.get_free_funding_asset_balance_for(usdt_id(), escrow_account);
+		assert_eq!(locked_usdt, total_usdt_locked);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::Contribution {
+				project_id,
+				contributor,
+				id: x,
+				ct_amount: contributions[0].amount,
+				funding_asset: AcceptedFundingAsset::USDT,
+				funding_amount: usdt[0].asset_amount,
+				plmc_bond: plmc[0].plmc_amount,
+				multiplier: contributions[0].multiplier,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn end_private_round() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.jump_to_block(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
+		whitelist_account!(anyone);
+
+		let mut project_metadata = default_project_metadata::<T>(issuer.clone());
+		project_metadata.private_round = Some(PrivateRoundConfig {
+			allocation_percentage: Percent::from_percent(20),
+			price: project_metadata.minimum_price,
+		});
+		let project_id = inst.create_private_round_project(project_metadata, issuer, None, default_evaluations::<T>());
+
+		let private_round_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+		inst.jump_to_block(private_round_end + One::one());
+
+		#[extrinsic_call]
+		end_private_round(RawOrigin::Signed(anyone), project_id);
+
+		// * validity checks *
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionRound);
+	}
 }
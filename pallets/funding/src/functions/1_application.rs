@@ -9,7 +9,7 @@ impl<T: Config> Pallet<T> {
 		issuer: AccountIdOf<T>,
 		did: Did,
 	) -> Result<(ProjectMetadataOf<T>, ProjectDetailsOf<T>, BucketOf<T>), DispatchError> {
-		if let Err(error) = project_metadata.is_valid() {
+		if let Err(error) = project_metadata.is_valid(&RoundDurationLimits::<T>::get(), &SoftCapLimits::<T>::get()) {
 			let pallet_error = match error {
 				MetadataError::PriceTooLow => Error::<T>::PriceTooLow,
 				MetadataError::TicketSizeError => Error::<T>::TicketSizeError,
@@ -21,9 +21,22 @@ impl<T: Config> Pallet<T> {
 				MetadataError::CidNotProvided => Error::<T>::CidNotProvided,
 				MetadataError::BadDecimals => Error::<T>::BadDecimals,
 				MetadataError::BadTokenomics => Error::<T>::BadTokenomics,
+				MetadataError::RoundDurationError => Error::<T>::RoundDurationError,
+				MetadataError::CtReleasePolicyError => Error::<T>::CtReleasePolicyError,
+				MetadataError::SoftCapError => Error::<T>::SoftCapError,
+				MetadataError::PrivateRoundError => Error::<T>::PrivateRoundError,
+				MetadataError::MilestoneError => Error::<T>::MilestoneError,
+				MetadataError::JurisdictionRestrictionError => Error::<T>::JurisdictionRestrictionError,
 			};
 			return Err(pallet_error.into());
 		}
+		ensure!(
+			project_metadata.participation_currencies.iter().all(|asset| Self::is_funding_asset_enabled(*asset)),
+			Error::<T>::ParticipationCurrenciesError
+		);
+		if let Some(location) = &project_metadata.funding_destination_location {
+			Self::ensure_valid_funding_destination(location, &project_metadata.participation_currencies)?;
+		}
 		let total_allocation_size = project_metadata.total_allocation_size;
 
 		let fundraising_target =
@@ -62,10 +75,10 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		// * Get variables *
 		let project_id = NextProjectId::<T>::get();
-		let maybe_active_project = DidWithActiveProjects::<T>::get(did.clone());
+		let mut active_projects = DidWithActiveProjects::<T>::get(did.clone());
 
 		// * Validity checks *
-		ensure!(maybe_active_project.is_none(), Error::<T>::HasActiveProject);
+		ensure!(active_projects.len() < ActiveProjectsLimit::<T>::get() as usize, Error::<T>::HasActiveProject);
 
 		let (project_metadata, project_details, bucket) =
 			Self::project_validation(project_metadata, issuer.clone(), did.clone())?;
@@ -87,7 +100,8 @@ impl<T: Config> Pallet<T> {
 		ProjectsDetails::<T>::insert(project_id, project_details);
 		Buckets::<T>::insert(project_id, bucket);
 		NextProjectId::<T>::mutate(|n| n.saturating_inc());
-		DidWithActiveProjects::<T>::set(did, Some(project_id));
+		active_projects.try_push(project_id).map_err(|_| Error::<T>::HasActiveProject)?;
+		DidWithActiveProjects::<T>::insert(did, active_projects);
 
 		// * Emit events *
 		Self::deposit_event(Event::ProjectCreated { project_id, issuer: issuer.clone(), metadata: project_metadata });
@@ -124,7 +138,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	#[transactional]
-	pub fn do_remove_project(issuer: AccountIdOf<T>, project_id: ProjectId, did: Did) -> DispatchResult {
+	pub fn do_remove_project(issuer: AccountIdOf<T>, project_id: ProjectId) -> DispatchResult {
 		// * Get variables *
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 
@@ -135,7 +149,7 @@ impl<T: Config> Pallet<T> {
 		// * Update storage *
 		ProjectsDetails::<T>::remove(project_id);
 		ProjectsMetadata::<T>::remove(project_id);
-		DidWithActiveProjects::<T>::set(did, None);
+		Self::remove_active_project(project_details.issuer_did, project_id);
 		Buckets::<T>::remove(project_id);
 
 		// * Emit events *
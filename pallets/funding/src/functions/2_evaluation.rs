@@ -10,6 +10,7 @@ impl<T: Config> Pallet<T> {
 	///
 	/// # Storage access
 	/// * [`ProjectsDetails`] - Checking and updating the round status, transition points and freezing the project.
+	/// * [`ProjectsFeeParameters`] - Snapshotting the current [`ProtocolFeeParameters`] for the project.
 	///
 	/// # Success path
 	/// The project information is found, its round status was in Application round, and It's not yet frozen.
@@ -31,6 +32,7 @@ impl<T: Config> Pallet<T> {
 
 		// * Update storage *
 		project_details.is_frozen = true;
+		ProjectsFeeParameters::<T>::insert(project_id, ProtocolFeeParameters::<T>::get());
 
 		// * Transition Round *
 		Self::transition_project(
@@ -38,7 +40,7 @@ impl<T: Config> Pallet<T> {
 			project_details,
 			ProjectStatus::Application,
 			ProjectStatus::EvaluationRound,
-			Some(T::EvaluationRoundDuration::get()),
+			Some(Self::evaluation_round_duration(&project_metadata)),
 			false,
 		)
 	}
@@ -46,6 +48,7 @@ impl<T: Config> Pallet<T> {
 	/// Called automatically by on_initialize.
 	/// Ends the evaluation round, and sets the current round to `AuctionInitializePeriod` if it
 	/// reached enough PLMC bonding, or to `FundingFailed` if it didn't.
+	/// Projects with a private round go to [`PrivateRound`](ProjectStatus::PrivateRound) before the auction.
 	///
 	/// # Arguments
 	/// * `project_id` - The id of the project to end the evaluation round for.
@@ -74,8 +77,12 @@ impl<T: Config> Pallet<T> {
 	#[transactional]
 	pub fn do_end_evaluation(project_id: ProjectId) -> DispatchResult {
 		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 
+		// * Validity checks *
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+
 		// * Calculate new variables *
 		let usd_total_amount_bonded = project_details.evaluation_round_info.total_bonded_usd;
 		let evaluation_target_usd =
@@ -85,20 +92,38 @@ impl<T: Config> Pallet<T> {
 		let is_funded = usd_total_amount_bonded >= evaluation_target_usd;
 
 		// * Branch in possible project paths *
-		// Successful path
-		return if is_funded {
+		// Successful path, through the private round if the project has one
+		return if is_funded && project_metadata.private_round.is_some() {
+			Self::transition_project(
+				project_id,
+				project_details,
+				ProjectStatus::EvaluationRound,
+				ProjectStatus::PrivateRound,
+				Some(T::PrivateRoundDuration::get()),
+				false,
+			)
+		} else if is_funded && !project_metadata.pricing_model.has_auction() {
+			Self::start_community_round(
+				project_id,
+				project_details,
+				&project_metadata,
+				ProjectStatus::EvaluationRound,
+				project_metadata.minimum_price,
+				false,
+			)
+		} else if is_funded {
 			Self::transition_project(
 				project_id,
 				project_details,
 				ProjectStatus::EvaluationRound,
 				ProjectStatus::AuctionRound,
-				Some(T::AuctionRoundDuration::get()),
+				Some(Self::auction_round_duration(&project_metadata)),
 				false,
 			)
 		// Unsuccessful path
 		} else {
 			let issuer_did = project_details.issuer_did.clone();
-			DidWithActiveProjects::<T>::set(issuer_did, None);
+			Self::remove_active_project(issuer_did, project_id);
 			Self::transition_project(
 				project_id,
 				project_details,
@@ -118,6 +143,7 @@ impl<T: Config> Pallet<T> {
 		usd_amount: Balance,
 		did: Did,
 		whitelisted_policy: Cid,
+		jurisdiction: Option<Jurisdiction>,
 	) -> DispatchResultWithPostInfo {
 		// * Get variables *
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
@@ -131,13 +157,15 @@ impl<T: Config> Pallet<T> {
 		let evaluation_round_info = &mut project_details.evaluation_round_info;
 		let total_evaluations_count = EvaluationCounts::<T>::get(project_id);
 		let user_evaluations_count = Evaluations::<T>::iter_prefix((project_id, evaluator)).count() as u32;
-		let project_policy = project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState)?;
+		let project_policy = project_metadata.policy_ipfs_cid.clone().ok_or(Error::<T>::ImpossibleState)?;
 
 		// * Validity Checks *
 		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
+		ensure!(project_metadata.allows_jurisdiction(jurisdiction.as_ref()), Error::<T>::JurisdictionNotAllowed);
 		ensure!(usd_amount >= T::MinUsdPerEvaluation::get(), Error::<T>::TooLow);
 		ensure!(project_details.issuer_did != did, Error::<T>::ParticipationToOwnProject);
 		ensure!(project_details.status == ProjectStatus::EvaluationRound, Error::<T>::IncorrectRound);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
 		ensure!(total_evaluations_count < T::MaxEvaluationsPerProject::get(), Error::<T>::TooManyProjectParticipations);
 		ensure!(user_evaluations_count < T::MaxEvaluationsPerUser::get(), Error::<T>::TooManyUserParticipations);
 
@@ -146,18 +174,11 @@ impl<T: Config> Pallet<T> {
 			.ok_or(Error::<T>::BadMath)?
 			.checked_mul_int(usd_amount)
 			.ok_or(Error::<T>::BadMath)?;
-		let previous_total_evaluation_bonded_usd = evaluation_round_info.total_bonded_usd;
-
-		let remaining_bond_to_reach_threshold =
-			early_evaluation_reward_threshold_usd.saturating_sub(previous_total_evaluation_bonded_usd);
-
-		let early_usd_amount = if usd_amount <= remaining_bond_to_reach_threshold {
-			usd_amount
-		} else {
-			remaining_bond_to_reach_threshold
-		};
-
-		let late_usd_amount = usd_amount.checked_sub(early_usd_amount).ok_or(Error::<T>::BadMath)?;
+		let (early_usd_amount, late_usd_amount) = Self::split_early_and_late_usd(
+			early_evaluation_reward_threshold_usd,
+			evaluation_round_info.total_bonded_usd,
+			usd_amount,
+		)?;
 
 		let new_evaluation = EvaluationInfoOf::<T> {
 			id: evaluation_id,
@@ -192,4 +213,188 @@ impl<T: Config> Pallet<T> {
 			pays_fee: Pays::Yes,
 		})
 	}
+
+	/// Bond more PLMC on an existing evaluation. The part of the new bond that still fits under the early evaluation
+	/// threshold is added to the evaluation's early USD amount, and the rest to its late USD amount.
+	#[transactional]
+	pub fn do_increase_evaluation(
+		evaluator: &AccountIdOf<T>,
+		project_id: ProjectId,
+		evaluation_id: u32,
+		usd_amount: Balance,
+		whitelisted_policy: Cid,
+		jurisdiction: Option<Jurisdiction>,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut evaluation =
+			Evaluations::<T>::get((project_id, evaluator, evaluation_id)).ok_or(Error::<T>::ParticipationNotFound)?;
+		let plmc_usd_price = T::PriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS)
+			.ok_or(Error::<T>::PriceNotFound)?;
+		let early_evaluation_reward_threshold_usd =
+			T::EvaluationSuccessThreshold::get() * project_details.fundraising_target_usd;
+		let evaluation_round_info = &mut project_details.evaluation_round_info;
+		let project_policy = project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState)?;
+
+		// * Validity Checks *
+		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
+		ensure!(project_metadata.allows_jurisdiction(jurisdiction.as_ref()), Error::<T>::JurisdictionNotAllowed);
+		ensure!(usd_amount >= T::MinUsdPerEvaluation::get(), Error::<T>::TooLow);
+		ensure!(project_details.status == ProjectStatus::EvaluationRound, Error::<T>::IncorrectRound);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+
+		// * Calculate new variables *
+		let plmc_bond = plmc_usd_price
+			.reciprocal()
+			.ok_or(Error::<T>::BadMath)?
+			.checked_mul_int(usd_amount)
+			.ok_or(Error::<T>::BadMath)?;
+		let (early_usd_amount, late_usd_amount) = Self::split_early_and_late_usd(
+			early_evaluation_reward_threshold_usd,
+			evaluation_round_info.total_bonded_usd,
+			usd_amount,
+		)?;
+
+		// * Update storage *
+		T::NativeCurrency::hold(&HoldReason::Evaluation.into(), evaluator, plmc_bond)?;
+		evaluation.original_plmc_bond.saturating_accrue(plmc_bond);
+		evaluation.current_plmc_bond.saturating_accrue(plmc_bond);
+		evaluation.early_usd_amount.saturating_accrue(early_usd_amount);
+		evaluation.late_usd_amount.saturating_accrue(late_usd_amount);
+		Evaluations::<T>::insert((project_id, evaluator, evaluation_id), evaluation);
+		evaluation_round_info.total_bonded_usd.saturating_accrue(usd_amount);
+		evaluation_round_info.total_bonded_plmc.saturating_accrue(plmc_bond);
+		ProjectsDetails::<T>::insert(project_id, project_details);
+
+		// * Emit events *
+		Self::deposit_event(Event::EvaluationIncreased {
+			project_id,
+			evaluator: evaluator.clone(),
+			id: evaluation_id,
+			plmc_amount: plmc_bond,
+		});
+
+		Ok(())
+	}
+
+	/// Withdraw `usd_amount` from an evaluation, and release the proportional part of its PLMC bond.
+	/// Withdrawing the whole amount removes the evaluation. Late USD is withdrawn first. If early USD is
+	/// withdrawn, the freed early slots go to the late USD of the other evaluations, in the order they were created.
+	#[transactional]
+	pub fn do_withdraw_evaluation(
+		evaluator: &AccountIdOf<T>,
+		project_id: ProjectId,
+		evaluation_id: u32,
+		usd_amount: Balance,
+	) -> DispatchResultWithPostInfo {
+		// * Get variables *
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut evaluation =
+			Evaluations::<T>::get((project_id, evaluator, evaluation_id)).ok_or(Error::<T>::ParticipationNotFound)?;
+		let total_usd_amount = evaluation.early_usd_amount.saturating_add(evaluation.late_usd_amount);
+		let remaining_usd_amount = total_usd_amount.saturating_sub(usd_amount);
+
+		// * Validity Checks *
+		ensure!(project_details.status == ProjectStatus::EvaluationRound, Error::<T>::IncorrectRound);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+		ensure!(usd_amount > Zero::zero(), Error::<T>::TooLow);
+		ensure!(usd_amount <= total_usd_amount, Error::<T>::TooHigh);
+		ensure!(
+			remaining_usd_amount.is_zero() || remaining_usd_amount >= T::MinUsdPerEvaluation::get(),
+			Error::<T>::TooLow
+		);
+
+		// * Calculate new variables *
+		let late_usd_withdrawn = usd_amount.min(evaluation.late_usd_amount);
+		let early_usd_withdrawn = usd_amount.saturating_sub(late_usd_withdrawn);
+		let plmc_released = if remaining_usd_amount.is_zero() {
+			evaluation.current_plmc_bond
+		} else {
+			Perquintill::from_rational(usd_amount, total_usd_amount) * evaluation.current_plmc_bond
+		};
+
+		// * Update storage *
+		T::NativeCurrency::release(&HoldReason::Evaluation.into(), evaluator, plmc_released, Precision::Exact)?;
+		if remaining_usd_amount.is_zero() {
+			Evaluations::<T>::remove((project_id, evaluator, evaluation_id));
+			EvaluationCounts::<T>::mutate(project_id, |c| *c = c.saturating_sub(1));
+			Self::forget_xcm_participant_if_done(project_id, evaluator);
+		} else {
+			evaluation.late_usd_amount.saturating_reduce(late_usd_withdrawn);
+			evaluation.early_usd_amount.saturating_reduce(early_usd_withdrawn);
+			evaluation.original_plmc_bond.saturating_reduce(plmc_released);
+			evaluation.current_plmc_bond.saturating_reduce(plmc_released);
+			Evaluations::<T>::insert((project_id, evaluator, evaluation_id), evaluation);
+		}
+		project_details.evaluation_round_info.total_bonded_usd.saturating_reduce(usd_amount);
+		project_details.evaluation_round_info.total_bonded_plmc.saturating_reduce(plmc_released);
+		ProjectsDetails::<T>::insert(project_id, project_details);
+
+		let evaluations_read = if early_usd_withdrawn.is_zero() {
+			0u32
+		} else {
+			Self::promote_late_evaluation_usd(project_id, early_usd_withdrawn)
+		};
+
+		// * Emit events *
+		Self::deposit_event(Event::EvaluationWithdrawn {
+			project_id,
+			evaluator: evaluator.clone(),
+			id: evaluation_id,
+			usd_amount,
+			plmc_released,
+		});
+
+		Ok(PostDispatchInfo {
+			actual_weight: Some(WeightInfoOf::<T>::withdraw_evaluation(evaluations_read)),
+			pays_fee: Pays::Yes,
+		})
+	}
+
+	/// Splits a new `usd_amount` bond into the part that still fits under the early evaluation threshold,
+	/// and the part that doesn't.
+	fn split_early_and_late_usd(
+		early_evaluation_reward_threshold_usd: Balance,
+		previous_total_evaluation_bonded_usd: Balance,
+		usd_amount: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let remaining_bond_to_reach_threshold =
+			early_evaluation_reward_threshold_usd.saturating_sub(previous_total_evaluation_bonded_usd);
+
+		let early_usd_amount = if usd_amount <= remaining_bond_to_reach_threshold {
+			usd_amount
+		} else {
+			remaining_bond_to_reach_threshold
+		};
+
+		let late_usd_amount = usd_amount.checked_sub(early_usd_amount).ok_or(Error::<T>::BadMath)?;
+
+		Ok((early_usd_amount, late_usd_amount))
+	}
+
+	/// Gives `freed_early_usd` of early evaluation slots to the late USD of the project's evaluations, ordered by id.
+	/// Late top-ups are recorded as new evaluations, so this is the order the late USD was bonded in.
+	/// Returns the amount of evaluations read.
+	fn promote_late_evaluation_usd(project_id: ProjectId, mut freed_early_usd: Balance) -> u32 {
+		let mut evaluations = Evaluations::<T>::iter_prefix_values((project_id,)).collect::<Vec<_>>();
+		let evaluations_read = evaluations.len() as u32;
+		evaluations.sort_by_key(|evaluation| evaluation.id);
+
+		for mut evaluation in evaluations {
+			if freed_early_usd.is_zero() {
+				break;
+			}
+			if evaluation.late_usd_amount.is_zero() {
+				continue;
+			}
+			let promoted_usd = freed_early_usd.min(evaluation.late_usd_amount);
+			evaluation.late_usd_amount.saturating_reduce(promoted_usd);
+			evaluation.early_usd_amount.saturating_accrue(promoted_usd);
+			freed_early_usd.saturating_reduce(promoted_usd);
+			Evaluations::<T>::insert((project_id, evaluation.evaluator.clone(), evaluation.id), evaluation);
+		}
+
+		evaluations_read
+	}
 }
@@ -1,48 +1,240 @@
 #[allow(clippy::wildcard_imports)]
 use super::*;
+use sp_runtime::traits::SaturatedConversion;
 
 impl<T: Config> Pallet<T> {
-	/// Decides which bids are accepted and which are rejected.
-	/// Deletes and refunds the rejected ones, and prepares the project for the WAP calculation the next block
+	/// Ends the auction round, and starts resolving which bids are accepted and which are rejected.
+	/// The WAP only depends on the bucket, so it is fixed here. The bids are then resolved in bounded chunks by
+	/// [`do_resolve_auction`](Self::do_resolve_auction), from the highest price bucket down.
+	///
+	/// # Storage access
+	/// * [`ProjectsMetadata`] - Get the auction allocation
+	/// * [`ProjectsDetails`] - Check the auction ended, and transition the project to `AuctionResolving`
+	/// * [`Buckets`] - Calculate the WAP
+	/// * [`HighestBidBuckets`] - Find the highest bucket with bids
+	/// * [`AuctionResolutions`] - Start the resolution at the highest bucket
 	#[transactional]
-	pub fn do_end_auction(project_id: ProjectId) -> DispatchResultWithPostInfo {
+	pub fn do_end_auction(project_id: ProjectId) -> DispatchResult {
 		// * Get variables *
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 		let bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
 
-		// * Calculate WAP *
+		// * Validity checks *
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+
+		// * Calculate new variables *
 		let auction_allocation_size =
 			project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
-		let weighted_token_price = bucket.calculate_wap(auction_allocation_size);
+		let resolution = AuctionResolution {
+			bucket: HighestBidBuckets::<T>::take(project_id).max(bucket.index_of(bucket.current_price)),
+			position: 0,
+			wap: bucket.calculate_wap(auction_allocation_size),
+			ct_left: auction_allocation_size,
+			usd_raised: Zero::zero(),
+		};
 
-		// * Update Storage *
-		let calculation_result = Self::decide_winning_bids(
+		// * Transition Round *
+		Self::transition_project(
 			project_id,
-			project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size,
-			weighted_token_price,
-		);
-		let updated_project_details =
-			ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
-
-		match calculation_result {
-			Err(e) => return Err(DispatchErrorWithPostInfo { post_info: ().into(), error: e }),
-			Ok((accepted_bids_count, rejected_bids_count)) => {
-				let now = <frame_system::Pallet<T>>::block_number();
-				// * Transition Round *
-				Self::transition_project(
-					project_id,
-					updated_project_details,
-					ProjectStatus::AuctionRound,
-					ProjectStatus::CommunityRound(now.saturating_add(T::CommunityRoundDuration::get())),
-					Some(T::CommunityRoundDuration::get() + T::RemainderRoundDuration::get()),
-					false,
-				)?;
-				Ok(PostDispatchInfo {
-					actual_weight: Some(WeightInfoOf::<T>::end_auction(accepted_bids_count, rejected_bids_count)),
-					pays_fee: Pays::Yes,
-				})
-			},
+			project_details,
+			ProjectStatus::AuctionRound,
+			ProjectStatus::AuctionResolving,
+			None,
+			false,
+		)?;
+
+		// * Update storage *
+		AuctionResolutions::<T>::insert(project_id, resolution);
+
+		Ok(())
+	}
+
+	/// Resolves up to `max_bids` bids of a project in `AuctionResolving`, from the highest price down.
+	/// Bids are accepted until the auction allocation runs out, the last one possibly partially, and the rest are
+	/// rejected. Once every bid is resolved, the funding reached is updated and the community round starts.
+	///
+	/// Returns the weight used.
+	///
+	/// # Storage access
+	/// * [`ProjectsDetails`] - Check the project is resolving its auction, and start the community round once done
+	/// * [`AuctionResolutions`] - Resume the resolution where it stopped, and remove it once done
+	/// * [`BucketBidCounts`], [`BidsByBucket`] - Walk the price-ordered bids, removing the index on the way
+	/// * [`Bids`] - Store the outcome of each bid
+	/// * [`DidWithWinningBids`] - Mark the DIDs with an accepted bid
+	#[transactional]
+	pub fn do_resolve_auction(project_id: ProjectId, max_bids: u32) -> Result<Weight, DispatchError> {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut resolution = AuctionResolutions::<T>::get(project_id).ok_or(Error::<T>::IncorrectRound)?;
+
+		// * Validity checks *
+		ensure!(matches!(project_details.status, ProjectStatus::AuctionResolving), Error::<T>::IncorrectRound);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+
+		// * Update storage *
+		let mut used_weight = Weight::zero();
+		let mut finished = false;
+		for _ in 0..max_bids {
+			used_weight.saturating_accrue(WeightInfoOf::<T>::resolve_bid());
+			if !Self::resolve_next_bid(project_id, &mut resolution)? {
+				finished = true;
+				break;
+			}
+		}
+
+		if finished {
+			Self::finish_auction_resolution(project_id, project_details, resolution)?;
+			used_weight.saturating_accrue(WeightInfoOf::<T>::finish_auction_resolution());
+		} else {
+			AuctionResolutions::<T>::insert(project_id, resolution);
+		}
+
+		Ok(used_weight)
+	}
+
+	/// Called by `on_idle` to resolve the auctions of the projects in `AuctionResolving`, as far as `max_weight`
+	/// allows. Paused projects are skipped. A project that fails to resolve is skipped until the others had their
+	/// turn, and left for the `resolve_auction` extrinsic, which reports the error. Returns the weight used.
+	pub fn do_automatic_auction_resolution(max_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// Reading the cursor, finding the next project to resolve, and checking it is not paused
+		let step_overhead = db_weight.reads(3);
+		let min_step_weight = WeightInfoOf::<T>::resolve_bid()
+			.saturating_add(WeightInfoOf::<T>::finish_auction_resolution())
+			.saturating_add(step_overhead);
+		let mut used_weight = Weight::zero();
+		let mut wrapped_around = false;
+
+		while used_weight.saturating_add(min_step_weight).all_lte(max_weight) {
+			used_weight.saturating_accrue(step_overhead);
+			let cursor = AuctionResolutionCursor::<T>::get();
+			let project_ids = match cursor {
+				Some(project_id) =>
+					AuctionResolutions::<T>::iter_keys_from(AuctionResolutions::<T>::hashed_key_for(project_id)),
+				None => AuctionResolutions::<T>::iter_keys(),
+			};
+			let (next_project, skipped) = Self::first_unpaused(project_ids);
+			used_weight.saturating_accrue(db_weight.reads(skipped.saturating_mul(2)));
+			let Some(project_id) = next_project else {
+				// Every project after the one that failed had its turn, so start over from the first one.
+				// Stop if that was done already, as only failing projects are left.
+				if cursor.is_none() || wrapped_around {
+					break;
+				}
+				AuctionResolutionCursor::<T>::kill();
+				used_weight.saturating_accrue(db_weight.writes(1));
+				wrapped_around = true;
+				continue;
+			};
+
+			let bids_weight =
+				max_weight.saturating_sub(used_weight).saturating_sub(WeightInfoOf::<T>::finish_auction_resolution());
+			let max_bids = bids_weight
+				.checked_div_per_component(&WeightInfoOf::<T>::resolve_bid())
+				.unwrap_or(1)
+				.saturated_into::<u32>();
+			match Self::do_resolve_auction(project_id, max_bids) {
+				Ok(weight) => used_weight.saturating_accrue(weight),
+				Err(_) => {
+					AuctionResolutionCursor::<T>::put(project_id);
+					used_weight.saturating_accrue(WeightInfoOf::<T>::resolve_bid().saturating_add(db_weight.writes(1)));
+				},
+			}
+		}
+
+		used_weight
+	}
+
+	/// Resolves the next bid of the price-ordered index, or moves down to the next bucket once the current one
+	/// is done. Returns `false` once every bucket was resolved.
+	fn resolve_next_bid(project_id: ProjectId, resolution: &mut AuctionResolutionOf<T>) -> Result<bool, DispatchError> {
+		if resolution.position >= BucketBidCounts::<T>::get(project_id, resolution.bucket) {
+			BucketBidCounts::<T>::remove(project_id, resolution.bucket);
+			if resolution.bucket.is_zero() {
+				return Ok(false);
+			}
+			resolution.bucket.saturating_dec();
+			resolution.position = Zero::zero();
+			return Ok(true);
 		}
+
+		let position = resolution.position;
+		resolution.position.saturating_inc();
+		let Some((bidder, bid_id)) = BidsByBucket::<T>::take((project_id, resolution.bucket, position)) else {
+			return Ok(true);
+		};
+		// Cancelled bids are not in `Bids` anymore
+		let Some(mut bid) = Bids::<T>::get((project_id, &bidder, bid_id)) else { return Ok(true) };
+
+		if resolution.ct_left.is_zero() {
+			bid.status = BidStatus::Rejected;
+		} else {
+			let accepted_ct_amount = bid.original_ct_amount.min(resolution.ct_left);
+			bid.status = if accepted_ct_amount == bid.original_ct_amount {
+				BidStatus::Accepted
+			} else {
+				BidStatus::PartiallyAccepted(accepted_ct_amount)
+			};
+			let final_ct_usd_price = bid.original_ct_usd_price.min(resolution.wap);
+			let usd_ticket = final_ct_usd_price.checked_mul_int(accepted_ct_amount).ok_or(Error::<T>::BadMath)?;
+			resolution.ct_left.saturating_reduce(accepted_ct_amount);
+			resolution.usd_raised = resolution.usd_raised.checked_add(usd_ticket).ok_or(Error::<T>::BadMath)?;
+			DidWithWinningBids::<T>::insert(project_id, bid.did.clone(), true);
+		}
+		Bids::<T>::insert((project_id, &bidder, bid_id), &bid);
+
+		Ok(true)
+	}
+
+	fn finish_auction_resolution(
+		project_id: ProjectId,
+		mut project_details: ProjectDetailsOf<T>,
+		resolution: AuctionResolutionOf<T>,
+	) -> DispatchResult {
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let auction_allocation_size =
+			project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
+
+		project_details
+			.remaining_contribution_tokens
+			.saturating_reduce(auction_allocation_size.saturating_sub(resolution.ct_left));
+		project_details.funding_amount_reached_usd.saturating_accrue(resolution.usd_raised);
+		AuctionResolutions::<T>::remove(project_id);
+
+		Self::start_community_round(
+			project_id,
+			project_details,
+			&project_metadata,
+			ProjectStatus::AuctionResolving,
+			resolution.wap,
+			true,
+		)
+	}
+
+	/// Starts the community round once the price of the CTs is known, either from the auction or, for projects
+	/// without one, from the minimum price.
+	pub(crate) fn start_community_round(
+		project_id: ProjectId,
+		mut project_details: ProjectDetailsOf<T>,
+		project_metadata: &ProjectMetadataOf<T>,
+		current_round: ProjectStatus<BlockNumberFor<T>>,
+		wap: PriceOf<T>,
+		skip_end_check: bool,
+	) -> DispatchResult {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let community_round_duration = Self::community_round_duration(project_metadata);
+		let remainder_round_duration = Self::remainder_round_duration(project_metadata);
+		project_details.weighted_average_price = Some(wap);
+
+		Self::transition_project(
+			project_id,
+			project_details,
+			current_round,
+			ProjectStatus::CommunityRound(now.saturating_add(community_round_duration)),
+			Some(community_round_duration.saturating_add(remainder_round_duration)),
+			skip_end_check,
+		)
 	}
 
 	/// Bid for a project in the bidding stage.
@@ -69,6 +261,8 @@ impl<T: Config> Pallet<T> {
 			investor_type,
 			did,
 			whitelisted_policy,
+			jurisdiction,
+			max_usd,
 		} = params;
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
@@ -78,13 +272,17 @@ impl<T: Config> Pallet<T> {
 		let now = <frame_system::Pallet<T>>::block_number();
 		let mut amount_to_bid = ct_amount;
 		let total_bids_for_project = BidCounts::<T>::get(project_id);
-		let project_policy = project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState)?;
+		let project_policy = project_metadata.policy_ipfs_cid.clone().ok_or(Error::<T>::ImpossibleState)?;
 
 		// User will spend at least this amount of USD for his bid(s). More if the bid gets split into different buckets
 		let min_total_ticket_size =
 			current_bucket.current_price.checked_mul_int(ct_amount).ok_or(Error::<T>::BadMath)?;
 		// weight return variables
 		let mut perform_bid_calls = 0;
+		// Ids of the pieces the bid is split into
+		let mut bid_ids = Vec::new();
+		// USD spent on all the bids, to check against the credential's limit
+		let mut total_usd_bid: Balance = Zero::zero();
 
 		let existing_bids = Bids::<T>::iter_prefix_values((project_id, bidder.clone())).collect::<Vec<_>>();
 		let existing_bids_amount = existing_bids.len() as u32;
@@ -103,6 +301,7 @@ impl<T: Config> Pallet<T> {
 
 		// * Validity checks *
 		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
+		ensure!(project_metadata.allows_jurisdiction(jurisdiction.as_ref()), Error::<T>::JurisdictionNotAllowed);
 		ensure!(
 			matches!(investor_type, InvestorType::Institutional | InvestorType::Professional),
 			DispatchError::from("Retail investors are not allowed to bid")
@@ -111,8 +310,10 @@ impl<T: Config> Pallet<T> {
 		ensure!(ct_amount > Zero::zero(), Error::<T>::TooLow);
 		ensure!(did != project_details.issuer_did, Error::<T>::ParticipationToOwnProject);
 		ensure!(matches!(project_details.status, ProjectStatus::AuctionRound), Error::<T>::IncorrectRound);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
 		ensure!(
-			project_metadata.participation_currencies.contains(&funding_asset),
+			project_metadata.participation_currencies.contains(&funding_asset) &&
+				Self::is_funding_asset_enabled(funding_asset),
 			Error::<T>::FundingAssetNotAccepted
 		);
 
@@ -153,16 +354,27 @@ impl<T: Config> Pallet<T> {
 				metadata_ticket_size_bounds,
 				total_bids_by_bidder: existing_bids_amount.saturating_add(perform_bid_calls),
 				total_bids_for_project: total_bids_for_project.saturating_add(perform_bid_calls),
+				bucket_index: current_bucket.index_of(current_bucket.current_price),
 			};
 			Self::do_perform_bid(perform_params)?;
 
+			bid_ids.push(bid_id);
 			perform_bid_calls += 1;
+			let ticket_size = current_bucket.current_price.checked_mul_int(ct_amount).ok_or(Error::<T>::BadMath)?;
+			total_usd_bid.saturating_accrue(ticket_size);
 
 			// Update the current bucket and reduce the amount to bid by the amount we just bid
 			current_bucket.update(ct_amount);
 			amount_to_bid.saturating_reduce(ct_amount);
 		}
 
+		ensure!(max_usd.map_or(true, |max_usd| total_usd_bid <= max_usd), Error::<T>::CredentialMaxUsdExceeded);
+
+		if let Some(&first_bid_id) = bid_ids.first() {
+			let bid_ids = bid_ids.try_into().map_err(|_| Error::<T>::TooManyUserParticipations)?;
+			BidGroups::<T>::insert((project_id, bidder, first_bid_id), BidGroupOf::<T> { bid_ids, investor_type });
+		}
+
 		// Note: If the bucket has been exhausted, the 'update' function has already made the 'current_bucket' point to the next one.
 		Buckets::<T>::insert(project_id, current_bucket);
 
@@ -187,6 +399,7 @@ impl<T: Config> Pallet<T> {
 			metadata_ticket_size_bounds,
 			total_bids_by_bidder,
 			total_bids_for_project,
+			bucket_index,
 		} = do_perform_bid_params;
 
 		let ticket_size = ct_usd_price.checked_mul_int(ct_amount).ok_or(Error::<T>::BadMath)?;
@@ -223,6 +436,10 @@ impl<T: Config> Pallet<T> {
 		Self::try_funding_asset_hold(&bidder, project_id, funding_asset_amount_locked, funding_asset.id())?;
 
 		Bids::<T>::insert((project_id, bidder.clone(), bid_id), &new_bid);
+		let position = BucketBidCounts::<T>::get(project_id, bucket_index);
+		BidsByBucket::<T>::insert((project_id, bucket_index, position), (bidder.clone(), bid_id));
+		BucketBidCounts::<T>::insert(project_id, bucket_index, position.saturating_add(1));
+		HighestBidBuckets::<T>::mutate(project_id, |highest| *highest = (*highest).max(bucket_index));
 		NextBidId::<T>::set(bid_id.saturating_add(One::one()));
 		BidCounts::<T>::mutate(project_id, |c| *c += 1);
 		AuctionBoughtUSD::<T>::mutate((project_id, did), |amount| *amount += ticket_size);
@@ -241,4 +458,237 @@ impl<T: Config> Pallet<T> {
 
 		Ok(new_bid)
 	}
+
+	/// Cancel a bid while the auction round is still open. A bid split across price buckets is cancelled with all
+	/// of its pieces. Returns the number of pieces cancelled.
+	///
+	/// # Arguments
+	/// * `bidder` - The account that made the bid
+	/// * `project_id` - The project the bid was made for
+	/// * `bid_id` - The id of the bid to cancel, or of any of its pieces
+	///
+	/// # Storage access
+	/// * [`Bids`] - Remove the pieces of the bid
+	/// * [`BidGroups`] - Remove the group of the pieces
+	/// * [`Buckets`] - Free the bid amount in the current bucket, without lowering its price
+	/// * [`BidCounts`] - Decrease the project's bid count
+	/// * [`AuctionBoughtUSD`] - Decrease the USD amount bid by the bidder's DID
+	/// * [`XcmParticipantLocations`] - Where the funding asset is refunded to, for bidders that joined through XCM
+	#[transactional]
+	pub fn do_cancel_bid(bidder: &AccountIdOf<T>, project_id: ProjectId, bid_id: u32) -> Result<u32, DispatchError> {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut current_bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
+		let (group_id, bid_ids, _) = Self::bid_pieces(bidder, project_id, bid_id, None)?;
+
+		// * Validity checks *
+		ensure!(matches!(project_details.status, ProjectStatus::AuctionRound), Error::<T>::IncorrectRound);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+
+		// * Update storage *
+		for piece_id in &bid_ids {
+			Self::cancel_bid_piece(bidder, project_id, *piece_id, &mut current_bucket)?;
+		}
+		if let Some(group_id) = group_id {
+			BidGroups::<T>::remove((project_id, bidder, group_id));
+		}
+		Buckets::<T>::insert(project_id, current_bucket);
+		Self::forget_xcm_participant_if_done(project_id, bidder);
+
+		Ok(bid_ids.len().saturated_into())
+	}
+
+	/// Lower the CT amount of a bid while the auction round is still open.
+	/// CTs are removed from the highest price pieces of a split bid first, dropping the pieces left empty. Each piece
+	/// keeps its original price, and the PLMC bond and funding asset are released pro rata.
+	/// Returns the number of pieces reduced or dropped.
+	///
+	/// # Arguments
+	/// * `bidder` - The account that made the bid
+	/// * `project_id` - The project the bid was made for
+	/// * `bid_id` - The id of the bid to reduce, or of any of its pieces
+	/// * `new_ct_amount` - The new amount of CTs of the whole bid. Must be lower than the current one
+	/// * `investor_type` - The bidder's credential type. Only used for bids placed before pieces were grouped, which
+	///   do not store the type they were placed with
+	/// * `jurisdiction` - The bidder's credential jurisdiction, which the project has to still allow
+	///
+	/// # Storage access
+	/// * [`Bids`] - Update the pieces with the new amounts, and remove the ones left empty
+	/// * [`BidGroups`] - Remove the dropped pieces from the group
+	/// * [`Buckets`] - Free the amount removed from the bid in the current bucket
+	/// * [`AuctionBoughtUSD`] - Decrease the USD amount bid by the bidder's DID
+	/// * [`XcmParticipantLocations`] - Where the funding asset is refunded to, for bidders that joined through XCM
+	#[transactional]
+	pub fn do_reduce_bid(
+		bidder: &AccountIdOf<T>,
+		project_id: ProjectId,
+		bid_id: u32,
+		new_ct_amount: Balance,
+		investor_type: InvestorType,
+		jurisdiction: Option<Jurisdiction>,
+	) -> Result<u32, DispatchError> {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut current_bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
+		let (group_id, bid_ids, investor_type) = Self::bid_pieces(bidder, project_id, bid_id, Some(investor_type))?;
+		let pieces = bid_ids
+			.iter()
+			.map(|piece_id| Bids::<T>::get((project_id, bidder, *piece_id)).ok_or(Error::<T>::ParticipationNotFound))
+			.collect::<Result<Vec<_>, _>>()?;
+		let total_ct_amount =
+			pieces.iter().fold(Balance::zero(), |total, bid| total.saturating_add(bid.original_ct_amount));
+
+		let metadata_ticket_size_bounds = match investor_type {
+			Some(InvestorType::Institutional) => project_metadata.bidding_ticket_sizes.institutional,
+			Some(InvestorType::Professional) => project_metadata.bidding_ticket_sizes.professional,
+			_ => return Err(Error::<T>::WrongInvestorType.into()),
+		};
+
+		// * Validity checks *
+		ensure!(project_metadata.allows_jurisdiction(jurisdiction.as_ref()), Error::<T>::JurisdictionNotAllowed);
+		ensure!(matches!(project_details.status, ProjectStatus::AuctionRound), Error::<T>::IncorrectRound);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+		// Reducing a bid to zero is a cancellation, which has its own extrinsic.
+		ensure!(new_ct_amount > Zero::zero(), Error::<T>::TooLow);
+		ensure!(new_ct_amount < total_ct_amount, Error::<T>::TooHigh);
+
+		// * Calculate new variables *
+		// The amount each piece keeps, walking down from the highest price piece
+		let mut ct_to_remove = total_ct_amount.saturating_sub(new_ct_amount);
+		let mut new_ticket_size: Balance = Zero::zero();
+		let mut reductions = Vec::new();
+		for bid in pieces.iter().rev() {
+			let removed = ct_to_remove.min(bid.original_ct_amount);
+			let kept = bid.original_ct_amount.saturating_sub(removed);
+			ct_to_remove.saturating_reduce(removed);
+			let kept_ticket_size = bid.original_ct_usd_price.checked_mul_int(kept).ok_or(Error::<T>::BadMath)?;
+			new_ticket_size = new_ticket_size.checked_add(kept_ticket_size).ok_or(Error::<T>::BadMath)?;
+			if !removed.is_zero() {
+				reductions.push((bid.id, kept));
+			}
+		}
+		ensure!(
+			metadata_ticket_size_bounds.usd_ticket_above_minimum_per_participation(new_ticket_size),
+			Error::<T>::TooLow
+		);
+
+		// * Update storage *
+		for (piece_id, kept) in &reductions {
+			if kept.is_zero() {
+				Self::cancel_bid_piece(bidder, project_id, *piece_id, &mut current_bucket)?;
+			} else {
+				Self::reduce_bid_piece(bidder, project_id, *piece_id, *kept, &mut current_bucket)?;
+			}
+		}
+		// The first piece always keeps some CTs, so the group keeps its key
+		if let Some(group_id) = group_id {
+			BidGroups::<T>::try_mutate((project_id, bidder, group_id), |maybe_group| -> DispatchResult {
+				let group = maybe_group.as_mut().ok_or(Error::<T>::ImpossibleState)?;
+				group.bid_ids.retain(|piece_id| !reductions.contains(&(*piece_id, Zero::zero())));
+				Ok(())
+			})?;
+		}
+		Buckets::<T>::insert(project_id, current_bucket);
+
+		Ok(reductions.len().saturated_into())
+	}
+
+	/// The ids of the pieces of the bid `bid_id` belongs to, with the id of their group and the investor type the bid
+	/// was placed with. Bids placed before pieces were grouped are a single piece, checked with `investor_type`.
+	fn bid_pieces(
+		bidder: &AccountIdOf<T>,
+		project_id: ProjectId,
+		bid_id: u32,
+		investor_type: Option<InvestorType>,
+	) -> Result<(Option<u32>, Vec<u32>, Option<InvestorType>), DispatchError> {
+		ensure!(Bids::<T>::contains_key((project_id, bidder, bid_id)), Error::<T>::ParticipationNotFound);
+
+		let group = match BidGroups::<T>::get((project_id, bidder, bid_id)) {
+			Some(group) => Some((bid_id, group)),
+			None => BidGroups::<T>::iter_prefix((project_id, bidder.clone()))
+				.find(|(_, group)| group.bid_ids.contains(&bid_id)),
+		};
+
+		Ok(match group {
+			Some((group_id, group)) => (Some(group_id), group.bid_ids.into_inner(), Some(group.investor_type)),
+			None => (None, vec![bid_id], investor_type),
+		})
+	}
+
+	/// Removes one piece of a bid, refunding its funds and freeing its amount in `current_bucket`.
+	fn cancel_bid_piece(
+		bidder: &AccountIdOf<T>,
+		project_id: ProjectId,
+		bid_id: u32,
+		current_bucket: &mut BucketOf<T>,
+	) -> DispatchResult {
+		let bid = Bids::<T>::get((project_id, bidder, bid_id)).ok_or(Error::<T>::ParticipationNotFound)?;
+		let ticket_size =
+			bid.original_ct_usd_price.checked_mul_int(bid.original_ct_amount).ok_or(Error::<T>::BadMath)?;
+		current_bucket.free(bid.original_ct_amount);
+
+		Self::release_participation_bond(bidder, bid.plmc_bond)?;
+		Self::refund_funding_asset(project_id, bidder, bid.funding_asset_amount_locked, bid.funding_asset)?;
+		Bids::<T>::remove((project_id, bidder, bid_id));
+		BidCounts::<T>::mutate(project_id, |c| *c = c.saturating_sub(1));
+		AuctionBoughtUSD::<T>::mutate((project_id, bid.did), |amount| amount.saturating_reduce(ticket_size));
+
+		Self::deposit_event(Event::BidCancelled {
+			project_id,
+			bidder: bidder.clone(),
+			id: bid_id,
+			ct_amount: bid.original_ct_amount,
+			funding_asset: bid.funding_asset,
+			funding_amount_released: bid.funding_asset_amount_locked,
+			plmc_released: bid.plmc_bond,
+		});
+
+		Ok(())
+	}
+
+	/// Lowers one piece of a bid to `new_ct_amount`, refunding its excess funds and freeing the difference in `current_bucket`.
+	fn reduce_bid_piece(
+		bidder: &AccountIdOf<T>,
+		project_id: ProjectId,
+		bid_id: u32,
+		new_ct_amount: Balance,
+		current_bucket: &mut BucketOf<T>,
+	) -> DispatchResult {
+		let mut bid = Bids::<T>::get((project_id, bidder, bid_id)).ok_or(Error::<T>::ParticipationNotFound)?;
+		let old_ticket_size =
+			bid.original_ct_usd_price.checked_mul_int(bid.original_ct_amount).ok_or(Error::<T>::BadMath)?;
+		let new_ticket_size = bid.original_ct_usd_price.checked_mul_int(new_ct_amount).ok_or(Error::<T>::BadMath)?;
+
+		// Amounts are reduced pro rata instead of being recalculated, since PLMC and funding asset prices
+		// might have changed since the bid was made.
+		let kept_proportion = Perquintill::from_rational(new_ct_amount, bid.original_ct_amount);
+		let new_plmc_bond = kept_proportion * bid.plmc_bond;
+		let new_funding_asset_amount = kept_proportion * bid.funding_asset_amount_locked;
+		let plmc_released = bid.plmc_bond.saturating_sub(new_plmc_bond);
+		let funding_amount_released = bid.funding_asset_amount_locked.saturating_sub(new_funding_asset_amount);
+		current_bucket.free(bid.original_ct_amount.saturating_sub(new_ct_amount));
+
+		Self::release_participation_bond(bidder, plmc_released)?;
+		Self::refund_funding_asset(project_id, bidder, funding_amount_released, bid.funding_asset)?;
+		bid.original_ct_amount = new_ct_amount;
+		bid.plmc_bond = new_plmc_bond;
+		bid.funding_asset_amount_locked = new_funding_asset_amount;
+		Bids::<T>::insert((project_id, bidder, bid_id), &bid);
+		AuctionBoughtUSD::<T>::mutate((project_id, bid.did.clone()), |amount| {
+			amount.saturating_reduce(old_ticket_size.saturating_sub(new_ticket_size))
+		});
+
+		Self::deposit_event(Event::BidReduced {
+			project_id,
+			bidder: bidder.clone(),
+			id: bid_id,
+			ct_amount: new_ct_amount,
+			funding_asset: bid.funding_asset,
+			funding_amount_released,
+			plmc_released,
+		});
+
+		Ok(())
+	}
 }
@@ -22,6 +22,8 @@ impl<T: Config> Pallet<T> {
 			investor_type,
 			did,
 			whitelisted_policy,
+			jurisdiction,
+			max_usd,
 		} = params;
 		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 		let did_has_winning_bid = DidWithWinningBids::<T>::get(project_id, did.clone());
@@ -36,7 +38,9 @@ impl<T: Config> Pallet<T> {
 		let round_end = project_details.round_duration.end().ok_or(Error::<T>::ImpossibleState)?;
 		ensure!(!did_has_winning_bid || remainder_started, Error::<T>::UserHasWinningBid);
 		ensure!(now < round_end, Error::<T>::TooLateForRound);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
 
+		let ct_usd_price = project_details.weighted_average_price.ok_or(Error::<T>::WapNotSet)?;
 		let buyable_tokens = token_amount.min(project_details.remaining_contribution_tokens);
 		if buyable_tokens.is_zero() {
 			return Err(Error::<T>::ProjectSoldOut.into());
@@ -48,28 +52,36 @@ impl<T: Config> Pallet<T> {
 			project_id,
 			project_details: &mut project_details,
 			buyable_tokens,
+			ct_usd_price,
+			private_round: false,
 			multiplier,
 			funding_asset,
 			investor_type,
 			did,
 			whitelisted_policy,
+			jurisdiction,
+			max_usd,
 		};
 
 		Self::do_perform_contribution(perform_params)
 	}
 
 	#[transactional]
-	fn do_perform_contribution(params: DoPerformContributionParams<T>) -> DispatchResultWithPostInfo {
+	pub(crate) fn do_perform_contribution(params: DoPerformContributionParams<T>) -> DispatchResultWithPostInfo {
 		let DoPerformContributionParams {
 			contributor,
 			project_id,
 			project_details,
 			buyable_tokens,
+			ct_usd_price,
+			private_round,
 			multiplier,
 			funding_asset,
 			investor_type,
 			did,
 			whitelisted_policy,
+			jurisdiction,
+			max_usd,
 		} = params;
 
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
@@ -77,8 +89,7 @@ impl<T: Config> Pallet<T> {
 			Contributions::<T>::iter_prefix_values((project_id, contributor.clone())).collect::<Vec<_>>();
 		let total_usd_bought_by_did = ContributionBoughtUSD::<T>::get((project_id, did.clone()));
 		let now = <frame_system::Pallet<T>>::block_number();
-		let ct_usd_price = project_details.weighted_average_price.ok_or(Error::<T>::WapNotSet)?;
-		let project_policy = project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState)?;
+		let project_policy = project_metadata.policy_ipfs_cid.clone().ok_or(Error::<T>::ImpossibleState)?;
 
 		let ticket_size = ct_usd_price.checked_mul_int(buyable_tokens).ok_or(Error::<T>::BadMath)?;
 		let contributor_ticket_size = match investor_type {
@@ -94,9 +105,11 @@ impl<T: Config> Pallet<T> {
 
 		// * Validity checks *
 		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
+		ensure!(project_metadata.allows_jurisdiction(jurisdiction.as_ref()), Error::<T>::JurisdictionNotAllowed);
 		ensure!(multiplier.into() <= max_multiplier && multiplier.into() > 0u8, Error::<T>::ForbiddenMultiplier);
 		ensure!(
-			project_metadata.participation_currencies.contains(&funding_asset),
+			project_metadata.participation_currencies.contains(&funding_asset) &&
+				Self::is_funding_asset_enabled(funding_asset),
 			Error::<T>::FundingAssetNotAccepted
 		);
 		ensure!(did.clone() != project_details.issuer_did, Error::<T>::ParticipationToOwnProject);
@@ -113,6 +126,7 @@ impl<T: Config> Pallet<T> {
 			contributor_ticket_size.usd_ticket_below_maximum_per_did(total_usd_bought_by_did + ticket_size),
 			Error::<T>::TooHigh
 		);
+		ensure!(max_usd.map_or(true, |max_usd| ticket_size <= max_usd), Error::<T>::CredentialMaxUsdExceeded);
 
 		let plmc_bond = Self::calculate_plmc_bond(ticket_size, multiplier)?;
 		let funding_asset_amount = Self::calculate_funding_asset_amount(ticket_size, funding_asset)?;
@@ -130,6 +144,7 @@ impl<T: Config> Pallet<T> {
 			funding_asset_amount,
 			plmc_bond,
 			when: now,
+			private_round,
 		};
 
 		// Try adding the new contribution to the system
@@ -156,7 +171,84 @@ impl<T: Config> Pallet<T> {
 		});
 
 		// return correct weight function
-		let actual_weight = Some(WeightInfoOf::<T>::contribute(caller_existing_contributions.len() as u32));
+		let existing_contributions_count = caller_existing_contributions.len() as u32;
+		let actual_weight = Some(if private_round {
+			WeightInfoOf::<T>::private_contribute(existing_contributions_count)
+		} else {
+			WeightInfoOf::<T>::contribute(existing_contributions_count)
+		});
 		Ok(PostDispatchInfo { actual_weight, pays_fee: Pays::Yes })
 	}
+
+	/// Withdraw a contribution while its cooling-off period is still running.
+	/// Contributions can only be withdrawn in the round they were made in, so private round contributions give their
+	/// CTs back to the private allocation.
+	///
+	/// # Arguments
+	/// * contributor: The account that made the contribution
+	/// * project_id: The identifier of the project
+	/// * contribution_id: The identifier of the contribution to withdraw
+	///
+	/// # Storage access
+	/// * [`Contributions`] - Remove the contribution
+	/// * [`XcmParticipantLocations`] - Where the funding asset is refunded to, for contributors that joined through XCM
+	/// * [`ProjectsDetails`] - Give back the CTs to the round and lower the funding reached
+	/// * [`ContributionBoughtUSD`] - Decrease the USD amount bought by the contributor's DID
+	#[transactional]
+	pub fn do_withdraw_contribution(
+		contributor: &AccountIdOf<T>,
+		project_id: ProjectId,
+		contribution_id: u32,
+	) -> DispatchResult {
+		// * Get variables *
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let contribution = Contributions::<T>::get((project_id, contributor, contribution_id))
+			.ok_or(Error::<T>::ParticipationNotFound)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		let round_end = project_details.round_duration.end().ok_or(Error::<T>::ImpossibleState)?;
+		let cooling_off_end = contribution.when.saturating_add(T::ContributionCoolingOffPeriod::get());
+
+		// * Validity checks *
+		ensure!(
+			matches!(project_details.status, ProjectStatus::PrivateRound | ProjectStatus::CommunityRound(..)),
+			Error::<T>::IncorrectRound
+		);
+		ensure!(
+			contribution.private_round == matches!(project_details.status, ProjectStatus::PrivateRound),
+			Error::<T>::ContributionRoundOver
+		);
+		ensure!(now < round_end, Error::<T>::TooLateForRound);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+		ensure!(now < cooling_off_end, Error::<T>::CoolingOffPeriodOver);
+
+		// * Update storage *
+		Self::release_participation_bond(contributor, contribution.plmc_bond)?;
+		Self::refund_funding_asset(
+			project_id,
+			contributor,
+			contribution.funding_asset_amount,
+			contribution.funding_asset,
+		)?;
+		Contributions::<T>::remove((project_id, contributor, contribution_id));
+		Self::forget_xcm_participant_if_done(project_id, contributor);
+		ContributionBoughtUSD::<T>::mutate((project_id, contribution.did), |amount| {
+			amount.saturating_reduce(contribution.usd_contribution_amount)
+		});
+		project_details.remaining_contribution_tokens.saturating_accrue(contribution.ct_amount);
+		project_details.funding_amount_reached_usd.saturating_reduce(contribution.usd_contribution_amount);
+		ProjectsDetails::<T>::insert(project_id, project_details);
+
+		// * Emit events *
+		Self::deposit_event(Event::ContributionWithdrawn {
+			project_id,
+			contributor: contributor.clone(),
+			id: contribution_id,
+			ct_amount: contribution.ct_amount,
+			funding_asset: contribution.funding_asset,
+			funding_amount_released: contribution.funding_asset_amount,
+			plmc_released: contribution.plmc_bond,
+		});
+
+		Ok(())
+	}
 }
@@ -35,6 +35,7 @@ impl<T: Config> Pallet<T> {
 	pub fn do_end_funding(project_id: ProjectId) -> DispatchResult {
 		// * Get variables *
 		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let remaining_cts = project_details.remaining_contribution_tokens;
 		let now = <frame_system::Pallet<T>>::block_number();
 		let issuer_did = project_details.issuer_did.clone();
@@ -47,6 +48,7 @@ impl<T: Config> Pallet<T> {
 				project_details.round_duration.ended(now) && matches!(project_details.status, ProjectStatus::CommunityRound(..)),
 			Error::<T>::TooEarlyForRound
 		);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
 
 		// * Calculate new variables *
 		let funding_target = project_details.fundraising_target_usd;
@@ -54,9 +56,9 @@ impl<T: Config> Pallet<T> {
 		let funding_ratio = Perquintill::from_rational(funding_reached, funding_target);
 
 		// * Update Storage *
-		DidWithActiveProjects::<T>::set(issuer_did, None);
+		Self::remove_active_project(issuer_did, project_id);
 
-		let next_status = if funding_ratio < T::FundingSuccessThreshold::get() {
+		let next_status = if funding_ratio < Self::success_threshold(&project_metadata) {
 			project_details.evaluation_round_info.evaluators_outcome = Some(EvaluatorsOutcome::Slashed);
 			ProjectStatus::FundingFailed
 		} else {
@@ -69,4 +71,60 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Called by governance to stop a project, e.g. when its issuer turns out to be fraudulent.
+	/// Any project that did not start its settlement can be cancelled.
+	///
+	/// The project is marked as failed without slashing its evaluators, and its settlement is started right away.
+	/// Evaluators get their whole PLMC bond back, bidders and contributors get all their PLMC and funding assets back,
+	/// and no contribution tokens are minted.
+	#[transactional]
+	pub fn do_cancel_project(project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let issuer_did = project_details.issuer_did.clone();
+		let current_status = project_details.status.clone();
+
+		// * Validity checks *
+		ensure!(
+			matches!(
+				current_status,
+				ProjectStatus::Application |
+					ProjectStatus::EvaluationRound |
+					ProjectStatus::PrivateRound |
+					ProjectStatus::AuctionRound |
+					ProjectStatus::AuctionResolving |
+					ProjectStatus::CommunityRound(..) |
+					ProjectStatus::FundingFailed |
+					ProjectStatus::FundingSuccessful
+			),
+			Error::<T>::ProjectNotCancellable
+		);
+
+		// * Update storage *
+		Self::remove_active_project(issuer_did, project_id);
+		PausedProjects::<T>::remove(project_id);
+		// Bids are refunded on settlement, and are never resolved
+		AuctionResolutions::<T>::remove(project_id);
+		let _ = BidsByBucket::<T>::clear_prefix((project_id,), T::MaxBidsPerProject::get(), None);
+		let _ = BucketBidCounts::<T>::clear_prefix(project_id, T::MaxBidsPerProject::get(), None);
+		HighestBidBuckets::<T>::remove(project_id);
+
+		// No evaluators outcome means the evaluators get their full bond back
+		project_details.evaluation_round_info.evaluators_outcome = None;
+		Self::transition_project(
+			project_id,
+			project_details,
+			current_status,
+			ProjectStatus::FundingFailed,
+			None,
+			true,
+		)?;
+		Self::do_start_settlement(project_id)?;
+
+		// * Emit events *
+		Self::deposit_event(Event::ProjectCancelled { project_id });
+
+		Ok(())
+	}
 }
@@ -4,20 +4,22 @@ use crate::{traits::VestingDurationCalculation, Balance};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
+	storage::with_storage_layer,
 	traits::{
 		fungible::MutateHold as FungibleMutateHold,
 		fungibles::Mutate as FungiblesMutate,
 		tokens::{Fortitude, Precision, Preservation, Restriction},
 		Get,
 	},
+	weights::Weight,
 };
 use on_slash_vesting::OnSlash;
 use polimec_common::{
-	migration_types::{MigrationInfo, MigrationOrigin, MigrationStatus, ParticipationType},
+	migration_types::{CtReleasePolicy, MigrationInfo, MigrationOrigin, MigrationStatus, ParticipationType},
 	ReleaseSchedule,
 };
 use sp_runtime::{
-	traits::{Convert, Zero},
+	traits::{Convert, One, Zero},
 	Perquintill,
 };
 
@@ -25,10 +27,12 @@ impl<T: Config> Pallet<T> {
 	#[transactional]
 	pub fn do_start_settlement(project_id: ProjectId) -> DispatchResult {
 		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
-		let token_information =
-			ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?.token_information;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let token_information = project_metadata.token_information;
 		let now = <frame_system::Pallet<T>>::block_number();
 
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+
 		project_details.funding_end_block = Some(now);
 
 		let escrow_account = Self::fund_account_id(project_id);
@@ -52,16 +56,47 @@ impl<T: Config> Pallet<T> {
 			let (liquidity_pools_ct_amount, long_term_holder_bonus_ct_amount) =
 				Self::generate_liquidity_pools_and_long_term_holder_rewards(project_id)?;
 
+			// The long-term-holder pot stays in the treasury until the holders claim their share
 			T::ContributionTokenCurrency::mint_into(
 				project_id,
 				&contribution_token_treasury_account,
 				long_term_holder_bonus_ct_amount,
 			)?;
-			T::ContributionTokenCurrency::mint_into(
+			LongTermHolderPots::<T>::insert(
 				project_id,
-				&contribution_token_treasury_account,
-				liquidity_pools_ct_amount,
-			)?;
+				LongTermHolderPot { reward: long_term_holder_bonus_ct_amount, total_held: Zero::zero() },
+			);
+
+			// The liquidity-pool pot is reserved for the LP account, and migrated with the participants' CTs
+			let liquidity_pools_account = T::LiquidityPoolsAccount::get();
+			if !liquidity_pools_ct_amount.is_zero() {
+				if !T::ContributionTokenCurrency::contains(&project_id, &liquidity_pools_account) {
+					T::ContributionTokenCurrency::touch(
+						project_id,
+						&liquidity_pools_account,
+						&liquidity_pools_account,
+					)?;
+				}
+				T::ContributionTokenCurrency::mint_into(
+					project_id,
+					&liquidity_pools_account,
+					liquidity_pools_ct_amount,
+				)?;
+				Self::create_migration(
+					project_id,
+					&liquidity_pools_account,
+					0,
+					ParticipationType::LiquidityPools,
+					liquidity_pools_ct_amount,
+					One::one(),
+					None,
+				)?;
+			}
+
+			// The raise is escrowed until the milestones release it
+			if project_metadata.milestones.is_some() {
+				MilestonesProgress::<T>::insert(project_id, MilestoneProgress::default());
+			}
 
 			Self::transition_project(
 				project_id,
@@ -82,21 +117,35 @@ impl<T: Config> Pallet<T> {
 			)?;
 		}
 
+		SettlementCursors::<T>::insert(project_id, SettlementCursorOf::<T>::default());
+
 		Ok(())
 	}
 
 	pub fn do_settle_evaluation(evaluation: EvaluationInfoOf<T>, project_id: ProjectId) -> DispatchResult {
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 
 		ensure!(
 			matches!(project_details.status, ProjectStatus::SettlementStarted(..)),
 			Error::<T>::SettlementNotStarted
 		);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
 
 		let (plmc_released, ct_rewarded): (Balance, Balance) =
 			match project_details.evaluation_round_info.evaluators_outcome {
 				Some(EvaluatorsOutcome::Slashed) => (Self::slash_evaluator(&evaluation)?, Zero::zero()),
-				Some(EvaluatorsOutcome::Rewarded(info)) => Self::reward_evaluator(project_id, &evaluation, &info)?,
+				Some(EvaluatorsOutcome::Rewarded(info)) => {
+					let evaluated_usd = evaluation.early_usd_amount.saturating_add(evaluation.late_usd_amount);
+					Self::record_milestone_voting_power(
+						project_id,
+						&project_metadata,
+						MilestoneApprovers::Evaluators,
+						&evaluation.evaluator,
+						evaluated_usd,
+					);
+					Self::reward_evaluator(project_id, &evaluation, &info)?
+				},
 				None => (evaluation.current_plmc_bond, Zero::zero()),
 			};
 
@@ -108,6 +157,14 @@ impl<T: Config> Pallet<T> {
 			Precision::Exact,
 		)?;
 
+		Self::record_milestone_voting_power(
+			project_id,
+			&project_metadata,
+			MilestoneApprovers::CtHolders,
+			&evaluation.evaluator,
+			ct_rewarded,
+		);
+
 		// Create Migration
 		if ct_rewarded > Zero::zero() {
 			let multiplier = MultiplierOf::<T>::try_from(1u8).map_err(|_| Error::<T>::BadMath)?;
@@ -119,9 +176,11 @@ impl<T: Config> Pallet<T> {
 				ParticipationType::Evaluation,
 				ct_rewarded,
 				duration,
+				project_metadata.ct_release_policy,
 			)?;
 		}
 		Evaluations::<T>::remove((project_id, evaluation.evaluator.clone(), evaluation.id));
+		Self::forget_xcm_participant_if_done(project_id, &evaluation.evaluator);
 
 		Self::deposit_event(Event::EvaluationSettled {
 			project_id,
@@ -139,12 +198,18 @@ impl<T: Config> Pallet<T> {
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let funding_success =
 			matches!(project_details.status, ProjectStatus::SettlementStarted(FundingOutcome::Success));
-		let wap = project_details.weighted_average_price.ok_or(Error::<T>::ImpossibleState)?;
+		// A cancelled project can be settled before the auction ended and its price was calculated
+		let wap = match project_details.weighted_average_price {
+			Some(wap) => wap,
+			None if !funding_success => bid.original_ct_usd_price,
+			None => return Err(Error::<T>::ImpossibleState.into()),
+		};
 
 		ensure!(
 			matches!(project_details.status, ProjectStatus::SettlementStarted(..)) || bid.status == BidStatus::Rejected,
 			Error::<T>::SettlementNotStarted
 		);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
 
 		// Return either the full amount to refund if bid is rejected/project failed,
 		// or a partial amount when the wap > paid price/bid is partially accepted
@@ -152,7 +217,8 @@ impl<T: Config> Pallet<T> {
 			Self::calculate_refund(&bid, funding_success, wap)?;
 
 		Self::release_participation_bond(&bid.bidder, refunded_plmc)?;
-		Self::release_funding_asset(project_id, &bid.bidder, refunded_funding_asset_amount, bid.funding_asset)?;
+		Self::refund_funding_asset(project_id, &bid.bidder, refunded_funding_asset_amount, bid.funding_asset)?;
+		let mut funding_asset_fee = Zero::zero();
 
 		if funding_success && bid.status != BidStatus::Rejected {
 			let funding_end_block = project_details.funding_end_block.ok_or(Error::<T>::ImpossibleState)?;
@@ -174,6 +240,13 @@ impl<T: Config> Pallet<T> {
 			}
 
 			Self::mint_contribution_tokens(project_id, &bid.bidder, final_ct_amount)?;
+			Self::record_milestone_voting_power(
+				project_id,
+				&project_metadata,
+				MilestoneApprovers::CtHolders,
+				&bid.bidder,
+				final_ct_amount,
+			);
 
 			Self::create_migration(
 				project_id,
@@ -182,17 +255,22 @@ impl<T: Config> Pallet<T> {
 				ParticipationType::Bid,
 				final_ct_amount,
 				vesting_info.duration,
+				project_metadata.ct_release_policy,
 			)?;
 
-			Self::release_funding_asset(
+			funding_asset_fee = Self::pay_out_funding_asset(
 				project_id,
-				&project_metadata.funding_destination_account,
+				&project_metadata,
+				&bid.bidder,
 				bid.funding_asset_amount_locked.saturating_sub(refunded_funding_asset_amount),
 				bid.funding_asset,
 			)?;
 		}
 
 		Bids::<T>::remove((project_id, bid.bidder.clone(), bid.id));
+		// Groups are keyed by their first piece, and not needed once the auction is over
+		BidGroups::<T>::remove((project_id, bid.bidder.clone(), bid.id));
+		Self::forget_xcm_participant_if_done(project_id, &bid.bidder);
 
 		Self::deposit_event(Event::BidSettled {
 			project_id,
@@ -200,6 +278,7 @@ impl<T: Config> Pallet<T> {
 			id: bid.id,
 			final_ct_amount,
 			final_ct_usd_price,
+			funding_asset_fee,
 		});
 
 		Ok(())
@@ -237,17 +316,19 @@ impl<T: Config> Pallet<T> {
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 		let mut final_ct_amount = Zero::zero();
+		let mut funding_asset_fee = Zero::zero();
 
 		let ProjectStatus::SettlementStarted(outcome) = project_details.status else {
 			return Err(Error::<T>::SettlementNotStarted.into());
 		};
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
 		let funding_end_block = project_details.funding_end_block.ok_or(Error::<T>::ImpossibleState)?;
 
 		if outcome == FundingOutcome::Failure {
 			// Release the held PLMC bond
 			Self::release_participation_bond(&contribution.contributor, contribution.plmc_bond)?;
 
-			Self::release_funding_asset(
+			Self::refund_funding_asset(
 				project_id,
 				&contribution.contributor,
 				contribution.funding_asset_amount,
@@ -276,11 +357,19 @@ impl<T: Config> Pallet<T> {
 
 			// Mint the contribution tokens
 			Self::mint_contribution_tokens(project_id, &contribution.contributor, contribution.ct_amount)?;
+			Self::record_milestone_voting_power(
+				project_id,
+				&project_metadata,
+				MilestoneApprovers::CtHolders,
+				&contribution.contributor,
+				contribution.ct_amount,
+			);
 
-			// Payout the bid funding asset amount to the project account
-			Self::release_funding_asset(
+			// Payout the contribution funding asset amount to the project account
+			funding_asset_fee = Self::pay_out_funding_asset(
 				project_id,
-				&project_metadata.funding_destination_account,
+				&project_metadata,
+				&contribution.contributor,
 				contribution.funding_asset_amount,
 				contribution.funding_asset,
 			)?;
@@ -290,21 +379,24 @@ impl<T: Config> Pallet<T> {
 				project_id,
 				&contribution.contributor,
 				contribution.id,
-				ParticipationType::Contribution,
+				contribution.participation_type(),
 				contribution.ct_amount,
 				vesting_info.duration,
+				project_metadata.ct_release_policy,
 			)?;
 
 			final_ct_amount = contribution.ct_amount;
 		}
 
 		Contributions::<T>::remove((project_id, contribution.contributor.clone(), contribution.id));
+		Self::forget_xcm_participant_if_done(project_id, &contribution.contributor);
 
 		Self::deposit_event(Event::ContributionSettled {
 			project_id,
 			account: contribution.contributor,
 			id: contribution.id,
 			ct_amount: final_ct_amount,
+			funding_asset_fee,
 		});
 
 		Ok(())
@@ -329,6 +421,7 @@ impl<T: Config> Pallet<T> {
 		);
 
 		// Mark the project as settled
+		SettlementCursors::<T>::remove(project_id);
 		Self::transition_project(
 			project_id,
 			project_details,
@@ -341,6 +434,221 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Transfers to a long-term holder its share of the long-term-holder CT pot, and adds it to its migrations.
+	///
+	/// The holder must have kept all the CTs it received on settlement for `LongTermHolderPeriod` blocks, and
+	/// its CTs must not be migrating yet. The share is pro-rata to the CTs received on settlement, so the
+	/// shares of participants that did not keep their CTs stay in the treasury.
+	///
+	/// # Storage access
+	/// * [`ProjectsDetails`] - Check the project was fully settled
+	/// * [`LongTermHoldings`] - Read and remove the CTs received by the holder
+	/// * [`LongTermHolderPots`] - Compute the holder's share of the pot
+	/// * [`UserMigrations`] - Check the holder's CTs are not being migrated, and add the reward to its migrations
+	#[transactional]
+	pub fn do_claim_long_term_holder_reward(project_id: ProjectId, holder: AccountIdOf<T>) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let holding = LongTermHoldings::<T>::get((project_id, &holder)).ok_or(Error::<T>::NoLongTermHolding)?;
+		let pot = LongTermHolderPots::<T>::get(project_id).ok_or(Error::<T>::ImpossibleState)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		let held_ct_amount = T::ContributionTokenCurrency::balance(project_id, &holder);
+		let migration_status = UserMigrations::<T>::get((project_id, &holder)).map(|(status, _)| status);
+
+		// * Validity checks *
+		ensure!(
+			matches!(
+				project_details.status,
+				ProjectStatus::SettlementFinished(FundingOutcome::Success) | ProjectStatus::CTMigrationStarted
+			),
+			Error::<T>::SettlementNotComplete
+		);
+		ensure!(
+			now >= holding.since.saturating_add(T::LongTermHolderPeriod::get()),
+			Error::<T>::LongTermHolderPeriodNotOver
+		);
+		ensure!(held_ct_amount >= holding.ct_amount, Error::<T>::LongTermHoldingNotKept);
+		ensure!(
+			matches!(migration_status, None | Some(MigrationStatus::NotStarted) | Some(MigrationStatus::Failed)),
+			Error::<T>::MigrationAlreadyStarted
+		);
+
+		// * Update storage *
+		let reward = pot.share_of(holding.ct_amount);
+		T::ContributionTokenCurrency::transfer(
+			project_id,
+			&T::ContributionTreasury::get(),
+			&holder,
+			reward,
+			Preservation::Expendable,
+		)?;
+		Self::create_migration(
+			project_id,
+			&holder,
+			0,
+			ParticipationType::LongTermHolderReward,
+			reward,
+			One::one(),
+			None,
+		)?;
+		LongTermHoldings::<T>::remove((project_id, &holder));
+
+		// * Emit events *
+		Self::deposit_event(Event::LongTermHolderRewardClaimed { project_id, account: holder, ct_amount: reward });
+
+		Ok(())
+	}
+
+	/// Stops tracking the CTs a participant received on settlement, which unfreezes them. The holder's share of
+	/// the long-term-holder pot stays in the treasury.
+	///
+	/// # Storage access
+	/// * [`LongTermHoldings`] - Remove the CTs received by the holder
+	#[transactional]
+	pub fn do_forfeit_long_term_holder_reward(project_id: ProjectId, holder: AccountIdOf<T>) -> DispatchResult {
+		// * Get variables *
+		let holding = LongTermHoldings::<T>::take((project_id, &holder)).ok_or(Error::<T>::NoLongTermHolding)?;
+
+		// * Emit events *
+		Self::deposit_event(Event::LongTermHolderRewardForfeited {
+			project_id,
+			account: holder,
+			ct_amount: holding.ct_amount,
+		});
+
+		Ok(())
+	}
+
+	/// Settles as many participations of the projects in `SettlementStarted` as `max_weight` allows, following each
+	/// project's [`SettlementCursor`]. Once all participations of a project were visited, it is marked as settled.
+	///
+	/// Paused projects are skipped. A participation that fails to settle is skipped, and has to be settled with its extrinsic.
+	/// In that case the project is left in `SettlementStarted` and [`Event::AutomaticSettlementIncomplete`] is emitted.
+	/// It is marked as settled with [`mark_project_as_settled`](Self::mark_project_as_settled) once the skipped
+	/// participations are settled.
+	pub fn do_automatic_settlement(max_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// Reading the cursor, the pause flag and the next participation, and writing back the cursor
+		let step_overhead = db_weight.reads_writes(3, 1);
+		// Bid and contribution refunds can be sent back over XCM
+		let settle_bid_weight =
+			WeightInfoOf::<T>::settle_accepted_bid_with_refund().saturating_add(Self::xcm_refund_weight());
+		let settle_contribution_weight =
+			WeightInfoOf::<T>::settle_contribution_project_successful().saturating_add(Self::xcm_refund_weight());
+		let max_step_weight = WeightInfoOf::<T>::settle_rewarded_evaluation()
+			.max(settle_bid_weight)
+			.max(settle_contribution_weight)
+			.max(WeightInfoOf::<T>::mark_project_as_settled())
+			.saturating_add(step_overhead);
+		let mut used_weight = Weight::zero();
+
+		while used_weight.saturating_add(max_step_weight).all_lte(max_weight) {
+			let (next_project, skipped) = Self::first_unpaused(SettlementCursors::<T>::iter_keys());
+			used_weight.saturating_accrue(db_weight.reads(skipped.saturating_mul(2)));
+			let Some((project_id, mut cursor)) =
+				next_project.and_then(|project_id| Some((project_id, SettlementCursors::<T>::get(project_id)?)))
+			else {
+				used_weight.saturating_accrue(db_weight.reads(1));
+				break;
+			};
+			used_weight.saturating_accrue(step_overhead);
+
+			match cursor.phase {
+				SettlementPhase::Evaluations => {
+					let mut evaluations = match &cursor.last_skipped {
+						Some((account, id)) => Evaluations::<T>::iter_prefix_from(
+							(project_id,),
+							Evaluations::<T>::hashed_key_for((project_id, account, id)),
+						),
+						None => Evaluations::<T>::iter_prefix((project_id,)),
+					};
+					if let Some(((evaluator, id), evaluation)) = evaluations.next() {
+						let result = with_storage_layer(|| Self::do_settle_evaluation(evaluation, project_id));
+						Self::skip_if_failed(
+							&mut cursor,
+							project_id,
+							ParticipationType::Evaluation,
+							evaluator,
+							id,
+							result,
+						);
+						used_weight.saturating_accrue(WeightInfoOf::<T>::settle_rewarded_evaluation());
+					} else {
+						cursor.next_phase(SettlementPhase::Bids);
+					}
+				},
+				SettlementPhase::Bids => {
+					let mut bids = match &cursor.last_skipped {
+						Some((account, id)) => Bids::<T>::iter_prefix_from(
+							(project_id,),
+							Bids::<T>::hashed_key_for((project_id, account, id)),
+						),
+						None => Bids::<T>::iter_prefix((project_id,)),
+					};
+					if let Some(((bidder, id), bid)) = bids.next() {
+						let result = with_storage_layer(|| Self::do_settle_bid(bid, project_id));
+						Self::skip_if_failed(&mut cursor, project_id, ParticipationType::Bid, bidder, id, result);
+						used_weight.saturating_accrue(settle_bid_weight);
+					} else {
+						cursor.next_phase(SettlementPhase::Contributions);
+					}
+				},
+				SettlementPhase::Contributions => {
+					let mut contributions = match &cursor.last_skipped {
+						Some((account, id)) => Contributions::<T>::iter_prefix_from(
+							(project_id,),
+							Contributions::<T>::hashed_key_for((project_id, account, id)),
+						),
+						None => Contributions::<T>::iter_prefix((project_id,)),
+					};
+					if let Some(((contributor, id), contribution)) = contributions.next() {
+						let participation_type = contribution.participation_type();
+						let result = with_storage_layer(|| Self::do_settle_contribution(contribution, project_id));
+						Self::skip_if_failed(&mut cursor, project_id, participation_type, contributor, id, result);
+						used_weight.saturating_accrue(settle_contribution_weight);
+					} else {
+						cursor.next_phase(SettlementPhase::Finished);
+					}
+				},
+				SettlementPhase::Finished => {
+					// Fails if some participations were skipped. They are left for the manual extrinsics, after which
+					// anyone can mark the project as settled.
+					if let Err(error) = with_storage_layer(|| Self::do_mark_project_as_settled(project_id)) {
+						Self::deposit_event(Event::AutomaticSettlementIncomplete { project_id, error });
+					}
+					SettlementCursors::<T>::remove(project_id);
+					used_weight.saturating_accrue(WeightInfoOf::<T>::mark_project_as_settled());
+					continue;
+				},
+			}
+
+			SettlementCursors::<T>::insert(project_id, cursor);
+		}
+
+		used_weight
+	}
+
+	/// Leaves a participation that failed to settle automatically for the manual settlement extrinsics.
+	fn skip_if_failed(
+		cursor: &mut SettlementCursorOf<T>,
+		project_id: ProjectId,
+		participation_type: ParticipationType,
+		account: AccountIdOf<T>,
+		id: u32,
+		result: DispatchResult,
+	) {
+		if let Err(error) = result {
+			cursor.last_skipped = Some((account.clone(), id));
+			Self::deposit_event(Event::AutomaticSettlementSkipped {
+				project_id,
+				participation_type,
+				account,
+				id,
+				error,
+			});
+		}
+	}
+
 	fn mint_contribution_tokens(
 		project_id: ProjectId,
 		participant: &AccountIdOf<T>,
@@ -350,10 +658,23 @@ impl<T: Config> Pallet<T> {
 			T::ContributionTokenCurrency::touch(project_id, participant, participant)?;
 		}
 		T::ContributionTokenCurrency::mint_into(project_id, participant, amount)?;
+
+		// Start tracking the holding for the long-term-holder rewards
+		let now = <frame_system::Pallet<T>>::block_number();
+		LongTermHoldings::<T>::mutate((project_id, participant), |maybe_holding| match maybe_holding {
+			Some(holding) => holding.ct_amount.saturating_accrue(amount),
+			None => *maybe_holding = Some(LongTermHolding { ct_amount: amount, since: now }),
+		});
+		LongTermHolderPots::<T>::mutate(project_id, |maybe_pot| {
+			if let Some(pot) = maybe_pot {
+				pot.total_held.saturating_accrue(amount);
+			}
+		});
+
 		Ok(())
 	}
 
-	fn release_funding_asset(
+	pub(crate) fn release_funding_asset(
 		project_id: ProjectId,
 		participant: &AccountIdOf<T>,
 		amount: Balance,
@@ -367,7 +688,28 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn release_participation_bond(participant: &AccountIdOf<T>, amount: Balance) -> DispatchResult {
+	/// Pays the funding assets of a participation out to the issuer's destination, minus the fee taken
+	/// for the `BlockchainOperationTreasury` if the project pays its fee in funding assets. Returns that fee.
+	/// Projects with milestones keep the whole amount in escrow instead, and take the fee as the milestones
+	/// release it, so that a failed milestone refunds the fee along with the rest.
+	fn pay_out_funding_asset(
+		project_id: ProjectId,
+		project_metadata: &ProjectMetadataOf<T>,
+		participant: &AccountIdOf<T>,
+		amount: Balance,
+		asset: AcceptedFundingAsset,
+	) -> Result<Balance, DispatchError> {
+		if project_metadata.milestones.is_some() {
+			Self::escrow_funding_asset(project_id, participant, amount, asset);
+			return Ok(Zero::zero());
+		}
+		let fee = Self::funding_asset_fee_percentage(project_id)? * amount;
+		Self::release_funding_asset(project_id, &T::BlockchainOperationTreasury::get(), fee, asset)?;
+		Self::pay_issuer(project_id, project_metadata, amount.saturating_sub(fee), asset)?;
+		Ok(fee)
+	}
+
+	pub(crate) fn release_participation_bond(participant: &AccountIdOf<T>, amount: Balance) -> DispatchResult {
 		if amount.is_zero() {
 			return Ok(());
 		}
@@ -429,13 +771,13 @@ impl<T: Config> Pallet<T> {
 		participation_type: ParticipationType,
 		ct_amount: Balance,
 		vesting_time: BlockNumberFor<T>,
+		release_policy: Option<CtReleasePolicy>,
 	) -> DispatchResult {
 		UserMigrations::<T>::try_mutate((project_id, origin), |maybe_migrations| -> DispatchResult {
-			let location_user =
-				Location::new(0, AccountId32 { network: None, id: T::AccountId32Conversion::convert(origin.clone()) });
+			let location_user = Self::migration_destination(project_id, origin);
 			let migration_origin = MigrationOrigin { user: location_user, id, participation_type };
 			let vesting_time: u64 = vesting_time.try_into().map_err(|_| Error::<T>::BadMath)?;
-			let migration_info: MigrationInfo = (ct_amount, vesting_time).into();
+			let migration_info: MigrationInfo = (ct_amount, vesting_time, release_policy).into();
 			let migration = Migration::new(migration_origin, migration_info);
 			if let Some((_, migrations)) = maybe_migrations {
 				migrations.try_push(migration).map_err(|_| Error::<T>::TooManyMigrations)?;
@@ -451,3 +793,13 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 }
+
+/// Set as the `Freezer` of the `pallet_assets` instance behind `ContributionTokenCurrency`, so that the CTs
+/// tracked for the long-term-holder reward cannot leave the holder's account until it claims or forfeits the reward.
+impl<T: Config> pallet_assets::FrozenBalance<ProjectId, AccountIdOf<T>, Balance> for Pallet<T> {
+	fn frozen_balance(project_id: ProjectId, who: &AccountIdOf<T>) -> Option<Balance> {
+		LongTermHoldings::<T>::get((project_id, who)).map(|holding| holding.ct_amount)
+	}
+
+	fn died(_project_id: ProjectId, _who: &AccountIdOf<T>) {}
+}
@@ -1,5 +1,6 @@
 #[allow(clippy::wildcard_imports)]
 use super::*;
+use sp_runtime::traits::Verify;
 use xcm::v4::MaxPalletNameLen;
 
 // Offchain migration functions
@@ -516,3 +517,74 @@ fn get_parachain_id(loc: &Location) -> Option<u32> {
 		_ => None,
 	}
 }
+
+// Migration destination functions
+impl<T: Config> Pallet<T> {
+	/// Registers the account on the project's chain that receives the migrated CTs of a participant, and points the
+	/// participant's existing migrations to it.
+	///
+	/// # Storage access
+	/// * [`UserMigrations`] - Check the participant's migrations were not sent yet, and update their destination
+	/// * [`MigrationDestinations`] - Store the destination, for the migrations created later
+	#[transactional]
+	pub fn do_set_migration_destination(
+		participant: AccountIdOf<T>,
+		project_id: ProjectId,
+		destination: Location,
+		signature: MigrationDestinationSignature,
+	) -> DispatchResult {
+		// * Get variables *
+		let maybe_migrations = UserMigrations::<T>::get((project_id, &participant));
+		let message = Self::migration_destination_message(project_id, &participant);
+
+		// * Validity checks *
+		if let Some((status, _)) = &maybe_migrations {
+			ensure!(*status == MigrationStatus::NotStarted, Error::<T>::MigrationAlreadyStarted);
+		}
+		let signed = match (destination.unpack(), &signature) {
+			((0, [AccountId32 { id, .. }]), MigrationDestinationSignature::Substrate(signature)) =>
+				signature.verify(&message[..], &sp_runtime::AccountId32::from(*id)),
+			((0, [AccountKey20 { key, .. }]), MigrationDestinationSignature::Ethereum(signature)) =>
+				Self::ethereum_signer(&message, signature).is_some_and(|signer| signer == *key),
+			((0, [AccountId32 { .. } | AccountKey20 { .. }]), _) => false,
+			_ => return Err(Error::<T>::BadMigrationDestination.into()),
+		};
+		ensure!(signed, Error::<T>::BadMigrationDestinationSignature);
+
+		// * Update storage *
+		if let Some((status, mut migrations)) = maybe_migrations {
+			for migration in migrations.iter_mut() {
+				migration.origin.user = destination.clone();
+			}
+			UserMigrations::<T>::insert((project_id, &participant), (status, migrations));
+		}
+		MigrationDestinations::<T>::insert(project_id, &participant, destination.clone());
+
+		// * Emit events *
+		Self::deposit_event(Event::MigrationDestinationSet { project_id, account: participant, destination });
+
+		Ok(())
+	}
+
+	/// The message a participant's migration destination signs, to prove it belongs to the participant.
+	pub fn migration_destination_message(project_id: ProjectId, participant: &AccountIdOf<T>) -> Vec<u8> {
+		(b"polimec:migration-destination", project_id, participant).encode()
+	}
+
+	/// Recovers the Ethereum address that made a `personal_sign` signature of `message`.
+	fn ethereum_signer(message: &[u8], signature: &[u8; 65]) -> Option<[u8; 20]> {
+		let mut prefixed = alloc::format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+		prefixed.extend_from_slice(message);
+		let hash = sp_io::hashing::keccak_256(&prefixed);
+		let public_key = sp_io::crypto::secp256k1_ecdsa_recover(signature, &hash).ok()?;
+		let public_key_hash = sp_io::hashing::keccak_256(&public_key);
+		public_key_hash[12..].try_into().ok()
+	}
+
+	/// The location on the project's chain that receives the migrated CTs of a participant.
+	pub(crate) fn migration_destination(project_id: ProjectId, participant: &AccountIdOf<T>) -> Location {
+		MigrationDestinations::<T>::get(project_id, participant).unwrap_or_else(|| {
+			Location::new(0, AccountId32 { network: None, id: T::AccountId32Conversion::convert(participant.clone()) })
+		})
+	}
+}
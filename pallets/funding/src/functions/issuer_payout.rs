@@ -0,0 +1,188 @@
+#[allow(clippy::wildcard_imports)]
+use super::*;
+use frame_support::traits::ContainsPair;
+use xcm_executor::traits::XcmAssetTransfers;
+
+impl<T: Config> Pallet<T> {
+	/// Pays funding assets from the project pot to the issuer. If the issuer chose a `funding_destination_location`,
+	/// they are queued in [`PendingIssuerPayouts`] instead, to be sent there over XCM with
+	/// [`do_pay_out_issuer`](Self::do_pay_out_issuer) or in `on_idle`.
+	pub(crate) fn pay_issuer(
+		project_id: ProjectId,
+		project_metadata: &ProjectMetadataOf<T>,
+		amount: Balance,
+		asset: AcceptedFundingAsset,
+	) -> DispatchResult {
+		if project_metadata.funding_destination_location.is_some() {
+			if !amount.is_zero() {
+				PendingIssuerPayouts::<T>::mutate(project_id, asset, |maybe_pending| {
+					maybe_pending.get_or_insert_with(Zero::zero).saturating_accrue(amount)
+				});
+			}
+			return Ok(());
+		}
+		Self::release_funding_asset(project_id, &project_metadata.funding_destination_account, amount, asset)
+	}
+
+	/// Checks that funding assets can be sent to `destination`: it must be an account on a chain that is a reserve
+	/// of every funding asset in `participation_currencies`, as [`send_funding_asset_to_location`](Self::send_funding_asset_to_location)
+	/// only sends assets back to their reserve.
+	pub(crate) fn ensure_valid_funding_destination(
+		destination: &Location,
+		participation_currencies: &[AcceptedFundingAsset],
+	) -> DispatchResult {
+		let (reserve, beneficiary) = destination.clone().split_last_interior();
+		ensure!(
+			matches!(beneficiary, Some(AccountId32 { .. } | AccountKey20 { .. })),
+			Error::<T>::FundingDestinationError
+		);
+		for asset in participation_currencies {
+			let asset_location = FundingAssets::<T>::get(asset).ok_or(Error::<T>::FundingAssetNotFound)?.location;
+			let asset: Asset = (asset_location, 1u128).into();
+			ensure!(
+				<<T as pallet_xcm::Config>::XcmExecutor as XcmAssetTransfers>::IsReserve::contains(&asset, &reserve),
+				Error::<T>::FundingDestinationError
+			);
+		}
+		Ok(())
+	}
+
+	/// Sends one funding asset queued for the issuer of a project to `destination`, and removes it from the queue.
+	fn send_pending_issuer_payout(
+		project_id: ProjectId,
+		destination: &Location,
+		asset: AcceptedFundingAsset,
+		amount: Balance,
+	) -> DispatchResult {
+		let message_id =
+			Self::send_funding_asset_to_location(&Self::fund_account_id(project_id), destination, amount, asset)?;
+		PendingIssuerPayouts::<T>::remove(project_id, asset);
+		Self::deposit_event(Event::IssuerPayoutSentThroughXcm {
+			project_id,
+			destination: destination.clone(),
+			asset,
+			amount,
+			message_id,
+		});
+		Ok(())
+	}
+
+	/// Sends each funding asset queued for the issuer of a project to its `funding_destination_location`.
+	/// A failed send is kept queued, so that it can be retried.
+	///
+	/// # Storage access
+	/// * [`ProjectsMetadata`] - Read the funding destination location and the funding assets of the project
+	/// * [`PendingIssuerPayouts`] - Read the queued funding assets, and remove the ones sent
+	/// * [`FundingAssets`] - Read the location of each funding asset sent
+	pub fn do_pay_out_issuer(project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let destination = project_metadata.funding_destination_location.ok_or(Error::<T>::NoPendingIssuerPayout)?;
+
+		// * Validity checks *
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+
+		// * Update storage *
+		let mut pending_any = false;
+		for asset in project_metadata.participation_currencies.iter() {
+			let Some(amount) = PendingIssuerPayouts::<T>::get(project_id, asset) else { continue };
+			pending_any = true;
+
+			// * Emit events *
+			if Self::send_pending_issuer_payout(project_id, &destination, *asset, amount).is_err() {
+				Self::deposit_event(Event::IssuerPayoutThroughXcmFailed {
+					project_id,
+					destination: destination.clone(),
+					asset: *asset,
+					amount,
+				});
+			}
+		}
+		ensure!(pending_any, Error::<T>::NoPendingIssuerPayout);
+
+		Ok(())
+	}
+
+	/// Changes where the funding assets of a project are paid out to the issuer. With a new location, the queued
+	/// payouts are sent there from now on. Without one, they are paid to the `funding_destination_account` on
+	/// Polimec right away, like the ones to come.
+	///
+	/// # Storage access
+	/// * [`ProjectsDetails`] - Check that the caller is the issuer of the project
+	/// * [`ProjectsMetadata`] - Update the funding destination location
+	/// * [`FundingAssets`] - Check that the new location is a reserve of every funding asset of the project
+	/// * [`PendingIssuerPayouts`] - Pay out the queued funding assets if the location is removed
+	#[transactional]
+	pub fn do_set_funding_destination_location(
+		issuer: AccountIdOf<T>,
+		project_id: ProjectId,
+		location: Option<Location>,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+
+		// * Validity checks *
+		ensure!(project_details.issuer_account == issuer, Error::<T>::NotIssuer);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+		if let Some(location) = &location {
+			Self::ensure_valid_funding_destination(location, &project_metadata.participation_currencies)?;
+		}
+
+		// * Update storage *
+		if location.is_none() {
+			for asset in project_metadata.participation_currencies.iter() {
+				let Some(amount) = PendingIssuerPayouts::<T>::take(project_id, asset) else { continue };
+				Self::release_funding_asset(project_id, &project_metadata.funding_destination_account, amount, *asset)?;
+			}
+		}
+		project_metadata.funding_destination_location = location.clone();
+		ProjectsMetadata::<T>::insert(project_id, project_metadata);
+
+		// * Emit events *
+		Self::deposit_event(Event::FundingDestinationLocationSet { project_id, location });
+
+		Ok(())
+	}
+
+	/// Called by `on_idle` to send the queued issuer payouts, as far as `max_weight` allows. Payouts of paused
+	/// projects are skipped, and failed sends stay queued for the next pass, like with the `pay_out_issuer`
+	/// extrinsic, which also reports the failure. Returns the weight used.
+	pub fn do_automatic_issuer_payouts(max_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// Reading the cursor, the next payout, the project metadata, whether it is paused, and the funding asset.
+		// Then sending it, removing it from the queue, and moving the cursor
+		let step_weight = Self::xcm_refund_weight().saturating_add(db_weight.reads_writes(5, 2));
+		let mut used_weight = Weight::zero();
+
+		while used_weight.saturating_add(step_weight).all_lte(max_weight) {
+			used_weight.saturating_accrue(step_weight);
+			let cursor = IssuerPayoutCursor::<T>::get();
+			let mut payouts = match cursor {
+				Some((project_id, asset)) =>
+					PendingIssuerPayouts::<T>::iter_from(PendingIssuerPayouts::<T>::hashed_key_for(project_id, asset)),
+				None => PendingIssuerPayouts::<T>::iter(),
+			};
+			// Every payout had its turn, so start over from the first one on the next block
+			let Some((project_id, asset, amount)) = payouts.next() else {
+				if cursor.is_some() {
+					IssuerPayoutCursor::<T>::kill();
+				}
+				break;
+			};
+			IssuerPayoutCursor::<T>::put((project_id, asset));
+
+			if PausedProjects::<T>::contains_key(project_id) {
+				continue;
+			}
+			let Some(destination) =
+				ProjectsMetadata::<T>::get(project_id).and_then(|metadata| metadata.funding_destination_location)
+			else {
+				continue;
+			};
+			let _ = Self::send_pending_issuer_payout(project_id, &destination, asset, amount);
+		}
+
+		used_weight
+	}
+}
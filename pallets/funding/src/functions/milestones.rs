@@ -0,0 +1,218 @@
+#[allow(clippy::wildcard_imports)]
+use super::*;
+
+impl<T: Config> Pallet<T> {
+	/// Approves the current milestone of a project on behalf of an approver. Once approvers holding more than half
+	/// of the voting power approved it, its share of each escrowed funding asset is released to the issuer, minus
+	/// the fee taken for the `BlockchainOperationTreasury` if the project pays its fee in funding assets.
+	///
+	/// # Arguments
+	/// * `approver` - The account approving the milestone
+	/// * `project_id` - The project with the milestones
+	///
+	/// # Storage access
+	/// * [`ProjectsMetadata`] - Read the milestones and the funding destination
+	/// * [`ProjectsDetails`] - Check the project was fully settled, and compute the deadline from its funding end
+	/// * [`MilestonesProgress`] - Check the milestone can still be approved, and count the approval
+	/// * [`MilestoneVotingPower`] - Read the voting power of the approver
+	/// * [`MilestoneApprovals`] - Check the approver did not approve already, and store its approval
+	/// * [`ProjectsFeeParameters`] - Compute the fee taken in funding assets
+	/// * [`Escrows`] - Release the milestone's share of each funding asset
+	#[transactional]
+	pub fn do_approve_milestone(approver: AccountIdOf<T>, project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let plan = project_metadata.milestones.as_ref().ok_or(Error::<T>::NoMilestones)?;
+		let mut progress = MilestonesProgress::<T>::get(project_id).ok_or(Error::<T>::SettlementNotComplete)?;
+		let power = MilestoneVotingPower::<T>::get((project_id, &approver)).unwrap_or_default();
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		// * Validity checks *
+		ensure!(
+			matches!(
+				project_details.status,
+				ProjectStatus::SettlementFinished(FundingOutcome::Success) |
+					ProjectStatus::CTMigrationStarted |
+					ProjectStatus::CTMigrationFinished
+			),
+			Error::<T>::SettlementNotComplete
+		);
+		ensure!(!progress.failed, Error::<T>::MilestonesFailed);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+		let deadline = Self::milestone_deadline(&project_details, plan, progress.current)?;
+		ensure!(now <= deadline, Error::<T>::MilestoneDeadlinePassed);
+		ensure!(!power.is_zero(), Error::<T>::NoMilestoneVotingPower);
+		ensure!(
+			!MilestoneApprovals::<T>::contains_key((project_id, progress.current, &approver)),
+			Error::<T>::MilestoneAlreadyApproved
+		);
+
+		// * Update storage *
+		let milestone = progress.current;
+		MilestoneApprovals::<T>::insert((project_id, milestone, &approver), ());
+		progress.approvals.saturating_accrue(power);
+
+		let approved =
+			Perquintill::from_rational(progress.approvals, progress.total_power) > Perquintill::from_percent(50);
+		if approved {
+			let is_last = milestone.saturating_add(1) as usize == plan.milestones.len();
+			let release_percentage =
+				plan.milestones.get(milestone as usize).ok_or(Error::<T>::ImpossibleState)?.release_percentage;
+			let fee_percentage = Self::funding_asset_fee_percentage(project_id)?;
+			for asset in project_metadata.participation_currencies.iter() {
+				let Some(mut escrow) = Escrows::<T>::get(project_id, asset) else { continue };
+				let unreleased = escrow.total.saturating_sub(escrow.released);
+				let amount = if is_last { unreleased } else { (release_percentage * escrow.total).min(unreleased) };
+				let fee = fee_percentage * amount;
+				Self::release_funding_asset(project_id, &T::BlockchainOperationTreasury::get(), fee, *asset)?;
+				Self::pay_issuer(project_id, &project_metadata, amount.saturating_sub(fee), *asset)?;
+				escrow.released.saturating_accrue(amount);
+				Escrows::<T>::insert(project_id, asset, escrow);
+			}
+			progress.current.saturating_inc();
+			progress.approvals = Zero::zero();
+		}
+		MilestonesProgress::<T>::insert(project_id, progress);
+
+		// * Emit events *
+		Self::deposit_event(Event::MilestoneApprovalGiven { project_id, milestone, account: approver, power });
+		if approved {
+			Self::deposit_event(Event::MilestoneReleased { project_id, milestone });
+		}
+
+		Ok(())
+	}
+
+	/// Marks the current milestone of a project as failed once its deadline passed without approval.
+	/// From then on, the participants can get back their share of what is left in escrow.
+	///
+	/// # Storage access
+	/// * [`ProjectsMetadata`] - Read the milestones
+	/// * [`ProjectsDetails`] - Check the project was fully settled, and compute the deadline from its funding end
+	/// * [`MilestonesProgress`] - Check the milestone can still fail, and mark it as failed
+	#[transactional]
+	pub fn do_fail_milestone(project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let plan = project_metadata.milestones.as_ref().ok_or(Error::<T>::NoMilestones)?;
+		let mut progress = MilestonesProgress::<T>::get(project_id).ok_or(Error::<T>::SettlementNotComplete)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		// * Validity checks *
+		// Failing before all participations are settled would refund from an incomplete escrow
+		ensure!(
+			matches!(
+				project_details.status,
+				ProjectStatus::SettlementFinished(FundingOutcome::Success) |
+					ProjectStatus::CTMigrationStarted |
+					ProjectStatus::CTMigrationFinished
+			),
+			Error::<T>::SettlementNotComplete
+		);
+		ensure!(!progress.failed, Error::<T>::MilestonesFailed);
+		let deadline = Self::milestone_deadline(&project_details, plan, progress.current)?;
+		ensure!(now > deadline, Error::<T>::MilestoneDeadlineNotPassed);
+
+		// * Update storage *
+		progress.failed = true;
+		MilestonesProgress::<T>::insert(project_id, progress);
+
+		// * Emit events *
+		Self::deposit_event(Event::MilestoneFailed { project_id, milestone: progress.current });
+
+		Ok(())
+	}
+
+	/// Refunds a participant its pro-rata share of each funding asset left in escrow after a milestone failed.
+	///
+	/// # Storage access
+	/// * [`ProjectsMetadata`] - Read the funding assets of the project
+	/// * [`MilestonesProgress`] - Check a milestone failed
+	/// * [`EscrowedParticipations`] - Read and remove the funding assets the participant put in escrow
+	/// * [`Escrows`] - Compute the share of the escrow that was not released to the issuer
+	/// * [`XcmParticipantLocations`] - Send the refund back to participants that joined through XCM
+	#[transactional]
+	pub fn do_claim_escrow_refund(project_id: ProjectId, participant: AccountIdOf<T>) -> DispatchResult {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let progress = MilestonesProgress::<T>::get(project_id).ok_or(Error::<T>::NoMilestones)?;
+
+		// * Validity checks *
+		ensure!(progress.failed, Error::<T>::MilestonesNotFailed);
+
+		// * Update storage *
+		let mut refunded_any = false;
+		for asset in project_metadata.participation_currencies.iter() {
+			let Some(escrowed) = EscrowedParticipations::<T>::take((project_id, &participant, asset)) else { continue };
+			let escrow = Escrows::<T>::get(project_id, asset).ok_or(Error::<T>::ImpossibleState)?;
+			let amount = escrow.unreleased_share_of(escrowed);
+			Self::refund_funding_asset(project_id, &participant, amount, *asset)?;
+			refunded_any = true;
+
+			// * Emit events *
+			Self::deposit_event(Event::EscrowRefunded {
+				project_id,
+				account: participant.clone(),
+				asset: *asset,
+				amount,
+			});
+		}
+		ensure!(refunded_any, Error::<T>::NoEscrowedFunds);
+		Self::forget_xcm_participant_if_done(project_id, &participant);
+
+		Ok(())
+	}
+
+	/// Keeps the funding assets paid by a participant in the project account until the milestones release them.
+	pub(crate) fn escrow_funding_asset(
+		project_id: ProjectId,
+		participant: &AccountIdOf<T>,
+		amount: Balance,
+		asset: AcceptedFundingAsset,
+	) {
+		if amount.is_zero() {
+			return;
+		}
+		Escrows::<T>::mutate(project_id, asset, |maybe_escrow| {
+			maybe_escrow.get_or_insert_with(Default::default).total.saturating_accrue(amount)
+		});
+		EscrowedParticipations::<T>::mutate((project_id, participant, asset), |maybe_escrowed| {
+			maybe_escrowed.get_or_insert_with(Zero::zero).saturating_accrue(amount)
+		});
+	}
+
+	/// Gives voting power over the milestones of a project to a participant, if the project's milestones are
+	/// approved by `approvers`.
+	pub(crate) fn record_milestone_voting_power(
+		project_id: ProjectId,
+		project_metadata: &ProjectMetadataOf<T>,
+		approvers: MilestoneApprovers,
+		account: &AccountIdOf<T>,
+		power: Balance,
+	) {
+		if power.is_zero() || project_metadata.milestones.as_ref().map(|plan| plan.approvers) != Some(approvers) {
+			return;
+		}
+		MilestoneVotingPower::<T>::mutate((project_id, account), |maybe_power| {
+			maybe_power.get_or_insert_with(Zero::zero).saturating_accrue(power)
+		});
+		MilestonesProgress::<T>::mutate(project_id, |maybe_progress| {
+			if let Some(progress) = maybe_progress {
+				progress.total_power.saturating_accrue(power);
+			}
+		});
+	}
+
+	/// The last block at which a milestone can be approved.
+	fn milestone_deadline(
+		project_details: &ProjectDetailsOf<T>,
+		plan: &MilestonePlan<BlockNumberFor<T>>,
+		milestone: u32,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		let milestone = plan.milestones.get(milestone as usize).ok_or(Error::<T>::MilestonesCompleted)?;
+		let funding_end_block = project_details.funding_end_block.ok_or(Error::<T>::ImpossibleState)?;
+		Ok(funding_end_block.saturating_add(milestone.deadline))
+	}
+}
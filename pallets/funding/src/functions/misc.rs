@@ -14,17 +14,56 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_sub_account_truncating(index.saturating_add(One::one()))
 	}
 
+	/// Checks that a call on `project_id` is allowed by the scope of its credential, and uses the credential's
+	/// nonce if it is single-use.
+	pub fn use_credential_scope(project_id: ProjectId, did: &Did, scope: &CredentialScope) -> DispatchResult {
+		ensure!(scope.allows_project(project_id), Error::<T>::CredentialProjectMismatch);
+		Self::use_credential_nonce(did, scope)
+	}
+
+	/// Like [`use_credential_scope`](Self::use_credential_scope), for the calls that are not made on an existing
+	/// project: credentials scoped to a project are rejected.
+	pub fn use_unscoped_credential(did: &Did, scope: &CredentialScope) -> DispatchResult {
+		ensure!(scope.project_id.is_none(), Error::<T>::CredentialProjectMismatch);
+		Self::use_credential_nonce(did, scope)
+	}
+
+	fn use_credential_nonce(did: &Did, scope: &CredentialScope) -> DispatchResult {
+		if let Some(nonce) = &scope.nonce {
+			ensure!(
+				<T::InvestorOrigin as EnsureOriginWithCredentials<_>>::Nonces::use_nonce(did, nonce, scope.expires_at),
+				Error::<T>::CredentialAlreadyUsed
+			);
+		}
+		Ok(())
+	}
+
 	pub fn create_bucket_from_metadata(metadata: &ProjectMetadataOf<T>) -> Result<BucketOf<T>, DispatchError> {
 		let auction_allocation_size = metadata.auction_round_allocation_percentage * metadata.total_allocation_size;
-		let bucket_delta_amount = Percent::from_percent(10) * auction_allocation_size;
-		let ten_percent_in_price: <T as Config>::Price =
-			PriceOf::<T>::checked_from_rational(1, 10).ok_or(Error::<T>::BadMath)?;
-		let bucket_delta_price: <T as Config>::Price = metadata.minimum_price.saturating_mul(ten_percent_in_price);
+		metadata
+			.pricing_model
+			.initial_bucket(auction_allocation_size, metadata.minimum_price)
+			.ok_or(Error::<T>::BadMath.into())
+	}
+
+	pub fn evaluation_round_duration(metadata: &ProjectMetadataOf<T>) -> BlockNumberFor<T> {
+		metadata.round_durations.evaluation.unwrap_or_else(T::EvaluationRoundDuration::get)
+	}
+
+	pub fn auction_round_duration(metadata: &ProjectMetadataOf<T>) -> BlockNumberFor<T> {
+		metadata.round_durations.auction.unwrap_or_else(T::AuctionRoundDuration::get)
+	}
+
+	pub fn community_round_duration(metadata: &ProjectMetadataOf<T>) -> BlockNumberFor<T> {
+		metadata.round_durations.community.unwrap_or_else(T::CommunityRoundDuration::get)
+	}
 
-		let bucket: BucketOf<T> =
-			Bucket::new(auction_allocation_size, metadata.minimum_price, bucket_delta_price, bucket_delta_amount);
+	pub fn remainder_round_duration(metadata: &ProjectMetadataOf<T>) -> BlockNumberFor<T> {
+		metadata.round_durations.remainder.unwrap_or_else(T::RemainderRoundDuration::get)
+	}
 
-		Ok(bucket)
+	pub fn success_threshold(metadata: &ProjectMetadataOf<T>) -> Perquintill {
+		metadata.soft_cap.unwrap_or_else(T::FundingSuccessThreshold::get)
 	}
 
 	pub fn calculate_plmc_bond(ticket_size: Balance, multiplier: MultiplierOf<T>) -> Result<Balance, DispatchError> {
@@ -38,14 +77,22 @@ impl<T: Config> Pallet<T> {
 			.ok_or(Error::<T>::BadMath.into())
 	}
 
+	/// The USD price of a registered funding asset, adjusted to the asset and USD decimals.
+	pub fn funding_asset_usd_price(asset: AcceptedFundingAsset) -> Result<PriceOf<T>, DispatchError> {
+		let asset_info = FundingAssets::<T>::get(asset).ok_or(Error::<T>::FundingAssetNotFound)?;
+		T::PriceProvider::get_decimals_aware_price(asset_info.oracle_key, USD_DECIMALS, asset_info.decimals)
+			.ok_or(Error::<T>::PriceNotFound.into())
+	}
+
+	pub fn is_funding_asset_enabled(asset: AcceptedFundingAsset) -> bool {
+		FundingAssets::<T>::get(asset).is_some_and(|info| info.enabled)
+	}
+
 	pub fn calculate_funding_asset_amount(
 		ticket_size: Balance,
 		asset_id: AcceptedFundingAsset,
 	) -> Result<Balance, DispatchError> {
-		let asset_id = asset_id.id();
-		let asset_decimals = T::FundingCurrency::decimals(asset_id);
-		let asset_usd_price = T::PriceProvider::get_decimals_aware_price(asset_id, USD_DECIMALS, asset_decimals)
-			.ok_or(Error::<T>::PriceNotFound)?;
+		let asset_usd_price = Self::funding_asset_usd_price(asset_id)?;
 		asset_usd_price
 			.reciprocal()
 			.and_then(|recip| recip.checked_mul_int(ticket_size))
@@ -70,65 +117,6 @@ impl<T: Config> Pallet<T> {
 		Ok(VestingInfo { total_amount: bonded_amount, amount_per_block, duration })
 	}
 
-	pub fn decide_winning_bids(
-		project_id: ProjectId,
-		auction_allocation_size: Balance,
-		wap: PriceOf<T>,
-	) -> Result<(u32, u32), DispatchError> {
-		let mut bids = Bids::<T>::iter_prefix_values((project_id,)).collect::<Vec<_>>();
-		// temp variable to store the sum of the bids
-		let mut bid_token_amount_sum = Zero::zero();
-		// sort bids by price, and equal prices sorted by id
-		bids.sort_by(|a, b| b.cmp(a));
-		let (accepted_bids, rejected_bids): (Vec<_>, Vec<_>) = bids
-			.into_iter()
-			.map(|mut bid| {
-				let buyable_amount = auction_allocation_size.saturating_sub(bid_token_amount_sum);
-				if buyable_amount.is_zero() {
-					bid.status = BidStatus::Rejected;
-				} else if bid.original_ct_amount <= buyable_amount {
-					bid_token_amount_sum.saturating_accrue(bid.original_ct_amount);
-					bid.status = BidStatus::Accepted;
-					DidWithWinningBids::<T>::mutate(project_id, bid.did.clone(), |flag| {
-						*flag = true;
-					});
-				} else {
-					bid_token_amount_sum.saturating_accrue(buyable_amount);
-					bid.status = BidStatus::PartiallyAccepted(buyable_amount);
-					DidWithWinningBids::<T>::mutate(project_id, bid.did.clone(), |flag| {
-						*flag = true;
-					});
-				}
-				Bids::<T>::insert((project_id, &bid.bidder, &bid.id), &bid);
-				bid
-			})
-			.partition(|bid| matches!(bid.status, BidStatus::Accepted | BidStatus::PartiallyAccepted(..)));
-
-		let accepted_bid_len = accepted_bids.len() as u32;
-		let total_auction_allocation_usd: Balance = accepted_bids
-			.into_iter()
-			.try_fold(Zero::zero(), |acc: Balance, bid: BidInfoOf<T>| {
-				let final_ct_usd_price = if bid.original_ct_usd_price > wap { wap } else { bid.original_ct_usd_price };
-				let final_ct_amount = bid.final_ct_amount();
-				final_ct_usd_price.checked_mul_int(final_ct_amount).and_then(|usd_ticket| acc.checked_add(usd_ticket))
-			})
-			.ok_or(Error::<T>::BadMath)?;
-
-		ProjectsDetails::<T>::mutate(project_id, |maybe_info| -> DispatchResult {
-			if let Some(info) = maybe_info {
-				info.remaining_contribution_tokens.saturating_reduce(bid_token_amount_sum);
-				info.funding_amount_reached_usd.saturating_accrue(total_auction_allocation_usd);
-				info.weighted_average_price = Some(wap);
-
-				Ok(())
-			} else {
-				Err(Error::<T>::ProjectDetailsNotFound.into())
-			}
-		})?;
-
-		Ok((accepted_bid_len, rejected_bids.len() as u32))
-	}
-
 	pub fn try_plmc_participation_lock(who: &T::AccountId, project_id: ProjectId, amount: Balance) -> DispatchResult {
 		// Check if the user has already locked tokens in the evaluation period
 		let user_evaluations = Evaluations::<T>::iter_prefix_values((project_id, who));
@@ -174,17 +162,27 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	// Calculate the total fee allocation for a project, based on the funding reached.
-	fn calculate_fee_allocation(project_id: ProjectId) -> Result<Balance, DispatchError> {
+	/// The fee parameters a project snapshotted when its evaluation started. Projects that started it before
+	/// the parameters were stored keep using the runtime defaults they were created with.
+	pub fn project_fee_parameters(project_id: ProjectId) -> FeeParameters {
+		ProjectsFeeParameters::<T>::get(project_id).unwrap_or_else(DefaultFeeParameters::<T>::get)
+	}
+
+	// Calculate the total fee allocation in CTs for a project, based on the funding reached.
+	// The share of the fee paid in funding assets is left out: it all goes to the `BlockchainOperationTreasury`,
+	// so the `FeeParameters` shares computed from this allocation only split the CT fee.
+	fn calculate_fee_allocation(
+		project_id: ProjectId,
+		fee_parameters: &FeeParameters,
+	) -> Result<Balance, DispatchError> {
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 
 		// Fetching the necessary data for a specific project.
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 
 		// Determine how much funding has been achieved.
-		let funding_amount_reached = project_details.funding_amount_reached_usd;
-		let fee_usd = Self::compute_total_fee_from_brackets(funding_amount_reached);
-		let fee_percentage = Perquintill::from_rational(fee_usd, funding_amount_reached);
+		let fee_percentage = Self::fee_percentage(&project_details, fee_parameters);
+		let ct_fee_percentage = project_metadata.fee_mode.funding_asset_share().left_from_one() * fee_percentage;
 
 		let initial_token_allocation_size = project_metadata.total_allocation_size;
 		let final_remaining_contribution_tokens = project_details.remaining_contribution_tokens;
@@ -194,18 +192,39 @@ impl<T: Config> Pallet<T> {
 			.checked_sub(final_remaining_contribution_tokens)
 			// Ensure safety by providing a default in case of unexpected situations.
 			.unwrap_or(initial_token_allocation_size);
-		let total_fee_allocation = fee_percentage * token_sold;
+		let total_fee_allocation = ct_fee_percentage * token_sold;
 
 		Ok(total_fee_allocation)
 	}
 
+	/// Share of each participation's funding assets taken as fee and sent to the `BlockchainOperationTreasury`.
+	/// Zero unless the project pays (part of) its fee in funding assets.
+	pub fn funding_asset_fee_percentage(project_id: ProjectId) -> Result<Perquintill, DispatchError> {
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let funding_asset_share = project_metadata.fee_mode.funding_asset_share();
+		if funding_asset_share.is_zero() {
+			return Ok(Perquintill::zero());
+		}
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let fee_parameters = Self::project_fee_parameters(project_id);
+
+		Ok(funding_asset_share * Self::fee_percentage(&project_details, &fee_parameters))
+	}
+
+	/// Share of the funding reached that the fee brackets take as fee.
+	fn fee_percentage(project_details: &ProjectDetailsOf<T>, fee_parameters: &FeeParameters) -> Perquintill {
+		let funding_amount_reached = project_details.funding_amount_reached_usd;
+		let fee_usd = Self::compute_total_fee_from_brackets(&fee_parameters.fee_brackets, funding_amount_reached);
+		Perquintill::from_rational(fee_usd, funding_amount_reached)
+	}
+
 	/// Computes the total fee from all defined fee brackets.
-	fn compute_total_fee_from_brackets(funding_reached: Balance) -> Balance {
+	fn compute_total_fee_from_brackets(fee_brackets: &[(Percent, Balance)], funding_reached: Balance) -> Balance {
 		let mut remaining_for_fee = funding_reached;
 
-		T::FeeBrackets::get()
-			.into_iter()
-			.map(|(fee, limit)| Self::compute_fee_for_bracket(&mut remaining_for_fee, fee, limit))
+		fee_brackets
+			.iter()
+			.map(|&(fee, limit)| Self::compute_fee_for_bracket(&mut remaining_for_fee, fee, limit))
 			.fold(Balance::zero(), |acc, fee| acc.saturating_add(fee))
 	}
 
@@ -232,14 +251,15 @@ impl<T: Config> Pallet<T> {
 	pub fn generate_evaluator_rewards_info(project_id: ProjectId) -> Result<RewardInfo, DispatchError> {
 		// Fetching the necessary data for a specific project.
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
-		let total_fee_allocation = Self::calculate_fee_allocation(project_id)?;
+		let fee_parameters = Self::project_fee_parameters(project_id);
+		let total_fee_allocation = Self::calculate_fee_allocation(project_id, &fee_parameters)?;
 
 		// Calculate rewards.
-		let evaluator_rewards = Perquintill::from_percent(30) * total_fee_allocation;
+		let evaluator_rewards = fee_parameters.evaluator_share * total_fee_allocation;
 
 		// Distribute rewards between early and normal evaluators.
-		let early_evaluator_reward_pot = Perquintill::from_percent(20) * evaluator_rewards;
-		let normal_evaluator_reward_pot = Perquintill::from_percent(80) * evaluator_rewards;
+		let early_evaluator_reward_pot = fee_parameters.early_evaluator_share * evaluator_rewards;
+		let normal_evaluator_reward_pot = fee_parameters.early_evaluator_share.left_from_one() * evaluator_rewards;
 
 		let normal_evaluator_total_bonded_usd = project_details.evaluation_round_info.total_bonded_usd;
 		let early_evaluation_reward_threshold_usd =
@@ -261,13 +281,11 @@ impl<T: Config> Pallet<T> {
 	pub fn generate_liquidity_pools_and_long_term_holder_rewards(
 		project_id: ProjectId,
 	) -> Result<(Balance, Balance), DispatchError> {
-		let total_fee_allocation = Self::calculate_fee_allocation(project_id)?;
+		let fee_parameters = Self::project_fee_parameters(project_id);
+		let total_fee_allocation = Self::calculate_fee_allocation(project_id, &fee_parameters)?;
 
-		let liquidity_pools_percentage = Perquintill::from_percent(50);
-		let liquidity_pools_reward_pot = liquidity_pools_percentage * total_fee_allocation;
-
-		let long_term_holder_percentage = Perquintill::from_percent(20);
-		let long_term_holder_reward_pot = long_term_holder_percentage * total_fee_allocation;
+		let liquidity_pools_reward_pot = fee_parameters.liquidity_pools_share * total_fee_allocation;
+		let long_term_holder_reward_pot = fee_parameters.long_term_holder_share * total_fee_allocation;
 
 		Ok((liquidity_pools_reward_pot, long_term_holder_reward_pot))
 	}
@@ -317,15 +335,18 @@ impl<T: Config> Pallet<T> {
 		// TODO: adjust this as benchmarks for polimec-receiver are written
 		const MAX_WEIGHT: Weight = Weight::from_parts(10_000, 0);
 		const MAX_RESPONSE_WEIGHT: Weight = Weight::from_parts(700_000_000, 50_000);
-		let migrations_item = Migrations::from(migrations.into());
-
-		// First byte is the pallet index, second byte is the call index
-		let mut encoded_call = vec![pallet_index, 0];
+		// Receivers that were not upgraded only decode the first layout of the message, so it is used whenever
+		// the migrations fit in it.
+		let v1_migrations = migrations.iter().cloned().map(v1::Migration::try_from).collect::<Result<Vec<_>, _>>();
 
-		// migrations_item can contain a Maximum of MaxParticipationsPerUser migrations which
+		// First byte is the pallet index, second byte is the call index.
+		// The migrations can contain a Maximum of MaxParticipationsPerUser migrations which
 		// is 48. So we know that there is an upper limit to this encoded call, namely 48 *
 		// Migration encode size.
-		encoded_call.extend_from_slice(migrations_item.encode().as_slice());
+		let encoded_call = match v1_migrations {
+			Ok(v1_migrations) => [vec![pallet_index, 0], v1::Migrations(v1_migrations).encode()].concat(),
+			Err(()) => [vec![pallet_index, 1], Migrations::from(migrations.into()).encode()].concat(),
+		};
 		Xcm(vec![
 			UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None },
 			Transact { origin_kind: OriginKind::Native, require_weight_at_most: MAX_WEIGHT, call: encoded_call.into() },
@@ -394,4 +415,149 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	#[transactional]
+	pub fn do_register_funding_asset(asset: AcceptedFundingAsset, info: FundingAssetInfo) -> DispatchResult {
+		// * Get variables *
+		let maybe_old_info = FundingAssets::<T>::get(asset);
+		let maybe_location_owner = FundingAssetLocations::<T>::get(&info.location);
+
+		// * Validity checks *
+		ensure!(maybe_location_owner.unwrap_or(asset) == asset, Error::<T>::FundingAssetLocationTaken);
+
+		// * Update storage *
+		if let Some(old_info) = maybe_old_info {
+			FundingAssetLocations::<T>::remove(old_info.location);
+		}
+		FundingAssetLocations::<T>::insert(info.location.clone(), asset);
+		FundingAssets::<T>::insert(asset, info.clone());
+
+		// * Emit events *
+		Self::deposit_event(Event::FundingAssetRegistered { asset, info });
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_set_funding_asset_enabled(asset: AcceptedFundingAsset, enabled: bool) -> DispatchResult {
+		// * Get variables *
+		let mut info = FundingAssets::<T>::get(asset).ok_or(Error::<T>::FundingAssetNotFound)?;
+
+		// * Update storage *
+		info.enabled = enabled;
+		FundingAssets::<T>::insert(asset, info);
+
+		// * Emit events *
+		Self::deposit_event(Event::FundingAssetStatusChanged { asset, enabled });
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_set_round_duration_bounds(bounds: RoundDurationBoundsOf<T>) -> DispatchResult {
+		// * Validity checks *
+		ensure!(bounds.is_valid(), Error::<T>::InvalidRoundDurationBounds);
+
+		// * Update storage *
+		RoundDurationLimits::<T>::put(bounds);
+
+		// * Emit events *
+		Self::deposit_event(Event::RoundDurationBoundsSet { bounds });
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_set_soft_cap_bounds(bounds: SoftCapBounds) -> DispatchResult {
+		// * Validity checks *
+		ensure!(bounds.is_valid(), Error::<T>::InvalidSoftCapBounds);
+
+		// * Update storage *
+		SoftCapLimits::<T>::put(bounds);
+
+		// * Emit events *
+		Self::deposit_event(Event::SoftCapBoundsSet { bounds });
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_set_fee_parameters(parameters: FeeParameters) -> DispatchResult {
+		// * Validity checks *
+		ensure!(parameters.is_valid(), Error::<T>::InvalidFeeParameters);
+
+		// * Update storage *
+		ProtocolFeeParameters::<T>::put(parameters.clone());
+
+		// * Emit events *
+		Self::deposit_event(Event::FeeParametersSet { parameters });
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_set_active_projects_limit(limit: u32) -> DispatchResult {
+		// * Validity checks *
+		ensure!(limit > 0 && limit <= T::MaxActiveProjectsPerDid::get(), Error::<T>::InvalidActiveProjectsLimit);
+
+		// * Update storage *
+		ActiveProjectsLimit::<T>::put(limit);
+
+		// * Emit events *
+		Self::deposit_event(Event::ActiveProjectsLimitSet { limit });
+
+		Ok(())
+	}
+
+	/// Removes a project from the active projects of its issuer's DID, if it is still there.
+	pub(crate) fn remove_active_project(did: Did, project_id: ProjectId) {
+		DidWithActiveProjects::<T>::mutate_exists(did, |maybe_projects| {
+			if let Some(projects) = maybe_projects {
+				projects.retain(|id| *id != project_id);
+				if projects.is_empty() {
+					*maybe_projects = None;
+				}
+			}
+		});
+	}
+
+	/// Finds the first project of `project_ids` that is not paused, for the `on_idle` hooks.
+	/// Also returns the number of paused projects skipped.
+	pub(crate) fn first_unpaused(mut project_ids: impl Iterator<Item = ProjectId>) -> (Option<ProjectId>, u64) {
+		let mut skipped = 0u64;
+		let next_project = project_ids.find(|project_id| {
+			let paused = PausedProjects::<T>::contains_key(project_id);
+			skipped.saturating_accrue(u64::from(paused));
+			!paused
+		});
+		(next_project, skipped)
+	}
+
+	#[transactional]
+	pub fn do_pause_project(project_id: ProjectId) -> DispatchResult {
+		// * Validity checks *
+		ensure!(ProjectsDetails::<T>::contains_key(project_id), Error::<T>::ProjectDetailsNotFound);
+
+		// * Update storage *
+		PausedProjects::<T>::insert(project_id, ());
+
+		// * Emit events *
+		Self::deposit_event(Event::ProjectPaused { project_id });
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_resume_project(project_id: ProjectId) -> DispatchResult {
+		// * Validity checks *
+		ensure!(PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectNotPaused);
+
+		// * Update storage *
+		PausedProjects::<T>::remove(project_id);
+
+		// * Emit events *
+		Self::deposit_event(Event::ProjectResumed { project_id });
+
+		Ok(())
+	}
 }
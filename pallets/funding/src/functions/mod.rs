@@ -2,14 +2,13 @@
 use super::{traits::*, *};
 use core::ops::Not;
 use frame_support::{
-	dispatch::{DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo},
+	dispatch::{DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo},
 	ensure,
 	pallet_prelude::*,
 	traits::{
 		fungible::{Mutate, MutateHold as FungibleMutateHold},
 		fungibles::{
-			metadata::{Inspect as MetadataInspect, Mutate as MetadataMutate},
-			Create, Inspect as FungibleInspect, Mutate as FungiblesMutate,
+			metadata::Mutate as MetadataMutate, Create, Inspect as FungibleInspect, Mutate as FungiblesMutate,
 		},
 		tokens::{Precision, Preservation},
 		Get,
@@ -19,7 +18,7 @@ use frame_support::{
 use frame_system::pallet_prelude::BlockNumberFor;
 use polimec_common::{
 	credentials::{Did, InvestorType},
-	migration_types::{MigrationInfo, Migrations},
+	migration_types::{v1, MigrationInfo, Migrations},
 	USD_DECIMALS,
 };
 use sp_arithmetic::{traits::Zero, Percent, Perquintill};
@@ -39,6 +38,10 @@ mod ct_migration;
 mod evaluation;
 #[path = "5_funding_end.rs"]
 mod funding_end;
+mod issuer_payout;
+mod milestones;
 mod misc;
+mod private_round;
 #[path = "6_settlement.rs"]
 mod settlement;
+mod xcm_participation;
@@ -0,0 +1,145 @@
+#[allow(clippy::wildcard_imports)]
+use super::*;
+
+impl<T: Config> Pallet<T> {
+	/// Adds a DID to the private round allow-list of a project, or removes it.
+	///
+	/// # Arguments
+	/// * `issuer` - The account of the project issuer
+	/// * `project_id` - The project with the private round
+	/// * `did` - The DID to allow or disallow
+	/// * `allowed` - Whether the DID can participate in the private round
+	///
+	/// # Storage access
+	/// * [`ProjectsDetails`] - Check the caller is the issuer, and the private round did not end yet
+	/// * [`ProjectsMetadata`] - Check the project has a private round
+	/// * [`PrivateRoundAllowList`] - Add or remove the DID
+	#[transactional]
+	pub fn do_set_private_round_access(
+		issuer: AccountIdOf<T>,
+		project_id: ProjectId,
+		did: Did,
+		allowed: bool,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+
+		// * Validity checks *
+		ensure!(project_details.issuer_account == issuer, Error::<T>::NotIssuer);
+		ensure!(project_metadata.private_round.is_some(), Error::<T>::NoPrivateRound);
+		ensure!(
+			matches!(
+				project_details.status,
+				ProjectStatus::Application | ProjectStatus::EvaluationRound | ProjectStatus::PrivateRound
+			),
+			Error::<T>::IncorrectRound
+		);
+
+		// * Update storage *
+		if allowed {
+			PrivateRoundAllowList::<T>::insert(project_id, did.clone(), true);
+		} else {
+			PrivateRoundAllowList::<T>::remove(project_id, did.clone());
+		}
+
+		// * Emit events *
+		Self::deposit_event(Event::PrivateRoundAccessSet { project_id, did, allowed });
+
+		Ok(())
+	}
+
+	/// Buy tokens in the Private Round at the fixed price set by the issuer.
+	///
+	/// # Arguments
+	/// * contributor: The account that is buying the tokens
+	/// * project_id: The identifier of the project
+	/// * token_amount: The amount of contribution tokens the contributor tries to buy. Tokens
+	///   are limited by what is left of the private round allocation.
+	/// * multiplier: Decides how much PLMC bonding is required for buying that amount of tokens
+	/// * asset: The asset used for the contribution
+	#[transactional]
+	pub fn do_private_contribute(params: DoContributeParams<T>) -> DispatchResultWithPostInfo {
+		let DoContributeParams {
+			contributor,
+			project_id,
+			ct_amount: token_amount,
+			multiplier,
+			funding_asset,
+			investor_type,
+			did,
+			whitelisted_policy,
+			jurisdiction,
+			max_usd,
+		} = params;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let private_round = project_metadata.private_round.ok_or(Error::<T>::NoPrivateRound)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		ensure!(matches!(project_details.status, ProjectStatus::PrivateRound), Error::<T>::IncorrectRound);
+		let round_end = project_details.round_duration.end().ok_or(Error::<T>::ImpossibleState)?;
+		ensure!(now < round_end, Error::<T>::TooLateForRound);
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+		ensure!(PrivateRoundAllowList::<T>::get(project_id, did.clone()), Error::<T>::NotAllowListed);
+
+		// Nothing else is sold before the private round ends, so the sold tokens are all private round tokens.
+		let private_allocation = private_round.allocation_percentage * project_metadata.total_allocation_size;
+		let sold_tokens =
+			project_metadata.total_allocation_size.saturating_sub(project_details.remaining_contribution_tokens);
+		let buyable_tokens = token_amount.min(private_allocation.saturating_sub(sold_tokens));
+		if buyable_tokens.is_zero() {
+			return Err(Error::<T>::ProjectSoldOut.into());
+		}
+		project_details.remaining_contribution_tokens.saturating_reduce(buyable_tokens);
+
+		let perform_params = DoPerformContributionParams {
+			contributor,
+			project_id,
+			project_details: &mut project_details,
+			buyable_tokens,
+			ct_usd_price: private_round.price,
+			private_round: true,
+			multiplier,
+			funding_asset,
+			investor_type,
+			did,
+			whitelisted_policy,
+			jurisdiction,
+			max_usd,
+		};
+
+		Self::do_perform_contribution(perform_params)
+	}
+
+	/// Ends the private round of a project and starts its auction, or its community round if it has no auction.
+	#[transactional]
+	pub fn do_end_private_round(project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+
+		// * Validity checks *
+		ensure!(!PausedProjects::<T>::contains_key(project_id), Error::<T>::ProjectPaused);
+
+		// * Transition Round *
+		if !project_metadata.pricing_model.has_auction() {
+			return Self::start_community_round(
+				project_id,
+				project_details,
+				&project_metadata,
+				ProjectStatus::PrivateRound,
+				project_metadata.minimum_price,
+				false,
+			);
+		}
+		Self::transition_project(
+			project_id,
+			project_details,
+			ProjectStatus::PrivateRound,
+			ProjectStatus::AuctionRound,
+			Some(Self::auction_round_duration(&project_metadata)),
+			false,
+		)
+	}
+}
@@ -0,0 +1,165 @@
+#[allow(clippy::wildcard_imports)]
+use super::*;
+use frame_support::traits::ContainsPair;
+use frame_system::pallet_prelude::OriginFor;
+use xcm_executor::traits::{ConvertLocation, WeightBounds, XcmAssetTransfers};
+
+impl<T: Config> Pallet<T> {
+	/// Checks the credentials of a participant. Users of other chains accepted by the
+	/// [`XcmParticipantOrigin`](Config::XcmParticipantOrigin) participate with the local account derived from their
+	/// location, so their JWT has to be issued for that account. Their location is kept, to send their refunds
+	/// from the project back.
+	pub(crate) fn ensure_participant(
+		origin: OriginFor<T>,
+		jwt: &UntrustedToken,
+		project_id: ProjectId,
+	) -> Result<(AccountIdOf<T>, Did, InvestorType, Cid, Option<Jurisdiction>, CredentialScope), DispatchError> {
+		let location = match T::XcmParticipantOrigin::try_origin(origin) {
+			Ok(location) => location,
+			Err(origin) => return Ok(T::InvestorOrigin::ensure_origin(origin, jwt, T::VerifierPublicKey::get())?),
+		};
+		let account = T::XcmParticipantAccount::convert_location(&location).ok_or(DispatchError::BadOrigin)?;
+		let credentials = T::InvestorOrigin::ensure_origin(
+			frame_system::RawOrigin::Signed(account.clone()).into(),
+			jwt,
+			T::VerifierPublicKey::get(),
+		)?;
+		XcmParticipantLocations::<T>::insert(project_id, account, location);
+
+		Ok(credentials)
+	}
+
+	/// Refunds funding assets from the project pot to a participant. The refunds of participants that joined through
+	/// XCM are sent back to their location. If that fails, the refund is kept on their local account.
+	pub(crate) fn refund_funding_asset(
+		project_id: ProjectId,
+		participant: &AccountIdOf<T>,
+		amount: Balance,
+		asset: AcceptedFundingAsset,
+	) -> DispatchResult {
+		Self::release_funding_asset(project_id, participant, amount, asset)?;
+		if amount.is_zero() {
+			return Ok(());
+		}
+		let Some(destination) = XcmParticipantLocations::<T>::get(project_id, participant) else { return Ok(()) };
+
+		match Self::send_funding_asset_to_location(participant, &destination, amount, asset) {
+			Ok(message_id) => Self::deposit_event(Event::RefundSentThroughXcm {
+				project_id,
+				account: participant.clone(),
+				destination,
+				asset,
+				amount,
+				message_id,
+			}),
+			Err(_) => Self::deposit_event(Event::RefundThroughXcmFailed {
+				project_id,
+				account: participant.clone(),
+				destination,
+				asset,
+				amount,
+			}),
+		}
+
+		Ok(())
+	}
+
+	/// Forgets the location of a participant that joined a project through XCM, once all its participations were
+	/// settled and nothing is left for it in escrow.
+	pub(crate) fn forget_xcm_participant_if_done(project_id: ProjectId, participant: &AccountIdOf<T>) {
+		if !XcmParticipantLocations::<T>::contains_key(project_id, participant) {
+			return;
+		}
+		let prefix = (project_id, participant.clone());
+		let has_participations = Evaluations::<T>::iter_prefix(prefix.clone()).next().is_some() ||
+			Bids::<T>::iter_prefix(prefix.clone()).next().is_some() ||
+			Contributions::<T>::iter_prefix(prefix.clone()).next().is_some() ||
+			EscrowedParticipations::<T>::iter_prefix(prefix).next().is_some();
+		if !has_participations {
+			XcmParticipantLocations::<T>::remove(project_id, participant);
+		}
+	}
+
+	/// Sends funding assets held by `from` to an account on the chain that is the reserve of the asset, e.g. an
+	/// `AccountId32` on AssetHub. The execution on the reserve is paid with the sent asset.
+	///
+	/// Returns the topic of the message sent to the reserve, which the reserve uses as its message id.
+	#[transactional]
+	pub(crate) fn send_funding_asset_to_location(
+		from: &AccountIdOf<T>,
+		destination: &Location,
+		amount: Balance,
+		asset: AcceptedFundingAsset,
+	) -> Result<XcmHash, DispatchError> {
+		let asset_location = FundingAssets::<T>::get(asset).ok_or(Error::<T>::FundingAssetNotFound)?.location;
+		let (reserve, beneficiary) = destination.clone().split_last_interior();
+		let beneficiary: Location = beneficiary.ok_or(Error::<T>::XcmFailed)?.into();
+		let sent_asset: Asset = (asset_location, amount).into();
+		ensure!(
+			<<T as pallet_xcm::Config>::XcmExecutor as XcmAssetTransfers>::IsReserve::contains(&sent_asset, &reserve),
+			Error::<T>::XcmFailed
+		);
+		let fees = sent_asset
+			.clone()
+			.reanchored(&reserve, &<T as pallet_xcm::Config>::UniversalLocation::get())
+			.map_err(|_| Error::<T>::XcmFailed)?;
+
+		let message_id = (
+			from,
+			destination,
+			&sent_asset,
+			<frame_system::Pallet<T>>::block_number(),
+			<frame_system::Pallet<T>>::event_count(),
+		)
+			.using_encoded(sp_io::hashing::blake2_256);
+		let mut message = Self::reserve_withdraw_message(sent_asset, reserve, fees, beneficiary, message_id);
+		let weight = <T as pallet_xcm::Config>::Weigher::weight(&mut message).map_err(|_| Error::<T>::XcmFailed)?;
+
+		let origin =
+			Location::new(0, AccountId32 { network: None, id: T::AccountId32Conversion::convert(from.clone()) });
+		let mut hash = message_id;
+		<T as pallet_xcm::Config>::XcmExecutor::prepare_and_execute(origin, message, &mut hash, weight, weight)
+			.ensure_complete()
+			.map_err(|_| Error::<T>::XcmFailed)?;
+
+		Ok(message_id)
+	}
+
+	/// Withdraws `asset` from the origin, and deposits it to `beneficiary` on the chain that is its reserve.
+	/// The message sent to the reserve ends with `topic`.
+	fn reserve_withdraw_message(
+		asset: Asset,
+		reserve: Location,
+		fees: Asset,
+		beneficiary: Location,
+		topic: XcmHash,
+	) -> Xcm<<T as pallet_xcm::Config>::RuntimeCall> {
+		Xcm(vec![
+			WithdrawAsset(asset.into()),
+			InitiateReserveWithdraw {
+				assets: Wild(AllCounted(1)),
+				reserve,
+				xcm: Xcm(vec![
+					BuyExecution { fees, weight_limit: Unlimited },
+					DepositAsset { assets: Wild(AllCounted(1)), beneficiary },
+					SetTopic(topic),
+				]),
+			},
+		])
+	}
+
+	/// Upper bound of the weight of sending a refund back over XCM, added to the extrinsics that can refund.
+	pub fn xcm_refund_weight() -> Weight {
+		let placeholder: Asset = (Location::here(), 0u128).into();
+		let mut message = Self::reserve_withdraw_message(
+			placeholder.clone(),
+			Location::here(),
+			placeholder,
+			Location::here(),
+			Default::default(),
+		);
+		let execution_weight = <T as pallet_xcm::Config>::Weigher::weight(&mut message).unwrap_or_default();
+		<<T as pallet_xcm::Config>::WeightInfo as pallet_xcm::WeightInfo>::transfer_assets()
+			.saturating_add(execution_weight)
+	}
+}
@@ -780,4 +780,25 @@ impl<
 			T::CommunityRoundDuration::get() +
 			One::one()
 	}
+
+	/// Signs the migration destination message of a participant like an Ethereum wallet would, with the key of `seed`.
+	/// Returns the `AccountKey20` location of the key, and its signature.
+	pub fn sign_migration_destination_with_ethereum_key(
+		&self,
+		project_id: ProjectId,
+		participant: &AccountIdOf<T>,
+		seed: [u8; 32],
+	) -> (Location, MigrationDestinationSignature) {
+		use sp_core::Pair;
+		let message = Pallet::<T>::migration_destination_message(project_id, participant);
+		let mut prefixed = alloc::format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+		prefixed.extend_from_slice(&message);
+		let hash = sp_io::hashing::keccak_256(&prefixed);
+
+		let signature = sp_core::ecdsa::Pair::from_seed(&seed).sign_prehashed(&hash).0;
+		let public_key = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &hash).unwrap();
+		let key: [u8; 20] = sp_io::hashing::keccak_256(&public_key)[12..].try_into().unwrap();
+
+		(Location::new(0, AccountKey20 { network: None, key }), MigrationDestinationSignature::Ethereum(signature))
+	}
 }
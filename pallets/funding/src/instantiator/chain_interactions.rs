@@ -389,8 +389,15 @@ impl<
 			ProjectStatus::EvaluationRound => {
 				self.execute(|| <Pallet<T>>::do_end_evaluation(project_id).unwrap());
 			},
+			ProjectStatus::PrivateRound => {
+				self.execute(|| <Pallet<T>>::do_end_private_round(project_id).unwrap());
+			},
 			ProjectStatus::AuctionRound => {
 				self.execute(|| <Pallet<T>>::do_end_auction(project_id).unwrap());
+				self.execute(|| <Pallet<T>>::do_resolve_auction(project_id, u32::MAX).unwrap());
+			},
+			ProjectStatus::AuctionResolving => {
+				self.execute(|| <Pallet<T>>::do_resolve_auction(project_id, u32::MAX).unwrap());
 			},
 			ProjectStatus::CommunityRound(..) => {
 				self.execute(|| <Pallet<T>>::do_end_funding(project_id).unwrap());
@@ -454,12 +461,47 @@ impl<
 					usd_amount,
 					generate_did_from_account(account),
 					project_policy.clone(),
+					None,
 				)
 			})?;
 		}
 		Ok(().into())
 	}
 
+	pub fn create_private_round_project(
+		&mut self,
+		project_metadata: ProjectMetadataOf<T>,
+		issuer: AccountIdOf<T>,
+		maybe_did: Option<Did>,
+		evaluations: Vec<UserToUSDBalance<T>>,
+	) -> ProjectId {
+		assert!(project_metadata.private_round.is_some(), "Project should have a private round");
+		let project_id = self.create_evaluating_project(project_metadata, issuer, maybe_did);
+
+		let plmc_evaluation_deposits = self.calculate_evaluation_plmc_spent(evaluations.clone(), true);
+		self.mint_plmc_to(plmc_evaluation_deposits);
+		self.evaluate_for_users(project_id, evaluations).unwrap();
+
+		assert_eq!(self.go_to_next_state(project_id), ProjectStatus::PrivateRound);
+
+		project_id
+	}
+
+	pub fn allow_list_for_private_round(&mut self, project_id: ProjectId, accounts: Vec<AccountIdOf<T>>) {
+		let issuer = self.get_issuer(project_id);
+		for account in accounts {
+			self.execute(|| {
+				crate::Pallet::<T>::do_set_private_round_access(
+					issuer.clone(),
+					project_id,
+					generate_did_from_account(account),
+					true,
+				)
+				.unwrap()
+			});
+		}
+	}
+
 	pub fn create_auctioning_project(
 		&mut self,
 		project_metadata: ProjectMetadataOf<T>,
@@ -467,6 +509,7 @@ impl<
 		maybe_did: Option<Did>,
 		evaluations: Vec<UserToUSDBalance<T>>,
 	) -> ProjectId {
+		let has_auction = project_metadata.pricing_model.has_auction();
 		let project_id = self.create_evaluating_project(project_metadata, issuer.clone(), maybe_did);
 
 		let evaluators = evaluations.accounts();
@@ -503,7 +546,12 @@ impl<
 			expected_total_plmc_supply,
 		);
 
-		assert_eq!(self.go_to_next_state(project_id), ProjectStatus::AuctionRound);
+		// Projects without an auction go straight to the community round
+		if has_auction {
+			assert_eq!(self.go_to_next_state(project_id), ProjectStatus::AuctionRound);
+		} else {
+			assert!(matches!(self.go_to_next_state(project_id), ProjectStatus::CommunityRound(..)));
+		}
 
 		project_id
 	}
@@ -523,6 +571,8 @@ impl<
 					did,
 					investor_type: InvestorType::Institutional,
 					whitelisted_policy: project_policy.clone(),
+					jurisdiction: None,
+					max_usd: None,
 				};
 				crate::Pallet::<T>::do_bid(params)
 			})?;
@@ -540,6 +590,10 @@ impl<
 	) -> ProjectId {
 		let project_id =
 			self.create_auctioning_project(project_metadata.clone(), issuer, maybe_did, evaluations.clone());
+		if !project_metadata.pricing_model.has_auction() {
+			assert!(bids.is_empty(), "Projects without an auction cannot take bids");
+			return project_id
+		}
 		if bids.is_empty() {
 			assert!(matches!(self.go_to_next_state(project_id), ProjectStatus::CommunityRound(_)));
 			return project_id
@@ -618,25 +672,33 @@ impl<
 	) -> DispatchResultWithPostInfo {
 		let project_policy = self.get_project_metadata(project_id).policy_ipfs_cid.unwrap();
 
-		match self.get_project_details(project_id).status {
-			ProjectStatus::CommunityRound(..) =>
-				for cont in contributions {
-					let did = generate_did_from_account(cont.contributor.clone());
-					// We use institutional to be able to test most multipliers.
-					let investor_type = InvestorType::Institutional;
-					let params = DoContributeParams::<T> {
-						contributor: cont.contributor,
-						project_id,
-						ct_amount: cont.amount,
-						multiplier: cont.multiplier,
-						funding_asset: cont.asset,
-						did,
-						investor_type,
-						whitelisted_policy: project_policy.clone(),
-					};
-					self.execute(|| crate::Pallet::<T>::do_contribute(params))?;
-				},
-			_ => panic!("Project should be in Community or Remainder status"),
+		let private_round = match self.get_project_details(project_id).status {
+			ProjectStatus::CommunityRound(..) => false,
+			ProjectStatus::PrivateRound => true,
+			_ => panic!("Project should be in Private, Community or Remainder status"),
+		};
+
+		for cont in contributions {
+			let did = generate_did_from_account(cont.contributor.clone());
+			// We use institutional to be able to test most multipliers.
+			let investor_type = InvestorType::Institutional;
+			let params = DoContributeParams::<T> {
+				contributor: cont.contributor,
+				project_id,
+				ct_amount: cont.amount,
+				multiplier: cont.multiplier,
+				funding_asset: cont.asset,
+				did,
+				investor_type,
+				whitelisted_policy: project_policy.clone(),
+				jurisdiction: None,
+				max_usd: None,
+			};
+			if private_round {
+				self.execute(|| crate::Pallet::<T>::do_private_contribute(params))?;
+			} else {
+				self.execute(|| crate::Pallet::<T>::do_contribute(params))?;
+			}
 		}
 
 		Ok(().into())
@@ -671,7 +733,7 @@ impl<
 		self.execute(|| Contributions::<T>::iter_prefix_values((project_id,)).collect())
 	}
 
-	// Used to check all the USDT/USDC/DOT was paid to the issuer funding account
+	// Used to check all the USDT/USDC/DOT was paid to the issuer funding account, minus the fee taken in funding assets
 	pub fn assert_total_funding_paid_out(
 		&mut self,
 		project_id: ProjectId,
@@ -682,20 +744,25 @@ impl<
 		let mut total_expected_dot: Balance = Zero::zero();
 		let mut total_expected_usdt: Balance = Zero::zero();
 		let mut total_expected_usdc: Balance = Zero::zero();
+		// The fee taken in funding assets is deducted from each participation separately
+		let fee_percentage = self.execute(|| Pallet::<T>::funding_asset_fee_percentage(project_id).unwrap());
+		let paid_out = |amount: Balance| amount.saturating_sub(fee_percentage * amount);
 
 		for bid in bids {
 			match bid.funding_asset {
-				AcceptedFundingAsset::DOT => total_expected_dot += bid.funding_asset_amount_locked,
-				AcceptedFundingAsset::USDT => total_expected_usdt += bid.funding_asset_amount_locked,
-				AcceptedFundingAsset::USDC => total_expected_usdc += bid.funding_asset_amount_locked,
+				AcceptedFundingAsset::DOT => total_expected_dot += paid_out(bid.funding_asset_amount_locked),
+				AcceptedFundingAsset::USDT => total_expected_usdt += paid_out(bid.funding_asset_amount_locked),
+				AcceptedFundingAsset::USDC => total_expected_usdc += paid_out(bid.funding_asset_amount_locked),
+				_ => panic!("Funding asset not supported by the instantiator"),
 			}
 		}
 
 		for contribution in contributions {
 			match contribution.funding_asset {
-				AcceptedFundingAsset::DOT => total_expected_dot += contribution.funding_asset_amount,
-				AcceptedFundingAsset::USDT => total_expected_usdt += contribution.funding_asset_amount,
-				AcceptedFundingAsset::USDC => total_expected_usdc += contribution.funding_asset_amount,
+				AcceptedFundingAsset::DOT => total_expected_dot += paid_out(contribution.funding_asset_amount),
+				AcceptedFundingAsset::USDT => total_expected_usdt += paid_out(contribution.funding_asset_amount),
+				AcceptedFundingAsset::USDC => total_expected_usdc += paid_out(contribution.funding_asset_amount),
+				_ => panic!("Funding asset not supported by the instantiator"),
 			}
 		}
 
@@ -789,7 +856,7 @@ impl<
 				account,
 				amount,
 				contribution.id,
-				ParticipationType::Contribution,
+				contribution.participation_type(),
 				is_successful,
 			);
 		}
@@ -807,9 +874,8 @@ impl<
 		match (should_exist, self.execute(|| UserMigrations::<T>::get((project_id, account.clone())))) {
 			// User has migrations, so we need to check if any matches our criteria
 			(_, Some((_, migrations))) => {
+				let location_user = self.execute(|| Pallet::<T>::migration_destination(project_id, &account));
 				let maybe_migration = migrations.into_iter().find(|migration| {
-					let user = T::AccountId32Conversion::convert(account.clone());
-					let location_user = Location::new(0,AccountId32 {network: None, id: user});
 					matches!(&migration.origin, MigrationOrigin { user: m_user, id: m_id, participation_type: m_participation_type } if *m_user == location_user && *m_id == id && *m_participation_type == participation_type)
 				});
 				match maybe_migration {
@@ -1086,6 +1152,8 @@ impl<
 			ProjectStatus::CommunityRound(..) =>
 				self.create_community_contributing_project(project_metadata, issuer, None, evaluations, bids),
 			ProjectStatus::AuctionRound => self.create_auctioning_project(project_metadata, issuer, None, evaluations),
+			ProjectStatus::PrivateRound =>
+				self.create_private_round_project(project_metadata, issuer, None, evaluations),
 			ProjectStatus::EvaluationRound => self.create_evaluating_project(project_metadata, issuer, None),
 			ProjectStatus::Application => self.create_new_project(project_metadata, issuer, None),
 			_ => panic!("unsupported project creation in that status"),
@@ -36,7 +36,7 @@ use sp_arithmetic::{
 	traits::{SaturatedConversion, Saturating, Zero},
 	FixedPointNumber, Percent, Perquintill,
 };
-use sp_runtime::traits::{Convert, Member, One};
+use sp_runtime::traits::{Member, One};
 use sp_std::{
 	cell::RefCell,
 	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
@@ -52,7 +52,16 @@ fn dry_run_wap() {
 		},
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: 0u32,
+		funding_destination_location: None,
 		policy_ipfs_cid: Some(metadata_hash),
+		round_durations: Default::default(),
+		ct_release_policy: None,
+		soft_cap: None,
+		private_round: None,
+		fee_mode: FeeMode::ContributionTokens,
+		milestones: None,
+		pricing_model: PricingModel::Buckets,
+		jurisdiction_restriction: None,
 	};
 
 	// overfund with plmc
@@ -134,7 +143,16 @@ fn find_bucket_for_wap() {
 		},
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: 0u32,
+		funding_destination_location: None,
 		policy_ipfs_cid: Some(metadata_hash),
+		round_durations: Default::default(),
+		ct_release_policy: None,
+		soft_cap: None,
+		private_round: None,
+		fee_mode: FeeMode::ContributionTokens,
+		milestones: None,
+		pricing_model: PricingModel::Buckets,
+		jurisdiction_restriction: None,
 	};
 
 	// overfund with plmc
@@ -45,26 +45,71 @@
 //! 3) **Evaluate**: Evaluators bond PLMC to evaluate a project with the [`evaluate`](Pallet::evaluate) extrinsic.
 //! 4) **Evaluation End**: Anyone can end the evaluation round with the [`end_evaluation`](Pallet::end_evaluation) extrinsic after the defined end block.
 //! 5) **Auction Start**: If the project receives at least 10% of its target funding (in USD) in PLMC bonded, the auction starts immediately after `end_evaluation` is called.
+//!     Projects with a private round first go through it: only the DIDs the issuer allow-listed with [`set_private_round_access`](Pallet::set_private_round_access)
+//!     can buy at the issuer's fixed price with [`private_contribute`](Pallet::private_contribute), and anyone can start the auction with
+//!     [`end_private_round`](Pallet::end_private_round) after the defined end block.
 //! 6) **Bid**: Professional and institutional investors can place bids on the project using the [`bid`](Pallet::bid) extrinsic. The price starts at the issuer-defined minimum, and increases by increments of 10% in price and bucket size.
+//!     Issuers can instead choose a fixed-price sale in the project's [`PricingModel`], where bids are accepted first-come-first-served, or the auction is skipped.
 //! 7) **Auction End**: Anyone can end the auction round with the [`end_auction`](Pallet::end_auction) extrinsic after the defined end block.
-//! 8) **Community Round Start**: After `end_auction` is called, a weighted average price is calculated from the bids, and the community round starts.
+//! 8) **Community Round Start**: After `end_auction` is called, a weighted average price is calculated from the bids, and the bids are resolved as accepted or
+//!     rejected from the highest price down. This happens in `on_idle` with the block's spare weight, or in chunks with the [`resolve_auction`](Pallet::resolve_auction)
+//!     extrinsic. Once all bids are resolved, the community round starts.
 //! 9) **Contribute**: Anyone without a winning bid can now contribute at the weighted average price with the [`contribute`](Pallet::contribute) extrinsic.
 //! 10) **Remainder Round Start**: After a defined [period](<T as Config>::CommunityRoundDuration), the remainder round starts.
 //! 11) **Contribute**: Participants with winning bids can also contribute at the weighted average price with the [`contribute`](Pallet::contribute) extrinsic.
 //! 12) **Funding End**: Anyone can end the project with the [`end_project`](Pallet::end_project) extrinsic after the defined end block.
-//!     The project will now be considered Failed if it reached <=33% of its target funding in USD, and Successful otherwise.
+//!     The project will now be considered Failed if it did not reach its soft cap, and Successful otherwise. The soft cap is chosen by the issuer
+//!     within governance bounds, and defaults to [`FundingSuccessThreshold`](<T as Config>::FundingSuccessThreshold) of its target funding in USD.
 //! 13) **Settlement Start**: Anyone can start the settlement process with the [`start_settlement`](Pallet::start_settlement) extrinsic after the defined end block.
+//!     From then on, the participations are settled automatically in `on_idle` with the block's spare weight, and the project is marked as settled
+//!     once all of them are. The extrinsics of steps 14 to 17 can still be used to settle participations without waiting.
+//!     Part of the CT fee is minted as a liquidity-pool pot to the [`LiquidityPoolsAccount`](Config::LiquidityPoolsAccount), and migrated with the
+//!     participants' CTs. Another part is kept in the [`ContributionTreasury`](Config::ContributionTreasury) as a long-term-holder pot.
 //! 14) **Settle Evaluation**: Anyone can now settle an evaluation with the [`settle_evaluation`](Pallet::settle_evaluation) extrinsic.
 //!     This will unlock the PLMC bonded, and either apply a slash to the PLMC, or reward CTs to the evaluator.
 //! 15) **Settle Bid**: Anyone can now settle a bid with the [`settle_bid`](Pallet::settle_bid) extrinsic.
 //!     This will set a vesting schedule on the PLMC bonded, and pay out the funding assets to the issuer. It will also issue refunds in case the bid failed,
-//!     or the price paid was higher than the weighted average price.
+//!     or the price paid was higher than the weighted average price. Projects paying (part of) their fee in funding assets, as chosen in their
+//!     [`FeeMode`], have it deducted here and sent to the [`BlockchainOperationTreasury`](Config::BlockchainOperationTreasury).
 //! 16) **Settle Contribution**: Anyone can now settle a contribution with the [`settle_contribution`](Pallet::settle_contribution) extrinsic.
 //!     This will set a vesting schedule on the PLMC bonded, and pay out the funding assets to the issuer.
 //! 17) **Settlement End**: Anyone can now mark the project settlement as finished by calling the [`mark_project_as_settled`](Pallet::mark_project_as_settled) extrinsic.
+//!     Participants that keep the CTs they received for [`LongTermHolderPeriod`](Config::LongTermHolderPeriod) blocks can then claim their
+//!     pro-rata share of the long-term-holder pot with the [`claim_long_term_holder_reward`](Pallet::claim_long_term_holder_reward) extrinsic,
+//!     as long as their CTs were not migrated yet. Those CTs are frozen until then, unless the participant gives up the reward with the
+//!     [`forfeit_long_term_holder_reward`](Pallet::forfeit_long_term_holder_reward) extrinsic.
 //! 18) **Migration Start**: Once the issuer has tokens to distribute on mainnet, he can start the migration process with the [`start_offchain`](Pallet::start_offchain_migration) extrinsic.
 //! 19) **Confirm Migration**: The issuer has to mark each participant's CTs as migrated with the [`confirm_offchain_migration`](Pallet::confirm_offchain_migration) extrinsic.
+//!     Before their CTs are migrated, participants can choose another account on the project's chain to receive them, e.g. an
+//!     `AccountKey20` on EVM chains, with the [`set_migration_destination`](Pallet::set_migration_destination) extrinsic.
 //! 20) **Migration End**: Once all participants have migrated their CTs, anyone can mark the migration as finished with the [`mark_project_ct_migration_as_finished`](Pallet::mark_project_ct_migration_as_finished) extrinsic.
+//!
+//! Projects that declared [`milestones`](ProjectMetadata::milestones) keep the funding assets paid out in steps 15 and 16 in escrow.
+//! After the settlement ends, the approvers chosen by the issuer release each milestone's share to the issuer with the
+//! [`approve_milestone`](Pallet::approve_milestone) extrinsic. If a milestone misses its deadline, anyone can call
+//! [`fail_milestone`](Pallet::fail_milestone), and each participant gets its share of the escrow left back with
+//! [`claim_escrow_refund`](Pallet::claim_escrow_refund).
+//!
+//! Issuers that set a [`funding_destination_location`](ProjectMetadata::funding_destination_location), e.g. an account
+//! on AssetHub, get the funding assets paid out in steps 15 and 16, or released by their milestones, queued in the project
+//! account instead. They are sent there over XCM in one message per funding asset in `on_idle`, or by anyone with the
+//! [`pay_out_issuer`](Pallet::pay_out_issuer) extrinsic. Assets that fail to be sent stay queued and are retried.
+//! The location must be an account on a reserve of every participation currency of the project. The issuer can point
+//! the payouts to another location, or back to the `funding_destination_account`, with the
+//! [`set_funding_destination_location`](Pallet::set_funding_destination_location) extrinsic.
+//!
+//! Users of other chains, e.g. AssetHub accounts, can also evaluate, bid and contribute by sending an XCM `Transact` from
+//! an origin accepted by [`XcmParticipantOrigin`](Config::XcmParticipantOrigin), together with their JWT. They participate
+//! with the account derived from their location, which holds the PLMC bond and the funding assets they transferred,
+//! and their funding asset refunds are sent back to their location over XCM.
+//! XCM v4 clears the origin of the message carrying reserve-transferred assets, so a user sends the funding assets
+//! and the `Transact` in two messages, e.g. from a single batch on AssetHub. The PLMC bond is not paid from the sent
+//! assets: the derived account has to be funded with PLMC beforehand, like any other Polimec account.
+//!
+//! In an emergency, the [`AdminOrigin`](Config::AdminOrigin) can stop a project at any point before its settlement:
+//! - [`pause_project`](Pallet::pause_project) rejects all participations to the project, until [`resume_project`](Pallet::resume_project) is called.
+//! - [`cancel_project`](Pallet::cancel_project) ends the project and starts its settlement as failed. Evaluators are not slashed,
+//!     bidders and contributors get all their PLMC and funding assets back, and no contribution tokens are minted.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -87,7 +132,10 @@ use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
 use pallet_xcm::ensure_response;
 use polimec_common::{
-	credentials::{Cid, Did, EnsureOriginWithCredentials, InvestorType, UntrustedToken},
+	credentials::{
+		Cid, CredentialNonces, CredentialScope, Did, EnsureOriginWithCredentials, InvestorType, Jurisdiction,
+		UntrustedToken,
+	},
 	migration_types::{Migration, MigrationStatusd},
 };
 use polkadot_parachain_primitives::primitives::Id as ParaId;
@@ -131,7 +179,8 @@ pub type AssetIdOf<T> =
 	<<T as Config>::FundingCurrency as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
 pub type VestingInfoOf<T> = VestingInfo<BlockNumberFor<T>>;
 
-pub type ProjectMetadataOf<T> = ProjectMetadata<BoundedVec<u8, StringLimitOf<T>>, PriceOf<T>, AccountIdOf<T>, Cid>;
+pub type ProjectMetadataOf<T> =
+	ProjectMetadata<BoundedVec<u8, StringLimitOf<T>>, PriceOf<T>, AccountIdOf<T>, Cid, BlockNumberFor<T>>;
 pub type ProjectDetailsOf<T> = ProjectDetails<AccountIdOf<T>, Did, BlockNumberFor<T>, PriceOf<T>, EvaluationRoundInfo>;
 pub type EvaluationInfoOf<T> = EvaluationInfo<u32, Did, ProjectId, AccountIdOf<T>, BlockNumberFor<T>>;
 pub type BidInfoOf<T> = BidInfo<ProjectId, Did, PriceOf<T>, AccountIdOf<T>, BlockNumberFor<T>, MultiplierOf<T>>;
@@ -140,6 +189,11 @@ pub type ContributionInfoOf<T> =
 	ContributionInfo<u32, Did, ProjectId, AccountIdOf<T>, BlockNumberFor<T>, MultiplierOf<T>>;
 
 pub type BucketOf<T> = Bucket<PriceOf<T>>;
+pub type AuctionResolutionOf<T> = AuctionResolution<PriceOf<T>>;
+pub type BidGroupOf<T> = BidGroup<BoundedVec<u32, <T as Config>::MaxBidsPerUser>>;
+pub type SettlementCursorOf<T> = SettlementCursor<AccountIdOf<T>>;
+pub type LongTermHoldingOf<T> = LongTermHolding<BlockNumberFor<T>>;
+pub type RoundDurationBoundsOf<T> = RoundDurationBounds<BlockNumberFor<T>>;
 pub type WeightInfoOf<T> = <T as Config>::WeightInfo;
 pub type VestingOf<T> = pallet_linear_release::Pallet<T>;
 pub type BlockNumberToBalanceOf<T> = <T as pallet_linear_release::Config>::BlockNumberToBalance;
@@ -165,6 +219,7 @@ pub mod pallet {
 		traits::{Convert, ConvertBack, Get},
 		Perquintill,
 	};
+	use xcm_executor::traits::ConvertLocation;
 
 	#[pallet::composite_enum]
 	pub enum HoldReason {
@@ -186,6 +241,9 @@ pub mod pallet {
 		/// A way to convert from and to the account type used in CT migrations
 		type AccountId32Conversion: ConvertBack<Self::AccountId, [u8; 32]>;
 
+		/// Origin allowed to manage the registry of accepted funding assets.
+		type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
 		/// Type used for testing and benchmarks
 		#[cfg(any(test, feature = "runtime-benchmarks", feature = "std"))]
 		type AllPalletsWithoutSystem: OnFinalize<BlockNumberFor<Self>>
@@ -200,6 +258,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type CommunityRoundDuration: Get<BlockNumberFor<Self>>;
 
+		/// The number of blocks after a contribution during which the contributor can still withdraw it.
+		#[pallet::constant]
+		type ContributionCoolingOffPeriod: Get<BlockNumberFor<Self>>;
+
 		/// The currency used for minting contribution tokens as fungible assets (i.e pallet-assets)
 		type ContributionTokenCurrency: fungibles::Create<AccountIdOf<Self>, AssetId = ProjectId, Balance = Balance>
 			+ fungibles::Destroy<AccountIdOf<Self>, AssetId = ProjectId, Balance = Balance>
@@ -234,7 +296,7 @@ pub mod pallet {
 		#[pallet::constant]
 		type EvaluatorSlash: Get<Percent>;
 
-		/// The fee brackets for the project's funding
+		/// The fee brackets for the project's funding, used until governance sets new ones
 		#[pallet::constant]
 		type FeeBrackets: Get<Vec<(Percent, Balance)>>;
 
@@ -244,15 +306,26 @@ pub mod pallet {
 			+ fungibles::metadata::Mutate<AccountIdOf<Self>, AssetId = u32>
 			+ fungibles::Mutate<AccountIdOf<Self>, Balance = Balance>;
 
+		/// Share of the funding target a project needs to raise to be successful, if its issuer did not set a soft cap.
 		type FundingSuccessThreshold: Get<Perquintill>;
 
 		/// Credentialized investor Origin, ensures users are of investing type Retail, or Professional, or Institutional.
 		type InvestorOrigin: EnsureOriginWithCredentials<
 			<Self as frame_system::Config>::RuntimeOrigin,
-			Success = (AccountIdOf<Self>, Did, InvestorType, Cid),
+			Success = (AccountIdOf<Self>, Did, InvestorType, Cid, Option<Jurisdiction>, CredentialScope),
 		>;
 
-		/// Max individual bids per project. Used to estimate worst case weight for price calculation
+		/// How many blocks a participant has to keep its CTs after settlement, to claim its share of the
+		/// long-term-holder CT pot.
+		#[pallet::constant]
+		type LongTermHolderPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Hard cap on the projects a DID can have active at the same time. Governance can only set the
+		/// [`ActiveProjectsLimit`] up to this value.
+		#[pallet::constant]
+		type MaxActiveProjectsPerDid: Get<u32>;
+
+		/// Max individual bids per project. Auction resolution is chunked, so this only bounds the bid index size
 		#[pallet::constant]
 		type MaxBidsPerProject: Get<u32>;
 
@@ -311,6 +384,10 @@ pub mod pallet {
 		/// Method to get the price of an asset like USDT or PLMC. Likely to come from an oracle
 		type PriceProvider: ProvideAssetPrice<AssetId = u32, Price = Self::Price>;
 
+		/// The length (expressed in number of blocks) of the Private Round, for projects that have one.
+		#[pallet::constant]
+		type PrivateRoundDuration: Get<BlockNumberFor<Self>>;
+
 		/// Something that provides randomness in the runtime.
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
@@ -359,6 +436,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type ContributionTreasury: Get<AccountIdOf<Self>>;
 
+		/// Account that receives the liquidity-pool CT pot of successful projects. The pot is migrated
+		/// together with the participants' CTs.
+		#[pallet::constant]
+		type LiquidityPoolsAccount: Get<AccountIdOf<Self>>;
+
 		/// The Ed25519 Verifier Public Key of credential JWTs
 		#[pallet::constant]
 		type VerifierPublicKey: Get<[u8; 32]>;
@@ -368,6 +450,13 @@ pub mod pallet {
 
 		/// Callbacks for dealing with an evaluator slash on other pallets
 		type OnSlash: OnSlash<AccountIdOf<Self>, Balance>;
+
+		/// Origin of the users of other chains that evaluate, bid and contribute through an XCM `Transact`.
+		/// Resolves to the location of the user.
+		type XcmParticipantOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin, Success = Location>;
+
+		/// Converts the location of a user participating through XCM into the local account holding its participations.
+		type XcmParticipantAccount: ConvertLocation<AccountIdOf<Self>>;
 	}
 
 	#[pallet::storage]
@@ -426,6 +515,37 @@ pub mod pallet {
 		BidInfoOf<T>,
 	>;
 
+	/// Bids split across price buckets, keyed by the id of their first piece.
+	#[pallet::storage]
+	pub type BidGroups<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, ProjectId>,
+			NMapKey<Blake2_128Concat, AccountIdOf<T>>,
+			NMapKey<Blake2_128Concat, u32>,
+		),
+		BidGroupOf<T>,
+	>;
+
+	/// Price-ordered index of the bids of each project, by price bucket and position in the bucket.
+	/// Bids are resolved from the highest bucket down, and in order of arrival within a bucket.
+	/// Cancelled bids keep their entry, and are skipped when resolving.
+	#[pallet::storage]
+	pub type BidsByBucket<T: Config> = StorageNMap<
+		_,
+		(NMapKey<Blake2_128Concat, ProjectId>, NMapKey<Twox64Concat, u32>, NMapKey<Twox64Concat, u32>),
+		(AccountIdOf<T>, u32),
+	>;
+
+	/// How many bids were placed in each price bucket of a project, i.e. the next free position in the bucket.
+	#[pallet::storage]
+	pub type BucketBidCounts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Twox64Concat, u32, u32, ValueQuery>;
+
+	/// The highest price bucket of a project that received a bid.
+	#[pallet::storage]
+	pub type HighestBidBuckets<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, u32, ValueQuery>;
+
 	#[pallet::storage]
 	/// Contributions made during the Community and Remainder round. i.e token buys
 	pub type Contributions<T: Config> = StorageNMap<
@@ -458,6 +578,12 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type UnmigratedCounter<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, u32, ValueQuery>;
 
+	/// The account on the project's chain where a participant receives its migrated CTs, e.g. an `AccountKey20` on
+	/// EVM chains. Participants without one receive them at the `AccountId32` of their Polimec account.
+	#[pallet::storage]
+	pub type MigrationDestinations<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, AccountIdOf<T>, Location>;
+
 	pub struct MaxParticipationsPerUser<T: Config>(PhantomData<T>);
 	impl<T: Config> Get<u32> for MaxParticipationsPerUser<T> {
 		fn get() -> u32 {
@@ -468,14 +594,191 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ActiveMigrationQueue<T: Config> = StorageMap<_, Blake2_128Concat, QueryId, (ProjectId, T::AccountId)>;
 
-	/// A map to keep track of what issuer's did has an active project. It prevents one issuer having multiple active projects
+	/// A map to keep track of the active projects of each issuer's did, from creation until the funding ends.
+	/// It prevents one issuer having more active projects than the [`ActiveProjectsLimit`].
 	#[pallet::storage]
-	pub type DidWithActiveProjects<T: Config> = StorageMap<_, Blake2_128Concat, Did, ProjectId, OptionQuery>;
+	pub type DidWithActiveProjects<T: Config> =
+		StorageMap<_, Blake2_128Concat, Did, BoundedVec<ProjectId, T::MaxActiveProjectsPerDid>, ValueQuery>;
+
+	/// How many projects a DID can have active at the same time. Set by governance, up to `MaxActiveProjectsPerDid`.
+	#[pallet::storage]
+	pub type ActiveProjectsLimit<T: Config> = StorageValue<_, u32, ValueQuery, ConstU32<1>>;
 
 	#[pallet::storage]
 	pub type DidWithWinningBids<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, Did, bool, ValueQuery>;
 
+	/// DIDs the issuer allowed to participate in the private round of a project.
+	#[pallet::storage]
+	pub type PrivateRoundAllowList<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, Did, bool, ValueQuery>;
+
+	/// Projects in `AuctionResolving` whose bids are being resolved, in `on_idle` or with `resolve_auction`
+	#[pallet::storage]
+	pub type AuctionResolutions<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, AuctionResolutionOf<T>>;
+
+	/// The last project whose auction failed to resolve in `on_idle`. Resolution resumes after it,
+	/// so that one failing project does not hold back the others
+	#[pallet::storage]
+	pub type AuctionResolutionCursor<T: Config> = StorageValue<_, ProjectId>;
+
+	/// Projects in `SettlementStarted` whose participations are being settled automatically in `on_idle`
+	#[pallet::storage]
+	pub type SettlementCursors<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, SettlementCursorOf<T>>;
+
+	/// The long-term-holder CT pot of each successful project, and the CTs it is shared between
+	#[pallet::storage]
+	pub type LongTermHolderPots<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, LongTermHolderPot>;
+
+	/// CTs received by each participant on settlement, which can claim a share of the long-term-holder pot.
+	/// They stay frozen in `ContributionTokenCurrency` while tracked here.
+	#[pallet::storage]
+	pub type LongTermHoldings<T: Config> = StorageNMap<
+		_,
+		(NMapKey<Blake2_128Concat, ProjectId>, NMapKey<Blake2_128Concat, AccountIdOf<T>>),
+		LongTermHoldingOf<T>,
+	>;
+
+	/// Milestone approval progress of each successful project with milestones
+	#[pallet::storage]
+	pub type MilestonesProgress<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, MilestoneProgress>;
+
+	/// Funding assets held in escrow in the project account, per project and asset
+	#[pallet::storage]
+	pub type Escrows<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, AcceptedFundingAsset, Escrow>;
+
+	/// Funding assets each participant put in escrow, refunded pro rata if a milestone fails
+	#[pallet::storage]
+	pub type EscrowedParticipations<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, ProjectId>,
+			NMapKey<Blake2_128Concat, AccountIdOf<T>>,
+			NMapKey<Blake2_128Concat, AcceptedFundingAsset>,
+		),
+		Balance,
+	>;
+
+	/// Voting power of each milestone approver, recorded on settlement
+	#[pallet::storage]
+	pub type MilestoneVotingPower<T: Config> =
+		StorageNMap<_, (NMapKey<Blake2_128Concat, ProjectId>, NMapKey<Blake2_128Concat, AccountIdOf<T>>), Balance>;
+
+	/// Approvals given to each milestone of a project, so an approver cannot approve twice
+	#[pallet::storage]
+	pub type MilestoneApprovals<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, ProjectId>,
+			NMapKey<Blake2_128Concat, u32>,
+			NMapKey<Blake2_128Concat, AccountIdOf<T>>,
+		),
+		(),
+	>;
+
+	/// Registry of the assets that can be used to participate in projects
+	#[pallet::storage]
+	pub type FundingAssets<T: Config> = StorageMap<_, Blake2_128Concat, AcceptedFundingAsset, FundingAssetInfo>;
+
+	/// Reverse index of `FundingAssets`, used to match the assets of incoming XCM transfers
+	#[pallet::storage]
+	pub type FundingAssetLocations<T: Config> = StorageMap<_, Blake2_128Concat, Location, AcceptedFundingAsset>;
+
+	/// Projects paused by governance. Participations to them are rejected, and they do not move forward, until they are
+	/// resumed or cancelled.
+	#[pallet::storage]
+	pub type PausedProjects<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, (), OptionQuery>;
+
+	pub struct DefaultRoundDurationBounds<T: Config>(PhantomData<T>);
+	impl<T: Config> Get<RoundDurationBoundsOf<T>> for DefaultRoundDurationBounds<T> {
+		fn get() -> RoundDurationBoundsOf<T> {
+			let fixed = |duration: BlockNumberFor<T>| DurationBounds::new(duration, duration);
+			RoundDurationBounds {
+				evaluation: fixed(T::EvaluationRoundDuration::get()),
+				auction: fixed(T::AuctionRoundDuration::get()),
+				community: fixed(T::CommunityRoundDuration::get()),
+				remainder: fixed(T::RemainderRoundDuration::get()),
+			}
+		}
+	}
+
+	/// Bounds on the round durations issuers can choose. Until governance sets them, only the
+	/// runtime default durations are allowed.
+	#[pallet::storage]
+	pub type RoundDurationLimits<T: Config> =
+		StorageValue<_, RoundDurationBoundsOf<T>, ValueQuery, DefaultRoundDurationBounds<T>>;
+
+	pub struct DefaultSoftCapBounds<T: Config>(PhantomData<T>);
+	impl<T: Config> Get<SoftCapBounds> for DefaultSoftCapBounds<T> {
+		fn get() -> SoftCapBounds {
+			let threshold = T::FundingSuccessThreshold::get();
+			SoftCapBounds::new(threshold, threshold)
+		}
+	}
+
+	/// Bounds on the soft cap issuers can choose. Until governance sets them, only the runtime's
+	/// `FundingSuccessThreshold` is allowed.
+	#[pallet::storage]
+	pub type SoftCapLimits<T: Config> = StorageValue<_, SoftCapBounds, ValueQuery, DefaultSoftCapBounds<T>>;
+
+	pub struct DefaultFeeParameters<T: Config>(PhantomData<T>);
+	impl<T: Config> Get<FeeParameters> for DefaultFeeParameters<T> {
+		fn get() -> FeeParameters {
+			FeeParameters {
+				fee_brackets: BoundedVec::truncate_from(T::FeeBrackets::get()),
+				evaluator_share: Perquintill::from_percent(30),
+				early_evaluator_share: Perquintill::from_percent(20),
+				liquidity_pools_share: Perquintill::from_percent(50),
+				long_term_holder_share: Perquintill::from_percent(20),
+			}
+		}
+	}
+
+	/// The fee parameters new projects snapshot when their evaluation starts. Until governance sets them,
+	/// the runtime's `FeeBrackets` are used.
+	#[pallet::storage]
+	pub type ProtocolFeeParameters<T: Config> = StorageValue<_, FeeParameters, ValueQuery, DefaultFeeParameters<T>>;
+
+	/// The fee parameters each project snapshotted when its evaluation started
+	#[pallet::storage]
+	pub type ProjectsFeeParameters<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, FeeParameters>;
+
+	/// The location of each user that participated in a project through XCM, keyed by project and derived account.
+	/// Refunds are sent back there, until nothing is left to refund the user in the project.
+	#[pallet::storage]
+	pub type XcmParticipantLocations<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, AccountIdOf<T>, Location>;
+
+	/// Funding assets raised by projects with a `funding_destination_location`, waiting in the project account to be
+	/// sent there over XCM. Sends that fail stay here until they are retried.
+	#[pallet::storage]
+	pub type PendingIssuerPayouts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, AcceptedFundingAsset, Balance>;
+
+	/// The last issuer payout tried in `on_idle`. The next block continues after it, so that failing sends do not
+	/// hold back the others
+	#[pallet::storage]
+	pub type IssuerPayoutCursor<T: Config> = StorageValue<_, (ProjectId, AcceptedFundingAsset)>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub funding_assets: Vec<(AcceptedFundingAsset, FundingAssetInfo)>,
+		#[serde(skip)]
+		pub _config: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (asset, info) in &self.funding_assets {
+				FundingAssetLocations::<T>::insert(info.location.clone(), asset);
+				FundingAssets::<T>::insert(asset, info);
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -507,6 +810,21 @@ pub mod pallet {
 			id: u32,
 			plmc_amount: Balance,
 		},
+		/// An evaluation's bond was increased by `plmc_amount`.
+		EvaluationIncreased {
+			project_id: ProjectId,
+			evaluator: AccountIdOf<T>,
+			id: u32,
+			plmc_amount: Balance,
+		},
+		/// Part or all of an evaluation's bond was withdrawn, and `plmc_released` unbonded.
+		EvaluationWithdrawn {
+			project_id: ProjectId,
+			evaluator: AccountIdOf<T>,
+			id: u32,
+			usd_amount: Balance,
+			plmc_released: Balance,
+		},
 		/// A bid was made for a project
 		Bid {
 			project_id: ProjectId,
@@ -519,6 +837,26 @@ pub mod pallet {
 			plmc_bond: Balance,
 			multiplier: MultiplierOf<T>,
 		},
+		/// A bid was cancelled during the auction round, and its funds released.
+		BidCancelled {
+			project_id: ProjectId,
+			bidder: AccountIdOf<T>,
+			id: u32,
+			ct_amount: Balance,
+			funding_asset: AcceptedFundingAsset,
+			funding_amount_released: Balance,
+			plmc_released: Balance,
+		},
+		/// A bid was reduced to `ct_amount` during the auction round, and the excess funds released.
+		BidReduced {
+			project_id: ProjectId,
+			bidder: AccountIdOf<T>,
+			id: u32,
+			ct_amount: Balance,
+			funding_asset: AcceptedFundingAsset,
+			funding_amount_released: Balance,
+			plmc_released: Balance,
+		},
 		/// A contribution was made for a project. i.e token purchase
 		Contribution {
 			project_id: ProjectId,
@@ -530,6 +868,16 @@ pub mod pallet {
 			plmc_bond: Balance,
 			multiplier: MultiplierOf<T>,
 		},
+		/// A contribution was withdrawn during its cooling-off period, and its funds released.
+		ContributionWithdrawn {
+			project_id: ProjectId,
+			contributor: AccountIdOf<T>,
+			id: u32,
+			ct_amount: Balance,
+			funding_asset: AcceptedFundingAsset,
+			funding_amount_released: Balance,
+			plmc_released: Balance,
+		},
 		BidRefunded {
 			project_id: ProjectId,
 			account: AccountIdOf<T>,
@@ -551,12 +899,104 @@ pub mod pallet {
 			id: u32,
 			final_ct_amount: Balance,
 			final_ct_usd_price: PriceOf<T>,
+			/// Funding assets of the bid taken as protocol fee instead of being paid out to the issuer.
+			funding_asset_fee: Balance,
 		},
 		ContributionSettled {
 			project_id: ProjectId,
 			account: AccountIdOf<T>,
 			id: u32,
 			ct_amount: Balance,
+			/// Funding assets of the contribution taken as protocol fee instead of being paid out to the issuer.
+			funding_asset_fee: Balance,
+		},
+		/// A participation could not be settled automatically, and has to be settled with its extrinsic.
+		AutomaticSettlementSkipped {
+			project_id: ProjectId,
+			participation_type: ParticipationType,
+			account: AccountIdOf<T>,
+			id: u32,
+			error: DispatchError,
+		},
+		/// The automatic settlement of a project went through all its participations, but some were skipped. Once
+		/// they are settled with their extrinsics, the project has to be marked as settled with
+		/// `mark_project_as_settled`.
+		AutomaticSettlementIncomplete { project_id: ProjectId, error: DispatchError },
+		/// A long-term holder received its share of the long-term-holder CT pot.
+		LongTermHolderRewardClaimed {
+			project_id: ProjectId,
+			account: AccountIdOf<T>,
+			ct_amount: Balance,
+		},
+		/// A long-term holder gave up its share of the long-term-holder CT pot, and its `ct_amount` CTs were unfrozen.
+		LongTermHolderRewardForfeited {
+			project_id: ProjectId,
+			account: AccountIdOf<T>,
+			ct_amount: Balance,
+		},
+		/// An approver approved the current milestone of a project.
+		MilestoneApprovalGiven {
+			project_id: ProjectId,
+			milestone: u32,
+			account: AccountIdOf<T>,
+			power: Balance,
+		},
+		/// A milestone was approved, and its share of the escrow was released to the issuer.
+		MilestoneReleased {
+			project_id: ProjectId,
+			milestone: u32,
+		},
+		/// A milestone missed its deadline. What is left in escrow is refunded to the participants.
+		MilestoneFailed {
+			project_id: ProjectId,
+			milestone: u32,
+		},
+		/// A participant was refunded its share of the escrow left after a milestone failed.
+		EscrowRefunded {
+			project_id: ProjectId,
+			account: AccountIdOf<T>,
+			asset: AcceptedFundingAsset,
+			amount: Balance,
+		},
+		/// A refund of a participant that joined through XCM was sent back to its location.
+		RefundSentThroughXcm {
+			project_id: ProjectId,
+			account: AccountIdOf<T>,
+			destination: Location,
+			asset: AcceptedFundingAsset,
+			amount: Balance,
+			message_id: XcmHash,
+		},
+		/// A refund of a participant that joined through XCM could not be sent back to its location.
+		/// The funding assets stay on its local account.
+		RefundThroughXcmFailed {
+			project_id: ProjectId,
+			account: AccountIdOf<T>,
+			destination: Location,
+			asset: AcceptedFundingAsset,
+			amount: Balance,
+		},
+		/// The funding assets raised by a project were sent to the issuer's `funding_destination_location`.
+		IssuerPayoutSentThroughXcm {
+			project_id: ProjectId,
+			destination: Location,
+			asset: AcceptedFundingAsset,
+			amount: Balance,
+			message_id: XcmHash,
+		},
+		/// The funding assets raised by a project could not be sent to the issuer's `funding_destination_location`.
+		/// They stay queued in the project account, to be sent with [`pay_out_issuer`](Pallet::pay_out_issuer) or in `on_idle`.
+		IssuerPayoutThroughXcmFailed {
+			project_id: ProjectId,
+			destination: Location,
+			asset: AcceptedFundingAsset,
+			amount: Balance,
+		},
+		/// The issuer changed where the funding assets of a project are paid out. Without a location, they are paid
+		/// to the `funding_destination_account` on Polimec.
+		FundingDestinationLocationSet {
+			project_id: ProjectId,
+			location: Option<Location>,
 		},
 		PalletMigrationStarted {
 			project_id: ProjectId,
@@ -592,10 +1032,60 @@ pub mod pallet {
 			account: AccountIdOf<T>,
 			status: MigrationStatus,
 		},
+		/// A participant registered the account on the project's chain that receives its migrated CTs.
+		MigrationDestinationSet {
+			project_id: ProjectId,
+			account: AccountIdOf<T>,
+			destination: Location,
+		},
 
 		CTMigrationFinished {
 			project_id: ProjectId,
 		},
+		/// A funding asset was added to the registry, or its entry was replaced.
+		FundingAssetRegistered {
+			asset: AcceptedFundingAsset,
+			info: FundingAssetInfo,
+		},
+		/// A funding asset was enabled or disabled for new participations.
+		FundingAssetStatusChanged {
+			asset: AcceptedFundingAsset,
+			enabled: bool,
+		},
+		/// Governance changed the bounds on the round durations issuers can choose.
+		RoundDurationBoundsSet {
+			bounds: RoundDurationBoundsOf<T>,
+		},
+		/// Governance changed the bounds on the soft cap issuers can choose.
+		SoftCapBoundsSet {
+			bounds: SoftCapBounds,
+		},
+		/// Governance changed the fee parameters of the projects starting their evaluation from now on.
+		FeeParametersSet {
+			parameters: FeeParameters,
+		},
+		/// Governance changed how many projects a DID can have active at the same time.
+		ActiveProjectsLimitSet {
+			limit: u32,
+		},
+		/// The issuer added a DID to the private round allow-list of a project, or removed it.
+		PrivateRoundAccessSet {
+			project_id: ProjectId,
+			did: Did,
+			allowed: bool,
+		},
+		/// Governance paused all participations to a project.
+		ProjectPaused {
+			project_id: ProjectId,
+		},
+		/// Governance resumed the participations to a paused project.
+		ProjectResumed {
+			project_id: ProjectId,
+		},
+		/// Governance cancelled a project. All its participations are refunded in the settlement.
+		ProjectCancelled {
+			project_id: ProjectId,
+		},
 	}
 
 	#[pallet::error]
@@ -650,7 +1140,7 @@ pub mod pallet {
 		/// The action's caller is not the issuer of the project and is not allowed to execute
 		/// this action.
 		NotIssuer,
-		/// The issuer already has an active project. The issuer can only have one active project.
+		/// The issuer already has as many active projects as the [`ActiveProjectsLimit`] allows.
 		HasActiveProject,
 		/// The issuer tries to participate to their own project.
 		ParticipationToOwnProject,
@@ -662,7 +1152,7 @@ pub mod pallet {
 		PriceTooLow,
 		/// The ticket sizes are not valid.
 		TicketSizeError,
-		/// The participation currencies are not unique.
+		/// The participation currencies are not unique, or one of them is not an enabled funding asset.
 		ParticipationCurrenciesError,
 		/// The allocation size is invalid. Either zero or higher than the max supply.
 		AllocationSizeError,
@@ -679,6 +1169,29 @@ pub mod pallet {
 		// The combination of decimals and price of this project is not representable within our 6 decimals USD system,
 		// and integer space of 128 bits.
 		BadTokenomics,
+		/// A round duration is outside the bounds set by governance.
+		RoundDurationError,
+		/// The CT release policy has a zero linear duration.
+		CtReleasePolicyError,
+		/// The round duration bounds have a zero minimum, or a minimum above the maximum.
+		InvalidRoundDurationBounds,
+		/// The soft cap is outside the bounds set by governance.
+		SoftCapError,
+		/// The soft cap bounds have a zero minimum, or a minimum above the maximum.
+		InvalidSoftCapBounds,
+		/// The fee parameters have no fee bracket, or split the fee CTs into more than 100%.
+		InvalidFeeParameters,
+		/// The active projects limit is zero, or above `MaxActiveProjectsPerDid`.
+		InvalidActiveProjectsLimit,
+		/// The private round has a zero price or allocation, or together with the auction it takes more than the
+		/// total allocation.
+		PrivateRoundError,
+		/// The milestones do not release 100% of the raise, or their deadlines are not increasing.
+		MilestoneError,
+		/// The jurisdiction restriction does not list any jurisdiction.
+		JurisdictionRestrictionError,
+		/// The funding destination location does not point to an account on a reserve of every participation currency.
+		FundingDestinationError,
 
 		// * Error related to an participation action. Evaluation, bid or contribution failed. *
 		/// The amount is too low.
@@ -700,8 +1213,24 @@ pub mod pallet {
 		ParticipantNotEnoughFunds,
 		/// The JWT included the wrong policy for participating in this project.
 		PolicyMismatch,
+		/// The project does not accept participants from the jurisdiction in the JWT, or the JWT has none.
+		JurisdictionNotAllowed,
+		/// The JWT was issued to be used on another project, or on a project while the call is not made on one.
+		CredentialProjectMismatch,
+		/// The participation is above the maximum USD amount of the JWT.
+		CredentialMaxUsdExceeded,
+		/// The JWT can only be used once, and it was already used.
+		CredentialAlreadyUsed,
 		/// Contribution tokens have all been sold
 		ProjectSoldOut,
+		/// The cooling-off period of the contribution is over, so it cannot be withdrawn anymore.
+		CoolingOffPeriodOver,
+		/// The round the contribution was made in is over, so it cannot be withdrawn anymore.
+		ContributionRoundOver,
+		/// The project was created without a private round.
+		NoPrivateRound,
+		/// The DID is not in the private round allow-list of the project.
+		NotAllowListed,
 
 		//  * An error related to the migration process. *
 		/// Tried to start a migration check but the bidirectional channel is not yet open
@@ -729,6 +1258,58 @@ pub mod pallet {
 		SettlementNotComplete,
 		/// Tried to mark a project's CT migration as finished but there are still migrations to be confirmed
 		MigrationsStillPending,
+		/// The migration destination is not a local `AccountId32` or `AccountKey20` of the project's chain.
+		BadMigrationDestination,
+		/// The signature does not prove the migration destination is controlled by the participant.
+		BadMigrationDestinationSignature,
+
+		// * An error related to the long-term-holder rewards. *
+		/// The account did not receive CTs on settlement, or already claimed or forfeited its long-term-holder reward.
+		NoLongTermHolding,
+		/// The account did not hold its CTs for `LongTermHolderPeriod` blocks yet.
+		LongTermHolderPeriodNotOver,
+		/// The account does not hold the CTs it received on settlement anymore.
+		LongTermHoldingNotKept,
+		/// The CTs of the account are already being migrated, so its migrations cannot be changed anymore.
+		MigrationAlreadyStarted,
+
+		// * An error related to the milestones. *
+		/// The project has no milestones.
+		NoMilestones,
+		/// All the milestones of the project were already approved.
+		MilestonesCompleted,
+		/// A milestone of the project failed, so no more milestones can be approved.
+		MilestonesFailed,
+		/// No milestone of the project failed, so the escrow cannot be refunded.
+		MilestonesNotFailed,
+		/// The deadline of the current milestone passed.
+		MilestoneDeadlinePassed,
+		/// The deadline of the current milestone did not pass yet.
+		MilestoneDeadlineNotPassed,
+		/// The account cannot approve the milestones of the project.
+		NoMilestoneVotingPower,
+		/// The account already approved the current milestone.
+		MilestoneAlreadyApproved,
+		/// The account has no funds left in the escrow of the project.
+		NoEscrowedFunds,
+
+		// * An error related to the issuer payouts over XCM. *
+		/// The project has no funding assets waiting to be sent to the issuer's location.
+		NoPendingIssuerPayout,
+
+		// * An error related to the funding asset registry. *
+		/// The funding asset is not in the registry.
+		FundingAssetNotFound,
+		/// The location is already used by another funding asset.
+		FundingAssetLocationTaken,
+
+		// * An error related to the governance emergency controls. *
+		/// The project was paused by governance, and does not accept participations.
+		ProjectPaused,
+		/// Tried to resume a project that is not paused.
+		ProjectNotPaused,
+		/// The project's settlement already started, so it cannot be cancelled anymore.
+		ProjectNotCancellable,
 	}
 
 	#[pallet::call]
@@ -741,19 +1322,21 @@ pub mod pallet {
 			jwt: UntrustedToken,
 			project: ProjectMetadataOf<T>,
 		) -> DispatchResult {
-			let (account, did, investor_type, _cid) =
+			let (account, did, investor_type, _cid, _jurisdiction, scope) =
 				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
 			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::use_unscoped_credential(&did, &scope)?;
 			Self::do_create_project(&account, project, did)
 		}
 
 		#[pallet::call_index(1)]
 		#[pallet::weight(WeightInfoOf::<T>::remove_project())]
 		pub fn remove_project(origin: OriginFor<T>, jwt: UntrustedToken, project_id: ProjectId) -> DispatchResult {
-			let (account, did, investor_type, _cid) =
+			let (account, did, investor_type, _cid, _jurisdiction, scope) =
 				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
 			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
-			Self::do_remove_project(account, project_id, did)
+			Self::use_credential_scope(project_id, &did, &scope)?;
+			Self::do_remove_project(account, project_id)
 		}
 
 		/// Change the metadata hash of a project
@@ -765,9 +1348,10 @@ pub mod pallet {
 			project_id: ProjectId,
 			new_project_metadata: ProjectMetadataOf<T>,
 		) -> DispatchResult {
-			let (account, _did, investor_type, _cid) =
+			let (account, did, investor_type, _cid, _jurisdiction, scope) =
 				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
 			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::use_credential_scope(project_id, &did, &scope)?;
 			Self::do_edit_project(account, project_id, new_project_metadata)
 		}
 
@@ -775,9 +1359,10 @@ pub mod pallet {
 		#[pallet::call_index(3)]
 		#[pallet::weight(WeightInfoOf::<T>::start_evaluation())]
 		pub fn start_evaluation(origin: OriginFor<T>, jwt: UntrustedToken, project_id: ProjectId) -> DispatchResult {
-			let (account, _did, investor_type, _cid) =
+			let (account, did, investor_type, _cid, _jurisdiction, scope) =
 				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
 			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::use_credential_scope(project_id, &did, &scope)?;
 			Self::do_start_evaluation(account, project_id)
 		}
 
@@ -790,10 +1375,54 @@ pub mod pallet {
 			project_id: ProjectId,
 			#[pallet::compact] usd_amount: Balance,
 		) -> DispatchResultWithPostInfo {
-			let (account, did, _investor_type, whitelisted_policy) =
-				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			let (account, did, _investor_type, whitelisted_policy, jurisdiction, scope) =
+				Self::ensure_participant(origin, &jwt, project_id)?;
+			ensure!(scope.allows_usd(usd_amount), Error::<T>::CredentialMaxUsdExceeded);
+			Self::use_credential_scope(project_id, &did, &scope)?;
 
-			Self::do_evaluate(&account, project_id, usd_amount, did, whitelisted_policy)
+			Self::do_evaluate(&account, project_id, usd_amount, did, whitelisted_policy, jurisdiction)
+		}
+
+		/// Bond more PLMC on an existing evaluation, during the evaluation stage
+		#[pallet::call_index(6)]
+		#[pallet::weight(WeightInfoOf::<T>::increase_evaluation())]
+		pub fn increase_evaluation(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			evaluation_id: u32,
+			#[pallet::compact] usd_amount: Balance,
+		) -> DispatchResult {
+			let (account, did, _investor_type, whitelisted_policy, jurisdiction, scope) =
+				Self::ensure_participant(origin, &jwt, project_id)?;
+			ensure!(scope.allows_usd(usd_amount), Error::<T>::CredentialMaxUsdExceeded);
+			Self::use_credential_scope(project_id, &did, &scope)?;
+
+			Self::do_increase_evaluation(
+				&account,
+				project_id,
+				evaluation_id,
+				usd_amount,
+				whitelisted_policy,
+				jurisdiction,
+			)
+		}
+
+		/// Withdraw part or all of an evaluation's bond, during the evaluation stage
+		#[pallet::call_index(27)]
+		#[pallet::weight(WeightInfoOf::<T>::withdraw_evaluation(<T as Config>::MaxEvaluationsPerProject::get()))]
+		pub fn withdraw_evaluation(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			evaluation_id: u32,
+			#[pallet::compact] usd_amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let (account, did, _investor_type, _cid, _jurisdiction, scope) =
+				Self::ensure_participant(origin, &jwt, project_id)?;
+			Self::use_credential_scope(project_id, &did, &scope)?;
+
+			Self::do_withdraw_evaluation(&account, project_id, evaluation_id, usd_amount)
 		}
 
 		#[pallet::call_index(5)]
@@ -803,6 +1432,61 @@ pub mod pallet {
 			Self::do_end_evaluation(project_id)
 		}
 
+		/// Add a DID to the private round allow-list of a project, or remove it. It needs to be called by the project issuer.
+		#[pallet::call_index(35)]
+		#[pallet::weight(WeightInfoOf::<T>::set_private_round_access())]
+		pub fn set_private_round_access(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			did: Did,
+			allowed: bool,
+		) -> DispatchResult {
+			let (account, issuer_did, investor_type, _cid, _jurisdiction, scope) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::use_credential_scope(project_id, &issuer_did, &scope)?;
+			Self::do_set_private_round_access(account, project_id, did, allowed)
+		}
+
+		/// Buy tokens in the Private Round at the fixed price set by the issuer. Only allow-listed DIDs can participate.
+		#[pallet::call_index(36)]
+		#[pallet::weight(
+			WeightInfoOf::<T>::private_contribute(T::MaxContributionsPerUser::get())
+		)]
+		pub fn private_contribute(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			#[pallet::compact] ct_amount: Balance,
+			multiplier: MultiplierOf<T>,
+			funding_asset: AcceptedFundingAsset,
+		) -> DispatchResultWithPostInfo {
+			let (contributor, did, investor_type, whitelisted_policy, jurisdiction, scope) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			Self::use_credential_scope(project_id, &did, &scope)?;
+			let params = DoContributeParams::<T> {
+				contributor,
+				project_id,
+				ct_amount,
+				multiplier,
+				funding_asset,
+				did,
+				investor_type,
+				whitelisted_policy,
+				jurisdiction,
+				max_usd: scope.max_usd,
+			};
+			Self::do_private_contribute(params)
+		}
+
+		#[pallet::call_index(37)]
+		#[pallet::weight(WeightInfoOf::<T>::end_private_round())]
+		pub fn end_private_round(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_end_private_round(project_id)
+		}
+
 		/// Bid for a project in the Auction round
 		#[pallet::call_index(7)]
 		#[pallet::weight(
@@ -821,8 +1505,9 @@ pub mod pallet {
 			multiplier: T::Multiplier,
 			funding_asset: AcceptedFundingAsset,
 		) -> DispatchResultWithPostInfo {
-			let (bidder, did, investor_type, whitelisted_policy) =
-				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			let (bidder, did, investor_type, whitelisted_policy, jurisdiction, scope) =
+				Self::ensure_participant(origin, &jwt, project_id)?;
+			Self::use_credential_scope(project_id, &did, &scope)?;
 			let params = DoBidParams::<T> {
 				bidder,
 				project_id,
@@ -832,28 +1517,76 @@ pub mod pallet {
 				did,
 				investor_type,
 				whitelisted_policy,
+				jurisdiction,
+				max_usd: scope.max_usd,
 			};
 			Self::do_bid(params)
 		}
 
+		/// Cancel a bid while the Auction round is still open, releasing its PLMC bond and funding asset.
+		/// A bid split across price buckets is cancelled with all of its pieces.
+		#[pallet::call_index(14)]
+		#[pallet::weight(WeightInfoOf::<T>::cancel_bid()
+			.saturating_add(Pallet::<T>::xcm_refund_weight())
+			.saturating_mul(u64::from(T::MaxBidsPerUser::get())))]
+		pub fn cancel_bid(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			bid_id: u32,
+		) -> DispatchResultWithPostInfo {
+			let (bidder, did, _investor_type, _cid, _jurisdiction, scope) =
+				Self::ensure_participant(origin, &jwt, project_id)?;
+			Self::use_credential_scope(project_id, &did, &scope)?;
+			let pieces = Self::do_cancel_bid(&bidder, project_id, bid_id)?;
+			let piece_weight = WeightInfoOf::<T>::cancel_bid().saturating_add(Self::xcm_refund_weight());
+			Ok(Some(piece_weight.saturating_mul(u64::from(pieces))).into())
+		}
+
+		/// Lower the CT amount of a bid while the Auction round is still open.
+		/// `new_ct_amount` is the new total of a bid split across price buckets.
+		#[pallet::call_index(15)]
+		#[pallet::weight(WeightInfoOf::<T>::reduce_bid()
+			.saturating_add(Pallet::<T>::xcm_refund_weight())
+			.saturating_mul(u64::from(T::MaxBidsPerUser::get())))]
+		pub fn reduce_bid(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			bid_id: u32,
+			#[pallet::compact] new_ct_amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let (bidder, did, investor_type, _cid, jurisdiction, scope) =
+				Self::ensure_participant(origin, &jwt, project_id)?;
+			Self::use_credential_scope(project_id, &did, &scope)?;
+			let pieces = Self::do_reduce_bid(&bidder, project_id, bid_id, new_ct_amount, investor_type, jurisdiction)?;
+			let piece_weight = WeightInfoOf::<T>::reduce_bid().saturating_add(Self::xcm_refund_weight());
+			Ok(Some(piece_weight.saturating_mul(u64::from(pieces))).into())
+		}
+
 		#[pallet::call_index(8)]
-		#[pallet::weight(WeightInfoOf::<T>::end_auction(
-			<T as Config>::MaxBidsPerProject::get() / 2,
-			<T as Config>::MaxBidsPerProject::get() / 2,
-		)
-		.max(WeightInfoOf::<T>::end_auction(
-			<T as Config>::MaxBidsPerProject::get(),
-			0u32,
-		))
-		.max(WeightInfoOf::<T>::end_auction(
-			0u32,
-			<T as Config>::MaxBidsPerProject::get(),
-		)))]
-		pub fn end_auction(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResultWithPostInfo {
+		#[pallet::weight(WeightInfoOf::<T>::end_auction())]
+		pub fn end_auction(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
 			ensure_signed(origin)?;
 			Self::do_end_auction(project_id)
 		}
 
+		/// Resolve up to `max_bids` bids of a project whose auction ended, from the highest price down.
+		/// The community round starts once all bids are resolved.
+		#[pallet::call_index(44)]
+		#[pallet::weight(WeightInfoOf::<T>::resolve_bid()
+			.saturating_mul(u64::from(*max_bids))
+			.saturating_add(WeightInfoOf::<T>::finish_auction_resolution()))]
+		pub fn resolve_auction(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			max_bids: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let actual_weight = Self::do_resolve_auction(project_id, max_bids)?;
+			Ok(Some(actual_weight).into())
+		}
+
 		/// Buy tokens in the Community or Remainder round at the price set in the Auction Round
 		#[pallet::call_index(9)]
 		#[pallet::weight(
@@ -867,8 +1600,9 @@ pub mod pallet {
 			multiplier: MultiplierOf<T>,
 			funding_asset: AcceptedFundingAsset,
 		) -> DispatchResultWithPostInfo {
-			let (contributor, did, investor_type, whitelisted_policy) =
-				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			let (contributor, did, investor_type, whitelisted_policy, jurisdiction, scope) =
+				Self::ensure_participant(origin, &jwt, project_id)?;
+			Self::use_credential_scope(project_id, &did, &scope)?;
 			let params = DoContributeParams::<T> {
 				contributor,
 				project_id,
@@ -878,10 +1612,28 @@ pub mod pallet {
 				did,
 				investor_type,
 				whitelisted_policy,
+				jurisdiction,
+				max_usd: scope.max_usd,
 			};
 			Self::do_contribute(params)
 		}
 
+		/// Withdraw a contribution during its cooling-off period and the round it was made in, releasing its PLMC bond
+		/// and funding asset
+		#[pallet::call_index(16)]
+		#[pallet::weight(WeightInfoOf::<T>::withdraw_contribution().saturating_add(Pallet::<T>::xcm_refund_weight()))]
+		pub fn withdraw_contribution(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			contribution_id: u32,
+		) -> DispatchResult {
+			let (contributor, did, _investor_type, _cid, _jurisdiction, scope) =
+				Self::ensure_participant(origin, &jwt, project_id)?;
+			Self::use_credential_scope(project_id, &did, &scope)?;
+			Self::do_withdraw_contribution(&contributor, project_id, contribution_id)
+		}
+
 		#[pallet::call_index(10)]
 		#[pallet::weight(WeightInfoOf::<T>::end_funding_project_successful())]
 		pub fn end_funding(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
@@ -911,7 +1663,8 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(13)]
-		#[pallet::weight(WeightInfoOf::<T>::settle_accepted_bid_with_refund())]
+		#[pallet::weight(WeightInfoOf::<T>::settle_accepted_bid_with_refund()
+			.saturating_add(Pallet::<T>::xcm_refund_weight()))]
 		pub fn settle_bid(
 			origin: OriginFor<T>,
 			project_id: ProjectId,
@@ -924,7 +1677,8 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(17)]
-		#[pallet::weight(WeightInfoOf::<T>::settle_contribution_project_successful())]
+		#[pallet::weight(WeightInfoOf::<T>::settle_contribution_project_successful()
+			.saturating_add(Pallet::<T>::xcm_refund_weight()))]
 		pub fn settle_contribution(
 			origin: OriginFor<T>,
 			project_id: ProjectId,
@@ -944,6 +1698,103 @@ pub mod pallet {
 			Self::do_mark_project_as_settled(project_id)
 		}
 
+		/// Transfer to a long-term holder its share of the long-term-holder CT pot of a project. The reward is
+		/// migrated together with the holder's CTs.
+		#[pallet::call_index(38)]
+		#[pallet::weight(WeightInfoOf::<T>::claim_long_term_holder_reward())]
+		pub fn claim_long_term_holder_reward(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			holder: AccountIdOf<T>,
+		) -> DispatchResult {
+			let _caller = ensure_signed(origin)?;
+			Self::do_claim_long_term_holder_reward(project_id, holder)
+		}
+
+		/// Give up the long-term-holder reward of a project, unfreezing the CTs received on settlement so they can
+		/// be transferred.
+		#[pallet::call_index(48)]
+		#[pallet::weight(WeightInfoOf::<T>::forfeit_long_term_holder_reward())]
+		pub fn forfeit_long_term_holder_reward(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			let holder = ensure_signed(origin)?;
+			Self::do_forfeit_long_term_holder_reward(project_id, holder)
+		}
+
+		/// Approve the current milestone of a project, with the voting power recorded on settlement.
+		/// Once approvers holding more than half of the voting power approved it, its share of the escrow
+		/// is released to the issuer.
+		#[pallet::call_index(40)]
+		#[pallet::weight(WeightInfoOf::<T>::approve_milestone())]
+		pub fn approve_milestone(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			let approver = ensure_signed(origin)?;
+			Self::do_approve_milestone(approver, project_id)
+		}
+
+		/// Mark the current milestone of a project as failed after its deadline passed without approval.
+		#[pallet::call_index(41)]
+		#[pallet::weight(WeightInfoOf::<T>::fail_milestone())]
+		pub fn fail_milestone(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			let _caller = ensure_signed(origin)?;
+			Self::do_fail_milestone(project_id)
+		}
+
+		/// Refund a participant its share of the escrow left after a milestone of the project failed.
+		#[pallet::call_index(42)]
+		#[pallet::weight(WeightInfoOf::<T>::claim_escrow_refund()
+			.saturating_add(Pallet::<T>::xcm_refund_weight().saturating_mul(u64::from(MAX_PARTICIPATION_CURRENCIES))))]
+		pub fn claim_escrow_refund(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			participant: AccountIdOf<T>,
+		) -> DispatchResult {
+			let _caller = ensure_signed(origin)?;
+			Self::do_claim_escrow_refund(project_id, participant)
+		}
+
+		/// Send the funding assets a project raised so far to the issuer's `funding_destination_location`, in one
+		/// XCM message per funding asset. The assets that cannot be sent stay queued for a later call.
+		#[pallet::call_index(45)]
+		#[pallet::weight(WeightInfoOf::<T>::pay_out_issuer()
+			.saturating_add(Pallet::<T>::xcm_refund_weight().saturating_mul(u64::from(MAX_PARTICIPATION_CURRENCIES))))]
+		pub fn pay_out_issuer(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			let _caller = ensure_signed(origin)?;
+			Self::do_pay_out_issuer(project_id)
+		}
+
+		/// Point the funding asset payouts of a project to another `funding_destination_location`, e.g. when sending
+		/// to the current one keeps failing. Without a location, the queued payouts and the ones to come are paid to
+		/// the `funding_destination_account` on Polimec instead.
+		#[pallet::call_index(47)]
+		#[pallet::weight(WeightInfoOf::<T>::set_funding_destination_location())]
+		pub fn set_funding_destination_location(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			location: Option<Location>,
+		) -> DispatchResult {
+			let (account, did, investor_type, _cid, _jurisdiction, scope) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::use_credential_scope(project_id, &did, &scope)?;
+
+			Self::do_set_funding_destination_location(account, project_id, location)
+		}
+
+		/// Register the account on the project's chain that receives the caller's migrated CTs, e.g. an
+		/// `AccountKey20` on EVM chains. The account proves it belongs to the caller by signing the
+		/// [`migration_destination_message`](Pallet::migration_destination_message).
+		#[pallet::call_index(46)]
+		#[pallet::weight(WeightInfoOf::<T>::set_migration_destination(MaxParticipationsPerUser::<T>::get()))]
+		pub fn set_migration_destination(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			destination: Location,
+			signature: MigrationDestinationSignature,
+		) -> DispatchResult {
+			let participant = ensure_signed(origin)?;
+			Self::do_set_migration_destination(participant, project_id, destination, signature)
+		}
+
 		#[pallet::call_index(19)]
 		#[pallet::weight(WeightInfoOf::<T>::start_offchain_migration())]
 		pub fn start_offchain_migration(
@@ -951,9 +1802,10 @@ pub mod pallet {
 			jwt: UntrustedToken,
 			project_id: ProjectId,
 		) -> DispatchResult {
-			let (account, _did, investor_type, _cid) =
+			let (account, did, investor_type, _cid, _jurisdiction, scope) =
 				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
 			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::use_credential_scope(project_id, &did, &scope)?;
 
 			Self::do_start_offchain_migration(project_id, account)
 		}
@@ -978,9 +1830,10 @@ pub mod pallet {
 			project_id: ProjectId,
 			para_id: ParaId,
 		) -> DispatchResult {
-			let (account, _did, investor_type, _cid) =
+			let (account, did, investor_type, _cid, _jurisdiction, scope) =
 				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
 			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::use_credential_scope(project_id, &did, &scope)?;
 
 			Self::do_start_pallet_migration(&account, project_id, para_id)
 		}
@@ -992,9 +1845,10 @@ pub mod pallet {
 			jwt: UntrustedToken,
 			project_id: ProjectId,
 		) -> DispatchResult {
-			let (account, _did, investor_type, _cid) =
+			let (account, did, investor_type, _cid, _jurisdiction, scope) =
 				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
 			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::use_credential_scope(project_id, &did, &scope)?;
 			Self::do_start_pallet_migration_readiness_check(&account, project_id)
 		}
 
@@ -1042,6 +1896,107 @@ pub mod pallet {
 
 			Self::do_mark_project_ct_migration_as_finished(project_id)
 		}
+
+		/// Adds a funding asset to the registry, or replaces its current entry.
+		#[pallet::call_index(28)]
+		#[pallet::weight(WeightInfoOf::<T>::register_funding_asset())]
+		pub fn register_funding_asset(
+			origin: OriginFor<T>,
+			asset: AcceptedFundingAsset,
+			info: FundingAssetInfo,
+		) -> DispatchResult {
+			<T as Config>::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_register_funding_asset(asset, info)
+		}
+
+		/// Enables or disables a registered funding asset for new participations.
+		#[pallet::call_index(29)]
+		#[pallet::weight(WeightInfoOf::<T>::set_funding_asset_enabled())]
+		pub fn set_funding_asset_enabled(
+			origin: OriginFor<T>,
+			asset: AcceptedFundingAsset,
+			enabled: bool,
+		) -> DispatchResult {
+			<T as Config>::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_set_funding_asset_enabled(asset, enabled)
+		}
+
+		/// Sets the bounds on the round durations issuers can choose for their projects.
+		#[pallet::call_index(30)]
+		#[pallet::weight(WeightInfoOf::<T>::set_round_duration_bounds())]
+		pub fn set_round_duration_bounds(origin: OriginFor<T>, bounds: RoundDurationBoundsOf<T>) -> DispatchResult {
+			<T as Config>::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_set_round_duration_bounds(bounds)
+		}
+
+		/// Rejects all participation calls for a project, and stops its round transitions, auction resolution,
+		/// settlement and payouts, until it is resumed or cancelled.
+		#[pallet::call_index(31)]
+		#[pallet::weight(WeightInfoOf::<T>::pause_project())]
+		pub fn pause_project(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			<T as Config>::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_pause_project(project_id)
+		}
+
+		/// Accepts participations again for a paused project.
+		#[pallet::call_index(32)]
+		#[pallet::weight(WeightInfoOf::<T>::resume_project())]
+		pub fn resume_project(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			<T as Config>::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_resume_project(project_id)
+		}
+
+		/// Ends a project that did not start its settlement, and starts a settlement where every
+		/// participation is refunded.
+		#[pallet::call_index(33)]
+		#[pallet::weight(WeightInfoOf::<T>::cancel_project())]
+		pub fn cancel_project(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			<T as Config>::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_cancel_project(project_id)
+		}
+
+		/// Sets the bounds on the soft cap issuers can choose for their projects.
+		#[pallet::call_index(34)]
+		#[pallet::weight(WeightInfoOf::<T>::set_soft_cap_bounds())]
+		pub fn set_soft_cap_bounds(origin: OriginFor<T>, bounds: SoftCapBounds) -> DispatchResult {
+			<T as Config>::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_set_soft_cap_bounds(bounds)
+		}
+
+		/// Sets the fee brackets and reward splits of the projects that start their evaluation from now on.
+		#[pallet::call_index(39)]
+		#[pallet::weight(WeightInfoOf::<T>::set_fee_parameters())]
+		pub fn set_fee_parameters(origin: OriginFor<T>, parameters: FeeParameters) -> DispatchResult {
+			<T as Config>::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_set_fee_parameters(parameters)
+		}
+
+		/// Sets how many projects a DID can have active at the same time.
+		#[pallet::call_index(43)]
+		#[pallet::weight(WeightInfoOf::<T>::set_active_projects_limit())]
+		pub fn set_active_projects_limit(origin: OriginFor<T>, limit: u32) -> DispatchResult {
+			<T as Config>::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_set_active_projects_limit(limit)
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut used_weight = Self::do_automatic_auction_resolution(remaining_weight);
+			used_weight.saturating_accrue(Self::do_automatic_settlement(remaining_weight.saturating_sub(used_weight)));
+			used_weight.saturating_accrue(Self::do_automatic_issuer_payouts(remaining_weight.saturating_sub(used_weight)));
+			used_weight
+		}
 	}
 }
 
@@ -29,12 +29,16 @@ use frame_support::{
 	construct_runtime, derive_impl,
 	pallet_prelude::Weight,
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Everything, OriginTrait, WithdrawReasons},
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Everything, Nothing, OriginTrait, WithdrawReasons},
 	PalletId,
 };
 use frame_system as system;
 use frame_system::{EnsureRoot, RawOrigin as SystemRawOrigin};
-use polimec_common::{credentials::EnsureInvestor, DummyXcmSender, USD_UNIT};
+use pallet_xcm::EnsureXcm;
+use polimec_common::{
+	credentials::{CredentialNonces, Did, EnsureInvestor, Nonce},
+	DummyXcmSender, USD_UNIT,
+};
 use polkadot_parachain_primitives::primitives::Sibling;
 use sp_arithmetic::Percent;
 use sp_core::H256;
@@ -46,7 +50,7 @@ use sp_std::collections::btree_map::BTreeMap;
 use std::cell::RefCell;
 use system::EnsureSigned;
 use xcm::v4::PalletInfo as XcmPalletInfo;
-use xcm_builder::{EnsureXcmOrigin, FixedWeightBounds, ParentIsPreset, SiblingParachainConvertsVia};
+use xcm_builder::{Case, EnsureXcmOrigin, FixedWeightBounds, ParentIsPreset, SiblingParachainConvertsVia};
 use xcm_executor::traits::XcmAssetTransfers;
 
 pub const PLMC: Balance = 10u128.pow(PLMC_DECIMALS as u32);
@@ -106,6 +110,10 @@ parameter_types! {
 	pub const MaxInstructions: u32 = 100;
 
 	pub const HereLocation: Location = Location::here();
+	/// AssetHub is the reserve of all the funding assets
+	pub AssetHubAssets: (AssetFilter, Location) = (Wild(All), Location::new(1, [Parachain(1000)]));
+	/// Makes every XCM message executed by the [`MockXcmExecutor`] fail, to test the handling of failed sends
+	pub static XcmExecutionFails: bool = false;
 }
 
 pub struct MockPrepared;
@@ -118,7 +126,7 @@ impl PreparedMessage for MockPrepared {
 pub struct MockXcmExecutor;
 impl XcmAssetTransfers for MockXcmExecutor {
 	type AssetTransactor = ();
-	type IsReserve = ();
+	type IsReserve = Case<AssetHubAssets>;
 	type IsTeleporter = ();
 }
 
@@ -135,6 +143,9 @@ impl ExecuteXcm<RuntimeCall> for MockXcmExecutor {
 		_id: &mut XcmHash,
 		_weight_credit: Weight,
 	) -> Outcome {
+		if XcmExecutionFails::get() {
+			return Outcome::Error { error: XcmError::Unroutable };
+		}
 		Outcome::Complete { used: Weight::zero() }
 	}
 
@@ -199,7 +210,7 @@ impl pallet_assets::Config<ContributionTokensInstance> for TestRuntime {
 	type Currency = Balances;
 	type Extra = ();
 	type ForceOrigin = EnsureRoot<AccountId>;
-	type Freezer = ();
+	type Freezer = PolimecFunding;
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type RemoveItemsLimit = ConstU32<1000>;
@@ -294,9 +305,12 @@ pub const HOURS: BlockNumber = 300u64;
 // We need all durations to use different times to catch bugs in the tests.
 parameter_types! {
 	pub const EvaluationRoundDuration: BlockNumber = 10u64;
+	pub const PrivateRoundDuration: BlockNumber = 8u64;
 	pub const AuctionRoundDuration: BlockNumber = 15u64;
 	pub const CommunityRoundDuration: BlockNumber = 18u64;
 	pub const RemainderRoundDuration: BlockNumber = 6u64;
+	pub const ContributionCoolingOffPeriod: BlockNumber = 4u64;
+	pub const LongTermHolderPeriod: BlockNumber = 25u64;
 
 	pub const FundingPalletId: PalletId = PalletId(*b"py/cfund");
 	pub FeeBrackets: Vec<(Percent, Balance)> = vec![
@@ -308,6 +322,7 @@ parameter_types! {
 	pub EvaluatorSlash: Percent = Percent::from_percent(20);
 	pub BlockchainOperationTreasuryAccount: AccountId = AccountId::from(696969u32);
 	pub ContributionTreasury: AccountId = AccountId::from(4204204206u32);
+	pub LiquidityPoolsAccount: AccountId = AccountId::from(4204204207u32);
 	pub FundingSuccessThreshold: Perquintill = Perquintill::from_percent(33);
 }
 
@@ -349,6 +364,25 @@ parameter_types! {
 		195, 18, 202, 111, 55, 39, 48, 123, 17, 101, 78, 215, 94,
 	];
 	pub MinUsdPerEvaluation: Balance = 100 * USD_UNIT;
+	pub storage UsedCredentialNonces: Vec<(Did, Nonce)> = vec![];
+}
+
+pub struct MockCredentialNonces;
+impl CredentialNonces for MockCredentialNonces {
+	fn is_used(did: &Did, nonce: &Nonce) -> bool {
+		UsedCredentialNonces::get().contains(&(did.clone(), nonce.clone()))
+	}
+
+	fn use_nonce(did: &Did, nonce: &Nonce, _expires_at: u64) -> bool {
+		let mut used_nonces = UsedCredentialNonces::get();
+		let used_nonce = (did.clone(), nonce.clone());
+		if used_nonces.contains(&used_nonce) {
+			return false;
+		}
+		used_nonces.push(used_nonce);
+		UsedCredentialNonces::set(&used_nonces);
+		true
+	}
 }
 
 pub struct DummyConverter;
@@ -392,12 +426,14 @@ impl ConstPriceProvider {
 }
 impl Config for TestRuntime {
 	type AccountId32Conversion = DummyConverter;
+	type AdminOrigin = EnsureRoot<AccountId>;
 	type AllPalletsWithoutSystem =
 		(Balances, ContributionTokens, ForeignAssets, PolimecFunding, LinearRelease, RandomnessCollectiveFlip);
 	type AuctionRoundDuration = AuctionRoundDuration;
 	type BlockNumber = BlockNumber;
 	type BlockchainOperationTreasury = BlockchainOperationTreasuryAccount;
 	type CommunityRoundDuration = CommunityRoundDuration;
+	type ContributionCoolingOffPeriod = ContributionCoolingOffPeriod;
 	type ContributionTokenCurrency = ContributionTokens;
 	type ContributionTreasury = ContributionTreasury;
 	type DaysToBlocks = DaysToBlocks;
@@ -407,7 +443,10 @@ impl Config for TestRuntime {
 	type FeeBrackets = FeeBrackets;
 	type FundingCurrency = ForeignAssets;
 	type FundingSuccessThreshold = FundingSuccessThreshold;
-	type InvestorOrigin = EnsureInvestor<TestRuntime>;
+	type InvestorOrigin = EnsureInvestor<TestRuntime, (), (), MockCredentialNonces>;
+	type LiquidityPoolsAccount = LiquidityPoolsAccount;
+	type LongTermHolderPeriod = LongTermHolderPeriod;
+	type MaxActiveProjectsPerDid = ConstU32<4>;
 	type MaxBidsPerProject = ConstU32<512>;
 	type MaxBidsPerUser = ConstU32<25>;
 	type MaxCapacityThresholds = MaxCapacityThresholds;
@@ -422,6 +461,7 @@ impl Config for TestRuntime {
 	type PalletId = FundingPalletId;
 	type Price = FixedU128;
 	type PriceProvider = ConstPriceProvider;
+	type PrivateRoundDuration = PrivateRoundDuration;
 	type Randomness = RandomnessCollectiveFlip;
 	type RemainderRoundDuration = RemainderRoundDuration;
 	type RequiredMaxCapacity = RequiredMaxCapacity;
@@ -435,6 +475,8 @@ impl Config for TestRuntime {
 	type StringLimit = ConstU32<64>;
 	type VerifierPublicKey = VerifierPublicKey;
 	type WeightInfo = weights::SubstrateWeight<TestRuntime>;
+	type XcmParticipantAccount = LocationToAccountId;
+	type XcmParticipantOrigin = EnsureXcm<Nothing>;
 }
 
 // Configure a mock runtime to test the pallet.
@@ -449,7 +491,7 @@ construct_runtime!(
 		ContributionTokens: pallet_assets::<Instance1>::{Pallet, Call, Storage, Event<T>},
 		ForeignAssets: pallet_assets::<Instance2>::{Pallet, Call, Storage, Event<T>, Config<T>},
 		PolkadotXcm: pallet_xcm,
-		PolimecFunding: pallet_funding::{Pallet, Call, Storage, Event<T>, HoldReason}  = 52,
+		PolimecFunding: pallet_funding::{Pallet, Call, Storage, Event<T>, Config<T>, HoldReason}  = 52,
 	}
 );
 
@@ -462,6 +504,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			balances: vec![
 				(<TestRuntime as Config>::PalletId::get().into_account_truncating(), ed),
 				(<TestRuntime as Config>::ContributionTreasury::get(), ed),
+				(<TestRuntime as Config>::LiquidityPoolsAccount::get(), ed),
 				(<TestRuntime as Config>::BlockchainOperationTreasury::get(), ed),
 			],
 		},
@@ -493,6 +536,38 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			],
 			accounts: vec![],
 		},
+		polimec_funding: PolimecFundingConfig {
+			funding_assets: vec![
+				(
+					AcceptedFundingAsset::USDT,
+					FundingAssetInfo {
+						location: Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)]),
+						decimals: 6,
+						oracle_key: AcceptedFundingAsset::USDT.id(),
+						enabled: true,
+					},
+				),
+				(
+					AcceptedFundingAsset::USDC,
+					FundingAssetInfo {
+						location: Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1337)]),
+						decimals: 6,
+						oracle_key: AcceptedFundingAsset::USDC.id(),
+						enabled: true,
+					},
+				),
+				(
+					AcceptedFundingAsset::DOT,
+					FundingAssetInfo {
+						location: Location::parent(),
+						decimals: 10,
+						oracle_key: AcceptedFundingAsset::DOT.id(),
+						enabled: true,
+					},
+				),
+			],
+			..Default::default()
+		},
 		..Default::default()
 	}
 	.assimilate_storage(&mut t)
@@ -547,6 +622,10 @@ sp_api::mock_impl_runtime_apis! {
 		fn projects_by_did(did: Did) -> Vec<ProjectId> {
 			PolimecFunding::projects_by_did(did)
 		}
+
+		fn funding_success_threshold(project_id: ProjectId) -> Perquintill {
+			PolimecFunding::funding_success_threshold(project_id)
+		}
 	}
 
 	impl ExtrinsicHelpers<Block, TestRuntime> for TestRuntime {
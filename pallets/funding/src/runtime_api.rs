@@ -1,14 +1,15 @@
 #[allow(clippy::wildcard_imports)]
 use crate::{traits::*, *};
 use alloc::collections::BTreeMap;
-use frame_support::traits::fungibles::{metadata::Inspect as MetadataInspect, Inspect, InspectEnumerable};
+use frame_support::traits::fungibles::{Inspect, InspectEnumerable};
 use itertools::Itertools;
 use parity_scale_codec::{Decode, Encode};
-use polimec_common::USD_DECIMALS;
 use scale_info::TypeInfo;
-use sp_runtime::traits::Zero;
+use serde::{Deserialize, Serialize};
+use sp_runtime::{traits::Zero, Perquintill};
 
-#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, Serialize, Deserialize)]
+#[serde(bound(serialize = "AccountIdOf<T>: Serialize", deserialize = "AccountIdOf<T>: Deserialize<'de>"))]
 pub struct ProjectParticipationIds<T: Config> {
 	account: AccountIdOf<T>,
 	evaluation_ids: Vec<u32>,
@@ -44,13 +45,16 @@ sp_api::decl_runtime_apis! {
 		fn all_project_participations_by_did(project_id: ProjectId, did: Did) -> Vec<ProjectParticipationIds<T>>;
 	}
 
-	#[api_version(1)]
+	#[api_version(2)]
 	pub trait ProjectInformation<T: Config> {
 		/// Get the percentage of the target reached for a project
 		fn usd_target_percent_reached(project_id: ProjectId) -> FixedU128;
 
 		/// Get all the projects created by a single DID.
 		fn projects_by_did(did: Did) -> Vec<ProjectId>;
+
+		/// Get the share of the funding target a project needs to raise to be successful.
+		fn funding_success_threshold(project_id: ProjectId) -> Perquintill;
 	}
 
 	#[api_version(2)]
@@ -138,11 +142,7 @@ impl<T: Config> Pallet<T> {
 		asset_amount: Balance,
 	) -> Balance {
 		let project_details = ProjectsDetails::<T>::get(project_id).expect("Project not found");
-		let funding_asset_id = asset.id();
-		let funding_asset_decimals = T::FundingCurrency::decimals(funding_asset_id);
-		let funding_asset_usd_price =
-			T::PriceProvider::get_decimals_aware_price(funding_asset_id, USD_DECIMALS, funding_asset_decimals)
-				.expect("Price not found");
+		let funding_asset_usd_price = Self::funding_asset_usd_price(asset).expect("Price not found");
 		let usd_ticket_size = funding_asset_usd_price.saturating_mul_int(asset_amount);
 
 		let mut ct_amount = Zero::zero();
@@ -262,4 +262,9 @@ impl<T: Config> Pallet<T> {
 			.map(|(project_id, _)| project_id)
 			.collect()
 	}
+
+	pub fn funding_success_threshold(project_id: ProjectId) -> Perquintill {
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).expect("Project not found");
+		Self::success_threshold(&project_metadata)
+	}
 }
@@ -226,6 +226,67 @@ mod create_project_extrinsic {
 			)));
 		}
 
+		#[test]
+		fn issuer_can_run_concurrent_projects_up_to_the_limit() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let did = generate_did_from_account(ISSUER_1);
+			let jwt = get_mock_jwt_with_cid(
+				ISSUER_1,
+				InvestorType::Institutional,
+				did.clone(),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			inst.mint_plmc_to(default_plmc_balances());
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::set_active_projects_limit(RuntimeOrigin::root(), 2));
+				for _ in 0..2 {
+					assert_ok!(PolimecFunding::create_project(
+						RuntimeOrigin::signed(ISSUER_1),
+						jwt.clone(),
+						project_metadata.clone()
+					));
+				}
+				assert_noop!(
+					PolimecFunding::create_project(
+						RuntimeOrigin::signed(ISSUER_1),
+						jwt.clone(),
+						project_metadata.clone()
+					),
+					Error::<TestRuntime>::HasActiveProject
+				);
+				assert_eq!(DidWithActiveProjects::<TestRuntime>::get(did.clone()).to_vec(), vec![0, 1]);
+			});
+
+			// Removing one of them frees a slot
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::remove_project(RuntimeOrigin::signed(ISSUER_1), jwt.clone(), 0));
+				assert_eq!(DidWithActiveProjects::<TestRuntime>::get(did.clone()).to_vec(), vec![1]);
+				assert_ok!(PolimecFunding::create_project(
+					RuntimeOrigin::signed(ISSUER_1),
+					jwt.clone(),
+					project_metadata.clone()
+				));
+			});
+
+			// Projects that fail their evaluation are no longer active
+			assert_eq!(inst.go_to_next_state(1), ProjectStatus::EvaluationRound);
+			assert_eq!(inst.go_to_next_state(1), ProjectStatus::FundingFailed);
+			inst.execute(|| {
+				assert_eq!(DidWithActiveProjects::<TestRuntime>::get(did.clone()).to_vec(), vec![2]);
+				assert_noop!(
+					PolimecFunding::set_active_projects_limit(RuntimeOrigin::root(), 0),
+					Error::<TestRuntime>::InvalidActiveProjectsLimit
+				);
+				let max = <TestRuntime as Config>::MaxActiveProjectsPerDid::get();
+				assert_noop!(
+					PolimecFunding::set_active_projects_limit(RuntimeOrigin::root(), max + 1),
+					Error::<TestRuntime>::InvalidActiveProjectsLimit
+				);
+			});
+		}
+
 		#[test]
 		fn shitcoin_tokenomics() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -306,6 +367,39 @@ mod create_project_extrinsic {
 			});
 		}
 
+		#[test]
+		fn project_scoped_credential_fails() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			inst.mint_plmc_to(default_plmc_balances());
+			let create_project = |jwt: UntrustedToken| {
+				PolimecFunding::create_project(RuntimeOrigin::signed(ISSUER_1), jwt, project_metadata.clone())
+			};
+
+			inst.execute(|| {
+				assert_noop!(
+					create_project(jwt_with_scope(ISSUER_1, InvestorType::Institutional, None, Some(0), None)),
+					Error::<TestRuntime>::CredentialProjectMismatch
+				);
+				// A single-use credential is used up by the creation
+				assert_ok!(create_project(jwt_with_scope(
+					ISSUER_1,
+					InvestorType::Institutional,
+					Some("nonce"),
+					None,
+					None
+				)));
+				assert_noop!(
+					PolimecFunding::start_evaluation(
+						RuntimeOrigin::signed(ISSUER_1),
+						jwt_with_scope(ISSUER_1, InvestorType::Institutional, Some("nonce"), None, None),
+						0
+					),
+					DispatchError::BadOrigin
+				);
+			});
+		}
+
 		#[test]
 		fn did_cannot_have_2_active_projects() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -503,6 +597,236 @@ mod create_project_extrinsic {
 			}
 		}
 
+		#[test]
+		fn unregistered_or_disabled_participation_currencies() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.mint_plmc_to(default_plmc_balances());
+			let default_project_metadata = default_project_metadata(ISSUER_1);
+
+			let mut unregistered_project = default_project_metadata.clone();
+			unregistered_project.participation_currencies =
+				vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset(4242)].try_into().unwrap();
+
+			let mut disabled_project = default_project_metadata.clone();
+			disabled_project.participation_currencies =
+				vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset::DOT].try_into().unwrap();
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::set_funding_asset_enabled(
+					RuntimeOrigin::root(),
+					AcceptedFundingAsset::DOT,
+					false
+				));
+				assert_noop!(
+					Pallet::<TestRuntime>::do_create_project(
+						&ISSUER_1,
+						unregistered_project,
+						generate_did_from_account(ISSUER_1)
+					),
+					Error::<TestRuntime>::ParticipationCurrenciesError
+				);
+				assert_noop!(
+					Pallet::<TestRuntime>::do_create_project(
+						&ISSUER_1,
+						disabled_project.clone(),
+						generate_did_from_account(ISSUER_1)
+					),
+					Error::<TestRuntime>::ParticipationCurrenciesError
+				);
+
+				assert_ok!(PolimecFunding::set_funding_asset_enabled(
+					RuntimeOrigin::root(),
+					AcceptedFundingAsset::DOT,
+					true
+				));
+				assert_ok!(Pallet::<TestRuntime>::do_create_project(
+					&ISSUER_1,
+					disabled_project,
+					generate_did_from_account(ISSUER_1)
+				));
+			});
+		}
+
+		#[test]
+		fn round_durations_out_of_bounds() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.mint_plmc_to(default_plmc_balances());
+			let create = |inst: &mut MockInstantiator, round_durations| {
+				let mut project_metadata = default_project_metadata(ISSUER_1);
+				project_metadata.round_durations = round_durations;
+				inst.execute(|| {
+					Pallet::<TestRuntime>::do_create_project(
+						&ISSUER_1,
+						project_metadata,
+						generate_did_from_account(ISSUER_1),
+					)
+				})
+			};
+
+			// Until governance sets some bounds, only the runtime defaults are accepted.
+			let custom_evaluation = RoundDurations { evaluation: Some(5), ..Default::default() };
+			assert_eq!(create(&mut inst, custom_evaluation), Err(Error::<TestRuntime>::RoundDurationError.into()));
+
+			let bounds = RoundDurationBounds {
+				evaluation: DurationBounds::new(5, 20),
+				auction: DurationBounds::new(5, 20),
+				community: DurationBounds::new(5, 30),
+				remainder: DurationBounds::new(1, 10),
+			};
+			inst.execute(|| assert_ok!(PolimecFunding::set_round_duration_bounds(RuntimeOrigin::root(), bounds)));
+
+			let too_short_auction = RoundDurations { auction: Some(4), ..Default::default() };
+			assert_eq!(create(&mut inst, too_short_auction), Err(Error::<TestRuntime>::RoundDurationError.into()));
+			let too_long_remainder = RoundDurations { remainder: Some(11), ..Default::default() };
+			assert_eq!(create(&mut inst, too_long_remainder), Err(Error::<TestRuntime>::RoundDurationError.into()));
+			assert_ok!(create(&mut inst, custom_evaluation));
+		}
+
+		#[test]
+		fn ct_release_policy_without_duration() {
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.ct_release_policy =
+				Some(CtReleasePolicy { cliff: 100, duration: 0, tge_unlock: Some(Percent::from_percent(10)) });
+
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.mint_plmc_to(default_plmc_balances());
+			let project_err = inst.execute(|| {
+				Pallet::<TestRuntime>::do_create_project(
+					&ISSUER_1,
+					project_metadata,
+					generate_did_from_account(ISSUER_1),
+				)
+				.unwrap_err()
+			});
+			assert_eq!(project_err, Error::<TestRuntime>::CtReleasePolicyError.into());
+		}
+
+		#[test]
+		fn soft_cap_out_of_bounds() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.mint_plmc_to(default_plmc_balances());
+			let create = |inst: &mut MockInstantiator, soft_cap| {
+				let mut project_metadata = default_project_metadata(ISSUER_1);
+				project_metadata.soft_cap = Some(soft_cap);
+				inst.execute(|| {
+					Pallet::<TestRuntime>::do_create_project(
+						&ISSUER_1,
+						project_metadata,
+						generate_did_from_account(ISSUER_1),
+					)
+				})
+			};
+
+			// Until governance sets some bounds, only the runtime threshold is accepted.
+			assert_eq!(
+				create(&mut inst, Perquintill::from_percent(50)),
+				Err(Error::<TestRuntime>::SoftCapError.into())
+			);
+
+			let bounds = SoftCapBounds::new(Perquintill::from_percent(20), Perquintill::from_percent(80));
+			inst.execute(|| assert_ok!(PolimecFunding::set_soft_cap_bounds(RuntimeOrigin::root(), bounds)));
+
+			assert_eq!(
+				create(&mut inst, Perquintill::from_percent(10)),
+				Err(Error::<TestRuntime>::SoftCapError.into())
+			);
+			assert_eq!(
+				create(&mut inst, Perquintill::from_percent(90)),
+				Err(Error::<TestRuntime>::SoftCapError.into())
+			);
+			assert_ok!(create(&mut inst, Perquintill::from_percent(50)));
+		}
+
+		#[test]
+		fn private_round_allocation_too_big() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.mint_plmc_to(default_plmc_balances());
+			let create = |inst: &mut MockInstantiator, allocation_percentage| {
+				let mut project_metadata = default_project_metadata(ISSUER_1);
+				let price = project_metadata.minimum_price;
+				project_metadata.private_round = Some(PrivateRoundConfig { allocation_percentage, price });
+				inst.execute(|| {
+					Pallet::<TestRuntime>::do_create_project(
+						&ISSUER_1,
+						project_metadata,
+						generate_did_from_account(ISSUER_1),
+					)
+				})
+			};
+
+			// The auction already takes 50% of the allocation
+			assert_eq!(
+				create(&mut inst, Percent::from_percent(51)),
+				Err(Error::<TestRuntime>::PrivateRoundError.into())
+			);
+			assert_eq!(create(&mut inst, Percent::zero()), Err(Error::<TestRuntime>::PrivateRoundError.into()));
+			assert_ok!(create(&mut inst, Percent::from_percent(50)));
+		}
+
+		#[test]
+		fn invalid_milestones() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.mint_plmc_to(default_plmc_balances());
+			let create = |inst: &mut MockInstantiator, milestones: Vec<(u8, u64)>| {
+				let mut project_metadata = default_project_metadata(ISSUER_1);
+				project_metadata.milestones = Some(MilestonePlan {
+					milestones: milestones
+						.into_iter()
+						.map(|(percentage, deadline)| Milestone {
+							release_percentage: Percent::from_percent(percentage),
+							deadline,
+						})
+						.collect::<Vec<_>>()
+						.try_into()
+						.unwrap(),
+					approvers: MilestoneApprovers::CtHolders,
+				});
+				inst.execute(|| {
+					Pallet::<TestRuntime>::do_create_project(
+						&ISSUER_1,
+						project_metadata,
+						generate_did_from_account(ISSUER_1),
+					)
+				})
+			};
+
+			// Releases must add up to 100%
+			assert_eq!(create(&mut inst, vec![(50, 10), (40, 20)]), Err(Error::<TestRuntime>::MilestoneError.into()));
+			// Deadlines must be strictly increasing
+			assert_eq!(create(&mut inst, vec![(50, 20), (50, 20)]), Err(Error::<TestRuntime>::MilestoneError.into()));
+			// Every milestone must release something
+			assert_eq!(create(&mut inst, vec![(100, 10), (0, 20)]), Err(Error::<TestRuntime>::MilestoneError.into()));
+			assert_eq!(create(&mut inst, vec![]), Err(Error::<TestRuntime>::MilestoneError.into()));
+			assert_ok!(create(&mut inst, vec![(50, 10), (50, 20)]));
+		}
+
+		#[test]
+		fn empty_jurisdiction_restriction() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.mint_plmc_to(default_plmc_balances());
+			let create = |inst: &mut MockInstantiator, restriction: JurisdictionRestriction| {
+				let mut project_metadata = default_project_metadata(ISSUER_1);
+				project_metadata.jurisdiction_restriction = Some(restriction);
+				inst.execute(|| {
+					Pallet::<TestRuntime>::do_create_project(
+						&ISSUER_1,
+						project_metadata,
+						generate_did_from_account(ISSUER_1),
+					)
+				})
+			};
+
+			assert_eq!(
+				create(&mut inst, JurisdictionRestriction::AllowList(jurisdictions(&[]))),
+				Err(Error::<TestRuntime>::JurisdictionRestrictionError.into())
+			);
+			assert_eq!(
+				create(&mut inst, JurisdictionRestriction::DenyList(jurisdictions(&[]))),
+				Err(Error::<TestRuntime>::JurisdictionRestrictionError.into())
+			);
+			assert_ok!(create(&mut inst, JurisdictionRestriction::DenyList(jurisdictions(&["US"]))));
+		}
+
 		#[test]
 		fn price_zero() {
 			let mut project_metadata = default_project_metadata(ISSUER_1);
@@ -884,7 +1208,16 @@ mod edit_project_extrinsic {
 					.unwrap(),
 
 				funding_destination_account: ISSUER_2,
+				funding_destination_location: None,
 				policy_ipfs_cid: Some(new_policy_hash),
+				round_durations: Default::default(),
+				ct_release_policy: None,
+				soft_cap: None,
+				private_round: None,
+				fee_mode: FeeMode::ContributionTokens,
+				milestones: None,
+				pricing_model: PricingModel::Buckets,
+				jurisdiction_restriction: None,
 			};
 
 			// No fields changed
@@ -988,6 +1321,25 @@ mod edit_project_extrinsic {
 	mod failure {
 		use super::*;
 
+		#[test]
+		fn credential_scoped_to_another_project() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_new_project(project_metadata.clone(), ISSUER_1, None);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::edit_project(
+						RuntimeOrigin::signed(ISSUER_1),
+						jwt_with_scope(ISSUER_1, InvestorType::Institutional, None, Some(project_id + 1), None),
+						project_id,
+						project_metadata
+					),
+					Error::<TestRuntime>::CredentialProjectMismatch
+				);
+			});
+		}
+
 		#[test]
 		fn called_by_different_issuer() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -1141,7 +1493,7 @@ mod remove_project_extrinsic {
 				assert!(ProjectsDetails::<TestRuntime>::get(project_id).is_none());
 				assert!(ProjectsMetadata::<TestRuntime>::get(project_id).is_none());
 				assert!(Buckets::<TestRuntime>::get(project_id).is_none());
-				assert!(DidWithActiveProjects::<TestRuntime>::get(generate_did_from_account(ISSUER_1)).is_none());
+				assert!(DidWithActiveProjects::<TestRuntime>::get(generate_did_from_account(ISSUER_1)).is_empty());
 			});
 		}
 
@@ -196,6 +196,52 @@ mod round_flow {
 				hash_set.insert(amount);
 			}
 		}
+
+		#[test]
+		fn issuer_chosen_round_durations() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let bounds = RoundDurationBounds {
+				evaluation: DurationBounds::new(5, 20),
+				auction: DurationBounds::new(5, 20),
+				community: DurationBounds::new(5, 30),
+				remainder: DurationBounds::new(1, 10),
+			};
+			inst.execute(|| assert_ok!(PolimecFunding::set_round_duration_bounds(RuntimeOrigin::root(), bounds)));
+
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.round_durations =
+				RoundDurations { evaluation: Some(7), auction: Some(12), community: Some(20), remainder: Some(3) };
+
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let round_duration = inst.get_project_details(project_id).round_duration;
+			assert_eq!(round_duration.end().unwrap() - round_duration.start().unwrap() + 1, 7);
+
+			let project_id = inst.create_community_contributing_project(
+				project_metadata,
+				ISSUER_2,
+				None,
+				default_evaluations(),
+				default_bids(),
+			);
+			let project_details = inst.get_project_details(project_id);
+			let community_start = project_details.round_duration.start().unwrap();
+			assert_eq!(project_details.status, ProjectStatus::CommunityRound(community_start + 20));
+			assert_eq!(project_details.round_duration.end().unwrap() - community_start + 1, 20 + 3);
+		}
+		#[test]
+		fn project_without_auction_goes_to_community_round() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.pricing_model = PricingModel::FixedPriceWithoutAuction;
+
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+			let project_details = inst.get_project_details(project_id);
+			assert!(matches!(project_details.status, ProjectStatus::CommunityRound(..)));
+			assert_eq!(project_details.weighted_average_price, Some(project_metadata.minimum_price));
+			assert_eq!(project_details.remaining_contribution_tokens, project_metadata.total_allocation_size);
+		}
 	}
 
 	#[cfg(test)]
@@ -870,6 +916,7 @@ mod evaluate_extrinsic {
 					500 * USD_UNIT,
 					generate_did_from_account(ISSUER_1),
 					project_metadata.clone().policy_ipfs_cid.unwrap(),
+					None,
 				)),
 				Error::<TestRuntime>::ParticipationToOwnProject
 			);
@@ -985,5 +1032,524 @@ mod evaluate_extrinsic {
 				);
 			});
 		}
+
+		#[test]
+		fn jurisdiction_not_allowed() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.jurisdiction_restriction = Some(JurisdictionRestriction::DenyList(jurisdictions(&["US"])));
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			inst.mint_plmc_to(vec![(EVALUATOR_1, 2000 * PLMC).into()]);
+
+			let evaluate = |jurisdiction: Option<&str>| {
+				PolimecFunding::evaluate(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					jwt_with_jurisdiction(EVALUATOR_1, InvestorType::Retail, jurisdiction),
+					project_id,
+					500 * USD_UNIT,
+				)
+			};
+
+			inst.execute(|| {
+				// A denied country also denies its subdivisions
+				assert_noop!(evaluate(Some("US")), Error::<TestRuntime>::JurisdictionNotAllowed);
+				assert_noop!(evaluate(Some("US-NY")), Error::<TestRuntime>::JurisdictionNotAllowed);
+				// Credentials without a jurisdiction cannot prove they are not denied
+				assert_noop!(evaluate(None), Error::<TestRuntime>::JurisdictionNotAllowed);
+				assert_ok!(evaluate(Some("CH")));
+			});
+		}
+
+		#[test]
+		fn credential_scope_is_enforced() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			inst.mint_plmc_to(vec![(EVALUATOR_1, 2000 * PLMC).into(), (EVALUATOR_2, 1000 * PLMC).into()]);
+
+			let evaluate = |jwt: UntrustedToken, usd_amount: Balance| {
+				PolimecFunding::evaluate(RuntimeOrigin::signed(EVALUATOR_1), jwt, project_id, usd_amount)
+			};
+			let jwt = |nonce: Option<&str>, scope_project_id: Option<ProjectId>, max_usd: Option<Balance>| {
+				jwt_with_scope(EVALUATOR_1, InvestorType::Retail, nonce, scope_project_id, max_usd)
+			};
+
+			inst.execute(|| {
+				assert_noop!(
+					evaluate(jwt(None, Some(project_id + 1), None), 500 * USD_UNIT),
+					Error::<TestRuntime>::CredentialProjectMismatch
+				);
+				assert_noop!(
+					evaluate(jwt(None, None, Some(499 * USD_UNIT)), 500 * USD_UNIT),
+					Error::<TestRuntime>::CredentialMaxUsdExceeded
+				);
+				assert_ok!(evaluate(jwt(None, Some(project_id), Some(500 * USD_UNIT)), 500 * USD_UNIT));
+
+				// A single-use credential is rejected once its nonce is used
+				assert_ok!(evaluate(jwt(Some("nonce"), None, None), 500 * USD_UNIT));
+				assert_noop!(evaluate(jwt(Some("nonce"), None, None), 500 * USD_UNIT), DispatchError::BadOrigin);
+				assert_ok!(evaluate(jwt(Some("other nonce"), None, None), 500 * USD_UNIT));
+
+				// Nonces are kept per DID, so another evaluator can use the same one
+				assert_ok!(PolimecFunding::evaluate(
+					RuntimeOrigin::signed(EVALUATOR_2),
+					jwt_with_scope(EVALUATOR_2, InvestorType::Retail, Some("nonce"), None, None),
+					project_id,
+					500 * USD_UNIT
+				));
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod increase_evaluation_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn increase_is_split_between_early_and_late_usd() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+
+			// The early evaluation threshold is 10% of the 10M USD target, so 1M USD
+			let evaluation = UserToUSDBalance::new(EVALUATOR_1, 995_000 * USD_UNIT);
+			let increase = UserToUSDBalance::new(EVALUATOR_1, 10_000 * USD_UNIT);
+			let plmc_for_evaluation = inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()], true);
+			let plmc_for_increase = inst.calculate_evaluation_plmc_spent(vec![increase.clone()], false);
+			inst.mint_plmc_to(plmc_for_evaluation.clone());
+			inst.mint_plmc_to(plmc_for_increase.clone());
+			inst.evaluate_for_users(project_id, vec![evaluation]).unwrap();
+
+			assert_ok!(inst.execute(|| PolimecFunding::increase_evaluation(
+				RuntimeOrigin::signed(EVALUATOR_1),
+				get_mock_jwt_with_cid(
+					EVALUATOR_1,
+					InvestorType::Retail,
+					generate_did_from_account(EVALUATOR_1),
+					project_metadata.clone().policy_ipfs_cid.unwrap()
+				),
+				project_id,
+				0,
+				increase.usd_amount,
+			)));
+
+			// Both parts are added to the same evaluation
+			let expected_plmc_bond =
+				plmc_for_evaluation[0].plmc_amount - inst.get_ed() + plmc_for_increase[0].plmc_amount;
+			inst.execute(|| {
+				let stored_evaluation = Evaluations::<TestRuntime>::get((project_id, EVALUATOR_1, 0)).unwrap();
+				assert_eq!(stored_evaluation.early_usd_amount, 1_000_000 * USD_UNIT);
+				assert_eq!(stored_evaluation.late_usd_amount, 5_000 * USD_UNIT);
+				assert_eq!(stored_evaluation.original_plmc_bond, expected_plmc_bond);
+				assert_eq!(stored_evaluation.current_plmc_bond, expected_plmc_bond);
+				assert_eq!(Evaluations::<TestRuntime>::iter_prefix((project_id, EVALUATOR_1)).count(), 1);
+				assert_eq!(EvaluationCounts::<TestRuntime>::get(project_id), 1);
+			});
+			inst.assert_plmc_held_balance(EVALUATOR_1, expected_plmc_bond, HoldReason::Evaluation.into());
+
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.evaluation_round_info.total_bonded_usd, 1_005_000 * USD_UNIT);
+			assert_eq!(project_details.evaluation_round_info.total_bonded_plmc, expected_plmc_bond);
+		}
+
+		#[test]
+		fn increase_does_not_use_an_evaluation_slot() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+
+			// EVALUATOR_1 uses all their evaluation slots, and reaches the 1M USD early evaluation threshold
+			let evaluations = (0u32..<TestRuntime as Config>::MaxEvaluationsPerUser::get())
+				.map(|_| UserToUSDBalance::<TestRuntime>::new(EVALUATOR_1, 250_000 * USD_UNIT))
+				.collect_vec();
+			let increase = UserToUSDBalance::new(EVALUATOR_1, 10_000 * USD_UNIT);
+			inst.mint_plmc_to(inst.calculate_evaluation_plmc_spent(evaluations.clone(), false));
+			inst.mint_plmc_to(evaluations.accounts().existential_deposits());
+			inst.mint_plmc_to(inst.calculate_evaluation_plmc_spent(vec![increase.clone()], false));
+			inst.evaluate_for_users(project_id, evaluations.clone()).unwrap();
+			let evaluation = inst
+				.execute(|| Evaluations::<TestRuntime>::iter_prefix_values((project_id, EVALUATOR_1)).next().unwrap());
+
+			assert_ok!(inst.execute(|| PolimecFunding::increase_evaluation(
+				RuntimeOrigin::signed(EVALUATOR_1),
+				get_mock_jwt_with_cid(
+					EVALUATOR_1,
+					InvestorType::Retail,
+					generate_did_from_account(EVALUATOR_1),
+					project_metadata.clone().policy_ipfs_cid.unwrap()
+				),
+				project_id,
+				evaluation.id,
+				increase.usd_amount,
+			)));
+
+			inst.execute(|| {
+				let stored_evaluation =
+					Evaluations::<TestRuntime>::get((project_id, EVALUATOR_1, evaluation.id)).unwrap();
+				assert_eq!(stored_evaluation.early_usd_amount, 250_000 * USD_UNIT);
+				assert_eq!(stored_evaluation.late_usd_amount, 10_000 * USD_UNIT);
+				assert_eq!(
+					Evaluations::<TestRuntime>::iter_prefix((project_id, EVALUATOR_1)).count(),
+					evaluations.len()
+				);
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_increase_outside_evaluation_round() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			inst.mint_plmc_to(vec![(EVALUATOR_1, 10_000 * PLMC).into()]);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::increase_evaluation(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						get_mock_jwt_with_cid(
+							EVALUATOR_1,
+							InvestorType::Retail,
+							generate_did_from_account(EVALUATOR_1),
+							project_metadata.clone().policy_ipfs_cid.unwrap()
+						),
+						project_id,
+						0,
+						500 * USD_UNIT,
+					),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_increase_someone_elses_evaluation() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let evaluation = UserToUSDBalance::new(EVALUATOR_1, 500 * USD_UNIT);
+			inst.mint_plmc_to(inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()], true));
+			inst.mint_plmc_to(vec![(EVALUATOR_2, 10_000 * PLMC).into()]);
+			inst.evaluate_for_users(project_id, vec![evaluation]).unwrap();
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::increase_evaluation(
+						RuntimeOrigin::signed(EVALUATOR_2),
+						get_mock_jwt_with_cid(
+							EVALUATOR_2,
+							InvestorType::Retail,
+							generate_did_from_account(EVALUATOR_2),
+							project_metadata.clone().policy_ipfs_cid.unwrap()
+						),
+						project_id,
+						0,
+						500 * USD_UNIT,
+					),
+					Error::<TestRuntime>::ParticipationNotFound
+				);
+			});
+		}
+
+		#[test]
+		fn amount_below_minimum_or_wrong_policy() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let evaluation = UserToUSDBalance::new(EVALUATOR_1, 500 * USD_UNIT);
+			inst.mint_plmc_to(inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()], true));
+			inst.mint_plmc_to(vec![(EVALUATOR_1, 10_000 * PLMC).into()]);
+			inst.evaluate_for_users(project_id, vec![evaluation]).unwrap();
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::increase_evaluation(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						get_mock_jwt_with_cid(
+							EVALUATOR_1,
+							InvestorType::Retail,
+							generate_did_from_account(EVALUATOR_1),
+							project_metadata.clone().policy_ipfs_cid.unwrap()
+						),
+						project_id,
+						0,
+						<TestRuntime as Config>::MinUsdPerEvaluation::get() - 1,
+					),
+					Error::<TestRuntime>::TooLow
+				);
+				assert_noop!(
+					PolimecFunding::increase_evaluation(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						get_mock_jwt_with_cid(
+							EVALUATOR_1,
+							InvestorType::Retail,
+							generate_did_from_account(EVALUATOR_1),
+							"wrong_cid".as_bytes().to_vec().try_into().unwrap()
+						),
+						project_id,
+						0,
+						500 * USD_UNIT,
+					),
+					Error::<TestRuntime>::PolicyMismatch
+				);
+			});
+		}
+
+		#[test]
+		fn jurisdiction_not_allowed() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.jurisdiction_restriction = Some(JurisdictionRestriction::DenyList(jurisdictions(&["US"])));
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			inst.mint_plmc_to(vec![(EVALUATOR_1, 20_000 * PLMC).into()]);
+
+			let evaluation_id = inst.execute(|| {
+				assert_ok!(PolimecFunding::evaluate(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					jwt_with_jurisdiction(EVALUATOR_1, InvestorType::Retail, Some("CH")),
+					project_id,
+					500 * USD_UNIT,
+				));
+				Evaluations::<TestRuntime>::iter_prefix_values((project_id, EVALUATOR_1)).next().unwrap().id
+			});
+
+			let increase_evaluation = |jurisdiction: Option<&str>| {
+				PolimecFunding::increase_evaluation(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					jwt_with_jurisdiction(EVALUATOR_1, InvestorType::Retail, jurisdiction),
+					project_id,
+					evaluation_id,
+					500 * USD_UNIT,
+				)
+			};
+
+			// The jurisdiction of the current credential is checked, not the one the evaluation was made with
+			inst.execute(|| {
+				assert_noop!(increase_evaluation(Some("US")), Error::<TestRuntime>::JurisdictionNotAllowed);
+				assert_noop!(increase_evaluation(None), Error::<TestRuntime>::JurisdictionNotAllowed);
+				assert_ok!(increase_evaluation(Some("CH")));
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod withdraw_evaluation_extrinsic {
+	use super::*;
+
+	fn withdraw(
+		project_id: ProjectId,
+		project_policy: Cid,
+		evaluator: AccountId,
+		evaluation_id: u32,
+		usd_amount: Balance,
+	) -> DispatchResultWithPostInfo {
+		PolimecFunding::withdraw_evaluation(
+			RuntimeOrigin::signed(evaluator),
+			get_mock_jwt_with_cid(
+				evaluator,
+				InvestorType::Retail,
+				generate_did_from_account(evaluator),
+				project_policy,
+			),
+			project_id,
+			evaluation_id,
+			usd_amount,
+		)
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn partial_withdrawal_releases_proportional_plmc() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let policy = project_metadata.policy_ipfs_cid.clone().unwrap();
+			let evaluation = UserToUSDBalance::new(EVALUATOR_1, 1_000 * USD_UNIT);
+			let plmc_for_evaluation = inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()], false);
+			inst.mint_plmc_to(plmc_for_evaluation.accounts().existential_deposits());
+			inst.mint_plmc_to(plmc_for_evaluation.clone());
+			inst.evaluate_for_users(project_id, vec![evaluation]).unwrap();
+
+			assert_ok!(inst.execute(|| withdraw(project_id, policy.clone(), EVALUATOR_1, 0, 400 * USD_UNIT)));
+
+			let remaining_plmc_bond =
+				plmc_for_evaluation[0].plmc_amount - Perquintill::from_percent(40) * plmc_for_evaluation[0].plmc_amount;
+			inst.execute(|| {
+				let stored_evaluation = Evaluations::<TestRuntime>::get((project_id, EVALUATOR_1, 0)).unwrap();
+				assert_eq!(stored_evaluation.early_usd_amount, 600 * USD_UNIT);
+				assert_eq!(stored_evaluation.late_usd_amount, 0);
+				assert_eq!(stored_evaluation.current_plmc_bond, remaining_plmc_bond);
+			});
+			inst.assert_plmc_held_balance(EVALUATOR_1, remaining_plmc_bond, HoldReason::Evaluation.into());
+			inst.assert_plmc_free_balance(
+				EVALUATOR_1,
+				inst.get_ed() + plmc_for_evaluation[0].plmc_amount - remaining_plmc_bond,
+			);
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.evaluation_round_info.total_bonded_usd, 600 * USD_UNIT);
+		}
+
+		#[test]
+		fn full_withdrawal_removes_evaluation() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let policy = project_metadata.policy_ipfs_cid.clone().unwrap();
+			let evaluation = UserToUSDBalance::new(EVALUATOR_1, 1_000 * USD_UNIT);
+			let plmc_for_evaluation = inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()], false);
+			inst.mint_plmc_to(plmc_for_evaluation.accounts().existential_deposits());
+			inst.mint_plmc_to(plmc_for_evaluation.clone());
+			inst.evaluate_for_users(project_id, vec![evaluation.clone()]).unwrap();
+
+			assert_ok!(inst.execute(|| withdraw(project_id, policy.clone(), EVALUATOR_1, 0, evaluation.usd_amount)));
+
+			inst.execute(|| {
+				assert!(Evaluations::<TestRuntime>::get((project_id, EVALUATOR_1, 0)).is_none());
+				assert_eq!(EvaluationCounts::<TestRuntime>::get(project_id), 0);
+			});
+			inst.assert_plmc_held_balance(EVALUATOR_1, 0, HoldReason::Evaluation.into());
+			inst.assert_plmc_free_balance(EVALUATOR_1, inst.get_ed() + plmc_for_evaluation[0].plmc_amount);
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.evaluation_round_info.total_bonded_usd, 0);
+			assert_eq!(project_details.evaluation_round_info.total_bonded_plmc, 0);
+		}
+
+		#[test]
+		fn withdrawing_early_usd_promotes_later_evaluations() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let policy = project_metadata.policy_ipfs_cid.clone().unwrap();
+
+			// The early evaluation threshold is 1M USD
+			let evaluations = vec![
+				UserToUSDBalance::new(EVALUATOR_1, 1_000_000 * USD_UNIT),
+				UserToUSDBalance::new(EVALUATOR_2, 30_000 * USD_UNIT),
+				UserToUSDBalance::new(EVALUATOR_3, 40_000 * USD_UNIT),
+			];
+			inst.mint_plmc_to(inst.calculate_evaluation_plmc_spent(evaluations.clone(), true));
+			inst.evaluate_for_users(project_id, evaluations).unwrap();
+
+			assert_ok!(inst.execute(|| withdraw(project_id, policy.clone(), EVALUATOR_1, 0, 50_000 * USD_UNIT)));
+
+			inst.execute(|| {
+				let evaluation_1 = Evaluations::<TestRuntime>::get((project_id, EVALUATOR_1, 0)).unwrap();
+				let evaluation_2 = Evaluations::<TestRuntime>::get((project_id, EVALUATOR_2, 1)).unwrap();
+				let evaluation_3 = Evaluations::<TestRuntime>::get((project_id, EVALUATOR_3, 2)).unwrap();
+				assert_eq!((evaluation_1.early_usd_amount, evaluation_1.late_usd_amount), (950_000 * USD_UNIT, 0));
+				assert_eq!((evaluation_2.early_usd_amount, evaluation_2.late_usd_amount), (30_000 * USD_UNIT, 0));
+				assert_eq!(
+					(evaluation_3.early_usd_amount, evaluation_3.late_usd_amount),
+					(20_000 * USD_UNIT, 20_000 * USD_UNIT)
+				);
+			});
+		}
+
+		#[test]
+		fn late_top_ups_are_promoted_with_their_evaluation() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let policy = project_metadata.policy_ipfs_cid.clone().unwrap();
+
+			// The early evaluation threshold is 1M USD
+			let evaluations = vec![
+				UserToUSDBalance::new(EVALUATOR_1, 1_000_000 * USD_UNIT),
+				UserToUSDBalance::new(EVALUATOR_2, 30_000 * USD_UNIT),
+				UserToUSDBalance::new(EVALUATOR_3, 40_000 * USD_UNIT),
+			];
+			let increase = UserToUSDBalance::new(EVALUATOR_2, 20_000 * USD_UNIT);
+			inst.mint_plmc_to(inst.calculate_evaluation_plmc_spent(evaluations.clone(), true));
+			inst.mint_plmc_to(inst.calculate_evaluation_plmc_spent(vec![increase.clone()], false));
+			inst.evaluate_for_users(project_id, evaluations).unwrap();
+
+			// EVALUATOR_2 tops up after EVALUATOR_3 bonded
+			assert_ok!(inst.execute(|| PolimecFunding::increase_evaluation(
+				RuntimeOrigin::signed(EVALUATOR_2),
+				get_mock_jwt_with_cid(
+					EVALUATOR_2,
+					InvestorType::Retail,
+					generate_did_from_account(EVALUATOR_2),
+					policy.clone()
+				),
+				project_id,
+				1,
+				increase.usd_amount,
+			)));
+			assert_ok!(inst.execute(|| withdraw(project_id, policy.clone(), EVALUATOR_1, 0, 50_000 * USD_UNIT)));
+
+			// The top-up is part of EVALUATOR_2's evaluation, so the freed 50k USD all go to it before EVALUATOR_3
+			inst.execute(|| {
+				let evaluation_2 = Evaluations::<TestRuntime>::get((project_id, EVALUATOR_2, 1)).unwrap();
+				let evaluation_3 = Evaluations::<TestRuntime>::get((project_id, EVALUATOR_3, 2)).unwrap();
+				assert_eq!((evaluation_2.early_usd_amount, evaluation_2.late_usd_amount), (50_000 * USD_UNIT, 0));
+				assert_eq!((evaluation_3.early_usd_amount, evaluation_3.late_usd_amount), (0, 40_000 * USD_UNIT));
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn invalid_amounts() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let policy = project_metadata.policy_ipfs_cid.clone().unwrap();
+			let evaluation = UserToUSDBalance::new(EVALUATOR_1, 1_000 * USD_UNIT);
+			inst.mint_plmc_to(inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()], true));
+			inst.evaluate_for_users(project_id, vec![evaluation]).unwrap();
+
+			inst.execute(|| {
+				assert_noop!(withdraw(project_id, policy.clone(), EVALUATOR_1, 0, 0), Error::<TestRuntime>::TooLow);
+				assert_noop!(
+					withdraw(project_id, policy.clone(), EVALUATOR_1, 0, 1_001 * USD_UNIT),
+					Error::<TestRuntime>::TooHigh
+				);
+				// The remaining evaluation would be under the minimum of 100 USD
+				assert_noop!(
+					withdraw(project_id, policy.clone(), EVALUATOR_1, 0, 950 * USD_UNIT),
+					Error::<TestRuntime>::TooLow
+				);
+				assert_noop!(
+					withdraw(project_id, policy.clone(), EVALUATOR_2, 0, 500 * USD_UNIT),
+					Error::<TestRuntime>::ParticipationNotFound
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_withdraw_after_evaluation_round() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = default_evaluations();
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations.clone());
+
+			let policy = project_metadata.policy_ipfs_cid.clone().unwrap();
+
+			inst.execute(|| {
+				assert_noop!(
+					withdraw(project_id, policy, evaluations[0].account, 0, 100 * USD_UNIT),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
 	}
 }
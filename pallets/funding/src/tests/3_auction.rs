@@ -161,6 +161,7 @@ mod round_flow {
 					AcceptedFundingAsset::USDT => usdt_price,
 					AcceptedFundingAsset::USDC => usdc_price,
 					AcceptedFundingAsset::DOT => dot_price,
+					_ => unreachable!(),
 				};
 
 				let mut project_metadata = default_project_metadata.clone();
@@ -337,6 +338,8 @@ mod round_flow {
 						did,
 						investor_type,
 						whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+						jurisdiction: None,
+						max_usd: None,
 					}),
 					Error::<TestRuntime>::IncorrectRound
 				);
@@ -998,6 +1001,8 @@ mod bid_extrinsic {
 						did,
 						investor_type,
 						whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+						jurisdiction: None,
+						max_usd: None,
 					}),
 					Error::<TestRuntime>::IncorrectRound
 				);
@@ -1248,6 +1253,8 @@ mod bid_extrinsic {
 						did: generate_did_from_account(BIDDER_1),
 						investor_type: InvestorType::Professional,
 						whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+						jurisdiction: None,
+						max_usd: None,
 					}),
 					Error::<TestRuntime>::TooLow
 				);
@@ -1264,6 +1271,8 @@ mod bid_extrinsic {
 						did: generate_did_from_account(BIDDER_1),
 						investor_type: InvestorType::Institutional,
 						whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+						jurisdiction: None,
+						max_usd: None,
 					}),
 					Error::<TestRuntime>::TooLow
 				);
@@ -1331,6 +1340,8 @@ mod bid_extrinsic {
 					did: generate_did_from_account(BIDDER_1),
 					investor_type: InvestorType::Professional,
 					whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+					jurisdiction: None,
+					max_usd: None,
 				}));
 			});
 			let smallest_ct_amount_at_20k_usd = bucket_increase_price
@@ -1350,6 +1361,8 @@ mod bid_extrinsic {
 					did: generate_did_from_account(BIDDER_1),
 					investor_type: InvestorType::Institutional,
 					whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+					jurisdiction: None,
+					max_usd: None,
 				}));
 			});
 		}
@@ -1501,6 +1514,8 @@ mod bid_extrinsic {
 					did: generate_did_from_account(ISSUER_1),
 					investor_type: InvestorType::Professional,
 					whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+					jurisdiction: None,
+					max_usd: None,
 				})),
 				Error::<TestRuntime>::ParticipationToOwnProject
 			);
@@ -1527,6 +1542,8 @@ mod bid_extrinsic {
 					did,
 					investor_type,
 					whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+					jurisdiction: None,
+					max_usd: None,
 				})
 			});
 			frame_support::assert_err!(outcome, Error::<TestRuntime>::FundingAssetNotAccepted);
@@ -1558,13 +1575,593 @@ mod bid_extrinsic {
 				);
 			});
 		}
+
+		#[test]
+		fn jurisdiction_not_allowed() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.jurisdiction_restriction =
+				Some(JurisdictionRestriction::AllowList(jurisdictions(&["CH", "LI"])));
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+			for jurisdiction in [Some("DE"), Some("CHE"), None] {
+				inst.execute(|| {
+					assert_noop!(
+						PolimecFunding::bid(
+							RuntimeOrigin::signed(BIDDER_1),
+							jwt_with_jurisdiction(BIDDER_1, InvestorType::Professional, jurisdiction),
+							project_id,
+							5000 * CT_UNIT,
+							1u8.try_into().unwrap(),
+							AcceptedFundingAsset::USDT
+						),
+						Error::<TestRuntime>::JurisdictionNotAllowed
+					);
+				});
+			}
+		}
+
+		#[test]
+		fn bid_above_credential_max_usd() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			let bid = BidParams::<TestRuntime>::new(BIDDER_1, 5000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			let plmc_mint = inst.calculate_auction_plmc_charged_with_given_price(
+				&vec![bid.clone()],
+				project_metadata.minimum_price,
+				true,
+			);
+			let funding_asset_mint = inst
+				.calculate_auction_funding_asset_charged_with_given_price(&vec![bid], project_metadata.minimum_price);
+			inst.mint_plmc_to(plmc_mint);
+			inst.mint_funding_asset_to(funding_asset_mint);
+
+			let usd_amount = project_metadata.minimum_price.saturating_mul_int(5000 * CT_UNIT);
+			let bid = |max_usd: Balance| {
+				PolimecFunding::bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					jwt_with_scope(BIDDER_1, InvestorType::Professional, None, None, Some(max_usd)),
+					project_id,
+					5000 * CT_UNIT,
+					1u8.try_into().unwrap(),
+					AcceptedFundingAsset::USDT,
+				)
+			};
+
+			inst.execute(|| {
+				assert_noop!(bid(usd_amount - 1), Error::<TestRuntime>::CredentialMaxUsdExceeded);
+				assert_ok!(bid(usd_amount));
+			});
+		}
 	}
 }
 
 #[cfg(test)]
-mod end_auction_extrinsic {
+mod cancel_bid_extrinsic {
 	use super::*;
 
+	pub(super) fn setup_two_bids(
+		inst: &mut MockInstantiator,
+	) -> (ProjectId, ProjectMetadataOf<TestRuntime>, BidInfoOf<TestRuntime>) {
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let project_id =
+			inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+		// BIDDER_1 takes 80% of the first bucket. BIDDER_2 fills the rest of it and the whole second bucket.
+		let bids = vec![
+			BidParams::new(BIDDER_1, 400_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT),
+			BidParams::new(BIDDER_2, 150_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT),
+		];
+		let necessary_plmc = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+			true,
+		);
+		let necessary_usdt = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+		);
+		inst.mint_plmc_to(necessary_plmc);
+		inst.mint_funding_asset_to(necessary_usdt);
+		inst.bid_for_users(project_id, bids).unwrap();
+
+		let bidder_1_bid =
+			inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_1)).next().unwrap());
+		(project_id, project_metadata, bidder_1_bid)
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn cancel_bid_releases_funds_and_frees_bucket_amount() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, bid) = setup_two_bids(&mut inst);
+
+			let prev_free_plmc = inst.get_free_plmc_balance_for(BIDDER_1);
+			let prev_free_usdt = inst.get_free_funding_asset_balance_for(AcceptedFundingAsset::USDT.id(), BIDDER_1);
+			let current_bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert!(current_bucket.current_price > project_metadata.minimum_price);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::cancel_bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					get_mock_jwt_with_cid(
+						BIDDER_1,
+						InvestorType::Institutional,
+						generate_did_from_account(BIDDER_1),
+						project_metadata.clone().policy_ipfs_cid.unwrap()
+					),
+					project_id,
+					bid.id,
+				));
+			});
+
+			// Storage
+			assert!(inst.execute(|| Bids::<TestRuntime>::get((project_id, BIDDER_1, bid.id))).is_none());
+			assert_eq!(inst.execute(|| BidCounts::<TestRuntime>::get(project_id)), 2);
+			assert_eq!(
+				inst.execute(|| AuctionBoughtUSD::<TestRuntime>::get((
+					project_id,
+					generate_did_from_account(BIDDER_1)
+				))),
+				0
+			);
+
+			// The cancelled CTs are sold again at the current price, which is not lowered
+			let mut expected_bucket = current_bucket;
+			expected_bucket.free(bid.original_ct_amount);
+			let current_bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert_eq!(current_bucket, expected_bucket);
+
+			// Balances
+			inst.assert_plmc_held_balance(BIDDER_1, 0, HoldReason::Participation.into());
+			inst.assert_plmc_free_balance(BIDDER_1, prev_free_plmc + bid.plmc_bond);
+			inst.assert_funding_asset_free_balance(
+				BIDDER_1,
+				AcceptedFundingAsset::USDT.id(),
+				prev_free_usdt + bid.funding_asset_amount_locked,
+			);
+
+			// Events
+			inst.execute(|| {
+				System::assert_last_event(RuntimeEvent::PolimecFunding(Event::BidCancelled {
+					project_id,
+					bidder: BIDDER_1,
+					id: bid.id,
+					ct_amount: bid.original_ct_amount,
+					funding_asset: AcceptedFundingAsset::USDT,
+					funding_amount_released: bid.funding_asset_amount_locked,
+					plmc_released: bid.plmc_bond,
+				}));
+			});
+		}
+
+		#[test]
+		fn cancel_split_bid_cancels_all_pieces() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, _) = setup_two_bids(&mut inst);
+
+			// BIDDER_2's bid was split between the first two buckets
+			let pieces = inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_2)).collect_vec());
+			assert_eq!(pieces.len(), 2);
+			let first_id = pieces.iter().map(|bid| bid.id).min().unwrap();
+			let last_id = pieces.iter().map(|bid| bid.id).max().unwrap();
+			let prev_free_plmc = inst.get_free_plmc_balance_for(BIDDER_2);
+			let prev_free_usdt = inst.get_free_funding_asset_balance_for(AcceptedFundingAsset::USDT.id(), BIDDER_2);
+			let mut expected_bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			expected_bucket.free(150_000 * CT_UNIT);
+
+			// Cancelling any piece cancels the whole bid
+			inst.execute(|| assert_eq!(PolimecFunding::do_cancel_bid(&BIDDER_2, project_id, last_id), Ok(2)));
+
+			assert_eq!(inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_2)).count()), 0);
+			assert!(inst.execute(|| BidGroups::<TestRuntime>::get((project_id, BIDDER_2, first_id))).is_none());
+			assert_eq!(inst.execute(|| BidCounts::<TestRuntime>::get(project_id)), 1);
+
+			let current_bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert_eq!(current_bucket, expected_bucket);
+
+			let plmc_bonded = pieces.iter().map(|bid| bid.plmc_bond).sum::<Balance>();
+			let usdt_locked = pieces.iter().map(|bid| bid.funding_asset_amount_locked).sum::<Balance>();
+			inst.assert_plmc_held_balance(BIDDER_2, 0, HoldReason::Participation.into());
+			inst.assert_plmc_free_balance(BIDDER_2, prev_free_plmc + plmc_bonded);
+			inst.assert_funding_asset_free_balance(
+				BIDDER_2,
+				AcceptedFundingAsset::USDT.id(),
+				prev_free_usdt + usdt_locked,
+			);
+		}
+
+		#[test]
+		fn bids_after_cancelling_a_lower_bid_pay_the_current_price() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, bid) = setup_two_bids(&mut inst);
+
+			// BIDDER_1's bid is in the first bucket, below BIDDER_2's second piece
+			let top_price = inst
+				.execute(|| {
+					Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_2))
+						.map(|bid| bid.original_ct_usd_price)
+						.max()
+				})
+				.unwrap();
+			assert!(bid.original_ct_usd_price < top_price);
+			inst.execute(|| assert_eq!(PolimecFunding::do_cancel_bid(&BIDDER_1, project_id, bid.id), Ok(1)));
+			let bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert!(bucket.current_price > top_price);
+
+			// The new bid takes the freed CTs at the current price, not at the price of the cancelled bid
+			let bids = vec![BidParams::new(BIDDER_3, 100_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT)];
+			let necessary_plmc = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				Some(bucket),
+				true,
+			);
+			let necessary_usdt = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				Some(bucket),
+			);
+			inst.mint_plmc_to(necessary_plmc);
+			inst.mint_funding_asset_to(necessary_usdt);
+			inst.bid_for_users(project_id, bids).unwrap();
+
+			let new_bids =
+				inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_3)).collect_vec());
+			assert_eq!(new_bids.len(), 1);
+			assert_eq!(new_bids[0].original_ct_usd_price, bucket.current_price);
+			let mut expected_bucket = bucket;
+			expected_bucket.update(100_000 * CT_UNIT);
+			assert_eq!(inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap(), expected_bucket);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_cancel_bid_after_auction_round() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, bid) = setup_two_bids(&mut inst);
+
+			assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound(..)));
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_cancel_bid(&BIDDER_1, project_id, bid.id),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_cancel_someone_elses_bid() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, bid) = setup_two_bids(&mut inst);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_cancel_bid(&BIDDER_2, project_id, bid.id),
+					Error::<TestRuntime>::ParticipationNotFound
+				);
+			});
+		}
+
+		#[test]
+		fn credential_scope_is_enforced() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, bid) = setup_two_bids(&mut inst);
+			let cancel_bid = |jwt: UntrustedToken| {
+				PolimecFunding::cancel_bid(RuntimeOrigin::signed(BIDDER_1), jwt, project_id, bid.id)
+			};
+
+			inst.execute(|| {
+				assert_noop!(
+					cancel_bid(jwt_with_scope(BIDDER_1, InvestorType::Institutional, None, Some(project_id + 1), None)),
+					Error::<TestRuntime>::CredentialProjectMismatch
+				);
+				let single_use_jwt =
+					jwt_with_scope(BIDDER_1, InvestorType::Institutional, Some("nonce"), Some(project_id), None);
+				assert_ok!(cancel_bid(single_use_jwt.clone()));
+				assert_noop!(cancel_bid(single_use_jwt), DispatchError::BadOrigin);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod reduce_bid_extrinsic {
+	use super::{cancel_bid_extrinsic::setup_two_bids, *};
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn reduce_bid_releases_excess_funds_and_frees_bucket_amount() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, bid) = setup_two_bids(&mut inst);
+			let new_ct_amount = 100_000 * CT_UNIT;
+
+			let prev_free_plmc = inst.get_free_plmc_balance_for(BIDDER_1);
+			let prev_free_usdt = inst.get_free_funding_asset_balance_for(AcceptedFundingAsset::USDT.id(), BIDDER_1);
+			let mut expected_bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			expected_bucket.free(bid.original_ct_amount - new_ct_amount);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::reduce_bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					get_mock_jwt_with_cid(
+						BIDDER_1,
+						InvestorType::Institutional,
+						generate_did_from_account(BIDDER_1),
+						project_metadata.clone().policy_ipfs_cid.unwrap()
+					),
+					project_id,
+					bid.id,
+					new_ct_amount,
+				));
+			});
+
+			// Storage
+			let stored_bid = inst.execute(|| Bids::<TestRuntime>::get((project_id, BIDDER_1, bid.id))).unwrap();
+			assert_eq!(stored_bid.original_ct_amount, new_ct_amount);
+			assert_eq!(stored_bid.original_ct_usd_price, bid.original_ct_usd_price);
+			assert_eq!(stored_bid.plmc_bond, Perquintill::from_rational(1u128, 4u128) * bid.plmc_bond);
+			assert_eq!(
+				stored_bid.funding_asset_amount_locked,
+				Perquintill::from_rational(1u128, 4u128) * bid.funding_asset_amount_locked
+			);
+			assert_eq!(
+				inst.execute(|| AuctionBoughtUSD::<TestRuntime>::get((
+					project_id,
+					generate_did_from_account(BIDDER_1)
+				))),
+				bid.original_ct_usd_price.saturating_mul_int(new_ct_amount)
+			);
+
+			// The 300k CTs removed from the bid are sold again at the current price
+			let current_bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert_eq!(current_bucket, expected_bucket);
+
+			// Balances
+			let plmc_released = bid.plmc_bond - stored_bid.plmc_bond;
+			let usdt_released = bid.funding_asset_amount_locked - stored_bid.funding_asset_amount_locked;
+			inst.assert_plmc_held_balance(BIDDER_1, stored_bid.plmc_bond, HoldReason::Participation.into());
+			inst.assert_plmc_free_balance(BIDDER_1, prev_free_plmc + plmc_released);
+			inst.assert_funding_asset_free_balance(
+				BIDDER_1,
+				AcceptedFundingAsset::USDT.id(),
+				prev_free_usdt + usdt_released,
+			);
+
+			// Events
+			inst.execute(|| {
+				System::assert_last_event(RuntimeEvent::PolimecFunding(Event::BidReduced {
+					project_id,
+					bidder: BIDDER_1,
+					id: bid.id,
+					ct_amount: new_ct_amount,
+					funding_asset: AcceptedFundingAsset::USDT,
+					funding_amount_released: usdt_released,
+					plmc_released,
+				}));
+			});
+		}
+
+		#[test]
+		fn reduce_split_bid_removes_highest_price_pieces_first() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, _) = setup_two_bids(&mut inst);
+			let mut expected_bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			expected_bucket.free(70_000 * CT_UNIT);
+
+			// BIDDER_2 bid 100k CTs in the first bucket and 50k CTs in the second one
+			let mut pieces =
+				inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_2)).collect_vec());
+			pieces.sort_by_key(|bid| bid.id);
+			let (first_piece, last_piece) = (pieces[0].clone(), pieces[1].clone());
+			assert_eq!(first_piece.original_ct_amount, 100_000 * CT_UNIT);
+			assert_eq!(last_piece.original_ct_amount, 50_000 * CT_UNIT);
+
+			// The ticket size minimum is checked with the investor type the bid was placed with, not the caller's
+			inst.execute(|| {
+				assert_eq!(
+					PolimecFunding::do_reduce_bid(
+						&BIDDER_2,
+						project_id,
+						last_piece.id,
+						80_000 * CT_UNIT,
+						InvestorType::Retail,
+						None
+					),
+					Ok(2)
+				)
+			});
+
+			// The second bucket piece is dropped, and the first one keeps 80k CTs
+			assert!(inst.execute(|| Bids::<TestRuntime>::get((project_id, BIDDER_2, last_piece.id))).is_none());
+			let stored_piece =
+				inst.execute(|| Bids::<TestRuntime>::get((project_id, BIDDER_2, first_piece.id))).unwrap();
+			assert_eq!(stored_piece.original_ct_amount, 80_000 * CT_UNIT);
+			assert_eq!(stored_piece.original_ct_usd_price, first_piece.original_ct_usd_price);
+			let group = inst.execute(|| BidGroups::<TestRuntime>::get((project_id, BIDDER_2, first_piece.id))).unwrap();
+			assert_eq!(group.bid_ids.into_inner(), vec![first_piece.id]);
+			assert_eq!(
+				inst.execute(|| AuctionBoughtUSD::<TestRuntime>::get((
+					project_id,
+					generate_did_from_account(BIDDER_2)
+				))),
+				first_piece.original_ct_usd_price.saturating_mul_int(80_000 * CT_UNIT)
+			);
+
+			let current_bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert_eq!(current_bucket, expected_bucket);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_reduce_bid_to_zero_or_increase_it() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, bid) = setup_two_bids(&mut inst);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_reduce_bid(&BIDDER_1, project_id, bid.id, 0, InvestorType::Institutional, None),
+					Error::<TestRuntime>::TooLow
+				);
+				assert_noop!(
+					PolimecFunding::do_reduce_bid(
+						&BIDDER_1,
+						project_id,
+						bid.id,
+						bid.original_ct_amount,
+						InvestorType::Institutional,
+						None
+					),
+					Error::<TestRuntime>::TooHigh
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_reduce_bid_below_ticket_size_minimum() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, bid) = setup_two_bids(&mut inst);
+
+			// 100 CTs at 10 USD is below the 5000 USD minimum
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_reduce_bid(
+						&BIDDER_1,
+						project_id,
+						bid.id,
+						100 * CT_UNIT,
+						InvestorType::Institutional,
+						None
+					),
+					Error::<TestRuntime>::TooLow
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_reduce_split_bid_below_ticket_size_minimum() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, _) = setup_two_bids(&mut inst);
+			let last_id = inst.execute(|| {
+				Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_2)).map(|bid| bid.id).max().unwrap()
+			});
+
+			// The minimum applies to the whole bid, whichever piece is given
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_reduce_bid(
+						&BIDDER_2,
+						project_id,
+						last_id,
+						100 * CT_UNIT,
+						InvestorType::Institutional,
+						None
+					),
+					Error::<TestRuntime>::TooLow
+				);
+				assert_noop!(
+					PolimecFunding::do_reduce_bid(
+						&BIDDER_2,
+						project_id,
+						last_id,
+						150_000 * CT_UNIT,
+						InvestorType::Institutional,
+						None
+					),
+					Error::<TestRuntime>::TooHigh
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_reduce_bid_after_auction_round() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, bid) = setup_two_bids(&mut inst);
+
+			assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound(..)));
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_reduce_bid(
+						&BIDDER_1,
+						project_id,
+						bid.id,
+						10_000 * CT_UNIT,
+						InvestorType::Institutional,
+						None
+					),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn jurisdiction_not_allowed() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.jurisdiction_restriction =
+				Some(JurisdictionRestriction::AllowList(jurisdictions(&["CH", "LI"])));
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			inst.mint_plmc_to(vec![(BIDDER_1, 1_000_000 * PLMC).into()]);
+			inst.mint_funding_asset_to(vec![(BIDDER_1, 1_000_000 * USDT_UNIT).into()]);
+
+			let bid_id = inst.execute(|| {
+				assert_ok!(PolimecFunding::bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					jwt_with_jurisdiction(BIDDER_1, InvestorType::Professional, Some("CH")),
+					project_id,
+					5000 * CT_UNIT,
+					1u8.try_into().unwrap(),
+					AcceptedFundingAsset::USDT
+				));
+				Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_1)).next().unwrap().id
+			});
+
+			let reduce_bid = |jurisdiction: Option<&str>| {
+				PolimecFunding::reduce_bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					jwt_with_jurisdiction(BIDDER_1, InvestorType::Professional, jurisdiction),
+					project_id,
+					bid_id,
+					4000 * CT_UNIT,
+				)
+			};
+
+			// The jurisdiction of the current credential is checked, not the one the bid was placed with
+			inst.execute(|| {
+				assert_noop!(reduce_bid(Some("DE")), Error::<TestRuntime>::JurisdictionNotAllowed);
+				assert_noop!(reduce_bid(None), Error::<TestRuntime>::JurisdictionNotAllowed);
+				assert_ok!(reduce_bid(Some("CH")));
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod end_auction_extrinsic {
+	use super::{cancel_bid_extrinsic::setup_two_bids, *};
+
 	#[cfg(test)]
 	mod success {
 		use super::*;
@@ -1616,7 +2213,16 @@ mod end_auction_extrinsic {
 				},
 				participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 				funding_destination_account: ISSUER_1,
+				funding_destination_location: None,
 				policy_ipfs_cid: Some(metadata_hash),
+				round_durations: Default::default(),
+				ct_release_policy: None,
+				soft_cap: None,
+				private_round: None,
+				fee_mode: FeeMode::ContributionTokens,
+				milestones: None,
+				pricing_model: PricingModel::Buckets,
+				jurisdiction_restriction: None,
 			};
 
 			// overfund with plmc
@@ -1821,7 +2427,7 @@ mod end_auction_extrinsic {
 				.collect_vec();
 
 			let fundings = [AcceptedFundingAsset::USDT, AcceptedFundingAsset::USDC, AcceptedFundingAsset::DOT];
-			assert_eq!(fundings.len(), AcceptedFundingAsset::VARIANT_COUNT);
+			assert_eq!(fundings.len(), inst.execute(|| FundingAssets::<TestRuntime>::iter().count()));
 			let mut fundings = fundings.into_iter().cycle();
 
 			let usdt_fundings = accounts
@@ -1865,6 +2471,244 @@ mod end_auction_extrinsic {
 				Perquintill::from_float(0.99)
 			);
 		}
+		#[test]
+		fn bids_are_resolved_in_chunks() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			let bids = default_bids();
+			let plmc_amounts = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				None,
+				true,
+			);
+			let funding_asset_amounts = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				None,
+			);
+			inst.mint_plmc_to(plmc_amounts);
+			inst.mint_funding_asset_to(funding_asset_amounts);
+			inst.bid_for_users(project_id, bids).unwrap();
+
+			let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(auction_end + 1);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::end_auction(RuntimeOrigin::signed(420), project_id));
+			});
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionResolving);
+
+			let mut calls = 0;
+			while inst.get_project_details(project_id).status == ProjectStatus::AuctionResolving {
+				inst.execute(|| {
+					assert_ok!(PolimecFunding::resolve_auction(RuntimeOrigin::signed(420), project_id, 2));
+				});
+				calls += 1;
+			}
+			assert!(calls > 1);
+			assert!(matches!(inst.get_project_details(project_id).status, ProjectStatus::CommunityRound(..)));
+			assert!(inst.get_project_details(project_id).weighted_average_price.is_some());
+
+			inst.execute(|| {
+				assert!(AuctionResolutions::<TestRuntime>::get(project_id).is_none());
+				assert_eq!(BidsByBucket::<TestRuntime>::iter_prefix((project_id,)).count(), 0);
+				assert!(Bids::<TestRuntime>::iter_prefix_values((project_id,))
+					.all(|bid| matches!(bid.status, BidStatus::Accepted)));
+			});
+		}
+
+		#[test]
+		fn auction_is_resolved_on_idle() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			let bids = default_bids();
+			let plmc_amounts = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				None,
+				true,
+			);
+			let funding_asset_amounts = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				None,
+			);
+			inst.mint_plmc_to(plmc_amounts);
+			inst.mint_funding_asset_to(funding_asset_amounts);
+			inst.bid_for_users(project_id, bids).unwrap();
+
+			let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(auction_end + 1);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::end_auction(RuntimeOrigin::signed(420), project_id));
+			});
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionResolving);
+
+			inst.advance_time(1);
+			assert!(matches!(inst.get_project_details(project_id).status, ProjectStatus::CommunityRound(..)));
+		}
+
+		#[test]
+		fn failing_resolution_does_not_block_other_projects_on_idle() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let auctioning_project = |inst: &mut MockInstantiator, issuer: AccountId| {
+				let project_metadata = default_project_metadata(issuer);
+				let project_id =
+					inst.create_auctioning_project(project_metadata.clone(), issuer, None, default_evaluations());
+				let bids = default_bids();
+				let plmc_amounts = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+					&bids,
+					project_metadata.clone(),
+					None,
+					true,
+				);
+				let funding_asset_amounts = inst
+					.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+						&bids,
+						project_metadata,
+						None,
+					);
+				inst.mint_plmc_to(plmc_amounts);
+				inst.mint_funding_asset_to(funding_asset_amounts);
+				inst.bid_for_users(project_id, bids).unwrap();
+				project_id
+			};
+			let project_ids = [auctioning_project(&mut inst, ISSUER_1), auctioning_project(&mut inst, ISSUER_2)];
+
+			let auction_end =
+				project_ids.iter().map(|id| inst.get_project_details(*id).round_duration.end().unwrap()).max().unwrap();
+			inst.jump_to_block(auction_end + 1);
+			inst.execute(|| {
+				for project_id in project_ids {
+					assert_ok!(PolimecFunding::end_auction(RuntimeOrigin::signed(420), project_id));
+				}
+			});
+
+			// Break the project `on_idle` would pick first
+			let failing_project = inst.execute(|| AuctionResolutions::<TestRuntime>::iter_keys().next().unwrap());
+			let other_project = project_ids.into_iter().find(|id| *id != failing_project).unwrap();
+			let set_status = |inst: &mut MockInstantiator, status: ProjectStatus<BlockNumberFor<TestRuntime>>| {
+				inst.execute(|| {
+					ProjectsDetails::<TestRuntime>::mutate(failing_project, |details| {
+						details.as_mut().unwrap().status = status
+					})
+				});
+			};
+			set_status(&mut inst, ProjectStatus::AuctionRound);
+
+			inst.advance_time(1);
+			assert!(matches!(inst.get_project_details(other_project).status, ProjectStatus::CommunityRound(..)));
+			inst.execute(|| {
+				assert!(AuctionResolutions::<TestRuntime>::contains_key(failing_project));
+				assert_eq!(AuctionResolutionCursor::<TestRuntime>::get(), Some(failing_project));
+			});
+
+			// Once fixed, the project is retried
+			set_status(&mut inst, ProjectStatus::AuctionResolving);
+			inst.advance_time(1);
+			assert!(matches!(inst.get_project_details(failing_project).status, ProjectStatus::CommunityRound(..)));
+			inst.execute(|| assert!(AuctionResolutionCursor::<TestRuntime>::get().is_none()));
+		}
+
+		#[test]
+		fn bids_are_resolved_after_a_lower_bid_is_cancelled() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, bid) = setup_two_bids(&mut inst);
+
+			// Cancelling BIDDER_1's bid leaves less than the auction allocation bid, at prices above the minimum
+			let current_price = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap().current_price;
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::cancel_bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					get_mock_jwt_with_cid(
+						BIDDER_1,
+						InvestorType::Institutional,
+						generate_did_from_account(BIDDER_1),
+						project_metadata.clone().policy_ipfs_cid.unwrap()
+					),
+					project_id,
+					bid.id,
+				));
+			});
+			assert_eq!(inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap().current_price, current_price);
+
+			assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound(..)));
+			inst.execute(|| {
+				assert!(Bids::<TestRuntime>::get((project_id, BIDDER_1, bid.id)).is_none());
+				let bidder_2_bids = Bids::<TestRuntime>::iter_prefix_values((project_id, BIDDER_2)).collect_vec();
+				assert_eq!(bidder_2_bids.len(), 2);
+				assert!(bidder_2_bids.iter().all(|bid| matches!(bid.status, BidStatus::Accepted)));
+			});
+		}
+		#[test]
+		fn fixed_price_bids_are_first_come_first_served() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.pricing_model = PricingModel::FixedPrice;
+			let auction_allocation =
+				project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
+
+			// The auction is oversubscribed, and BIDDER_2's bid is split when it reaches the end of the allocation
+			let bids = vec![
+				BidParams::new(
+					BIDDER_1,
+					Percent::from_percent(60) * auction_allocation,
+					1u8,
+					AcceptedFundingAsset::USDT,
+				),
+				BidParams::new(
+					BIDDER_2,
+					Percent::from_percent(60) * auction_allocation,
+					1u8,
+					AcceptedFundingAsset::USDT,
+				),
+				BidParams::new(
+					BIDDER_3,
+					Percent::from_percent(10) * auction_allocation,
+					1u8,
+					AcceptedFundingAsset::USDT,
+				),
+			];
+			let project_id =
+				inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			let plmc_amounts = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				None,
+				true,
+			);
+			let funding_asset_amounts = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				None,
+			);
+			inst.mint_plmc_to(plmc_amounts);
+			inst.mint_funding_asset_to(funding_asset_amounts);
+			inst.bid_for_users(project_id, bids).unwrap();
+			assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound(..)));
+
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.weighted_average_price, Some(project_metadata.minimum_price));
+
+			inst.execute(|| {
+				let bids_of = |bidder| {
+					Bids::<TestRuntime>::iter_prefix_values((project_id, bidder))
+						.sorted_by_key(|bid| bid.id)
+						.map(|bid| {
+							assert_eq!(bid.original_ct_usd_price, project_metadata.minimum_price);
+							bid.status
+						})
+						.collect_vec()
+				};
+				assert_eq!(bids_of(BIDDER_1), vec![BidStatus::Accepted]);
+				assert_eq!(bids_of(BIDDER_2), vec![BidStatus::Accepted, BidStatus::Rejected]);
+				assert_eq!(bids_of(BIDDER_3), vec![BidStatus::Rejected]);
+			});
+		}
 	}
 
 	#[cfg(test)]
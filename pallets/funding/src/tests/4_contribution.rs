@@ -223,6 +223,7 @@ mod round_flow {
 					AcceptedFundingAsset::USDT => usdt_price,
 					AcceptedFundingAsset::USDC => usdc_price,
 					AcceptedFundingAsset::DOT => dot_price,
+					_ => unreachable!(),
 				};
 
 				let mut project_metadata = default_project_metadata.clone();
@@ -1103,6 +1104,8 @@ mod contribute_extrinsic {
 					did: generate_did_from_account(ISSUER_1),
 					investor_type: InvestorType::Institutional,
 					whitelisted_policy: project_metadata.policy_ipfs_cid.unwrap(),
+					jurisdiction: None,
+					max_usd: None,
 				})),
 				Error::<TestRuntime>::ParticipationToOwnProject
 			);
@@ -1742,6 +1745,83 @@ mod contribute_extrinsic {
 			});
 		}
 
+		#[test]
+		fn jurisdiction_not_allowed() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.jurisdiction_restriction =
+				Some(JurisdictionRestriction::AllowList(jurisdictions(&["CH", "LI"])));
+			let project_id = inst.create_community_contributing_project(
+				project_metadata.clone(),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+			);
+			let wap = inst.get_project_details(project_id).weighted_average_price.unwrap();
+			let contribution =
+				ContributionParams::<TestRuntime>::new(BUYER_1, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			let plmc_mint = inst.calculate_contributed_plmc_spent(vec![contribution.clone()], wap, true);
+			let funding_asset_mint = inst.calculate_contributed_funding_asset_spent(vec![contribution.clone()], wap);
+			inst.mint_plmc_to(plmc_mint);
+			inst.mint_funding_asset_to(funding_asset_mint);
+
+			let contribute = |jurisdiction: Option<&str>| {
+				PolimecFunding::contribute(
+					RuntimeOrigin::signed(BUYER_1),
+					jwt_with_jurisdiction(BUYER_1, InvestorType::Retail, jurisdiction),
+					project_id,
+					1000 * CT_UNIT,
+					1u8.try_into().unwrap(),
+					AcceptedFundingAsset::USDT,
+				)
+			};
+
+			inst.execute(|| {
+				assert_noop!(contribute(Some("DE")), Error::<TestRuntime>::JurisdictionNotAllowed);
+				assert_noop!(contribute(None), Error::<TestRuntime>::JurisdictionNotAllowed);
+				// An allowed country also allows its subdivisions
+				assert_ok!(contribute(Some("CH-ZH")));
+			});
+		}
+
+		#[test]
+		fn contribution_above_credential_max_usd() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_community_contributing_project(
+				project_metadata.clone(),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+			);
+			let wap = inst.get_project_details(project_id).weighted_average_price.unwrap();
+			let contribution =
+				ContributionParams::<TestRuntime>::new(BUYER_1, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			let plmc_mint = inst.calculate_contributed_plmc_spent(vec![contribution.clone()], wap, true);
+			let funding_asset_mint = inst.calculate_contributed_funding_asset_spent(vec![contribution.clone()], wap);
+			inst.mint_plmc_to(plmc_mint);
+			inst.mint_funding_asset_to(funding_asset_mint);
+
+			let usd_amount = wap.saturating_mul_int(1000 * CT_UNIT);
+			let contribute = |max_usd: Balance| {
+				PolimecFunding::contribute(
+					RuntimeOrigin::signed(BUYER_1),
+					jwt_with_scope(BUYER_1, InvestorType::Retail, None, None, Some(max_usd)),
+					project_id,
+					1000 * CT_UNIT,
+					1u8.try_into().unwrap(),
+					AcceptedFundingAsset::USDT,
+				)
+			};
+
+			inst.execute(|| {
+				assert_noop!(contribute(usd_amount - 1), Error::<TestRuntime>::CredentialMaxUsdExceeded);
+				assert_ok!(contribute(usd_amount));
+			});
+		}
+
 		#[test]
 		fn ct_sold_out() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -1792,3 +1872,180 @@ mod contribute_extrinsic {
 		}
 	}
 }
+
+#[cfg(test)]
+mod withdraw_contribution_extrinsic {
+	use super::*;
+
+	fn setup_contribution(
+		inst: &mut MockInstantiator,
+		ct_amount: Balance,
+	) -> (ProjectId, ProjectMetadataOf<TestRuntime>, ContributionInfoOf<TestRuntime>) {
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let project_id = inst.create_community_contributing_project(
+			project_metadata.clone(),
+			ISSUER_1,
+			None,
+			default_evaluations(),
+			default_bids(),
+		);
+		let wap = inst.get_project_details(project_id).weighted_average_price.unwrap();
+		let contribution = ContributionParams::new(BUYER_1, ct_amount, 1u8, AcceptedFundingAsset::USDT);
+		let plmc_mint = inst.calculate_contributed_plmc_spent(vec![contribution.clone()], wap, true);
+		let funding_asset_mint = inst.calculate_contributed_funding_asset_spent(vec![contribution.clone()], wap);
+		inst.mint_plmc_to(plmc_mint);
+		inst.mint_funding_asset_to(funding_asset_mint);
+		inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+
+		let stored_contribution =
+			inst.execute(|| Contributions::<TestRuntime>::iter_prefix_values((project_id, BUYER_1)).next().unwrap());
+		(project_id, project_metadata, stored_contribution)
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn withdraw_contribution_releases_funds_and_restores_round() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, contribution) = setup_contribution(&mut inst, 1000 * CT_UNIT);
+
+			let prev_project_details = inst.get_project_details(project_id);
+			let prev_free_plmc = inst.get_free_plmc_balance_for(BUYER_1);
+			let prev_free_usdt = inst.get_free_funding_asset_balance_for(AcceptedFundingAsset::USDT.id(), BUYER_1);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::withdraw_contribution(
+					RuntimeOrigin::signed(BUYER_1),
+					get_mock_jwt_with_cid(
+						BUYER_1,
+						InvestorType::Retail,
+						generate_did_from_account(BUYER_1),
+						project_metadata.clone().policy_ipfs_cid.unwrap()
+					),
+					project_id,
+					contribution.id,
+				));
+			});
+
+			// Storage
+			assert!(inst
+				.execute(|| Contributions::<TestRuntime>::get((project_id, BUYER_1, contribution.id)))
+				.is_none());
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(
+				project_details.remaining_contribution_tokens,
+				prev_project_details.remaining_contribution_tokens + contribution.ct_amount
+			);
+			assert_eq!(
+				project_details.funding_amount_reached_usd,
+				prev_project_details.funding_amount_reached_usd - contribution.usd_contribution_amount
+			);
+			assert_eq!(
+				inst.execute(|| ContributionBoughtUSD::<TestRuntime>::get((
+					project_id,
+					generate_did_from_account(BUYER_1)
+				))),
+				0
+			);
+
+			// Balances
+			inst.assert_plmc_held_balance(BUYER_1, 0, HoldReason::Participation.into());
+			inst.assert_plmc_free_balance(BUYER_1, prev_free_plmc + contribution.plmc_bond);
+			inst.assert_funding_asset_free_balance(
+				BUYER_1,
+				AcceptedFundingAsset::USDT.id(),
+				prev_free_usdt + contribution.funding_asset_amount,
+			);
+		}
+
+		#[test]
+		fn withdrawn_tokens_can_be_bought_again() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_community_contributing_project(
+				project_metadata.clone(),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+			);
+			let project_details = inst.get_project_details(project_id);
+			let remaining_cts = project_details.remaining_contribution_tokens;
+			let wap = project_details.weighted_average_price.unwrap();
+
+			let glutton_contribution = ContributionParams::new(BUYER_1, remaining_cts, 1u8, AcceptedFundingAsset::USDT);
+			let plmc_mint = inst.calculate_contributed_plmc_spent(vec![glutton_contribution.clone()], wap, true);
+			let funding_asset_mint =
+				inst.calculate_contributed_funding_asset_spent(vec![glutton_contribution.clone()], wap);
+			inst.mint_plmc_to(plmc_mint);
+			inst.mint_funding_asset_to(funding_asset_mint);
+			inst.contribute_for_users(project_id, vec![glutton_contribution]).unwrap();
+			assert_eq!(inst.get_project_details(project_id).remaining_contribution_tokens, 0);
+
+			let glutton_contribution = inst
+				.execute(|| Contributions::<TestRuntime>::iter_prefix_values((project_id, BUYER_1)).next().unwrap());
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::do_withdraw_contribution(&BUYER_1, project_id, glutton_contribution.id));
+			});
+			assert_eq!(inst.get_project_details(project_id).remaining_contribution_tokens, remaining_cts);
+
+			let contribution = ContributionParams::new(BUYER_2, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			let plmc_mint = inst.calculate_contributed_plmc_spent(vec![contribution.clone()], wap, true);
+			let funding_asset_mint = inst.calculate_contributed_funding_asset_spent(vec![contribution.clone()], wap);
+			inst.mint_plmc_to(plmc_mint);
+			inst.mint_funding_asset_to(funding_asset_mint);
+			assert_ok!(inst.contribute_for_users(project_id, vec![contribution]));
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_withdraw_after_cooling_off_period() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, contribution) = setup_contribution(&mut inst, 1000 * CT_UNIT);
+
+			let cooling_off_period = <TestRuntime as Config>::ContributionCoolingOffPeriod::get();
+			inst.advance_time(cooling_off_period);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_withdraw_contribution(&BUYER_1, project_id, contribution.id),
+					Error::<TestRuntime>::CoolingOffPeriodOver
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_withdraw_after_funding_ended() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, contribution) = setup_contribution(&mut inst, 1000 * CT_UNIT);
+
+			inst.go_to_next_state(project_id);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_withdraw_contribution(&BUYER_1, project_id, contribution.id),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_withdraw_someone_elses_contribution() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, contribution) = setup_contribution(&mut inst, 1000 * CT_UNIT);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_withdraw_contribution(&BUYER_2, project_id, contribution.id),
+					Error::<TestRuntime>::ParticipationNotFound
+				);
+			});
+		}
+	}
+}
@@ -9,6 +9,54 @@ mod round_flow {
 	mod success {
 		use super::*;
 
+		#[test]
+		fn issuer_soft_cap_decides_the_outcome() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::set_soft_cap_bounds(
+					RuntimeOrigin::root(),
+					SoftCapBounds::new(Perquintill::from_percent(10), Perquintill::from_percent(90))
+				));
+			});
+
+			let mut create_finished_project = |issuer: AccountId, soft_cap: u64, funding_percentage: u64| {
+				let mut project_metadata = default_project_metadata(issuer);
+				project_metadata.soft_cap = Some(Perquintill::from_percent(soft_cap));
+				let min_price = project_metadata.minimum_price;
+				let funding_usd = Perquintill::from_percent(funding_percentage) *
+					min_price.checked_mul_int(project_metadata.total_allocation_size).unwrap();
+				let bids = inst.generate_bids_from_total_usd(
+					Percent::from_percent(50u8) * funding_usd,
+					min_price,
+					default_weights(),
+					default_bidders(),
+					default_multipliers(),
+				);
+				let contributions = inst.generate_contributions_from_total_usd(
+					Percent::from_percent(50u8) * funding_usd,
+					min_price,
+					default_weights(),
+					default_community_contributors(),
+					default_multipliers(),
+				);
+				let project_id = inst.create_finished_project(
+					project_metadata,
+					issuer,
+					None,
+					default_evaluations(),
+					bids,
+					contributions,
+					vec![],
+				);
+				inst.get_project_details(project_id).status
+			};
+
+			// The runtime threshold is 33%, but the issuer only needs 20% of its target.
+			assert_eq!(create_finished_project(ISSUER_1, 20, 30), ProjectStatus::FundingSuccessful);
+			// The runtime threshold would be reached, but not the issuer's 60%.
+			assert_eq!(create_finished_project(ISSUER_2, 60, 50), ProjectStatus::FundingFailed);
+		}
+
 		#[test]
 		fn auction_oversubscription() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -1,5 +1,8 @@
 use super::*;
-use frame_support::traits::fungibles::Inspect;
+use frame_support::{
+	traits::{fungibles::Inspect, Hooks},
+	weights::Weight,
+};
 use sp_runtime::bounded_vec;
 
 #[cfg(test)]
@@ -40,6 +43,280 @@ mod round_flow {
 			inst.assert_bids_migrations_created(project_id, bids, false);
 			inst.assert_contributions_migrations_created(project_id, contributions, false);
 		}
+
+		#[test]
+		fn accepted_project_is_settled_on_idle() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(100, true);
+			let evaluations = inst.get_evaluations(project_id);
+			let bids = inst.get_bids(project_id);
+			let contributions = inst.get_contributions(project_id);
+
+			inst.advance_time(1u64);
+
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementFinished(FundingOutcome::Success)
+			);
+			assert!(inst.get_evaluations(project_id).is_empty());
+			assert!(inst.get_bids(project_id).is_empty());
+			assert!(inst.get_contributions(project_id).is_empty());
+			assert!(inst.execute(|| SettlementCursors::<TestRuntime>::get(project_id)).is_none());
+
+			inst.assert_total_funding_paid_out(project_id, bids.clone(), contributions.clone());
+			inst.assert_evaluations_migrations_created(project_id, evaluations, true);
+			inst.assert_bids_migrations_created(project_id, bids, true);
+			inst.assert_contributions_migrations_created(project_id, contributions, true);
+		}
+
+		#[test]
+		fn failed_project_is_settled_on_idle() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(32, true);
+			let evaluations = inst.get_evaluations(project_id);
+			let bids = inst.get_bids(project_id);
+			let contributions = inst.get_contributions(project_id);
+
+			inst.advance_time(1u64);
+
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementFinished(FundingOutcome::Failure)
+			);
+			inst.assert_evaluations_migrations_created(project_id, evaluations, false);
+			inst.assert_bids_migrations_created(project_id, bids, false);
+			inst.assert_contributions_migrations_created(project_id, contributions, false);
+		}
+
+		#[test]
+		fn skipped_participations_are_left_for_manual_settlement() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(100, true);
+			let evaluation = inst.get_evaluations(project_id)[0].clone();
+			let evaluation_key = (project_id, evaluation.evaluator, evaluation.id);
+
+			// The evaluation's bond can't be released, so it fails to settle
+			inst.execute(|| {
+				Evaluations::<TestRuntime>::mutate(evaluation_key, |maybe_evaluation| {
+					maybe_evaluation.as_mut().unwrap().current_plmc_bond += 1_000_000 * PLMC;
+				})
+			});
+
+			inst.advance_time(1u64);
+
+			// Everything else was settled, and the project waits for the skipped evaluation
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementStarted(FundingOutcome::Success)
+			);
+			assert_eq!(inst.get_evaluations(project_id).len(), 1);
+			assert!(inst.get_bids(project_id).is_empty());
+			assert!(inst.get_contributions(project_id).is_empty());
+			assert!(inst.execute(|| SettlementCursors::<TestRuntime>::get(project_id)).is_none());
+			inst.execute(|| {
+				assert!(System::events().iter().any(|record| matches!(
+					record.event,
+					RuntimeEvent::PolimecFunding(Event::AutomaticSettlementIncomplete { project_id: id, .. })
+						if id == project_id
+				)));
+				assert_noop!(
+					PolimecFunding::mark_project_as_settled(RuntimeOrigin::signed(ISSUER_1), project_id),
+					Error::<TestRuntime>::SettlementNotComplete
+				);
+			});
+
+			// Once the evaluation is fixed and settled manually, anyone can mark the project as settled
+			inst.execute(|| {
+				Evaluations::<TestRuntime>::insert(evaluation_key, evaluation.clone());
+				assert_ok!(PolimecFunding::settle_evaluation(
+					RuntimeOrigin::signed(evaluation.evaluator),
+					project_id,
+					evaluation.evaluator,
+					evaluation.id
+				));
+				assert_ok!(PolimecFunding::mark_project_as_settled(RuntimeOrigin::signed(ISSUER_1), project_id));
+			});
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementFinished(FundingOutcome::Success)
+			);
+		}
+
+		#[test]
+		fn on_idle_settlement_is_limited_by_weight() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(100, true);
+			let evaluations_count = inst.get_evaluations(project_id).len();
+			let now = inst.current_block();
+
+			let db_weight = <TestRuntime as frame_system::Config>::DbWeight::get();
+			let max_step_weight = <TestRuntime as Config>::WeightInfo::settle_rewarded_evaluation()
+				.max(
+					<TestRuntime as Config>::WeightInfo::settle_accepted_bid_with_refund()
+						.saturating_add(PolimecFunding::xcm_refund_weight()),
+				)
+				.max(
+					<TestRuntime as Config>::WeightInfo::settle_contribution_project_successful()
+						.saturating_add(PolimecFunding::xcm_refund_weight()),
+				)
+				.max(<TestRuntime as Config>::WeightInfo::mark_project_as_settled())
+				.saturating_add(db_weight.reads_writes(3, 1));
+
+			// Not enough weight for a single settlement
+			inst.execute(|| {
+				assert_eq!(
+					PolimecFunding::on_idle(now, max_step_weight.saturating_sub(Weight::from_parts(1, 0))),
+					Weight::zero()
+				);
+			});
+			assert_eq!(inst.get_evaluations(project_id).len(), evaluations_count);
+
+			// Enough weight for exactly one settlement
+			inst.execute(|| {
+				let used_weight = PolimecFunding::on_idle(now, max_step_weight);
+				assert!(used_weight.all_lte(max_step_weight));
+			});
+			assert_eq!(inst.get_evaluations(project_id).len(), evaluations_count - 1);
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementStarted(FundingOutcome::Success)
+			);
+
+			// A participation settled manually is not visited again
+			let evaluation = inst.get_evaluations(project_id)[0].clone();
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::settle_evaluation(
+					RuntimeOrigin::signed(evaluation.evaluator),
+					project_id,
+					evaluation.evaluator,
+					evaluation.id
+				));
+			});
+
+			inst.advance_time(1u64);
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementFinished(FundingOutcome::Success)
+			);
+		}
+
+		#[test]
+		fn ct_release_policy_is_stored_in_migrations() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let release_policy =
+				CtReleasePolicy { cliff: 100, duration: 1_000, tge_unlock: Some(Percent::from_percent(20)) };
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.ct_release_policy = Some(release_policy);
+			let project_id = inst.create_finished_project(
+				project_metadata,
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+			);
+			assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(_)));
+			inst.settle_project(project_id, true);
+
+			let migrations = inst.execute(|| {
+				UserMigrations::<TestRuntime>::iter_prefix_values((project_id,))
+					.flat_map(|(_, migrations)| migrations.into_iter())
+					.filter(|migration| migration.origin.participation_type != ParticipationType::LiquidityPools)
+					.collect_vec()
+			});
+			assert!(!migrations.is_empty());
+			for migration in migrations {
+				assert_eq!(migration.info.release_policy, Some(release_policy));
+				assert_eq!(migration.info.vesting_end(), 1_100);
+			}
+		}
+
+		#[test]
+		fn fee_can_be_paid_in_funding_assets() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let treasury = <TestRuntime as Config>::BlockchainOperationTreasury::get();
+			let liquidity_pools_account = <TestRuntime as Config>::LiquidityPoolsAccount::get();
+			let usdt_id = AcceptedFundingAsset::USDT.id();
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.fee_mode = FeeMode::FundingAssets(Perquintill::one());
+			let project_id = inst.create_finished_project(
+				project_metadata,
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+			);
+			let treasury_usdt_before =
+				inst.get_free_funding_asset_balances_for(usdt_id, vec![treasury])[0].asset_amount;
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+			inst.settle_project(project_id, true);
+
+			// Fee brackets of the mock: 10% of the first 1M USD, 8% of the next 4M, and 6% of the rest
+			let funding_reached = inst.get_project_details(project_id).funding_amount_reached_usd;
+			let bracket = |from: Balance, to: Balance| funding_reached.clamp(from, to) - from;
+			let expected_fee = Percent::from_percent(10) * bracket(0, 1_000_000 * USD_UNIT) +
+				Percent::from_percent(8) * bracket(1_000_000 * USD_UNIT, 5_000_000 * USD_UNIT) +
+				Percent::from_percent(6) * bracket(5_000_000 * USD_UNIT, Balance::MAX);
+
+			let treasury_usdt_after = inst.get_free_funding_asset_balances_for(usdt_id, vec![treasury])[0].asset_amount;
+			let issuer_usdt = inst.get_free_funding_asset_balances_for(usdt_id, vec![ISSUER_1])[0].asset_amount;
+			let fee = treasury_usdt_after - treasury_usdt_before;
+			assert_close_enough!(fee, expected_fee, Perquintill::from_float(0.9999));
+			assert_close_enough!(issuer_usdt, funding_reached - expected_fee, Perquintill::from_float(0.9999));
+
+			// No fee is left to be paid in CTs
+			inst.assert_ct_balance(project_id, liquidity_pools_account, Zero::zero());
+			let pot = inst.execute(|| LongTermHolderPots::<TestRuntime>::get(project_id).unwrap());
+			assert_eq!(pot.reward, Zero::zero());
+		}
+
+		#[test]
+		fn fee_parameters_only_split_the_ct_fee() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let create_project = |inst: &mut MockInstantiator, issuer: AccountId, fee_mode: FeeMode| {
+				let mut project_metadata = default_project_metadata(issuer);
+				project_metadata.fee_mode = fee_mode;
+				inst.create_finished_project(
+					project_metadata,
+					issuer,
+					None,
+					default_evaluations(),
+					default_bids(),
+					default_community_contributions(),
+					default_remainder_contributions(),
+				)
+			};
+			let ct_fee_project = create_project(&mut inst, ISSUER_1, FeeMode::ContributionTokens);
+			let half_ct_fee_project =
+				create_project(&mut inst, ISSUER_2, FeeMode::FundingAssets(Perquintill::from_percent(50)));
+
+			// Half of the fee is paid in funding assets, so every CT pot is halved. The funding assets are not split.
+			inst.execute(|| {
+				let (lp_pot, long_term_holder_pot) =
+					PolimecFunding::generate_liquidity_pools_and_long_term_holder_rewards(ct_fee_project).unwrap();
+				let (half_lp_pot, half_long_term_holder_pot) =
+					PolimecFunding::generate_liquidity_pools_and_long_term_holder_rewards(half_ct_fee_project).unwrap();
+				assert_close_enough!(half_lp_pot, lp_pot / 2, Perquintill::from_float(0.9999));
+				assert_close_enough!(
+					half_long_term_holder_pot,
+					long_term_holder_pot / 2,
+					Perquintill::from_float(0.9999)
+				);
+
+				let rewards = PolimecFunding::generate_evaluator_rewards_info(ct_fee_project).unwrap();
+				let half_rewards = PolimecFunding::generate_evaluator_rewards_info(half_ct_fee_project).unwrap();
+				assert_close_enough!(
+					half_rewards.early_evaluator_reward_pot,
+					rewards.early_evaluator_reward_pot / 2,
+					Perquintill::from_float(0.9999)
+				);
+				assert_close_enough!(
+					half_rewards.normal_evaluator_reward_pot,
+					rewards.normal_evaluator_reward_pot / 2,
+					Perquintill::from_float(0.9999)
+				);
+			});
+		}
 	}
 }
 
@@ -55,6 +332,7 @@ mod start_settlement_extrinsic {
 		fn funding_success_settlement() {
 			let (mut inst, project_id) = create_project_with_funding_percentage(40, false);
 			let ct_treasury = <TestRuntime as Config>::ContributionTreasury::get();
+			let liquidity_pools_account = <TestRuntime as Config>::LiquidityPoolsAccount::get();
 			let project_details = inst.get_project_details(project_id);
 
 			assert_eq!(project_details.funding_amount_reached_usd, 4_000_000 * USD_UNIT);
@@ -62,8 +340,8 @@ mod start_settlement_extrinsic {
 				Percent::from_percent(8u8) * (3_000_000 * USD_UNIT);
 			let ct_fee =
 				project_details.weighted_average_price.unwrap().reciprocal().unwrap().saturating_mul_int(usd_fee);
-			// Liquidity Pools and Long Term Holder Bonus treasury allocation
-			let treasury_allocation = Percent::from_percent(50) * ct_fee + Percent::from_percent(20) * ct_fee;
+			let liquidity_pools_allocation = Percent::from_percent(50) * ct_fee;
+			let long_term_holder_allocation = Percent::from_percent(20) * ct_fee;
 
 			assert_eq!(project_details.funding_end_block, None);
 			assert_eq!(project_details.status, ProjectStatus::FundingSuccessful);
@@ -82,7 +360,15 @@ mod start_settlement_extrinsic {
 				assert_eq!(<TestRuntime as Config>::ContributionTokenCurrency::asset_exists(project_id), true)
 			});
 
-			inst.assert_ct_balance(project_id, ct_treasury, treasury_allocation);
+			inst.assert_ct_balance(project_id, ct_treasury, long_term_holder_allocation);
+			inst.assert_ct_balance(project_id, liquidity_pools_account, liquidity_pools_allocation);
+			let (_, lp_migrations) =
+				inst.execute(|| UserMigrations::<TestRuntime>::get((project_id, liquidity_pools_account)).unwrap());
+			assert_eq!(lp_migrations.len(), 1);
+			assert_eq!(lp_migrations[0].origin.participation_type, ParticipationType::LiquidityPools);
+			assert_eq!(lp_migrations[0].info.contribution_token_amount, liquidity_pools_allocation);
+			let pot = inst.execute(|| LongTermHolderPots::<TestRuntime>::get(project_id).unwrap());
+			assert_eq!(pot, LongTermHolderPot { reward: long_term_holder_allocation, total_held: 0 });
 		}
 
 		#[test]
@@ -451,6 +737,8 @@ mod settle_bid_extrinsic {
 
 			inst.assert_plmc_free_balance(BIDDER_1, expected_plmc_refund + ed);
 			inst.assert_ct_balance(project_id, BIDDER_1, auction_allocation - 2000 * CT_UNIT);
+			let bidder_1_expected_plmc_refund = expected_plmc_refund;
+			let bidder_1_expected_final_plmc_bonded = expected_final_plmc_bonded;
 
 			inst.assert_migration(
 				project_id,
@@ -461,14 +749,6 @@ mod settle_bid_extrinsic {
 				true,
 			);
 
-			let hold_reason: RuntimeHoldReason = HoldReason::Participation.into();
-			let vesting_time = Multiplier::force_new(3).calculate_vesting_duration::<TestRuntime>();
-			let now = inst.current_block();
-			inst.jump_to_block(now + vesting_time + 1u64);
-			inst.execute(|| LinearRelease::vest(RuntimeOrigin::signed(BIDDER_1), hold_reason).expect("Vesting failed"));
-
-			inst.assert_plmc_free_balance(BIDDER_1, expected_plmc_refund + expected_final_plmc_bonded + ed);
-
 			// Price > wap bid assertions
 			let lower_price_bid_stored = inst.execute(|| Bids::<TestRuntime>::get((project_id, BIDDER_2, 1)).unwrap());
 			let expected_final_plmc_bonded =
@@ -503,6 +783,18 @@ mod settle_bid_extrinsic {
 
 			inst.assert_migration(project_id, BIDDER_2, 2000 * CT_UNIT, 1, ParticipationType::Bid, true);
 
+			// Both bids are settled before advancing time, as the remaining participations get settled in `on_idle`
+			let hold_reason: RuntimeHoldReason = HoldReason::Participation.into();
+			let vesting_time = Multiplier::force_new(3).calculate_vesting_duration::<TestRuntime>();
+			let now = inst.current_block();
+			inst.jump_to_block(now + vesting_time + 1u64);
+			inst.execute(|| LinearRelease::vest(RuntimeOrigin::signed(BIDDER_1), hold_reason).expect("Vesting failed"));
+
+			inst.assert_plmc_free_balance(
+				BIDDER_1,
+				bidder_1_expected_plmc_refund + bidder_1_expected_final_plmc_bonded + ed,
+			);
+
 			// Multiplier 5 should be unbonded no earlier than after 8.67 weeks (i.e. 436'867 blocks)
 			let vesting_time = lower_price_bid_params.multiplier.calculate_vesting_duration::<TestRuntime>();
 
@@ -1049,6 +1341,59 @@ mod settle_contribution_extrinsic {
 			inst.assert_plmc_held_balance(BUYER_1, Zero::zero(), hold_reason);
 		}
 
+		#[test]
+		fn contribution_with_fee_in_funding_assets() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let treasury = <TestRuntime as Config>::BlockchainOperationTreasury::get();
+			let usdt_id = AcceptedFundingAsset::USDT.id();
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.fee_mode = FeeMode::FundingAssets(Perquintill::from_percent(50));
+
+			let contribution =
+				ContributionParams::<TestRuntime>::new(BUYER_1, 1000 * CT_UNIT, 1, AcceptedFundingAsset::USDT);
+			let project_id = inst.create_finished_project(
+				project_metadata.clone(),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				vec![contribution],
+				vec![],
+			);
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+
+			let stored_contribution =
+				inst.execute(|| Contributions::<TestRuntime>::get((project_id, BUYER_1, 0)).unwrap());
+			let treasury_usdt_before =
+				inst.get_free_funding_asset_balances_for(usdt_id, vec![treasury])[0].asset_amount;
+			let fee_percentage = inst.execute(|| PolimecFunding::funding_asset_fee_percentage(project_id).unwrap());
+			assert!(!fee_percentage.is_zero());
+			let expected_fee = fee_percentage * stored_contribution.funding_asset_amount;
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::settle_contribution(RuntimeOrigin::signed(BUYER_1), project_id, BUYER_1, 0));
+			});
+
+			inst.assert_funding_asset_free_balance(
+				project_metadata.funding_destination_account,
+				usdt_id,
+				stored_contribution.funding_asset_amount - expected_fee,
+			);
+			inst.assert_funding_asset_free_balance(treasury, usdt_id, treasury_usdt_before + expected_fee);
+			inst.execute(|| {
+				System::assert_last_event(
+					Event::ContributionSettled {
+						project_id,
+						account: BUYER_1,
+						id: 0,
+						ct_amount: stored_contribution.ct_amount,
+						funding_asset_fee: expected_fee,
+					}
+					.into(),
+				)
+			});
+		}
+
 		#[test]
 		fn contribution_on_failed_project() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -1354,3 +1699,231 @@ mod mark_project_as_settled_extrinsic {
 		}
 	}
 }
+
+#[cfg(test)]
+mod claim_long_term_holder_reward_extrinsic {
+	use super::*;
+
+	fn settled_project_after_holding_period() -> (MockInstantiator, ProjectId) {
+		let (mut inst, project_id) = create_project_with_funding_percentage(40, true);
+		inst.settle_project(project_id, true);
+		let holding = inst.execute(|| LongTermHoldings::<TestRuntime>::get((project_id, BUYER_1)).unwrap());
+		inst.jump_to_block(holding.since + <TestRuntime as Config>::LongTermHolderPeriod::get());
+		(inst, project_id)
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn holder_receives_its_share_of_the_pot() {
+			let (mut inst, project_id) = settled_project_after_holding_period();
+			let ct_treasury = <TestRuntime as Config>::ContributionTreasury::get();
+
+			let pot = inst.execute(|| LongTermHolderPots::<TestRuntime>::get(project_id).unwrap());
+			let total_held = inst.execute(|| {
+				LongTermHoldings::<TestRuntime>::iter_prefix_values((project_id,))
+					.map(|holding| holding.ct_amount)
+					.sum::<Balance>()
+			});
+			assert_eq!(pot.total_held, total_held);
+
+			let holding = inst.execute(|| LongTermHoldings::<TestRuntime>::get((project_id, BUYER_1)).unwrap());
+			let reward = Perquintill::from_rational(holding.ct_amount, total_held) * pot.reward;
+			assert!(reward > 0);
+			let prev_holder_balance = inst.get_ct_asset_balance_for(project_id, BUYER_1);
+			let prev_treasury_balance = inst.get_ct_asset_balance_for(project_id, ct_treasury);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::claim_long_term_holder_reward(
+					RuntimeOrigin::signed(80085),
+					project_id,
+					BUYER_1
+				));
+			});
+
+			inst.assert_ct_balance(project_id, BUYER_1, prev_holder_balance + reward);
+			inst.assert_ct_balance(project_id, ct_treasury, prev_treasury_balance - reward);
+			let (_, migrations) = inst.execute(|| UserMigrations::<TestRuntime>::get((project_id, BUYER_1)).unwrap());
+			let reward_migration = migrations.last().unwrap();
+			assert_eq!(reward_migration.origin.participation_type, ParticipationType::LongTermHolderReward);
+			assert_eq!(reward_migration.info.contribution_token_amount, reward);
+			assert!(inst.execute(|| LongTermHoldings::<TestRuntime>::get((project_id, BUYER_1))).is_none());
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+		use frame_support::traits::{fungibles::Mutate, tokens::Preservation};
+
+		#[test]
+		fn cannot_claim_before_the_holding_period() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(40, true);
+			inst.settle_project(project_id, true);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::claim_long_term_holder_reward(RuntimeOrigin::signed(80085), project_id, BUYER_1),
+					Error::<TestRuntime>::LongTermHolderPeriodNotOver
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_claim_before_settlement_finished() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(40, true);
+			inst.settle_project(project_id, false);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::claim_long_term_holder_reward(RuntimeOrigin::signed(80085), project_id, BUYER_1),
+					Error::<TestRuntime>::SettlementNotComplete
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_claim_twice() {
+			let (mut inst, project_id) = settled_project_after_holding_period();
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::claim_long_term_holder_reward(
+					RuntimeOrigin::signed(80085),
+					project_id,
+					BUYER_1
+				));
+				assert_noop!(
+					PolimecFunding::claim_long_term_holder_reward(RuntimeOrigin::signed(80085), project_id, BUYER_1),
+					Error::<TestRuntime>::NoLongTermHolding
+				);
+			});
+		}
+
+		#[test]
+		fn held_cts_cannot_be_moved() {
+			let (mut inst, project_id) = settled_project_after_holding_period();
+
+			inst.execute(|| {
+				assert!(<TestRuntime as Config>::ContributionTokenCurrency::transfer(
+					project_id,
+					&BUYER_1,
+					&BUYER_2,
+					CT_UNIT,
+					Preservation::Expendable
+				)
+				.is_err());
+			});
+		}
+
+		#[test]
+		fn cannot_claim_after_moving_the_cts_away_and_back() {
+			let (mut inst, project_id) = settled_project_after_holding_period();
+			let ct_amount = inst.get_ct_asset_balance_for(project_id, BUYER_1);
+			inst.mint_plmc_to(vec![(80085, inst.get_ed()).into()]);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::forfeit_long_term_holder_reward(RuntimeOrigin::signed(BUYER_1), project_id));
+				assert_ok!(<TestRuntime as Config>::ContributionTokenCurrency::transfer(
+					project_id,
+					&BUYER_1,
+					&80085,
+					ct_amount,
+					Preservation::Expendable
+				));
+				assert_ok!(<TestRuntime as Config>::ContributionTokenCurrency::transfer(
+					project_id,
+					&80085,
+					&BUYER_1,
+					ct_amount,
+					Preservation::Expendable
+				));
+				assert_noop!(
+					PolimecFunding::claim_long_term_holder_reward(RuntimeOrigin::signed(80085), project_id, BUYER_1),
+					Error::<TestRuntime>::NoLongTermHolding
+				);
+			});
+			inst.assert_ct_balance(project_id, BUYER_1, ct_amount);
+		}
+
+		#[test]
+		fn cannot_claim_after_the_cts_migrated() {
+			let (mut inst, project_id) = settled_project_after_holding_period();
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::do_start_offchain_migration(project_id, ISSUER_1));
+				assert_ok!(PolimecFunding::do_confirm_offchain_migration(project_id, ISSUER_1, BUYER_1));
+				assert_noop!(
+					PolimecFunding::claim_long_term_holder_reward(RuntimeOrigin::signed(80085), project_id, BUYER_1),
+					Error::<TestRuntime>::MigrationAlreadyStarted
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod forfeit_long_term_holder_reward_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn forfeiting_unfreezes_the_cts() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(40, true);
+			inst.settle_project(project_id, true);
+			let ct_treasury = <TestRuntime as Config>::ContributionTreasury::get();
+			let holding = inst.execute(|| LongTermHoldings::<TestRuntime>::get((project_id, BUYER_1)).unwrap());
+			let prev_treasury_balance = inst.get_ct_asset_balance_for(project_id, ct_treasury);
+			inst.execute(|| {
+				assert_eq!(
+					<PolimecFunding as pallet_assets::FrozenBalance<_, _, _>>::frozen_balance(project_id, &BUYER_1),
+					Some(holding.ct_amount)
+				);
+			});
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::forfeit_long_term_holder_reward(RuntimeOrigin::signed(BUYER_1), project_id));
+				System::assert_last_event(RuntimeEvent::PolimecFunding(Event::LongTermHolderRewardForfeited {
+					project_id,
+					account: BUYER_1,
+					ct_amount: holding.ct_amount,
+				}));
+				assert!(LongTermHoldings::<TestRuntime>::get((project_id, BUYER_1)).is_none());
+				assert_eq!(
+					<PolimecFunding as pallet_assets::FrozenBalance<_, _, _>>::frozen_balance(project_id, &BUYER_1),
+					None
+				);
+			});
+
+			// The forfeited share stays in the treasury
+			inst.assert_ct_balance(project_id, ct_treasury, prev_treasury_balance);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_forfeit_without_holding() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(40, true);
+			inst.settle_project(project_id, true);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::forfeit_long_term_holder_reward(RuntimeOrigin::signed(80085), project_id),
+					Error::<TestRuntime>::NoLongTermHolding
+				);
+				assert_ok!(PolimecFunding::forfeit_long_term_holder_reward(RuntimeOrigin::signed(BUYER_1), project_id));
+				assert_noop!(
+					PolimecFunding::forfeit_long_term_holder_reward(RuntimeOrigin::signed(BUYER_1), project_id),
+					Error::<TestRuntime>::NoLongTermHolding
+				);
+			});
+		}
+	}
+}
@@ -1,5 +1,7 @@
 use super::*;
 use frame_support::{assert_err, traits::fungibles::Inspect};
+use parity_scale_codec::Decode;
+use polimec_common::migration_types::{v1, MigrationOrigin, Migrations, ParticipationType};
 use sp_runtime::bounded_vec;
 use xcm::v4::MaxPalletNameLen;
 
@@ -58,7 +60,8 @@ mod pallet_migration {
 			}))
 		);
 		assert_eq!(project_details.status, ProjectStatus::CTMigrationStarted);
-		assert_eq!(inst.execute(|| UnmigratedCounter::<TestRuntime>::get(project_id)), 10);
+		// 10 participants and the liquidity pools account
+		assert_eq!(inst.execute(|| UnmigratedCounter::<TestRuntime>::get(project_id)), 11);
 	}
 
 	fn create_pallet_migration_project(mut inst: MockInstantiator) -> (ProjectId, MockInstantiator) {
@@ -179,6 +182,45 @@ mod pallet_migration {
 			panic!("Migration type is not Pallet")
 		}
 	}
+
+	/// Returns the call index and the encoded arguments of the receiver call sent with `migrations`.
+	fn sent_receiver_call(migrations: Vec<Migration>) -> (u8, Vec<u8>) {
+		let xcm = PolimecFunding::construct_migration_xcm_message(BoundedVec::truncate_from(migrations), 0, 69);
+		let Some(Transact { call, .. }) = xcm.0.get(1) else { panic!("Migration message without a Transact") };
+		let encoded_call = call.clone().into_encoded();
+		assert_eq!(encoded_call[0], 69);
+		(encoded_call[1], encoded_call[2..].to_vec())
+	}
+
+	#[test]
+	fn migration_message_keeps_the_first_layout_when_possible() {
+		let user = Location::new(0, [AccountId32 { network: None, id: [1u8; 32] }]);
+		let origin = MigrationOrigin { user, id: 0, participation_type: ParticipationType::Bid };
+		let migration = Migration::new(origin.clone(), (100 * CT_UNIT, 10).into());
+
+		let (call_index, encoded_migrations) = sent_receiver_call(vec![migration.clone()]);
+		assert_eq!(call_index, 0);
+		let v1_migrations: Migrations = v1::Migrations::decode(&mut &encoded_migrations[..]).unwrap().into();
+		assert_eq!(v1_migrations, Migrations::from(vec![migration.clone()]));
+
+		// Any migration that does not fit in the first layout makes the whole message use the second one
+		let release_policy = CtReleasePolicy { cliff: 5, duration: 10, tge_unlock: None };
+		let with_release_policy = Migration::new(origin.clone(), (100 * CT_UNIT, 10, Some(release_policy)).into());
+		let private_contribution = Migration::new(
+			MigrationOrigin { participation_type: ParticipationType::PrivateContribution, ..origin.clone() },
+			(100 * CT_UNIT, 10).into(),
+		);
+		let ethereum_user = Migration::new(
+			MigrationOrigin { user: Location::new(0, [AccountKey20 { network: None, key: [1u8; 20] }]), ..origin },
+			(100 * CT_UNIT, 10).into(),
+		);
+		for other_migration in [with_release_policy, private_contribution, ethereum_user] {
+			let migrations = vec![migration.clone(), other_migration];
+			let (call_index, encoded_migrations) = sent_receiver_call(migrations.clone());
+			assert_eq!(call_index, 1);
+			assert_eq!(Migrations::decode(&mut &encoded_migrations[..]).unwrap(), Migrations::from(migrations));
+		}
+	}
 }
 
 mod offchain_migration {
@@ -222,7 +264,8 @@ mod offchain_migration {
 		});
 
 		let project_details = inst.get_project_details(project_id);
-		assert_eq!(inst.execute(|| UnmigratedCounter::<TestRuntime>::get(project_id)), 10);
+		// 10 participants and the liquidity pools account
+		assert_eq!(inst.execute(|| UnmigratedCounter::<TestRuntime>::get(project_id)), 11);
 		assert_eq!(project_details.status, ProjectStatus::CTMigrationStarted);
 	}
 
@@ -281,3 +324,217 @@ mod offchain_migration {
 		});
 	}
 }
+
+mod set_migration_destination_extrinsic {
+	use super::*;
+	use sp_core::Pair;
+	use sp_runtime::traits::Convert;
+
+	fn sign_with_substrate_key(
+		project_id: ProjectId,
+		participant: AccountIdOf<TestRuntime>,
+		seed: [u8; 32],
+	) -> (Location, MigrationDestinationSignature) {
+		let pair = sp_core::sr25519::Pair::from_seed(&seed);
+		let message = crate::Pallet::<TestRuntime>::migration_destination_message(project_id, &participant);
+		let signature = sp_runtime::MultiSignature::Sr25519(pair.sign(&message));
+		let destination = Location::new(0, AccountId32 { network: None, id: pair.public().0 });
+		(destination, MigrationDestinationSignature::Substrate(signature))
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn substrate_destination_replaces_existing_migrations() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_settled_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+				true,
+			);
+			let (destination, signature) = sign_with_substrate_key(project_id, BIDDER_1, [1u8; 32]);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::set_migration_destination(
+					RuntimeOrigin::signed(BIDDER_1),
+					project_id,
+					destination.clone(),
+					signature,
+				));
+			});
+
+			let (status, migrations) =
+				inst.execute(|| UserMigrations::<TestRuntime>::get((project_id, BIDDER_1))).unwrap();
+			assert_eq!(status, MigrationStatus::NotStarted);
+			assert!(!migrations.is_empty());
+			assert!(migrations.iter().all(|migration| migration.origin.user == destination));
+			inst.execute(|| {
+				assert_eq!(MigrationDestinations::<TestRuntime>::get(project_id, BIDDER_1), Some(destination.clone()));
+				System::assert_last_event(
+					Event::MigrationDestinationSet { project_id, account: BIDDER_1, destination }.into(),
+				);
+			});
+		}
+
+		#[test]
+		fn ethereum_destination_is_used_by_migrations_created_later() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_finished_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+			);
+
+			let (destination, signature) =
+				inst.sign_migration_destination_with_ethereum_key(project_id, &BUYER_1, [2u8; 32]);
+			inst.execute(|| {
+				assert_eq!(UserMigrations::<TestRuntime>::get((project_id, BUYER_1)), None);
+				assert_ok!(PolimecFunding::set_migration_destination(
+					RuntimeOrigin::signed(BUYER_1),
+					project_id,
+					destination.clone(),
+					signature,
+				));
+			});
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+			inst.settle_project(project_id, true);
+
+			let (_, migrations) = inst.execute(|| UserMigrations::<TestRuntime>::get((project_id, BUYER_1))).unwrap();
+			assert!(!migrations.is_empty());
+			assert!(migrations.iter().all(|migration| migration.origin.user == destination));
+
+			// Other participants keep receiving their CTs at their own account
+			let (_, migrations) = inst.execute(|| UserMigrations::<TestRuntime>::get((project_id, BUYER_2))).unwrap();
+			let buyer_2_id = <TestRuntime as Config>::AccountId32Conversion::convert(BUYER_2);
+			let buyer_2_location = Location::new(0, AccountId32 { network: None, id: buyer_2_id });
+			assert!(migrations.iter().all(|migration| migration.origin.user == buyer_2_location));
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn signature_of_another_account() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_settled_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+				true,
+			);
+			let (destination, _) = sign_with_substrate_key(project_id, BIDDER_1, [1u8; 32]);
+			let (_, other_signature) = sign_with_substrate_key(project_id, BIDDER_1, [3u8; 32]);
+			// The signature must be made for the caller, so it cannot be replayed by someone else
+			let (_, replayed_signature) = sign_with_substrate_key(project_id, BIDDER_2, [1u8; 32]);
+			let (ethereum_destination, ethereum_signature) =
+				inst.sign_migration_destination_with_ethereum_key(project_id, &BIDDER_1, [2u8; 32]);
+
+			inst.execute(|| {
+				for (destination, signature) in [
+					(destination.clone(), other_signature),
+					(destination.clone(), replayed_signature),
+					(destination, ethereum_signature.clone()),
+				] {
+					assert_noop!(
+						PolimecFunding::set_migration_destination(
+							RuntimeOrigin::signed(BIDDER_1),
+							project_id,
+							destination,
+							signature,
+						),
+						Error::<TestRuntime>::BadMigrationDestinationSignature
+					);
+				}
+				assert_noop!(
+					PolimecFunding::set_migration_destination(
+						RuntimeOrigin::signed(BIDDER_2),
+						project_id,
+						ethereum_destination,
+						ethereum_signature,
+					),
+					Error::<TestRuntime>::BadMigrationDestinationSignature
+				);
+			});
+		}
+
+		#[test]
+		fn destination_is_not_a_local_account() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_settled_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+				true,
+			);
+			let (destination, signature) = sign_with_substrate_key(project_id, BIDDER_1, [1u8; 32]);
+			let remote_destination = destination.clone().pushed_front_with(Parachain(2000)).unwrap();
+			let pallet_destination = Location::new(0, PalletInstance(50));
+
+			inst.execute(|| {
+				for destination in [remote_destination, pallet_destination] {
+					assert_noop!(
+						PolimecFunding::set_migration_destination(
+							RuntimeOrigin::signed(BIDDER_1),
+							project_id,
+							destination,
+							signature.clone(),
+						),
+						Error::<TestRuntime>::BadMigrationDestination
+					);
+				}
+			});
+		}
+
+		#[test]
+		fn migration_already_started() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_settled_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+				true,
+			);
+			let (destination, signature) = sign_with_substrate_key(project_id, BIDDER_1, [1u8; 32]);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::do_start_offchain_migration(project_id, ISSUER_1));
+				assert_ok!(PolimecFunding::do_confirm_offchain_migration(project_id, ISSUER_1, BIDDER_1));
+				assert_noop!(
+					PolimecFunding::set_migration_destination(
+						RuntimeOrigin::signed(BIDDER_1),
+						project_id,
+						destination,
+						signature,
+					),
+					Error::<TestRuntime>::MigrationAlreadyStarted
+				);
+			});
+		}
+	}
+}
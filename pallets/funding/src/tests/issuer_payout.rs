@@ -0,0 +1,316 @@
+use super::*;
+use polimec_common::credentials::InvestorType;
+
+fn issuer_location() -> Location {
+	Location::new(1, [Parachain(1000), AccountId32 { network: None, id: [42u8; 32] }])
+}
+
+fn issuer_jwt(project_metadata: &ProjectMetadataOf<TestRuntime>) -> UntrustedToken {
+	get_mock_jwt_with_cid(
+		ISSUER_1,
+		InvestorType::Institutional,
+		generate_did_from_account(ISSUER_1),
+		project_metadata.clone().policy_ipfs_cid.unwrap(),
+	)
+}
+
+fn create_settled_project_paying_out_to(inst: &mut MockInstantiator, location: Option<Location>) -> ProjectId {
+	let mut project_metadata = default_project_metadata(ISSUER_1);
+	project_metadata.funding_destination_location = location;
+	inst.create_settled_project(
+		project_metadata,
+		ISSUER_1,
+		None,
+		default_evaluations(),
+		default_bids(),
+		default_community_contributions(),
+		default_remainder_contributions(),
+		true,
+	)
+}
+
+#[cfg(test)]
+mod pay_out_issuer_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn settlement_queues_the_payout_for_the_issuer_location() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_paying_out_to(&mut inst, Some(issuer_location()));
+			let usdt_id = AcceptedFundingAsset::USDT.id();
+
+			let pending = inst
+				.execute(|| PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).unwrap());
+			assert!(pending > 0);
+			let issuer_usdt = inst.get_free_funding_asset_balances_for(usdt_id, vec![ISSUER_1])[0].asset_amount;
+			assert_eq!(issuer_usdt, 0);
+			let project_pot = inst.execute(|| PolimecFunding::fund_account_id(project_id));
+			let pot_usdt = inst.get_free_funding_asset_balances_for(usdt_id, vec![project_pot])[0].asset_amount;
+			assert!(pot_usdt >= pending);
+		}
+
+		#[test]
+		fn payout_is_sent_to_the_issuer_location() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_paying_out_to(&mut inst, Some(issuer_location()));
+			let pending = inst
+				.execute(|| PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).unwrap());
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::pay_out_issuer(RuntimeOrigin::signed(BUYER_1), project_id));
+				assert!(PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).is_none());
+				assert!(System::events().iter().any(|record| matches!(
+					&record.event,
+					RuntimeEvent::PolimecFunding(Event::IssuerPayoutSentThroughXcm {
+						project_id: id,
+						destination,
+						asset: AcceptedFundingAsset::USDT,
+						amount,
+						..
+					}) if *id == project_id && *destination == issuer_location() && *amount == pending
+				)));
+			});
+		}
+
+		#[test]
+		fn failed_payout_stays_queued() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_paying_out_to(&mut inst, Some(issuer_location()));
+			let pending = inst
+				.execute(|| PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).unwrap());
+
+			XcmExecutionFails::set(true);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::pay_out_issuer(RuntimeOrigin::signed(BUYER_1), project_id));
+				System::assert_last_event(
+					Event::IssuerPayoutThroughXcmFailed {
+						project_id,
+						destination: issuer_location(),
+						asset: AcceptedFundingAsset::USDT,
+						amount: pending,
+					}
+					.into(),
+				);
+			});
+			let still_pending =
+				inst.execute(|| PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT));
+			assert_eq!(still_pending, Some(pending));
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn project_without_destination_location() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_paying_out_to(&mut inst, None);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::pay_out_issuer(RuntimeOrigin::signed(BUYER_1), project_id),
+					Error::<TestRuntime>::NoPendingIssuerPayout
+				);
+			});
+		}
+
+		#[test]
+		fn destination_location_must_be_an_account() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.funding_destination_location = Some(Location::new(1, [Parachain(1000)]));
+			let jwt = issuer_jwt(&project_metadata);
+			inst.mint_plmc_to(default_plmc_balances());
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::create_project(RuntimeOrigin::signed(ISSUER_1), jwt, project_metadata),
+					Error::<TestRuntime>::FundingDestinationError
+				);
+			});
+		}
+
+		#[test]
+		fn destination_location_must_be_a_reserve_of_the_participation_currencies() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let jwt = issuer_jwt(&project_metadata);
+			let sibling_account = Location::new(1, [Parachain(2000), AccountId32 { network: None, id: [42u8; 32] }]);
+
+			let mut invalid_metadata = project_metadata.clone();
+			invalid_metadata.funding_destination_location = Some(sibling_account);
+			inst.mint_plmc_to(default_plmc_balances());
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::create_project(
+						RuntimeOrigin::signed(ISSUER_1),
+						jwt.clone(),
+						invalid_metadata.clone()
+					),
+					Error::<TestRuntime>::FundingDestinationError
+				);
+			});
+
+			let project_id = inst.create_new_project(project_metadata, ISSUER_1, None);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::edit_project(RuntimeOrigin::signed(ISSUER_1), jwt, project_id, invalid_metadata),
+					Error::<TestRuntime>::FundingDestinationError
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod set_funding_destination_location_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn removing_the_location_pays_the_queued_payouts_to_the_destination_account() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_paying_out_to(&mut inst, Some(issuer_location()));
+			let project_metadata = inst.get_project_metadata(project_id);
+			let usdt_id = AcceptedFundingAsset::USDT.id();
+			let pending = inst
+				.execute(|| PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).unwrap());
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::set_funding_destination_location(
+					RuntimeOrigin::signed(ISSUER_1),
+					issuer_jwt(&project_metadata),
+					project_id,
+					None
+				));
+				System::assert_last_event(Event::FundingDestinationLocationSet { project_id, location: None }.into());
+				assert!(PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).is_none());
+			});
+			let issuer_usdt = inst.get_free_funding_asset_balances_for(usdt_id, vec![ISSUER_1])[0].asset_amount;
+			assert_eq!(issuer_usdt, pending);
+			assert_eq!(inst.get_project_metadata(project_id).funding_destination_location, None);
+		}
+
+		#[test]
+		fn queued_payouts_are_sent_to_the_new_location() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_paying_out_to(&mut inst, Some(issuer_location()));
+			let project_metadata = inst.get_project_metadata(project_id);
+			let new_location = Location::new(1, [Parachain(1000), AccountId32 { network: None, id: [43u8; 32] }]);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::set_funding_destination_location(
+					RuntimeOrigin::signed(ISSUER_1),
+					issuer_jwt(&project_metadata),
+					project_id,
+					Some(new_location.clone())
+				));
+				assert!(PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).is_some());
+				assert_ok!(PolimecFunding::pay_out_issuer(RuntimeOrigin::signed(BUYER_1), project_id));
+				assert!(System::events().iter().any(|record| matches!(
+					&record.event,
+					RuntimeEvent::PolimecFunding(Event::IssuerPayoutSentThroughXcm { destination, .. })
+						if *destination == new_location
+				)));
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn only_the_issuer_can_set_the_location() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_paying_out_to(&mut inst, Some(issuer_location()));
+			let project_metadata = inst.get_project_metadata(project_id);
+			let jwt = get_mock_jwt_with_cid(
+				ISSUER_2,
+				InvestorType::Institutional,
+				generate_did_from_account(ISSUER_2),
+				project_metadata.policy_ipfs_cid.unwrap(),
+			);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::set_funding_destination_location(
+						RuntimeOrigin::signed(ISSUER_2),
+						jwt,
+						project_id,
+						None
+					),
+					Error::<TestRuntime>::NotIssuer
+				);
+			});
+		}
+
+		#[test]
+		fn new_location_must_be_a_reserve_of_the_participation_currencies() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_paying_out_to(&mut inst, Some(issuer_location()));
+			let project_metadata = inst.get_project_metadata(project_id);
+			let sibling_account = Location::new(1, [Parachain(2000), AccountId32 { network: None, id: [42u8; 32] }]);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::set_funding_destination_location(
+						RuntimeOrigin::signed(ISSUER_1),
+						issuer_jwt(&project_metadata),
+						project_id,
+						Some(sibling_account)
+					),
+					Error::<TestRuntime>::FundingDestinationError
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod on_idle {
+	use super::*;
+
+	#[test]
+	fn queued_payouts_are_retried_until_they_are_sent() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = create_settled_project_paying_out_to(&mut inst, Some(issuer_location()));
+		let pending =
+			inst.execute(|| PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).unwrap());
+
+		XcmExecutionFails::set(true);
+		inst.advance_time(2);
+		let still_pending =
+			inst.execute(|| PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT));
+		assert_eq!(still_pending, Some(pending));
+
+		XcmExecutionFails::set(false);
+		inst.advance_time(1);
+		inst.execute(|| {
+			assert!(PendingIssuerPayouts::<TestRuntime>::iter_prefix(project_id).next().is_none());
+			assert!(IssuerPayoutCursor::<TestRuntime>::get().is_none());
+		});
+	}
+
+	#[test]
+	fn payouts_of_paused_projects_are_skipped() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = create_settled_project_paying_out_to(&mut inst, Some(issuer_location()));
+		let pending =
+			inst.execute(|| PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).unwrap());
+
+		inst.execute(|| PausedProjects::<TestRuntime>::insert(project_id, ()));
+		inst.advance_time(1);
+		let still_pending =
+			inst.execute(|| PendingIssuerPayouts::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT));
+		assert_eq!(still_pending, Some(pending));
+	}
+}
@@ -0,0 +1,306 @@
+use super::*;
+
+pub fn milestones_project_metadata(issuer: AccountId) -> ProjectMetadataOf<TestRuntime> {
+	let mut project_metadata = default_project_metadata(issuer);
+	let milestone = |deadline| Milestone { release_percentage: Percent::from_percent(50), deadline };
+	project_metadata.milestones = Some(MilestonePlan {
+		milestones: vec![milestone(10), milestone(20)].try_into().unwrap(),
+		approvers: MilestoneApprovers::Evaluators,
+	});
+	project_metadata
+}
+
+fn create_settled_project_with_milestones(inst: &mut MockInstantiator) -> ProjectId {
+	inst.create_settled_project(
+		milestones_project_metadata(ISSUER_1),
+		ISSUER_1,
+		None,
+		default_evaluations(),
+		default_bids(),
+		default_community_contributions(),
+		default_remainder_contributions(),
+		true,
+	)
+}
+
+fn funding_end_block(inst: &mut MockInstantiator, project_id: ProjectId) -> BlockNumberFor<TestRuntime> {
+	inst.get_project_details(project_id).funding_end_block.unwrap()
+}
+
+#[cfg(test)]
+mod approve_milestone_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn funds_are_released_as_milestones_get_approved() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_with_milestones(&mut inst);
+			let usdt_id = AcceptedFundingAsset::USDT.id();
+
+			// Settlement keeps the funding assets in escrow
+			let escrow = inst.execute(|| Escrows::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).unwrap());
+			assert!(escrow.total > 0);
+			assert_eq!(escrow.released, 0);
+			let issuer_usdt = inst.get_free_funding_asset_balances_for(usdt_id, vec![ISSUER_1])[0].asset_amount;
+			assert_eq!(issuer_usdt, 0);
+
+			// EVALUATOR_1 alone holds less than half of the voting power
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_1), project_id));
+			});
+			let progress = inst.execute(|| MilestonesProgress::<TestRuntime>::get(project_id).unwrap());
+			assert_eq!(progress.current, 0);
+			assert_eq!(progress.approvals, 500_000 * USD_UNIT);
+			assert_eq!(progress.total_power, 1_070_000 * USD_UNIT);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_3), project_id));
+			});
+			inst.execute(|| {
+				find_event!(
+					TestRuntime,
+					Event::<TestRuntime>::MilestoneReleased { project_id: id, milestone },
+					id == project_id,
+					milestone == 0
+				)
+				.unwrap();
+			});
+			let issuer_usdt = inst.get_free_funding_asset_balances_for(usdt_id, vec![ISSUER_1])[0].asset_amount;
+			assert_eq!(issuer_usdt, escrow.total / 2);
+			let progress = inst.execute(|| MilestonesProgress::<TestRuntime>::get(project_id).unwrap());
+			assert_eq!(progress.current, 1);
+			assert_eq!(progress.approvals, 0);
+
+			// The last milestone releases whatever is left
+			inst.jump_to_block(funding_end_block(&mut inst, project_id) + 15);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_1), project_id));
+				assert_ok!(PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_2), project_id));
+			});
+			let issuer_usdt = inst.get_free_funding_asset_balances_for(usdt_id, vec![ISSUER_1])[0].asset_amount;
+			assert_eq!(issuer_usdt, escrow.total);
+			let escrow = inst.execute(|| Escrows::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).unwrap());
+			assert_eq!(escrow.released, escrow.total);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_3), project_id),
+					Error::<TestRuntime>::MilestonesCompleted
+				);
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_approve_twice() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_with_milestones(&mut inst);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_1), project_id));
+				assert_noop!(
+					PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_1), project_id),
+					Error::<TestRuntime>::MilestoneAlreadyApproved
+				);
+			});
+		}
+
+		#[test]
+		fn only_approvers_can_approve() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_with_milestones(&mut inst);
+
+			// Buyers hold CTs, but this project's milestones are approved by the evaluators
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::approve_milestone(RuntimeOrigin::signed(BUYER_1), project_id),
+					Error::<TestRuntime>::NoMilestoneVotingPower
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_approve_after_the_deadline() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_with_milestones(&mut inst);
+
+			inst.jump_to_block(funding_end_block(&mut inst, project_id) + 11);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_1), project_id),
+					Error::<TestRuntime>::MilestoneDeadlinePassed
+				);
+			});
+		}
+
+		#[test]
+		fn project_without_milestones() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_settled_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+				true,
+			);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_1), project_id),
+					Error::<TestRuntime>::NoMilestones
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod fail_milestone_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn participants_get_back_what_is_left_in_escrow() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_with_milestones(&mut inst);
+			let usdt_id = AcceptedFundingAsset::USDT.id();
+
+			// The first milestone is approved, the second one misses its deadline
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_1), project_id));
+				assert_ok!(PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_3), project_id));
+			});
+			inst.jump_to_block(funding_end_block(&mut inst, project_id) + 21);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::fail_milestone(RuntimeOrigin::signed(BUYER_1), project_id));
+			});
+
+			let escrowed = inst.execute(|| {
+				EscrowedParticipations::<TestRuntime>::get((project_id, BUYER_1, AcceptedFundingAsset::USDT)).unwrap()
+			});
+			let balance_before = inst.get_free_funding_asset_balances_for(usdt_id, vec![BUYER_1])[0].asset_amount;
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::claim_escrow_refund(RuntimeOrigin::signed(BUYER_2), project_id, BUYER_1));
+			});
+			let balance_after = inst.get_free_funding_asset_balances_for(usdt_id, vec![BUYER_1])[0].asset_amount;
+			assert_close_enough!(balance_after - balance_before, escrowed / 2, Perquintill::from_float(0.9999));
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::claim_escrow_refund(RuntimeOrigin::signed(BUYER_1), project_id, BUYER_1),
+					Error::<TestRuntime>::NoEscrowedFunds
+				);
+				assert_noop!(
+					PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_1), project_id),
+					Error::<TestRuntime>::MilestonesFailed
+				);
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod fee_in_funding_assets {
+		use super::*;
+
+		#[test]
+		fn fee_is_taken_on_release_and_refunded_with_the_escrow() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let treasury = <TestRuntime as Config>::BlockchainOperationTreasury::get();
+			let usdt_id = AcceptedFundingAsset::USDT.id();
+			let mut project_metadata = milestones_project_metadata(ISSUER_1);
+			project_metadata.fee_mode = FeeMode::FundingAssets(Perquintill::one());
+			let project_id = inst.create_finished_project(
+				project_metadata,
+				ISSUER_1,
+				None,
+				default_evaluations(),
+				default_bids(),
+				default_community_contributions(),
+				default_remainder_contributions(),
+			);
+			let treasury_usdt_before =
+				inst.get_free_funding_asset_balances_for(usdt_id, vec![treasury])[0].asset_amount;
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+			let contribution = inst
+				.get_contributions(project_id)
+				.into_iter()
+				.find(|contribution| {
+					contribution.contributor == BUYER_1 && contribution.funding_asset == AcceptedFundingAsset::USDT
+				})
+				.unwrap();
+			inst.settle_project(project_id, true);
+
+			// Settlement escrows the participations in full
+			let treasury_usdt = inst.get_free_funding_asset_balances_for(usdt_id, vec![treasury])[0].asset_amount;
+			assert_eq!(treasury_usdt, treasury_usdt_before);
+			let escrowed = inst.execute(|| {
+				EscrowedParticipations::<TestRuntime>::get((project_id, BUYER_1, AcceptedFundingAsset::USDT)).unwrap()
+			});
+			assert!(escrowed >= contribution.funding_asset_amount);
+
+			// The first milestone releases half of the escrow, minus the fee
+			let escrow = inst.execute(|| Escrows::<TestRuntime>::get(project_id, AcceptedFundingAsset::USDT).unwrap());
+			let fee_percentage = inst.execute(|| PolimecFunding::funding_asset_fee_percentage(project_id).unwrap());
+			assert!(!fee_percentage.is_zero());
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_1), project_id));
+				assert_ok!(PolimecFunding::approve_milestone(RuntimeOrigin::signed(EVALUATOR_3), project_id));
+			});
+			let released = escrow.total / 2;
+			let fee = fee_percentage * released;
+			let treasury_usdt = inst.get_free_funding_asset_balances_for(usdt_id, vec![treasury])[0].asset_amount;
+			let issuer_usdt = inst.get_free_funding_asset_balances_for(usdt_id, vec![ISSUER_1])[0].asset_amount;
+			assert_eq!(treasury_usdt, treasury_usdt_before + fee);
+			assert_eq!(issuer_usdt, released - fee);
+
+			// The fee on the unreleased half goes back to the participants
+			inst.jump_to_block(funding_end_block(&mut inst, project_id) + 21);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::fail_milestone(RuntimeOrigin::signed(BUYER_1), project_id));
+			});
+			let balance_before = inst.get_free_funding_asset_balances_for(usdt_id, vec![BUYER_1])[0].asset_amount;
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::claim_escrow_refund(RuntimeOrigin::signed(BUYER_1), project_id, BUYER_1));
+			});
+			let balance_after = inst.get_free_funding_asset_balances_for(usdt_id, vec![BUYER_1])[0].asset_amount;
+			assert_close_enough!(balance_after - balance_before, escrowed / 2, Perquintill::from_float(0.9999));
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_fail_before_the_deadline() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_with_milestones(&mut inst);
+
+			inst.jump_to_block(funding_end_block(&mut inst, project_id) + 10);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::fail_milestone(RuntimeOrigin::signed(BUYER_1), project_id),
+					Error::<TestRuntime>::MilestoneDeadlineNotPassed
+				);
+				assert_noop!(
+					PolimecFunding::claim_escrow_refund(RuntimeOrigin::signed(BUYER_1), project_id, BUYER_1),
+					Error::<TestRuntime>::MilestonesNotFailed
+				);
+			});
+		}
+	}
+}
@@ -188,7 +188,16 @@ mod helper_functions {
 			},
 			participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 			funding_destination_account: ISSUER_1,
+			funding_destination_location: None,
 			policy_ipfs_cid: Some(ipfs_hash()),
+			round_durations: Default::default(),
+			ct_release_policy: None,
+			soft_cap: None,
+			private_round: None,
+			fee_mode: FeeMode::ContributionTokens,
+			milestones: None,
+			pricing_model: PricingModel::Buckets,
+			jurisdiction_restriction: None,
 		};
 
 		let project_id = inst.create_community_contributing_project(
@@ -240,6 +249,35 @@ mod helper_functions {
 		assert!(diff <= FixedU128::from_float(0.001));
 	}
 
+	#[test]
+	fn bucket_free_keeps_the_current_price() {
+		let initial_price = FixedU128::from_float(10.0);
+		let new_bucket = || Bucket::new(100u128, initial_price, FixedU128::from_float(1.0), 10u128);
+
+		// Simulate total bidding amount of 128, then free 25 of it
+		let mut bucket = new_bucket();
+		bucket.update(100u128);
+		bucket.update(10u128);
+		bucket.update(10u128);
+		bucket.update(8u128);
+		bucket.free(25u128);
+		assert_eq!(bucket.current_price, FixedU128::from_float(13.0));
+		assert_eq!(bucket.amount_left, 27u128);
+
+		// The wap only counts the amount still bid, as if the total bid had been 103
+		let mut expected_bucket = new_bucket();
+		expected_bucket.update(100u128);
+		expected_bucket.update(3u128);
+		assert_eq!(bucket.calculate_wap(100u128), expected_bucket.calculate_wap(100u128));
+
+		// The freed amount is sold at the current price before moving to the next bucket
+		bucket.update(20u128);
+		assert_eq!(bucket.current_price, FixedU128::from_float(13.0));
+		bucket.update(7u128);
+		assert_eq!(bucket.current_price, FixedU128::from_float(14.0));
+		assert_eq!(bucket.amount_left, 10u128);
+	}
+
 	#[test]
 	fn calculate_contributed_plmc_spent() {
 		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -409,3 +447,731 @@ fn project_state_transition_event() {
 		assert_eq!(event, Event::ProjectPhaseTransition { project_id, phase: desired_transitions.next().unwrap() });
 	});
 }
+
+mod funding_asset_registry {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	fn new_asset_info(location: Location) -> FundingAssetInfo {
+		FundingAssetInfo { location, decimals: 12, oracle_key: 4242, enabled: true }
+	}
+
+	#[test]
+	fn admin_can_register_and_update_funding_assets() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let asset = AcceptedFundingAsset(4242);
+		let old_location = Location::new(1, [Parachain(4242)]);
+		let new_location = Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(4242)]);
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::register_funding_asset(
+				RuntimeOrigin::root(),
+				asset,
+				new_asset_info(old_location.clone())
+			));
+			assert_eq!(FundingAssets::<TestRuntime>::get(asset), Some(new_asset_info(old_location.clone())));
+			assert_eq!(FundingAssetLocations::<TestRuntime>::get(&old_location), Some(asset));
+			System::assert_last_event(
+				Event::FundingAssetRegistered { asset, info: new_asset_info(old_location.clone()) }.into(),
+			);
+
+			// Registering it again replaces the entry, and frees the old location.
+			assert_ok!(PolimecFunding::register_funding_asset(
+				RuntimeOrigin::root(),
+				asset,
+				new_asset_info(new_location.clone())
+			));
+			assert_eq!(FundingAssets::<TestRuntime>::get(asset), Some(new_asset_info(new_location.clone())));
+			assert_eq!(FundingAssetLocations::<TestRuntime>::get(&new_location), Some(asset));
+			assert_eq!(FundingAssetLocations::<TestRuntime>::get(&old_location), None);
+
+			assert_ok!(PolimecFunding::set_funding_asset_enabled(RuntimeOrigin::root(), asset, false));
+			assert!(!FundingAssets::<TestRuntime>::get(asset).unwrap().enabled);
+			System::assert_last_event(Event::FundingAssetStatusChanged { asset, enabled: false }.into());
+			// Disabled assets are still matched by their location.
+			assert_eq!(FundingAssetLocations::<TestRuntime>::get(&new_location), Some(asset));
+		});
+	}
+
+	#[test]
+	fn registry_uses_asset_decimals_and_oracle_key() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		inst.execute(|| {
+			let usdt_amount =
+				PolimecFunding::calculate_funding_asset_amount(100 * USD_UNIT, AcceptedFundingAsset::USDT);
+
+			// Same oracle price, but the asset now has 2 more decimals.
+			let mut info = FundingAssets::<TestRuntime>::get(AcceptedFundingAsset::USDT).unwrap();
+			let asset = AcceptedFundingAsset(4242);
+			info.location = Location::new(1, [Parachain(4242)]);
+			info.decimals += 2;
+			assert_ok!(PolimecFunding::register_funding_asset(RuntimeOrigin::root(), asset, info));
+
+			assert_eq!(
+				PolimecFunding::calculate_funding_asset_amount(100 * USD_UNIT, asset),
+				usdt_amount.map(|amount| amount * 100)
+			);
+			assert_err!(
+				PolimecFunding::calculate_funding_asset_amount(100 * USD_UNIT, AcceptedFundingAsset(4343)),
+				Error::<TestRuntime>::FundingAssetNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn only_admin_can_manage_registry() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::register_funding_asset(
+					RuntimeOrigin::signed(ISSUER_1),
+					AcceptedFundingAsset(4242),
+					new_asset_info(Location::new(1, [Parachain(4242)]))
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				PolimecFunding::set_funding_asset_enabled(
+					RuntimeOrigin::signed(ISSUER_1),
+					AcceptedFundingAsset::USDT,
+					false
+				),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_reuse_location_or_update_unknown_asset() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		inst.execute(|| {
+			let usdc_location = FundingAssets::<TestRuntime>::get(AcceptedFundingAsset::USDC).unwrap().location;
+			assert_noop!(
+				PolimecFunding::register_funding_asset(
+					RuntimeOrigin::root(),
+					AcceptedFundingAsset(4242),
+					new_asset_info(usdc_location)
+				),
+				Error::<TestRuntime>::FundingAssetLocationTaken
+			);
+			assert_noop!(
+				PolimecFunding::set_funding_asset_enabled(RuntimeOrigin::root(), AcceptedFundingAsset(4242), true),
+				Error::<TestRuntime>::FundingAssetNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn disabled_funding_asset_cannot_be_used_for_new_bids() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let mut project_metadata = default_project_metadata(ISSUER_1);
+		project_metadata.participation_currencies =
+			vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset::DOT].try_into().unwrap();
+		let project_id =
+			inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+
+		let dot_bid = BidParams::new(BIDDER_1, 10_000 * CT_UNIT, 1u8, AcceptedFundingAsset::DOT);
+		let plmc_fundings = inst.calculate_auction_plmc_charged_with_given_price(
+			&vec![dot_bid.clone()],
+			project_metadata.minimum_price,
+			true,
+		);
+		let dot_fundings = inst.calculate_auction_funding_asset_charged_with_given_price(
+			&vec![dot_bid.clone()],
+			project_metadata.minimum_price,
+		);
+		inst.mint_plmc_to(plmc_fundings);
+		inst.mint_funding_asset_to(dot_fundings);
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::set_funding_asset_enabled(
+				RuntimeOrigin::root(),
+				AcceptedFundingAsset::DOT,
+				false
+			));
+		});
+		assert_err!(
+			inst.bid_for_users(project_id, vec![dot_bid.clone()]),
+			Error::<TestRuntime>::FundingAssetNotAccepted
+		);
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::set_funding_asset_enabled(
+				RuntimeOrigin::root(),
+				AcceptedFundingAsset::DOT,
+				true
+			));
+		});
+		assert_ok!(inst.bid_for_users(project_id, vec![dot_bid]));
+	}
+}
+
+mod round_duration_bounds {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	fn bounds(min: u64, max: u64) -> RoundDurationBoundsOf<TestRuntime> {
+		let bounds = DurationBounds::new(min, max);
+		RoundDurationBounds { evaluation: bounds, auction: bounds, community: bounds, remainder: bounds }
+	}
+
+	#[test]
+	fn defaults_only_allow_runtime_durations() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		inst.execute(|| {
+			let limits = RoundDurationLimits::<TestRuntime>::get();
+			assert_eq!(
+				limits.evaluation,
+				DurationBounds::new(EvaluationRoundDuration::get(), EvaluationRoundDuration::get())
+			);
+			assert_eq!(
+				limits.remainder,
+				DurationBounds::new(RemainderRoundDuration::get(), RemainderRoundDuration::get())
+			);
+		});
+	}
+
+	#[test]
+	fn admin_can_set_bounds() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::set_round_duration_bounds(RuntimeOrigin::root(), bounds(1, 100)));
+			assert_eq!(RoundDurationLimits::<TestRuntime>::get(), bounds(1, 100));
+			System::assert_last_event(Event::RoundDurationBoundsSet { bounds: bounds(1, 100) }.into());
+		});
+	}
+
+	#[test]
+	fn invalid_bounds_are_rejected() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::set_round_duration_bounds(RuntimeOrigin::signed(ISSUER_1), bounds(1, 100)),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				PolimecFunding::set_round_duration_bounds(RuntimeOrigin::root(), bounds(0, 100)),
+				Error::<TestRuntime>::InvalidRoundDurationBounds
+			);
+			assert_noop!(
+				PolimecFunding::set_round_duration_bounds(RuntimeOrigin::root(), bounds(20, 10)),
+				Error::<TestRuntime>::InvalidRoundDurationBounds
+			);
+		});
+	}
+}
+
+mod soft_cap_bounds {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	#[test]
+	fn defaults_only_allow_runtime_threshold() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		inst.execute(|| {
+			let threshold = <TestRuntime as Config>::FundingSuccessThreshold::get();
+			assert_eq!(SoftCapLimits::<TestRuntime>::get(), SoftCapBounds::new(threshold, threshold));
+		});
+	}
+
+	#[test]
+	fn admin_can_set_bounds() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let bounds = SoftCapBounds::new(Perquintill::from_percent(10), Perquintill::from_percent(90));
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::set_soft_cap_bounds(RuntimeOrigin::root(), bounds));
+			assert_eq!(SoftCapLimits::<TestRuntime>::get(), bounds);
+			System::assert_last_event(Event::SoftCapBoundsSet { bounds }.into());
+		});
+	}
+
+	#[test]
+	fn invalid_bounds_are_rejected() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::set_soft_cap_bounds(
+					RuntimeOrigin::signed(ISSUER_1),
+					SoftCapBounds::new(Perquintill::from_percent(10), Perquintill::from_percent(90))
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				PolimecFunding::set_soft_cap_bounds(
+					RuntimeOrigin::root(),
+					SoftCapBounds::new(Perquintill::zero(), Perquintill::from_percent(90))
+				),
+				Error::<TestRuntime>::InvalidSoftCapBounds
+			);
+			assert_noop!(
+				PolimecFunding::set_soft_cap_bounds(
+					RuntimeOrigin::root(),
+					SoftCapBounds::new(Perquintill::from_percent(60), Perquintill::from_percent(40))
+				),
+				Error::<TestRuntime>::InvalidSoftCapBounds
+			);
+		});
+	}
+}
+
+mod fee_parameters {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	fn custom_fee_parameters() -> FeeParameters {
+		FeeParameters {
+			fee_brackets: BoundedVec::truncate_from(vec![(Percent::from_percent(5), u128::MAX)]),
+			evaluator_share: Perquintill::from_percent(40),
+			early_evaluator_share: Perquintill::from_percent(50),
+			liquidity_pools_share: Perquintill::from_percent(40),
+			long_term_holder_share: Perquintill::from_percent(20),
+		}
+	}
+
+	#[test]
+	fn defaults_use_runtime_fee_brackets() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		inst.execute(|| {
+			let parameters = ProtocolFeeParameters::<TestRuntime>::get();
+			assert_eq!(parameters.fee_brackets.into_inner(), <TestRuntime as Config>::FeeBrackets::get());
+			assert_eq!(parameters.evaluator_share, Perquintill::from_percent(30));
+			assert_eq!(parameters.early_evaluator_share, Perquintill::from_percent(20));
+			assert_eq!(parameters.liquidity_pools_share, Perquintill::from_percent(50));
+			assert_eq!(parameters.long_term_holder_share, Perquintill::from_percent(20));
+		});
+	}
+
+	#[test]
+	fn admin_can_set_parameters() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let parameters = custom_fee_parameters();
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::set_fee_parameters(RuntimeOrigin::root(), parameters.clone()));
+			assert_eq!(ProtocolFeeParameters::<TestRuntime>::get(), parameters);
+			System::assert_last_event(Event::FeeParametersSet { parameters }.into());
+		});
+	}
+
+	#[test]
+	fn invalid_parameters_are_rejected() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::set_fee_parameters(RuntimeOrigin::signed(ISSUER_1), custom_fee_parameters()),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				PolimecFunding::set_fee_parameters(
+					RuntimeOrigin::root(),
+					FeeParameters { fee_brackets: BoundedVec::new(), ..custom_fee_parameters() }
+				),
+				Error::<TestRuntime>::InvalidFeeParameters
+			);
+			assert_noop!(
+				PolimecFunding::set_fee_parameters(
+					RuntimeOrigin::root(),
+					FeeParameters { long_term_holder_share: Perquintill::from_percent(21), ..custom_fee_parameters() }
+				),
+				Error::<TestRuntime>::InvalidFeeParameters
+			);
+		});
+	}
+
+	#[test]
+	fn projects_keep_the_parameters_of_their_evaluation_start() {
+		let (mut inst, finished_project_id) = create_project_with_funding_percentage(100, false);
+		let old_parameters = inst.execute(|| ProtocolFeeParameters::<TestRuntime>::get());
+		let new_parameters = custom_fee_parameters();
+
+		let (old_evaluator_rewards, old_pots) = inst.execute(|| {
+			assert_eq!(ProjectsFeeParameters::<TestRuntime>::get(finished_project_id), Some(old_parameters.clone()));
+			(
+				PolimecFunding::generate_evaluator_rewards_info(finished_project_id).unwrap(),
+				PolimecFunding::generate_liquidity_pools_and_long_term_holder_rewards(finished_project_id).unwrap(),
+			)
+		});
+
+		inst.execute(|| assert_ok!(PolimecFunding::set_fee_parameters(RuntimeOrigin::root(), new_parameters.clone())));
+
+		inst.execute(|| {
+			assert_eq!(
+				PolimecFunding::generate_evaluator_rewards_info(finished_project_id).unwrap(),
+				old_evaluator_rewards
+			);
+			assert_eq!(
+				PolimecFunding::generate_liquidity_pools_and_long_term_holder_rewards(finished_project_id).unwrap(),
+				old_pots
+			);
+		});
+
+		let new_project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_2), ISSUER_2, None);
+		inst.execute(|| {
+			assert_eq!(ProjectsFeeParameters::<TestRuntime>::get(new_project_id), Some(new_parameters));
+			assert_eq!(ProjectsFeeParameters::<TestRuntime>::get(finished_project_id), Some(old_parameters));
+		});
+	}
+}
+
+mod emergency_controls {
+	use super::*;
+	use sp_runtime::DispatchError;
+	use std::collections::BTreeMap;
+
+	fn evaluating_project_with_evaluations(inst: &mut MockInstantiator, issuer: AccountId) -> ProjectId {
+		let project_id = inst.create_evaluating_project(default_project_metadata(issuer), issuer, None);
+		let evaluations = default_evaluations();
+		let plmc = inst.calculate_evaluation_plmc_spent(evaluations.clone(), true);
+		inst.mint_plmc_to(plmc);
+		inst.evaluate_for_users(project_id, evaluations).unwrap();
+		project_id
+	}
+
+	fn auctioning_project_with_bids(inst: &mut MockInstantiator, issuer: AccountId) -> ProjectId {
+		let project_metadata = default_project_metadata(issuer);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, None, default_evaluations());
+		let bids = default_bids();
+		let plmc = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+			true,
+		);
+		let usdt = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata,
+			None,
+		);
+		inst.mint_plmc_to(plmc);
+		inst.mint_funding_asset_to(usdt);
+		inst.bid_for_users(project_id, bids).unwrap();
+		project_id
+	}
+
+	/// Cancels the project, settles it, and checks that every participant got back all the PLMC and funding assets
+	/// they had locked in it.
+	fn cancel_and_assert_full_refunds(inst: &mut MockInstantiator, project_id: ProjectId) {
+		let evaluations = inst.get_evaluations(project_id);
+		let bids = inst.get_bids(project_id);
+		let contributions = inst.get_contributions(project_id);
+		let issuer_did = inst.get_project_details(project_id).issuer_did;
+
+		let mut expected_plmc = BTreeMap::<AccountId, Balance>::new();
+		let mut expected_funding_assets = BTreeMap::<(AccountId, AcceptedFundingAsset), Balance>::new();
+		for evaluation in evaluations.iter() {
+			*expected_plmc.entry(evaluation.evaluator).or_default() += evaluation.current_plmc_bond;
+		}
+		for bid in bids.iter() {
+			*expected_plmc.entry(bid.bidder).or_default() += bid.plmc_bond;
+			*expected_funding_assets.entry((bid.bidder, bid.funding_asset)).or_default() +=
+				bid.funding_asset_amount_locked;
+		}
+		for contribution in contributions.iter() {
+			*expected_plmc.entry(contribution.contributor).or_default() += contribution.plmc_bond;
+			*expected_funding_assets.entry((contribution.contributor, contribution.funding_asset)).or_default() +=
+				contribution.funding_asset_amount;
+		}
+		for (account, amount) in expected_plmc.iter_mut() {
+			*amount += inst.get_free_plmc_balance_for(*account);
+		}
+		for ((account, asset), amount) in expected_funding_assets.iter_mut() {
+			*amount += inst.get_free_funding_asset_balance_for(asset.id(), *account);
+		}
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::cancel_project(RuntimeOrigin::root(), project_id));
+			System::assert_last_event(Event::ProjectCancelled { project_id }.into());
+			assert!(DidWithActiveProjects::<TestRuntime>::get(issuer_did).is_empty());
+		});
+		assert_eq!(
+			inst.get_project_details(project_id).status,
+			ProjectStatus::SettlementStarted(FundingOutcome::Failure)
+		);
+
+		inst.settle_project(project_id, true);
+		assert_eq!(
+			inst.get_project_details(project_id).status,
+			ProjectStatus::SettlementFinished(FundingOutcome::Failure)
+		);
+
+		for (account, amount) in expected_plmc {
+			assert_eq!(inst.get_free_plmc_balance_for(account), amount);
+			assert_eq!(inst.get_reserved_plmc_balance_for(account, HoldReason::Evaluation.into()), 0);
+			assert_eq!(inst.get_reserved_plmc_balance_for(account, HoldReason::Participation.into()), 0);
+		}
+		for ((account, asset), amount) in expected_funding_assets {
+			assert_eq!(inst.get_free_funding_asset_balance_for(asset.id(), account), amount);
+		}
+		inst.test_ct_not_created_for(project_id);
+	}
+
+	#[test]
+	fn pause_rejects_participations_until_resumed() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+		let evaluations = default_evaluations();
+		let plmc = inst.calculate_evaluation_plmc_spent(evaluations.clone(), true);
+		inst.mint_plmc_to(plmc);
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::pause_project(RuntimeOrigin::root(), project_id));
+			System::assert_last_event(Event::ProjectPaused { project_id }.into());
+		});
+		assert_err!(inst.evaluate_for_users(project_id, evaluations.clone()), Error::<TestRuntime>::ProjectPaused);
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::resume_project(RuntimeOrigin::root(), project_id));
+			System::assert_last_event(Event::ProjectResumed { project_id }.into());
+		});
+		assert_ok!(inst.evaluate_for_users(project_id, evaluations));
+
+		let project_id = auctioning_project_with_bids(&mut inst, ISSUER_2);
+		let bid = inst.get_bids(project_id)[0].clone();
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::pause_project(RuntimeOrigin::root(), project_id));
+			assert_noop!(
+				PolimecFunding::do_cancel_bid(&bid.bidder, project_id, bid.id),
+				Error::<TestRuntime>::ProjectPaused
+			);
+		});
+		assert_err!(inst.bid_for_users(project_id, default_bids()), Error::<TestRuntime>::ProjectPaused);
+		inst.execute(|| assert_ok!(PolimecFunding::resume_project(RuntimeOrigin::root(), project_id)));
+
+		assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound(..)));
+		inst.execute(|| assert_ok!(PolimecFunding::pause_project(RuntimeOrigin::root(), project_id)));
+		assert_err!(
+			inst.contribute_for_users(project_id, default_community_contributions()),
+			Error::<TestRuntime>::ProjectPaused
+		);
+	}
+
+	#[test]
+	fn pause_blocks_round_transitions_and_settlement() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = auctioning_project_with_bids(&mut inst, ISSUER_1);
+		let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+		inst.jump_to_block(auction_end + 1);
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::pause_project(RuntimeOrigin::root(), project_id));
+			assert_noop!(PolimecFunding::do_end_auction(project_id), Error::<TestRuntime>::ProjectPaused);
+			assert_ok!(PolimecFunding::resume_project(RuntimeOrigin::root(), project_id));
+			assert_ok!(PolimecFunding::do_end_auction(project_id));
+			assert_ok!(PolimecFunding::pause_project(RuntimeOrigin::root(), project_id));
+			assert_noop!(PolimecFunding::do_resolve_auction(project_id, u32::MAX), Error::<TestRuntime>::ProjectPaused);
+		});
+
+		// The auction is not resolved on idle until the project is resumed
+		inst.advance_time(1);
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionResolving);
+		inst.execute(|| assert_ok!(PolimecFunding::resume_project(RuntimeOrigin::root(), project_id)));
+		inst.advance_time(1);
+		assert!(matches!(inst.get_project_details(project_id).status, ProjectStatus::CommunityRound(..)));
+
+		let community_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+		inst.jump_to_block(community_end + 1);
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::pause_project(RuntimeOrigin::root(), project_id));
+			assert_noop!(PolimecFunding::do_end_funding(project_id), Error::<TestRuntime>::ProjectPaused);
+			assert_ok!(PolimecFunding::resume_project(RuntimeOrigin::root(), project_id));
+		});
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::pause_project(RuntimeOrigin::root(), project_id));
+			assert_noop!(PolimecFunding::do_start_settlement(project_id), Error::<TestRuntime>::ProjectPaused);
+			assert_ok!(PolimecFunding::resume_project(RuntimeOrigin::root(), project_id));
+		});
+		assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(..)));
+
+		// Nothing is paid out on idle or with the extrinsics until the project is resumed
+		let evaluation = inst.get_evaluations(project_id)[0].clone();
+		inst.execute(|| assert_ok!(PolimecFunding::pause_project(RuntimeOrigin::root(), project_id)));
+		inst.advance_time(1);
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::do_settle_evaluation(evaluation, project_id),
+				Error::<TestRuntime>::ProjectPaused
+			);
+			assert_eq!(SettlementCursors::<TestRuntime>::get(project_id), Some(SettlementCursor::default()));
+			assert_ok!(PolimecFunding::resume_project(RuntimeOrigin::root(), project_id));
+		});
+		inst.advance_time(1);
+		assert!(matches!(inst.get_project_details(project_id).status, ProjectStatus::SettlementFinished(..)));
+	}
+
+	#[test]
+	fn cancel_in_application_round() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = inst.create_new_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+		cancel_and_assert_full_refunds(&mut inst, project_id);
+	}
+
+	#[test]
+	fn cancel_in_evaluation_round() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = evaluating_project_with_evaluations(&mut inst, ISSUER_1);
+		// A paused project can be cancelled
+		inst.execute(|| assert_ok!(PolimecFunding::pause_project(RuntimeOrigin::root(), project_id)));
+		cancel_and_assert_full_refunds(&mut inst, project_id);
+		inst.execute(|| assert!(!PausedProjects::<TestRuntime>::contains_key(project_id)));
+	}
+
+	#[test]
+	fn cancel_in_private_round() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = crate::tests::private_round::private_round_project_metadata(ISSUER_1);
+		let project_id =
+			inst.create_private_round_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+		inst.allow_list_for_private_round(project_id, vec![BUYER_1]);
+
+		let contribution = ContributionParams::new(BUYER_1, 10_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+		let price = project_metadata.private_round.unwrap().price;
+		let plmc = inst.calculate_contributed_plmc_spent(vec![contribution.clone()], price, true);
+		let usdt = inst.calculate_contributed_funding_asset_spent(vec![contribution.clone()], price);
+		inst.mint_plmc_to(plmc);
+		inst.mint_funding_asset_to(usdt);
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::private_contribute(
+				RuntimeOrigin::signed(BUYER_1),
+				get_mock_jwt_with_cid(
+					BUYER_1,
+					InvestorType::Retail,
+					generate_did_from_account(BUYER_1),
+					project_metadata.clone().policy_ipfs_cid.unwrap()
+				),
+				project_id,
+				contribution.amount,
+				contribution.multiplier,
+				contribution.asset
+			));
+		});
+		let stored_contribution =
+			inst.execute(|| Contributions::<TestRuntime>::iter_prefix_values((project_id, BUYER_1)).next().unwrap());
+		assert!(stored_contribution.private_round);
+
+		// The private contribution's PLMC bond and USDT are refunded like any other participation
+		cancel_and_assert_full_refunds(&mut inst, project_id);
+	}
+
+	#[test]
+	fn cancel_in_auction_round() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = auctioning_project_with_bids(&mut inst, ISSUER_1);
+		cancel_and_assert_full_refunds(&mut inst, project_id);
+	}
+
+	#[test]
+	fn cancel_in_auction_resolving() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = auctioning_project_with_bids(&mut inst, ISSUER_1);
+		let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+		inst.jump_to_block(auction_end + 1);
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::end_auction(RuntimeOrigin::signed(420), project_id));
+			// Resolve part of the bids only
+			assert_ok!(PolimecFunding::resolve_auction(RuntimeOrigin::signed(420), project_id, 2));
+		});
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionResolving);
+
+		cancel_and_assert_full_refunds(&mut inst, project_id);
+		inst.execute(|| {
+			assert!(AuctionResolutions::<TestRuntime>::get(project_id).is_none());
+			assert_eq!(BidsByBucket::<TestRuntime>::iter_prefix((project_id,)).count(), 0);
+			assert_eq!(BucketBidCounts::<TestRuntime>::iter_prefix(project_id).count(), 0);
+		});
+	}
+
+	#[test]
+	fn cancel_in_community_round() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = inst.create_remainder_contributing_project(
+			default_project_metadata(ISSUER_1),
+			ISSUER_1,
+			None,
+			default_evaluations(),
+			default_bids(),
+			default_community_contributions(),
+		);
+		cancel_and_assert_full_refunds(&mut inst, project_id);
+	}
+
+	#[test]
+	fn cancel_after_funding_succeeded() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = inst.create_finished_project(
+			default_project_metadata(ISSUER_1),
+			ISSUER_1,
+			None,
+			default_evaluations(),
+			default_bids(),
+			default_community_contributions(),
+			default_remainder_contributions(),
+		);
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingSuccessful);
+		cancel_and_assert_full_refunds(&mut inst, project_id);
+	}
+
+	#[test]
+	fn cancel_after_funding_failed() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+		let evaluations = default_failing_evaluations();
+		let plmc = inst.calculate_evaluation_plmc_spent(evaluations.clone(), true);
+		inst.mint_plmc_to(plmc);
+		inst.evaluate_for_users(project_id, evaluations).unwrap();
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingFailed);
+
+		// The evaluators are not slashed, even though the evaluation round failed
+		cancel_and_assert_full_refunds(&mut inst, project_id);
+	}
+
+	#[test]
+	fn cannot_cancel_once_settlement_started() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = inst.create_finished_project(
+			default_project_metadata(ISSUER_1),
+			ISSUER_1,
+			None,
+			default_evaluations(),
+			default_bids(),
+			default_community_contributions(),
+			default_remainder_contributions(),
+		);
+		assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(..)));
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::cancel_project(RuntimeOrigin::root(), project_id),
+				Error::<TestRuntime>::ProjectNotCancellable
+			);
+		});
+	}
+
+	#[test]
+	fn only_admin_can_pause_resume_or_cancel() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::pause_project(RuntimeOrigin::signed(ISSUER_1), project_id),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				PolimecFunding::cancel_project(RuntimeOrigin::signed(ISSUER_1), project_id),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				PolimecFunding::resume_project(RuntimeOrigin::root(), project_id),
+				Error::<TestRuntime>::ProjectNotPaused
+			);
+			assert_ok!(PolimecFunding::pause_project(RuntimeOrigin::root(), project_id));
+			assert_noop!(
+				PolimecFunding::resume_project(RuntimeOrigin::signed(ISSUER_1), project_id),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				PolimecFunding::pause_project(RuntimeOrigin::root(), 42),
+				Error::<TestRuntime>::ProjectDetailsNotFound
+			);
+		});
+	}
+}
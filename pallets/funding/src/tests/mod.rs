@@ -15,8 +15,10 @@ use frame_support::{
 };
 use itertools::Itertools;
 use parachains_common::DAYS;
-use polimec_common::{ReleaseSchedule, USD_DECIMALS, USD_UNIT};
-use polimec_common_test_utils::{generate_did_from_account, get_mock_jwt_with_cid};
+use polimec_common::{migration_types::CtReleasePolicy, ReleaseSchedule, USD_DECIMALS, USD_UNIT};
+use polimec_common_test_utils::{
+	generate_did_from_account, get_mock_jwt_with_cid, get_mock_jwt_with_options, MockJwtOptions,
+};
 use sp_arithmetic::{traits::Zero, Percent, Perquintill};
 use sp_runtime::TokenError;
 use sp_std::cell::RefCell;
@@ -34,7 +36,10 @@ mod ct_migration;
 mod evaluation;
 #[path = "5_funding_end.rs"]
 mod funding_end;
+mod issuer_payout;
+mod milestones;
 mod misc;
+mod private_round;
 mod runtime_api;
 #[path = "6_settlement.rs"]
 mod settlement;
@@ -108,7 +113,16 @@ pub mod defaults {
 			},
 			participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 			funding_destination_account: issuer,
+			funding_destination_location: None,
 			policy_ipfs_cid: Some(metadata_hash),
+			round_durations: Default::default(),
+			ct_release_policy: None,
+			soft_cap: None,
+			private_round: None,
+			fee_mode: FeeMode::ContributionTokens,
+			milestones: None,
+			pricing_model: PricingModel::Buckets,
+			jurisdiction_restriction: None,
 		}
 	}
 
@@ -142,7 +156,16 @@ pub mod defaults {
 			},
 			participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 			funding_destination_account: ISSUER_1,
+			funding_destination_location: None,
 			policy_ipfs_cid: Some(metadata_hash),
+			round_durations: Default::default(),
+			ct_release_policy: None,
+			soft_cap: None,
+			private_round: None,
+			fee_mode: FeeMode::ContributionTokens,
+			milestones: None,
+			pricing_model: PricingModel::Buckets,
+			jurisdiction_restriction: None,
 		};
 		project_metadata
 	}
@@ -257,6 +280,53 @@ pub mod defaults {
 	pub fn ipfs_hash() -> BoundedVec<u8, sp_core::ConstU32<96>> {
 		BoundedVec::try_from(IPFS_CID.as_bytes().to_vec()).unwrap()
 	}
+	pub fn jurisdictions(codes: &[&str]) -> BoundedVec<Jurisdiction, sp_core::ConstU32<MAX_JURISDICTIONS>> {
+		codes
+			.iter()
+			.map(|code| Jurisdiction::try_from(code.as_bytes().to_vec()).unwrap())
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap()
+	}
+	/// A JWT for the project policy of [`default_project_metadata`], with an optional `jurisdiction` claim.
+	pub fn jwt_with_jurisdiction(
+		account: AccountId,
+		investor_type: InvestorType,
+		jurisdiction: Option<&str>,
+	) -> UntrustedToken {
+		get_mock_jwt_with_options(
+			account,
+			investor_type,
+			generate_did_from_account(account),
+			MockJwtOptions {
+				ipfs_cid: Some(ipfs_hash()),
+				jurisdiction: jurisdiction.map(ToString::to_string),
+				..Default::default()
+			},
+		)
+	}
+
+	/// A JWT for the project policy of [`default_project_metadata`], with the given scope claims.
+	pub fn jwt_with_scope(
+		account: AccountId,
+		investor_type: InvestorType,
+		nonce: Option<&str>,
+		project_id: Option<ProjectId>,
+		max_usd: Option<Balance>,
+	) -> UntrustedToken {
+		get_mock_jwt_with_options(
+			account,
+			investor_type,
+			generate_did_from_account(account),
+			MockJwtOptions {
+				ipfs_cid: Some(ipfs_hash()),
+				nonce: nonce.map(ToString::to_string),
+				project_id,
+				max_usd,
+				..Default::default()
+			},
+		)
+	}
 
 	pub fn default_weights() -> Vec<u8> {
 		vec![20u8, 15u8, 10u8, 25u8, 30u8]
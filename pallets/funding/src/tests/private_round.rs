@@ -0,0 +1,372 @@
+use super::*;
+
+pub fn private_round_project_metadata(issuer: AccountId) -> ProjectMetadataOf<TestRuntime> {
+	let mut project_metadata = default_project_metadata(issuer);
+	let price = <TestRuntime as Config>::PriceProvider::calculate_decimals_aware_price(
+		PriceOf::<TestRuntime>::from_float(5.0),
+		USD_DECIMALS,
+		CT_DECIMALS,
+	)
+	.unwrap();
+	project_metadata.private_round =
+		Some(PrivateRoundConfig { allocation_percentage: Percent::from_percent(20), price });
+	project_metadata
+}
+
+fn mint_for_private_contributions(
+	inst: &mut MockInstantiator,
+	project_metadata: &ProjectMetadataOf<TestRuntime>,
+	contributions: Vec<ContributionParams<TestRuntime>>,
+) {
+	let price = project_metadata.private_round.unwrap().price;
+	let plmc = inst.calculate_contributed_plmc_spent(contributions.clone(), price, true);
+	let usdt = inst.calculate_contributed_funding_asset_spent(contributions, price);
+	inst.mint_plmc_to(plmc);
+	inst.mint_funding_asset_to(usdt);
+}
+
+#[cfg(test)]
+mod private_contribute_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn allow_listed_investor_buys_at_the_private_price() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = private_round_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_private_round_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			inst.allow_list_for_private_round(project_id, vec![BUYER_1]);
+
+			let contribution = ContributionParams::new(BUYER_1, 10_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			let price = project_metadata.private_round.unwrap().price;
+			let usdt = inst.calculate_contributed_funding_asset_spent(vec![contribution.clone()], price);
+			mint_for_private_contributions(&mut inst, &project_metadata, vec![contribution.clone()]);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::private_contribute(
+					RuntimeOrigin::signed(BUYER_1),
+					get_mock_jwt_with_cid(
+						BUYER_1,
+						InvestorType::Retail,
+						generate_did_from_account(BUYER_1),
+						project_metadata.clone().policy_ipfs_cid.unwrap()
+					),
+					project_id,
+					contribution.amount,
+					contribution.multiplier,
+					contribution.asset
+				));
+			});
+
+			let stored_contribution = inst
+				.execute(|| Contributions::<TestRuntime>::iter_prefix_values((project_id, BUYER_1)).next().unwrap());
+			assert!(stored_contribution.private_round);
+			assert_eq!(stored_contribution.ct_amount, contribution.amount);
+			assert_eq!(stored_contribution.funding_asset_amount, usdt[0].asset_amount);
+			assert_eq!(
+				inst.get_project_details(project_id).remaining_contribution_tokens,
+				project_metadata.total_allocation_size - contribution.amount
+			);
+		}
+
+		#[test]
+		fn contribution_is_capped_by_the_private_allocation() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = private_round_project_metadata(ISSUER_1);
+			let private_allocation = Percent::from_percent(20) * project_metadata.total_allocation_size;
+			let project_id =
+				inst.create_private_round_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			inst.allow_list_for_private_round(project_id, vec![BUYER_1]);
+
+			let contribution =
+				ContributionParams::new(BUYER_1, private_allocation + 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			mint_for_private_contributions(&mut inst, &project_metadata, vec![contribution.clone()]);
+			inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+
+			let stored_contribution = inst
+				.execute(|| Contributions::<TestRuntime>::iter_prefix_values((project_id, BUYER_1)).next().unwrap());
+			assert_eq!(stored_contribution.ct_amount, private_allocation);
+		}
+
+		#[test]
+		fn unsold_private_tokens_go_to_the_community() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = private_round_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_private_round_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			inst.allow_list_for_private_round(project_id, vec![BUYER_6]);
+
+			let private_contribution =
+				ContributionParams::new(BUYER_6, 100_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			mint_for_private_contributions(&mut inst, &project_metadata, vec![private_contribution.clone()]);
+			inst.contribute_for_users(project_id, vec![private_contribution.clone()]).unwrap();
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::AuctionRound);
+			assert_eq!(
+				inst.get_project_details(project_id).remaining_contribution_tokens,
+				project_metadata.total_allocation_size - private_contribution.amount
+			);
+
+			let bids = default_bids();
+			let plmc = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				None,
+				true,
+			);
+			let usdt = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				None,
+			);
+			inst.mint_plmc_to(plmc);
+			inst.mint_funding_asset_to(usdt);
+			inst.bid_for_users(project_id, bids.clone()).unwrap();
+			assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound(_)));
+
+			// The community can buy everything the private round and the auction did not sell
+			let sold_in_auction = bids.iter().map(|bid| bid.amount).sum::<Balance>();
+			let community_contribution = ContributionParams::new(
+				BUYER_1,
+				project_metadata.total_allocation_size - private_contribution.amount - sold_in_auction,
+				1u8,
+				AcceptedFundingAsset::USDT,
+			);
+			let wap = inst.get_project_details(project_id).weighted_average_price.unwrap();
+			let plmc = inst.calculate_contributed_plmc_spent(vec![community_contribution.clone()], wap, true);
+			let usdt = inst.calculate_contributed_funding_asset_spent(vec![community_contribution.clone()], wap);
+			inst.mint_plmc_to(plmc);
+			inst.mint_funding_asset_to(usdt);
+			inst.contribute_for_users(project_id, vec![community_contribution.clone()]).unwrap();
+			assert_eq!(inst.get_project_details(project_id).remaining_contribution_tokens, 0);
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+			inst.settle_project(project_id, true);
+
+			let (_, migrations) = inst.execute(|| UserMigrations::<TestRuntime>::get((project_id, BUYER_6)).unwrap());
+			assert_eq!(migrations.len(), 1);
+			assert_eq!(migrations[0].origin.participation_type, ParticipationType::PrivateContribution);
+			let (_, migrations) = inst.execute(|| UserMigrations::<TestRuntime>::get((project_id, BUYER_1)).unwrap());
+			assert_eq!(migrations[0].origin.participation_type, ParticipationType::Contribution);
+		}
+
+		#[test]
+		fn project_without_private_round_skips_it() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_auctioning_project(
+				default_project_metadata(ISSUER_1),
+				ISSUER_1,
+				None,
+				default_evaluations(),
+			);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionRound);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn not_allow_listed() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = private_round_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_private_round_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			inst.allow_list_for_private_round(project_id, vec![BUYER_1]);
+
+			let contribution = ContributionParams::new(BUYER_2, 10_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			mint_for_private_contributions(&mut inst, &project_metadata, vec![contribution.clone()]);
+			assert_err!(
+				inst.contribute_for_users(project_id, vec![contribution]),
+				Error::<TestRuntime>::NotAllowListed
+			);
+
+			// Access can be revoked before the investor buys
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::do_set_private_round_access(
+					ISSUER_1,
+					project_id,
+					generate_did_from_account(BUYER_1),
+					false
+				));
+			});
+			let contribution = ContributionParams::new(BUYER_1, 10_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			mint_for_private_contributions(&mut inst, &project_metadata, vec![contribution.clone()]);
+			assert_err!(
+				inst.contribute_for_users(project_id, vec![contribution]),
+				Error::<TestRuntime>::NotAllowListed
+			);
+		}
+
+		#[test]
+		fn private_allocation_sold_out() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = private_round_project_metadata(ISSUER_1);
+			let private_allocation = Percent::from_percent(20) * project_metadata.total_allocation_size;
+			let project_id =
+				inst.create_private_round_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			inst.allow_list_for_private_round(project_id, vec![BUYER_1, BUYER_2]);
+
+			let contributions = vec![
+				ContributionParams::new(BUYER_1, private_allocation, 1u8, AcceptedFundingAsset::USDT),
+				ContributionParams::new(BUYER_2, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT),
+			];
+			mint_for_private_contributions(&mut inst, &project_metadata, contributions.clone());
+			inst.contribute_for_users(project_id, vec![contributions[0].clone()]).unwrap();
+			assert_err!(
+				inst.contribute_for_users(project_id, vec![contributions[1].clone()]),
+				Error::<TestRuntime>::ProjectSoldOut
+			);
+		}
+
+		#[test]
+		fn private_round_ended() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = private_round_project_metadata(ISSUER_1);
+			let project_id =
+				inst.create_private_round_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			inst.allow_list_for_private_round(project_id, vec![BUYER_1]);
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::AuctionRound);
+
+			let contribution = ContributionParams::new(BUYER_1, 10_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			mint_for_private_contributions(&mut inst, &project_metadata, vec![contribution.clone()]);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::private_contribute(
+						RuntimeOrigin::signed(BUYER_1),
+						get_mock_jwt_with_cid(
+							BUYER_1,
+							InvestorType::Retail,
+							generate_did_from_account(BUYER_1),
+							project_metadata.clone().policy_ipfs_cid.unwrap()
+						),
+						project_id,
+						contribution.amount,
+						contribution.multiplier,
+						contribution.asset
+					),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn only_issuer_can_set_access() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(private_round_project_metadata(ISSUER_1), ISSUER_1, None);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_set_private_round_access(
+						ISSUER_2,
+						project_id,
+						generate_did_from_account(BUYER_1),
+						true
+					),
+					Error::<TestRuntime>::NotIssuer
+				);
+			});
+
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_2), ISSUER_2, None);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_set_private_round_access(
+						ISSUER_2,
+						project_id,
+						generate_did_from_account(BUYER_1),
+						true
+					),
+					Error::<TestRuntime>::NoPrivateRound
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod withdraw_contribution_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn withdrawn_private_tokens_go_back_to_the_private_allocation() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = private_round_project_metadata(ISSUER_1);
+			let private_allocation = Percent::from_percent(20) * project_metadata.total_allocation_size;
+			let project_id =
+				inst.create_private_round_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			inst.allow_list_for_private_round(project_id, vec![BUYER_1, BUYER_2]);
+
+			let contribution = ContributionParams::new(BUYER_1, private_allocation, 1u8, AcceptedFundingAsset::USDT);
+			mint_for_private_contributions(&mut inst, &project_metadata, vec![contribution.clone()]);
+			inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+			let stored_contribution = inst
+				.execute(|| Contributions::<TestRuntime>::iter_prefix_values((project_id, BUYER_1)).next().unwrap());
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::withdraw_contribution(
+					RuntimeOrigin::signed(BUYER_1),
+					get_mock_jwt_with_cid(
+						BUYER_1,
+						InvestorType::Retail,
+						generate_did_from_account(BUYER_1),
+						project_metadata.clone().policy_ipfs_cid.unwrap()
+					),
+					project_id,
+					stored_contribution.id,
+				));
+			});
+			assert_eq!(
+				inst.get_project_details(project_id).remaining_contribution_tokens,
+				project_metadata.total_allocation_size
+			);
+
+			// The whole private allocation can be bought again in the private round
+			let contribution = ContributionParams::new(BUYER_2, private_allocation, 1u8, AcceptedFundingAsset::USDT);
+			mint_for_private_contributions(&mut inst, &project_metadata, vec![contribution.clone()]);
+			inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+			let stored_contribution = inst
+				.execute(|| Contributions::<TestRuntime>::iter_prefix_values((project_id, BUYER_2)).next().unwrap());
+			assert!(stored_contribution.private_round);
+			assert_eq!(stored_contribution.ct_amount, private_allocation);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_withdraw_private_contribution_after_private_round() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = private_round_project_metadata(ISSUER_1);
+			project_metadata.pricing_model = PricingModel::FixedPriceWithoutAuction;
+			let project_id =
+				inst.create_private_round_project(project_metadata.clone(), ISSUER_1, None, default_evaluations());
+			inst.allow_list_for_private_round(project_id, vec![BUYER_1]);
+
+			let contribution = ContributionParams::new(BUYER_1, 10_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			mint_for_private_contributions(&mut inst, &project_metadata, vec![contribution.clone()]);
+			inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+			let stored_contribution = inst
+				.execute(|| Contributions::<TestRuntime>::iter_prefix_values((project_id, BUYER_1)).next().unwrap());
+
+			// The CTs would otherwise go to the community round
+			assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound(..)));
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_withdraw_contribution(&BUYER_1, project_id, stored_contribution.id),
+					Error::<TestRuntime>::ContributionRoundOver
+				);
+			});
+		}
+	}
+}
@@ -771,3 +771,29 @@ fn projects_by_did() {
 		assert_eq!(project_ids, vec![project_id_1, project_id_3]);
 	});
 }
+
+#[test]
+fn funding_success_threshold() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let soft_cap = Perquintill::from_percent(50);
+	inst.execute(|| {
+		assert_ok!(PolimecFunding::set_soft_cap_bounds(
+			RuntimeOrigin::root(),
+			SoftCapBounds::new(Perquintill::from_percent(10), Perquintill::from_percent(90))
+		));
+	});
+
+	let project_id_1 = inst.create_new_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+	let mut project_metadata = default_project_metadata(ISSUER_2);
+	project_metadata.soft_cap = Some(soft_cap);
+	let project_id_2 = inst.create_new_project(project_metadata, ISSUER_2, None);
+
+	inst.execute(|| {
+		let block_hash = System::block_hash(System::block_number());
+		assert_eq!(
+			TestRuntime::funding_success_threshold(&TestRuntime, block_hash, project_id_1).unwrap(),
+			<TestRuntime as Config>::FundingSuccessThreshold::get()
+		);
+		assert_eq!(TestRuntime::funding_success_threshold(&TestRuntime, block_hash, project_id_2).unwrap(), soft_cap);
+	});
+}
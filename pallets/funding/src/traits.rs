@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Balance, Config, ProjectId};
+use crate::{Balance, Bucket, Config, ProjectId};
 use frame_support::weights::Weight;
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_arithmetic::{
@@ -75,6 +75,20 @@ pub trait ProvideAssetPrice {
 	}
 }
 
+/// How a project discovers the price of its CTs, chosen by the issuer in the project metadata.
+pub trait PriceDiscovery {
+	/// Whether part of the allocation is sold in an auction round. Without one, the community round starts right
+	/// after the evaluation (or private) round, at the minimum price.
+	fn has_auction(&self) -> bool;
+
+	/// The bucket the auction starts from. The price of each bid and the WAP follow from how it moves as CTs are bid.
+	fn initial_bucket<Price: FixedPointNumber>(
+		&self,
+		auction_allocation_size: Balance,
+		minimum_price: Price,
+	) -> Option<Bucket<Price>>;
+}
+
 pub trait DoRemainingOperation<T: Config> {
 	fn has_remaining_operations(&self) -> bool;
 
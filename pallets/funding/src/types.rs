@@ -25,15 +25,22 @@ use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use inner::*;
 use parachains_common::DAYS;
-use polimec_common::USD_DECIMALS;
+use polimec_common::{
+	credentials::{InvestorType, Jurisdiction},
+	migration_types::{CtReleasePolicy, ParticipationType},
+	USD_DECIMALS,
+};
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use serde::{Deserialize, Serialize};
-use sp_arithmetic::{traits::Saturating, FixedPointNumber, FixedU128, Percent};
+use sp_arithmetic::{traits::Saturating, FixedPointNumber, FixedU128, Percent, Perquintill};
 use sp_runtime::traits::{Convert, One};
 use sp_std::{cmp::Eq, prelude::*};
 pub use storage::*;
 
-use crate::{traits::VestingDurationCalculation, Config};
+use crate::{
+	traits::{PriceDiscovery, VestingDurationCalculation},
+	Config,
+};
 
 use sp_runtime::traits::Zero;
 
@@ -155,14 +162,18 @@ pub mod storage {
 	#[allow(clippy::wildcard_imports)]
 	use super::*;
 	use crate::Balance;
+	use sp_runtime::traits::{CheckedDiv, SaturatedConversion, UniqueSaturatedInto};
+	use xcm::v4::Location;
 
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize)]
-	pub struct ProjectMetadata<BoundedString, Price: FixedPointNumber, AccountId, Cid> {
+	pub struct ProjectMetadata<BoundedString, Price: FixedPointNumber, AccountId, Cid, BlockNumber> {
 		/// Token Metadata
 		pub token_information: CurrencyMetadata<BoundedString>,
 		/// Mainnet Token Max Supply
+		#[serde(with = "serde_balance")]
 		pub mainnet_token_max_supply: Balance,
 		/// Total allocation of Contribution Tokens available for the Funding Round.
+		#[serde(with = "serde_balance")]
 		pub total_allocation_size: Balance,
 		/// Percentage of the total allocation of Contribution Tokens available for the Auction Round
 		pub auction_round_allocation_percentage: Percent,
@@ -172,22 +183,55 @@ pub mod storage {
 		pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
 		/// Maximum and minimum ticket sizes for community/remainder rounds
 		pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
-		/// Participation currencies (e.g stablecoin, DOT, KSM)
-		/// e.g. https://github.com/paritytech/substrate/blob/427fd09bcb193c1e79dec85b1e207c718b686c35/frame/uniques/src/types.rs#L110
-		/// For now is easier to handle the case where only just one Currency is accepted
-		pub participation_currencies:
-			BoundedVec<AcceptedFundingAsset, ConstU32<{ AcceptedFundingAsset::VARIANT_COUNT as u32 }>>,
+		/// Participation currencies (e.g stablecoin, DOT, KSM). Each of them must be an enabled entry of the
+		/// `FundingAssets` registry.
+		pub participation_currencies: BoundedVec<AcceptedFundingAsset, ConstU32<MAX_PARTICIPATION_CURRENCIES>>,
 		pub funding_destination_account: AccountId,
+		/// Account on another chain, e.g. AssetHub, that receives the raised funding assets over XCM instead of the
+		/// `funding_destination_account`. It has to be on the reserve chain of every participation currency.
+		pub funding_destination_location: Option<Location>,
 		/// Additional metadata
 		pub policy_ipfs_cid: Option<Cid>,
+		/// Round durations chosen by the issuer. Rounds left unset use the runtime defaults.
+		pub round_durations: RoundDurations<BlockNumber>,
+		/// How the CTs are released to participants after the TGE. If not set, each participation vests
+		/// over the duration given by its multiplier.
+		pub ct_release_policy: Option<CtReleasePolicy>,
+		/// Share of the funding target the project needs to raise to be successful. If not set, the runtime's
+		/// `FundingSuccessThreshold` is used.
+		pub soft_cap: Option<Perquintill>,
+		/// Fixed-price round before the auction, open only to the DIDs allow-listed by the issuer.
+		pub private_round: Option<PrivateRoundConfig<Price>>,
+		/// How the protocol fee is paid on success.
+		pub fee_mode: FeeMode,
+		/// Milestones that release the raised funding assets from escrow. If not set, the funding assets are
+		/// paid out to the issuer when each participation is settled.
+		pub milestones: Option<MilestonePlan<BlockNumber>>,
+		/// How the price of the CTs is discovered.
+		pub pricing_model: PricingModel,
+		/// Jurisdictions the issuer can or cannot sell to. If set, only credentials with a `jurisdiction` claim
+		/// it allows can evaluate, bid or contribute.
+		pub jurisdiction_restriction: Option<JurisdictionRestriction>,
 	}
 
-	impl<BoundedString, Price: FixedPointNumber, AccountId, Cid> ProjectMetadata<BoundedString, Price, AccountId, Cid> {
+	impl<BoundedString, Price: FixedPointNumber, AccountId, Cid, BlockNumber: Copy + PartialOrd + Zero>
+		ProjectMetadata<BoundedString, Price, AccountId, Cid, BlockNumber>
+	{
 		/// Validate issuer metadata for the following checks:
 		/// - Minimum price is not zero
 		/// - Minimum bidding ticket sizes are higher than 5k USD
 		/// - Specified participation currencies are unique
-		pub fn is_valid(&self) -> Result<(), MetadataError> {
+		/// - Specified round durations are within the governance bounds
+		/// - The CT release policy, if any, has a non-zero linear duration
+		/// - The soft cap, if any, is within the governance bounds
+		/// - The private round, if any, has a price and an allocation that fits next to the auction allocation
+		/// - The milestones, if any, release 100% of the raise and have increasing deadlines
+		/// - The jurisdiction restriction, if any, lists at least one jurisdiction
+		pub fn is_valid(
+			&self,
+			duration_bounds: &RoundDurationBounds<BlockNumber>,
+			soft_cap_bounds: &SoftCapBounds,
+		) -> Result<(), MetadataError> {
 			if self.minimum_price == Price::zero() {
 				return Err(MetadataError::PriceTooLow);
 			}
@@ -218,6 +262,37 @@ pub mod storage {
 				return Err(MetadataError::AuctionRoundPercentageError);
 			}
 
+			if !duration_bounds.contains(&self.round_durations) {
+				return Err(MetadataError::RoundDurationError);
+			}
+
+			if self.ct_release_policy.is_some_and(|policy| !policy.is_valid()) {
+				return Err(MetadataError::CtReleasePolicyError);
+			}
+
+			if !soft_cap_bounds.contains(self.soft_cap) {
+				return Err(MetadataError::SoftCapError);
+			}
+
+			if let Some(private_round) = &self.private_round {
+				let unallocated_percentage =
+					Percent::from_percent(100).saturating_sub(self.auction_round_allocation_percentage);
+				if private_round.price == Price::zero() ||
+					private_round.allocation_percentage.is_zero() ||
+					private_round.allocation_percentage > unallocated_percentage
+				{
+					return Err(MetadataError::PrivateRoundError);
+				}
+			}
+
+			if self.milestones.as_ref().is_some_and(|plan| !plan.is_valid()) {
+				return Err(MetadataError::MilestoneError);
+			}
+
+			if self.jurisdiction_restriction.as_ref().is_some_and(|restriction| !restriction.is_valid()) {
+				return Err(MetadataError::JurisdictionRestrictionError);
+			}
+
 			let mut deduped = self.participation_currencies.clone().to_vec();
 			deduped.sort();
 			deduped.dedup();
@@ -255,6 +330,11 @@ pub mod storage {
 
 			Ok(())
 		}
+
+		/// Whether an investor subject to `jurisdiction` can participate in the project.
+		pub fn allows_jurisdiction(&self, jurisdiction: Option<&Jurisdiction>) -> bool {
+			self.jurisdiction_restriction.as_ref().map_or(true, |restriction| restriction.allows(jurisdiction))
+		}
 	}
 
 	pub struct Bound {
@@ -274,13 +354,13 @@ pub mod storage {
 		Institutional(Bound),
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize)]
 	pub enum MigrationType {
 		Offchain,
 		Pallet(PalletMigrationInfo),
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize)]
 	pub struct PalletMigrationInfo {
 		/// ParaId of project
 		pub parachain_id: ParaId,
@@ -290,7 +370,7 @@ pub mod storage {
 		pub migration_readiness_check: Option<PalletMigrationReadinessCheck>,
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize)]
 	pub struct ProjectDetails<AccountId, Did, BlockNumber, Price: FixedPointNumber, EvaluationRoundInfo> {
 		pub issuer_account: AccountId,
 		pub issuer_did: Did,
@@ -303,14 +383,18 @@ pub mod storage {
 		/// When the different project phases start and end
 		pub round_duration: BlockNumberPair<BlockNumber>,
 		/// Fundraising target amount in USD (6 decimals)
+		#[serde(with = "serde_balance")]
 		pub fundraising_target_usd: Balance,
 		/// The amount of Contribution Tokens that have not yet been sold
+		#[serde(with = "serde_balance")]
 		pub remaining_contribution_tokens: Balance,
 		/// Funding reached amount in USD (6 decimals)
+		#[serde(with = "serde_balance")]
 		pub funding_amount_reached_usd: Balance,
 		/// Information about the total amount bonded, and the outcome in regards to reward/slash/nothing
 		pub evaluation_round_info: EvaluationRoundInfo,
 		/// If the auction was oversubscribed, how much USD was raised across all winning bids
+		#[serde(with = "serde_balance::option")]
 		pub usd_bid_on_oversubscription: Option<Balance>,
 		/// When the Funding Round ends
 		pub funding_end_block: Option<BlockNumber>,
@@ -330,21 +414,38 @@ pub mod storage {
 		StartSettlement,
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Ord, PartialOrd)]
+	#[derive(
+		Clone,
+		Encode,
+		Decode,
+		Eq,
+		PartialEq,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+		Ord,
+		PartialOrd,
+		Serialize,
+		Deserialize,
+	)]
 	pub struct EvaluationInfo<Id, Did, ProjectId, AccountId, BlockNumber> {
 		pub id: Id,
 		pub did: Did,
 		pub project_id: ProjectId,
 		pub evaluator: AccountId,
+		#[serde(with = "serde_balance")]
 		pub original_plmc_bond: Balance,
 		// An evaluation bond can be converted to participation bond
+		#[serde(with = "serde_balance")]
 		pub current_plmc_bond: Balance,
+		#[serde(with = "serde_balance")]
 		pub early_usd_amount: Balance,
+		#[serde(with = "serde_balance")]
 		pub late_usd_amount: Balance,
 		pub when: BlockNumber,
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize)]
 	pub struct BidInfo<ProjectId, Did, Price: FixedPointNumber, AccountId, BlockNumber, Multiplier> {
 		pub id: u32,
 		pub project_id: ProjectId,
@@ -352,11 +453,14 @@ pub mod storage {
 		pub did: Did,
 		pub status: BidStatus,
 		#[codec(compact)]
+		#[serde(with = "serde_balance")]
 		pub original_ct_amount: Balance,
 		pub original_ct_usd_price: Price,
 		pub funding_asset: AcceptedFundingAsset,
+		#[serde(with = "serde_balance")]
 		pub funding_asset_amount_locked: Balance,
 		pub multiplier: Multiplier,
+		#[serde(with = "serde_balance")]
 		pub plmc_bond: Balance,
 		pub when: BlockNumber,
 	}
@@ -391,19 +495,184 @@ pub mod storage {
 		}
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize)]
 	pub struct ContributionInfo<Id, Did, ProjectId, AccountId, BlockNumber, Multiplier> {
 		pub id: Id,
 		pub did: Did,
 		pub project_id: ProjectId,
 		pub contributor: AccountId,
+		#[serde(with = "serde_balance")]
 		pub ct_amount: Balance,
+		#[serde(with = "serde_balance")]
 		pub usd_contribution_amount: Balance,
 		pub multiplier: Multiplier,
 		pub funding_asset: AcceptedFundingAsset,
+		#[serde(with = "serde_balance")]
 		pub funding_asset_amount: Balance,
+		#[serde(with = "serde_balance")]
 		pub plmc_bond: Balance,
 		pub when: BlockNumber,
+		/// Made in the private round, at the issuer's fixed price.
+		pub private_round: bool,
+	}
+
+	impl<Id, Did, ProjectId, AccountId, BlockNumber, Multiplier>
+		ContributionInfo<Id, Did, ProjectId, AccountId, BlockNumber, Multiplier>
+	{
+		pub const fn participation_type(&self) -> ParticipationType {
+			if self.private_round {
+				ParticipationType::PrivateContribution
+			} else {
+				ParticipationType::Contribution
+			}
+		}
+	}
+
+	/// Registry entry of an asset that can be used to participate in projects.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize)]
+	pub struct FundingAssetInfo {
+		/// Location of the asset, used to match it in incoming XCM transfers.
+		pub location: Location,
+		/// Decimals of the asset in `T::FundingCurrency`.
+		pub decimals: u8,
+		/// Key under which the oracle publishes the USD price of the asset.
+		pub oracle_key: u32,
+		/// Whether new participations can use this asset. Existing ones are still settled and refunded with it.
+		pub enabled: bool,
+	}
+
+	/// The kind of participation the automatic settlement of a project is currently going through.
+	#[derive(Default, Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub enum SettlementPhase {
+		#[default]
+		Evaluations,
+		Bids,
+		Contributions,
+		/// All participations were visited, the project can be marked as settled.
+		Finished,
+	}
+
+	/// Progress of the automatic settlement of a project, done in `on_idle`.
+	#[derive(Default, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct SettlementCursor<AccountId> {
+		pub phase: SettlementPhase,
+		/// The last participation of the current phase that failed to settle automatically.
+		/// Settlement resumes after it, and it is left for the manual settlement extrinsics.
+		pub last_skipped: Option<(AccountId, u32)>,
+	}
+
+	impl<AccountId> SettlementCursor<AccountId> {
+		pub fn next_phase(&mut self, phase: SettlementPhase) {
+			self.phase = phase;
+			self.last_skipped = None;
+		}
+	}
+
+	/// Progress of the resolution of the winning bids of a project, walking its price buckets from the highest down.
+	/// Done in `on_idle`, or with the `resolve_auction` extrinsic.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct AuctionResolution<Price> {
+		/// Index of the price bucket being resolved.
+		pub bucket: u32,
+		/// Position in the bucket of the next bid to resolve.
+		pub position: u32,
+		/// The weighted average price of the auction. Accepted bids pay at most this price.
+		pub wap: Price,
+		/// CTs of the auction allocation not given to any bid yet.
+		pub ct_left: Balance,
+		/// USD raised by the bids accepted so far, at their final price.
+		pub usd_raised: Balance,
+	}
+
+	/// The pieces a bid was split into across price buckets, and the credential type it was placed with.
+	/// Cancelling or reducing a bid acts on all of its pieces.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct BidGroup<BidIds> {
+		/// Ids of the pieces, from the lowest price bucket up.
+		pub bid_ids: BidIds,
+		/// The investor type of the bidder when the bid was placed, which sets the ticket size minimum.
+		pub investor_type: InvestorType,
+	}
+
+	/// The long-term-holder CT pot of a successful project, shared pro-rata between the participants that kept
+	/// their CTs for at least `LongTermHolderPeriod` blocks.
+	#[derive(Default, Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct LongTermHolderPot {
+		/// CTs minted to the contribution treasury for the long-term holders.
+		pub reward: Balance,
+		/// Sum of the CTs minted to all participants during settlement.
+		pub total_held: Balance,
+	}
+
+	impl LongTermHolderPot {
+		/// The share of the pot corresponding to `ct_amount` held CTs.
+		pub fn share_of(&self, ct_amount: Balance) -> Balance {
+			Perquintill::from_rational(ct_amount, self.total_held) * self.reward
+		}
+	}
+
+	/// The CTs a participant received on settlement, and since when it holds them.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct LongTermHolding<BlockNumber> {
+		pub ct_amount: Balance,
+		pub since: BlockNumber,
+	}
+
+	/// The protocol fee schedule, and how the CTs taken as fee are split. Set by governance, and
+	/// snapshotted by each project when its evaluation starts.
+	///
+	/// The shares only split the part of the fee paid in CTs. The part a project pays in funding assets
+	/// (see [`FeeMode::FundingAssets`]) goes to the `BlockchainOperationTreasury` as a whole.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct FeeParameters {
+		/// The fee charged on each USD bracket of the funding reached, as `(fee, bracket size)`.
+		pub fee_brackets: BoundedVec<(Percent, Balance), ConstU32<10>>,
+		/// Share of the fee CTs given to the evaluators.
+		pub evaluator_share: Perquintill,
+		/// Share of the evaluator rewards reserved to the early evaluators. The rest goes to all evaluators.
+		pub early_evaluator_share: Perquintill,
+		/// Share of the fee CTs given to the liquidity pools.
+		pub liquidity_pools_share: Perquintill,
+		/// Share of the fee CTs given to the long-term holders.
+		pub long_term_holder_share: Perquintill,
+	}
+
+	impl FeeParameters {
+		/// There needs to be at least one fee bracket, and the fee CTs cannot be split into more than 100%.
+		pub fn is_valid(&self) -> bool {
+			let total_share = [self.evaluator_share, self.liquidity_pools_share, self.long_term_holder_share]
+				.iter()
+				.map(|share| u128::from(share.deconstruct()))
+				.sum::<u128>();
+			!self.fee_brackets.is_empty() && total_share <= u128::from(Perquintill::one().deconstruct())
+		}
+	}
+
+	/// Approval progress of the milestones of a successful project.
+	#[derive(Default, Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct MilestoneProgress {
+		/// Index of the milestone up for approval. Equal to the amount of milestones once all were approved.
+		pub current: u32,
+		/// Voting power that approved the current milestone.
+		pub approvals: Balance,
+		/// Voting power of all the approvers, recorded on settlement.
+		pub total_power: Balance,
+		/// A milestone missed its deadline, and what is left in escrow is refunded to the participants.
+		pub failed: bool,
+	}
+
+	/// The funding assets of one kind escrowed for a project, and how much of them was released to the issuer.
+	#[derive(Default, Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct Escrow {
+		pub total: Balance,
+		pub released: Balance,
+	}
+
+	impl Escrow {
+		/// The part of `amount` escrowed by a participant that was not released to the issuer.
+		pub fn unreleased_share_of(&self, amount: Balance) -> Balance {
+			Perquintill::from_rational(self.total.saturating_sub(self.released), self.total) * amount
+		}
 	}
 
 	/// Represents a bucket that holds a specific amount of tokens at a given price.
@@ -442,6 +711,23 @@ pub mod storage {
 			}
 		}
 
+		/// Give back `returned_amount` from a cancelled or reduced bid to the current bucket.
+		/// The price is not lowered, so bids placed afterwards never pay less than the bids still standing below them.
+		pub fn free(&mut self, returned_amount: Balance) {
+			self.amount_left.saturating_accrue(returned_amount);
+		}
+
+		/// Index of the bucket selling at `price`, the first bucket being 0.
+		pub fn index_of(&self, price: Price) -> u32 {
+			let steps = price
+				.saturating_sub(self.initial_price)
+				.into_inner()
+				.checked_div(&self.delta_price.into_inner())
+				.unwrap_or_else(Zero::zero);
+			let steps: u128 = steps.unique_saturated_into();
+			steps.saturated_into()
+		}
+
 		/// Updates the bucket to represent the next one in the sequence. This involves:
 		/// - resetting the amount left,
 		/// - recalculating the price based on the current price and the price increments defined by the `delta_price`.
@@ -456,13 +742,18 @@ pub mod storage {
 				return self.current_price;
 			}
 			let mut amount: Balance = self.delta_amount.saturating_sub(self.amount_left);
+			// Amounts freed by cancelled or reduced bids sit in the current bucket, so they are missing from the
+			// buckets below it
+			let mut freed_amount: Balance = self.amount_left.saturating_sub(self.delta_amount);
 			let mut price: Price = self.current_price;
 			let mut bucket_sizes: Vec<(Balance, Price)> = Vec::new();
 			while price > self.initial_price && total_amount > Balance::zero() {
 				total_amount.saturating_reduce(amount);
 				bucket_sizes.push((price.saturating_mul_int(amount), price));
 				price = price.saturating_sub(self.delta_price);
-				amount = self.delta_amount;
+				let freed_in_bucket = freed_amount.min(self.delta_amount);
+				freed_amount.saturating_reduce(freed_in_bucket);
+				amount = self.delta_amount.saturating_sub(freed_in_bucket);
 			}
 
 			if total_amount > Balance::zero() {
@@ -483,7 +774,7 @@ pub mod inner {
 	#[allow(clippy::wildcard_imports)]
 	use super::*;
 	use crate::Balance;
-	use variant_count::VariantCount;
+	use sp_runtime::MultiSignature;
 	use xcm::v4::QueryId;
 
 	pub enum MetadataError {
@@ -508,6 +799,19 @@ pub mod inner {
 		// The combination of decimals and price of this project is not representable within our 6 decimals USD system,
 		// and integer space of 128 bits.
 		BadTokenomics,
+		/// A round duration is outside the bounds set by governance.
+		RoundDurationError,
+		/// The CT release policy has a zero linear duration.
+		CtReleasePolicyError,
+		/// The soft cap is outside the bounds set by governance.
+		SoftCapError,
+		/// The private round has a zero price or allocation, or together with the auction it takes more than the
+		/// total allocation.
+		PrivateRoundError,
+		/// The milestones do not release 100% of the raise, or their deadlines are not increasing.
+		MilestoneError,
+		/// The jurisdiction restriction does not list any jurisdiction.
+		JurisdictionRestrictionError,
 	}
 
 	#[derive(
@@ -526,7 +830,9 @@ pub mod inner {
 		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
 	)]
 	pub struct TicketSize {
+		#[serde(with = "serde_balance")]
 		pub usd_minimum_per_participation: Balance,
+		#[serde(with = "serde_balance::option")]
 		pub usd_maximum_per_did: Option<Balance>,
 	}
 	impl TicketSize {
@@ -624,8 +930,12 @@ pub mod inner {
 		}
 	}
 
+	/// Maximum amount of funding assets a project can accept.
+	pub const MAX_PARTICIPATION_CURRENCIES: u32 = 8;
+
+	/// The id of a funding asset in `T::FundingCurrency`. Only assets registered in the `FundingAssets`
+	/// storage can be used to participate in projects.
 	#[derive(
-		VariantCount,
 		Clone,
 		Copy,
 		Encode,
@@ -640,21 +950,14 @@ pub mod inner {
 		Serialize,
 		Deserialize,
 	)]
-	pub enum AcceptedFundingAsset {
-		#[codec(index = 0)]
-		USDT,
-		#[codec(index = 1)]
-		USDC,
-		#[codec(index = 2)]
-		DOT,
-	}
+	pub struct AcceptedFundingAsset(pub u32);
 	impl AcceptedFundingAsset {
+		pub const DOT: Self = Self(10);
+		pub const USDC: Self = Self(1337);
+		pub const USDT: Self = Self(1984);
+
 		pub const fn id(&self) -> u32 {
-			match self {
-				AcceptedFundingAsset::USDT => 1984,
-				AcceptedFundingAsset::DOT => 10,
-				AcceptedFundingAsset::USDC => 1337,
-			}
+			self.0
 		}
 	}
 
@@ -673,6 +976,10 @@ pub mod inner {
 		SettlementFinished(FundingOutcome),
 		CTMigrationStarted,
 		CTMigrationFinished,
+		/// Between the evaluation and the auction round, for projects with a private round.
+		PrivateRound,
+		/// After the auction round, until all bids are resolved as accepted or rejected.
+		AuctionResolving,
 	}
 
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize)]
@@ -681,7 +988,9 @@ pub mod inner {
 		Failure,
 	}
 
-	#[derive(Default, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[derive(
+		Default, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
+	)]
 	pub struct BlockNumberPair<BlockNumber> {
 		pub start: Option<BlockNumber>,
 		pub end: Option<BlockNumber>,
@@ -709,7 +1018,303 @@ pub mod inner {
 		}
 	}
 
-	#[derive(Default, Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	/// Duration in blocks of each round of a project. `None` means the runtime default is used.
+	#[derive(
+		Default,
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		Eq,
+		PartialEq,
+		RuntimeDebug,
+		MaxEncodedLen,
+		TypeInfo,
+		Serialize,
+		Deserialize,
+	)]
+	pub struct RoundDurations<BlockNumber> {
+		pub evaluation: Option<BlockNumber>,
+		pub auction: Option<BlockNumber>,
+		pub community: Option<BlockNumber>,
+		pub remainder: Option<BlockNumber>,
+	}
+
+	/// A round where only the DIDs allow-listed by the issuer can buy, at a fixed price, before the auction.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
+	)]
+	pub struct PrivateRoundConfig<Price> {
+		/// Percentage of the total allocation of Contribution Tokens available for the Private Round.
+		/// Tokens left unsold are added to the Community Round.
+		pub allocation_percentage: Percent,
+		/// The fixed price per token in USD, decimal-aware.
+		pub price: Price,
+	}
+
+	/// Maximum amount of jurisdictions a project can allow or deny.
+	pub const MAX_JURISDICTIONS: u32 = 64;
+
+	/// Jurisdictions the issuer of a project can or cannot sell to, as uppercase ISO 3166 codes. A country code also
+	/// covers its subdivisions, e.g. `US` covers `US-NY`.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize)]
+	pub enum JurisdictionRestriction {
+		/// Only investors subject to one of these jurisdictions can participate.
+		AllowList(BoundedVec<Jurisdiction, ConstU32<MAX_JURISDICTIONS>>),
+		/// Investors subject to one of these jurisdictions cannot participate.
+		DenyList(BoundedVec<Jurisdiction, ConstU32<MAX_JURISDICTIONS>>),
+	}
+
+	impl JurisdictionRestriction {
+		pub fn is_valid(&self) -> bool {
+			!self.jurisdictions().is_empty()
+		}
+
+		/// Whether an investor subject to `jurisdiction` can participate. Investors whose credential does not say
+		/// their jurisdiction cannot.
+		pub fn allows(&self, jurisdiction: Option<&Jurisdiction>) -> bool {
+			let Some(jurisdiction) = jurisdiction else { return false };
+			let listed = self.jurisdictions().iter().any(|listed| {
+				jurisdiction.starts_with(listed) &&
+					(jurisdiction.len() == listed.len() || jurisdiction.get(listed.len()) == Some(&b'-'))
+			});
+			match self {
+				JurisdictionRestriction::AllowList(_) => listed,
+				JurisdictionRestriction::DenyList(_) => !listed,
+			}
+		}
+
+		fn jurisdictions(&self) -> &[Jurisdiction] {
+			match self {
+				JurisdictionRestriction::AllowList(jurisdictions) | JurisdictionRestriction::DenyList(jurisdictions) =>
+					jurisdictions,
+			}
+		}
+	}
+
+	/// Maximum amount of milestones a project can declare.
+	pub const MAX_MILESTONES: u32 = 10;
+
+	/// A milestone the issuer commits to. Its share of the raise is released from escrow once approved.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
+	)]
+	pub struct Milestone<BlockNumber> {
+		/// Share of the escrowed funding assets released to the issuer on approval.
+		pub release_percentage: Percent,
+		/// Blocks after the funding end within which the milestone has to be approved.
+		pub deadline: BlockNumber,
+	}
+
+	/// Who approves the milestones of a project.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
+	)]
+	pub enum MilestoneApprovers {
+		/// Participants that received CTs on settlement, weighted by the CTs received.
+		CtHolders,
+		/// Evaluators of the project, weighted by the USD they bonded.
+		Evaluators,
+	}
+
+	/// Milestones releasing the raised funding assets from escrow, in order.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize)]
+	pub struct MilestonePlan<BlockNumber> {
+		pub milestones: BoundedVec<Milestone<BlockNumber>, ConstU32<MAX_MILESTONES>>,
+		pub approvers: MilestoneApprovers,
+	}
+
+	impl<BlockNumber: Copy + PartialOrd + Zero> MilestonePlan<BlockNumber> {
+		/// The milestones need to release exactly 100% of the raise, each a non-zero share, before deadlines
+		/// that are strictly increasing.
+		pub fn is_valid(&self) -> bool {
+			let total_percentage = self
+				.milestones
+				.iter()
+				.map(|milestone| u32::from(milestone.release_percentage.deconstruct()))
+				.sum::<u32>();
+			let mut previous_deadline = Zero::zero();
+			let deadlines_increasing = self.milestones.iter().all(|milestone| {
+				let increasing = milestone.deadline > previous_deadline;
+				previous_deadline = milestone.deadline;
+				increasing
+			});
+			total_percentage == 100 &&
+				deadlines_increasing &&
+				self.milestones.iter().all(|milestone| !milestone.release_percentage.is_zero())
+		}
+	}
+
+	/// How the protocol fee of a successful project is paid.
+	#[derive(
+		Default,
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		Eq,
+		PartialEq,
+		RuntimeDebug,
+		MaxEncodedLen,
+		TypeInfo,
+		Serialize,
+		Deserialize,
+	)]
+	pub enum FeeMode {
+		/// The whole fee is paid in CTs, shared between evaluators, liquidity pools and long-term holders.
+		#[default]
+		ContributionTokens,
+		/// This share of the fee is deducted from the funding assets raised and sent to the
+		/// `BlockchainOperationTreasury`, without being split between evaluators, liquidity pools and
+		/// long-term holders. The rest is paid in CTs, and split as usual.
+		FundingAssets(Perquintill),
+	}
+
+	impl FeeMode {
+		/// Share of the fee paid in funding assets.
+		pub fn funding_asset_share(&self) -> Perquintill {
+			match self {
+				FeeMode::ContributionTokens => Perquintill::zero(),
+				FeeMode::FundingAssets(share) => *share,
+			}
+		}
+	}
+
+	/// How the price of the CTs is discovered.
+	#[derive(
+		Default,
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		Eq,
+		PartialEq,
+		RuntimeDebug,
+		MaxEncodedLen,
+		TypeInfo,
+		Serialize,
+		Deserialize,
+	)]
+	pub enum PricingModel {
+		/// Ascending auction. The price starts at the minimum price, and rises by 10% of it each time another 10% of
+		/// the auction allocation is bid.
+		#[default]
+		Buckets,
+		/// Every CT is sold at the minimum price. Bids are accepted first-come-first-served until the auction
+		/// allocation runs out.
+		FixedPrice,
+		/// Every CT is sold at the minimum price, without an auction round.
+		FixedPriceWithoutAuction,
+	}
+
+	impl PriceDiscovery for PricingModel {
+		fn has_auction(&self) -> bool {
+			!matches!(self, PricingModel::FixedPriceWithoutAuction)
+		}
+
+		fn initial_bucket<Price: FixedPointNumber>(
+			&self,
+			auction_allocation_size: Balance,
+			minimum_price: Price,
+		) -> Option<Bucket<Price>> {
+			match self {
+				PricingModel::Buckets => {
+					let delta_amount = Percent::from_percent(10) * auction_allocation_size;
+					let delta_price = minimum_price.saturating_mul(Price::checked_from_rational(1, 10)?);
+					Some(Bucket::new(auction_allocation_size, minimum_price, delta_price, delta_amount))
+				},
+				// A bucket whose price never moves. Bids over the allocation stay in the first bucket, after the
+				// earlier ones.
+				PricingModel::FixedPrice | PricingModel::FixedPriceWithoutAuction =>
+					Some(Bucket::new(auction_allocation_size, minimum_price, Price::zero(), auction_allocation_size)),
+			}
+		}
+	}
+
+	/// Inclusive range of durations an issuer can choose for a round.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
+	)]
+	pub struct DurationBounds<BlockNumber> {
+		pub min: BlockNumber,
+		pub max: BlockNumber,
+	}
+
+	impl<BlockNumber: Copy + PartialOrd> DurationBounds<BlockNumber> {
+		pub const fn new(min: BlockNumber, max: BlockNumber) -> Self {
+			Self { min, max }
+		}
+
+		pub fn contains(&self, maybe_duration: Option<BlockNumber>) -> bool {
+			maybe_duration.map_or(true, |duration| self.min <= duration && duration <= self.max)
+		}
+	}
+
+	/// Bounds set by governance on the round durations issuers can choose.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
+	)]
+	pub struct RoundDurationBounds<BlockNumber> {
+		pub evaluation: DurationBounds<BlockNumber>,
+		pub auction: DurationBounds<BlockNumber>,
+		pub community: DurationBounds<BlockNumber>,
+		pub remainder: DurationBounds<BlockNumber>,
+	}
+
+	impl<BlockNumber: Copy + PartialOrd + Zero> RoundDurationBounds<BlockNumber> {
+		/// Every round needs to last at least one block, and each minimum cannot be above its maximum.
+		pub fn is_valid(&self) -> bool {
+			[self.evaluation, self.auction, self.community, self.remainder]
+				.iter()
+				.all(|bounds| bounds.min > Zero::zero() && bounds.min <= bounds.max)
+		}
+
+		pub fn contains(&self, durations: &RoundDurations<BlockNumber>) -> bool {
+			self.evaluation.contains(durations.evaluation) &&
+				self.auction.contains(durations.auction) &&
+				self.community.contains(durations.community) &&
+				self.remainder.contains(durations.remainder)
+		}
+	}
+
+	/// Bounds set by governance on the soft cap issuers can choose, as a share of the funding target.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
+	)]
+	pub struct SoftCapBounds {
+		pub min: Perquintill,
+		pub max: Perquintill,
+	}
+
+	impl SoftCapBounds {
+		pub const fn new(min: Perquintill, max: Perquintill) -> Self {
+			Self { min, max }
+		}
+
+		/// A project cannot succeed without raising anything, and the minimum cannot be above the maximum.
+		pub fn is_valid(&self) -> bool {
+			!self.min.is_zero() && self.min <= self.max
+		}
+
+		pub fn contains(&self, maybe_soft_cap: Option<Perquintill>) -> bool {
+			maybe_soft_cap.map_or(true, |soft_cap| self.min <= soft_cap && soft_cap <= self.max)
+		}
+	}
+
+	#[derive(
+		Default,
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		Eq,
+		PartialEq,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+		Serialize,
+		Deserialize,
+	)]
 	pub enum BidStatus {
 		/// The bid is not yet accepted or rejected
 		#[default]
@@ -719,7 +1324,7 @@ pub mod inner {
 		/// The bid is rejected because the ct tokens ran out
 		Rejected,
 		/// The bid is partially accepted as there were not enough tokens to fill the full bid
-		PartiallyAccepted(Balance),
+		PartiallyAccepted(#[serde(with = "serde_balance")] Balance),
 	}
 
 	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -729,26 +1334,34 @@ pub mod inner {
 		pub duration: BlockNumber,
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize)]
 	pub struct EvaluationRoundInfo {
+		#[serde(with = "serde_balance")]
 		pub total_bonded_usd: Balance,
+		#[serde(with = "serde_balance")]
 		pub total_bonded_plmc: Balance,
 		pub evaluators_outcome: Option<EvaluatorsOutcome>,
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize)]
 	pub enum EvaluatorsOutcome {
 		Rewarded(RewardInfo),
 		Slashed,
 	}
 
-	#[derive(Default, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(
+		Default, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+	)]
 	pub struct RewardInfo {
 		// Total "Early Evaluators" rewards amount in Contribution Tokens
+		#[serde(with = "serde_balance")]
 		pub early_evaluator_reward_pot: Balance,
 		// Total "Normal Evaluators" rewards amount in Contribution Tokens
+		#[serde(with = "serde_balance")]
 		pub normal_evaluator_reward_pot: Balance,
+		#[serde(with = "serde_balance")]
 		pub early_evaluator_total_bonded_usd: Balance,
+		#[serde(with = "serde_balance")]
 		pub normal_evaluator_total_bonded_usd: Balance,
 	}
 
@@ -758,7 +1371,9 @@ pub mod inner {
 		RejectFunding,
 	}
 
-	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+	)]
 	pub struct PalletMigrationReadinessCheck {
 		pub holding_check: (QueryId, CheckOutcome),
 		pub pallet_check: (QueryId, CheckOutcome),
@@ -772,20 +1387,26 @@ pub mod inner {
 	}
 
 	pub type PalletIndex = u8;
-	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+	)]
 	pub enum CheckOutcome {
 		AwaitingResponse,
 		Passed(Option<PalletIndex>),
 		Failed,
 	}
 
-	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+	)]
 	pub struct HRMPChannelStatus {
 		pub project_to_polimec: ChannelStatus,
 		pub polimec_to_project: ChannelStatus,
 	}
 
-	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+	)]
 	pub enum ChannelStatus {
 		/// hrmp channel is closed.
 		Closed,
@@ -795,6 +1416,16 @@ pub mod inner {
 		AwaitingAcceptance,
 	}
 
+	/// Proof that a participant controls the account it registered to receive its migrated CTs.
+	/// Signs the message built by [`migration_destination_message`](crate::Pallet::migration_destination_message).
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum MigrationDestinationSignature {
+		/// Signature of an `AccountId32` destination, made with its sr25519, ed25519 or ecdsa key.
+		Substrate(MultiSignature),
+		/// `personal_sign` signature of an `AccountKey20` destination, as produced by Ethereum wallets.
+		Ethereum([u8; 65]),
+	}
+
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct ProjectMigrationOrigins<ProjectId, MigrationOrigins> {
 		pub project_id: ProjectId,
@@ -808,7 +1439,7 @@ pub mod extrinsic {
 		TicketSize,
 	};
 	use frame_system::pallet_prelude::BlockNumberFor;
-	use polimec_common::credentials::{Cid, Did, InvestorType};
+	use polimec_common::credentials::{Cid, Did, InvestorType, Jurisdiction};
 
 	pub struct DoBidParams<T: Config> {
 		pub bidder: AccountIdOf<T>,
@@ -819,6 +1450,8 @@ pub mod extrinsic {
 		pub did: Did,
 		pub investor_type: InvestorType,
 		pub whitelisted_policy: Cid,
+		pub jurisdiction: Option<Jurisdiction>,
+		pub max_usd: Option<Balance>,
 	}
 
 	pub struct DoPerformBidParams<T: Config> {
@@ -834,6 +1467,7 @@ pub mod extrinsic {
 		pub metadata_ticket_size_bounds: TicketSize,
 		pub total_bids_by_bidder: u32,
 		pub total_bids_for_project: u32,
+		pub bucket_index: u32,
 	}
 
 	pub struct DoContributeParams<T: Config> {
@@ -845,6 +1479,8 @@ pub mod extrinsic {
 		pub did: Did,
 		pub investor_type: InvestorType,
 		pub whitelisted_policy: Cid,
+		pub jurisdiction: Option<Jurisdiction>,
+		pub max_usd: Option<Balance>,
 	}
 
 	pub struct DoPerformContributionParams<'a, T: Config> {
@@ -852,11 +1488,15 @@ pub mod extrinsic {
 		pub project_id: ProjectId,
 		pub project_details: &'a mut ProjectDetailsOf<T>,
 		pub buyable_tokens: Balance,
+		pub ct_usd_price: PriceOf<T>,
+		pub private_round: bool,
 		pub multiplier: MultiplierOf<T>,
 		pub funding_asset: AcceptedFundingAsset,
 		pub investor_type: InvestorType,
 		pub did: Did,
 		pub whitelisted_policy: Cid,
+		pub jurisdiction: Option<Jurisdiction>,
+		pub max_usd: Option<Balance>,
 	}
 
 	pub struct BidRefund<T: Config> {
@@ -866,3 +1506,58 @@ pub mod extrinsic {
 		pub refunded_funding_asset_amount: Balance,
 	}
 }
+
+/// Serializes balances as decimal strings, since JSON numbers cannot hold every `u128` precisely. Balances are
+/// deserialized from strings or numbers. Use with `#[serde(with = "serde_balance")]`.
+pub mod serde_balance {
+	use crate::Balance;
+	use core::fmt;
+	use serde::{de, Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer>(balance: &Balance, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(balance)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Balance, D::Error> {
+		deserializer.deserialize_any(BalanceVisitor)
+	}
+
+	struct BalanceVisitor;
+
+	impl<'de> de::Visitor<'de> for BalanceVisitor {
+		type Value = Balance;
+
+		fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+			formatter.write_str("a balance as a decimal string or a number")
+		}
+
+		fn visit_str<E: de::Error>(self, value: &str) -> Result<Balance, E> {
+			value.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+		}
+
+		fn visit_u64<E: de::Error>(self, value: u64) -> Result<Balance, E> {
+			Ok(value.into())
+		}
+
+		fn visit_u128<E: de::Error>(self, value: u128) -> Result<Balance, E> {
+			Ok(value)
+		}
+	}
+
+	/// Like [`serde_balance`](self), for optional balances.
+	pub mod option {
+		use super::*;
+		use serde::{Deserialize, Serialize};
+
+		#[derive(Serialize, Deserialize)]
+		struct Wrapper(#[serde(with = "super")] Balance);
+
+		pub fn serialize<S: Serializer>(balance: &Option<Balance>, serializer: S) -> Result<S::Ok, S::Error> {
+			balance.map(Wrapper).serialize(serializer)
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Balance>, D::Error> {
+			Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(balance)| balance))
+		}
+	}
+}
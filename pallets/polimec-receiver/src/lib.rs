@@ -30,20 +30,19 @@ pub mod pallet {
 		traits::{tokens::Balance, Currency, ExistenceRequirement::KeepAlive, VestingSchedule},
 	};
 	use frame_system::pallet_prelude::*;
-	use polimec_common::migration_types::{Migration, MigrationInfo, MigrationOrigin, Migrations, ParticipationType};
+	use polimec_common::migration_types::{
+		v1, Migration, MigrationInfo, MigrationOrigin, Migrations, ParticipationType,
+	};
 	use polkadot_parachain_primitives::primitives::{Id as ParaId, Sibling};
 	use sp_runtime::traits::{AccountIdConversion, Convert};
 	use sp_std::prelude::*;
-	use xcm::v4::{Junction::AccountId32, Location};
+	use xcm::v4::Location;
+	use xcm_executor::traits::ConvertLocation;
 
 	type MomentOf<T> = <<T as Config>::Vesting as VestingSchedule<<T as frame_system::Config>::AccountId>>::Moment;
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config
-	where
-		// Used for converting a polimec account into a local account for Contribution Token migrations
-		Self::AccountId: From<[u8; 32]>,
-	{
+	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type PolimecParaId: Get<ParaId>;
 		type RuntimeOrigin: IsType<<Self as frame_system::Config>::RuntimeOrigin>
@@ -53,7 +52,12 @@ pub mod pallet {
 		type Balance: Balance + From<u128> + MaybeSerializeDeserialize;
 		type GenesisMoment: Get<MomentOf<Self>>;
 		type MigrationInfoToPerBlockBalance: Convert<MigrationInfo, Self::Balance>;
+		/// Moment at which the vesting of a migration with a CT release policy starts, given the policy's cliff.
+		type CliffToStartingMoment: Convert<u64, MomentOf<Self>>;
 		type MaxMigrations: Get<u128>;
+		/// Converts the location a participant chose on Polimec to receive its CTs, into a local account.
+		/// e.g. `AccountId32Aliases` on Substrate chains, or `AccountKey20Aliases` on EVM chains.
+		type UserLocationToAccountId: ConvertLocation<Self::AccountId>;
 	}
 
 	#[pallet::pallet]
@@ -74,10 +78,7 @@ pub mod pallet {
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config>
-	where
-		T::AccountId: From<[u8; 32]>,
-	{
+	pub enum Event<T: Config> {
 		/// A Migration executed sucessfully
 		MigrationExecuted { migration: Migration },
 		/// A Migration was found which was already executed, and was skipped.
@@ -88,19 +89,33 @@ pub mod pallet {
 	pub enum Error<T> {
 		NoneValue,
 		StorageOverflow,
+		/// The location of the migration's user cannot be converted into a local account.
+		UnsupportedUserLocation,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> where T::AccountId: From<[u8; 32]> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T>
-	where
-		T::AccountId: From<[u8; 32]>,
-	{
+	impl<T: Config> Pallet<T> {
+		/// Executes migrations encoded in the first layout of the message, still sent by Polimec whenever they fit in it.
 		#[pallet::call_index(0)]
 		#[pallet::weight(Weight::from_parts(10_000, 0))]
-		pub fn execute_migrations(origin: OriginFor<T>, migrations: Migrations) -> DispatchResult {
+		pub fn execute_migrations(origin: OriginFor<T>, migrations: v1::Migrations) -> DispatchResult {
+			Self::do_execute_migrations(origin, migrations.into())
+		}
+
+		/// Executes migrations with a CT release policy, a participation type added after the first layout of the
+		/// message, or a user with a non-`AccountId32` location.
+		#[pallet::call_index(1)]
+		#[pallet::weight(Weight::from_parts(10_000, 0))]
+		pub fn execute_migrations_v2(origin: OriginFor<T>, migrations: Migrations) -> DispatchResult {
+			Self::do_execute_migrations(origin, migrations)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn do_execute_migrations(origin: OriginFor<T>, migrations: Migrations) -> DispatchResult {
 			let para_id = ensure_sibling_para(<T as Config>::RuntimeOrigin::from(origin))?;
 			let polimec_id = T::PolimecParaId::get();
 			let polimec_sovereign_account = Sibling(polimec_id).into_account_truncating();
@@ -109,14 +124,11 @@ pub mod pallet {
 
 			for migration @ Migration {
 				origin: MigrationOrigin { user, id, participation_type },
-				info: MigrationInfo { contribution_token_amount, .. },
-				..
+				info: MigrationInfo { contribution_token_amount, release_policy, .. },
 			} in migrations.inner().iter()
 			{
-				let user_32 = match user.unpack() {
-					(0, [AccountId32 { id, .. }]) => Ok(*id),
-					_ => Err(Error::<T>::NoneValue),
-				}?;
+				let user_account =
+					T::UserLocationToAccountId::convert_location(user).ok_or(Error::<T>::UnsupportedUserLocation)?;
 
 				if ExecutedMigrations::<T>::get((&user, &participation_type, &id)) {
 					Self::deposit_event(Event::DuplicatedMigrationSkipped { migration: migration.clone() });
@@ -125,16 +137,25 @@ pub mod pallet {
 
 				T::Balances::transfer(
 					&polimec_sovereign_account,
-					&user_32.into(),
+					&user_account,
 					(*contribution_token_amount).into(),
 					KeepAlive,
 				)?;
-				T::Vesting::add_vesting_schedule(
-					&user_32.into(),
-					(*contribution_token_amount).into(),
-					T::MigrationInfoToPerBlockBalance::convert(migration.info.clone()),
-					T::GenesisMoment::get(),
-				)?;
+				// With a release policy, the part unlocked at the TGE is left free, and the rest vests linearly
+				// once the cliff is over.
+				let (locked_amount, per_block, starting_moment) = match release_policy {
+					Some(policy) => {
+						let (_, locked_amount) = policy.split(*contribution_token_amount);
+						let per_block = locked_amount.div_ceil(u128::from(policy.duration.max(1)));
+						(locked_amount, per_block.into(), T::CliffToStartingMoment::convert(policy.cliff))
+					},
+					None => (
+						*contribution_token_amount,
+						T::MigrationInfoToPerBlockBalance::convert(migration.info.clone()),
+						T::GenesisMoment::get(),
+					),
+				};
+				T::Vesting::add_vesting_schedule(&user_account, locked_amount.into(), per_block, starting_moment)?;
 				ExecutedMigrations::<T>::insert((&user, &participation_type, &id), true);
 				Self::deposit_event(Event::MigrationExecuted { migration: migration.clone() });
 			}
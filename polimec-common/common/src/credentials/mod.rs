@@ -27,7 +27,9 @@ pub use jwt_compact::{
 };
 use serde::Deserializer;
 
-#[derive(Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo, Deserialize, Serialize)]
+#[derive(
+	Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo, MaxEncodedLen, Deserialize, Serialize,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum InvestorType {
 	Retail,
@@ -63,18 +65,150 @@ pub struct SampleClaims<AccountId> {
 	pub investor_type: InvestorType,
 	#[serde(deserialize_with = "from_bounded_did")]
 	pub did: Did,
+	/// Unique identifier of the token, so that the verifier can revoke it.
+	#[serde(rename = "jti", default, deserialize_with = "from_optional_bounded_token_id")]
+	pub token_id: Option<TokenId>,
+	/// Jurisdiction the investor is subject to, as an uppercase ISO 3166 code: a country (e.g. `CH`) or a
+	/// subdivision (e.g. `US-NY`). Projects can restrict their participants by it.
+	#[serde(default, deserialize_with = "from_optional_bounded_jurisdiction")]
+	pub jurisdiction: Option<Jurisdiction>,
+	/// Makes the token single-use: the calls consuming it reject it once its nonce was used.
+	#[serde(default, deserialize_with = "from_optional_bounded_nonce")]
+	pub nonce: Option<Nonce>,
+	/// The only project the token can be used to participate in.
+	#[serde(default)]
+	pub project_id: Option<u32>,
+	/// The maximum USD amount, with `USD_DECIMALS` decimals, of a participation made with the token.
+	#[serde(default)]
+	pub max_usd: Option<u128>,
 }
 
 pub type Did = BoundedVec<u8, ConstU32<57>>;
 pub type Cid = BoundedVec<u8, ConstU32<96>>;
+pub type TokenId = BoundedVec<u8, ConstU32<64>>;
+pub type Jurisdiction = BoundedVec<u8, ConstU32<6>>;
+pub type Nonce = BoundedVec<u8, ConstU32<64>>;
 
-pub struct EnsureInvestor<T>(sp_std::marker::PhantomData<T>);
-impl<T> EnsureOriginWithCredentials<T::RuntimeOrigin> for EnsureInvestor<T>
+/// The calls a token can be used for, set by its optional `nonce`, `project_id` and `max_usd` claims.
+#[derive(Clone, Default, Eq, PartialEq, RuntimeDebug)]
+pub struct CredentialScope {
+	/// The nonce of a single-use token.
+	pub nonce: Option<Nonce>,
+	/// The moment (in milliseconds) the token expires. Its nonce does not need to be remembered after it.
+	pub expires_at: u64,
+	/// The only project the token can be used to participate in.
+	pub project_id: Option<u32>,
+	/// The maximum USD amount of a participation made with the token.
+	pub max_usd: Option<u128>,
+}
+
+impl CredentialScope {
+	#[must_use]
+	pub fn allows_project(&self, project_id: u32) -> bool {
+		self.project_id.map_or(true, |scope_project_id| scope_project_id == project_id)
+	}
+
+	#[must_use]
+	pub fn allows_usd(&self, usd_amount: u128) -> bool {
+		self.max_usd.map_or(true, |max_usd| usd_amount <= max_usd)
+	}
+}
+
+/// Credentials revoked by the verifier before they expired, e.g. after a KYC provider revoked or downgraded an
+/// investor. Checked by [`EnsureInvestor`] on every token it accepts.
+pub trait CredentialRevocations {
+	/// Whether the token of `did`, with the `jti` claim `token_id`, issued at the moment `issued_at` (in
+	/// milliseconds) was revoked.
+	fn is_revoked(did: &Did, token_id: Option<&TokenId>, issued_at: Option<u64>) -> bool;
+}
+
+impl CredentialRevocations for () {
+	fn is_revoked(_did: &Did, _token_id: Option<&TokenId>, _issued_at: Option<u64>) -> bool {
+		false
+	}
+}
+
+pub type KeyId = BoundedVec<u8, ConstU32<64>>;
+pub type Issuer = BoundedVec<u8, ConstU32<64>>;
+
+/// A key a verifier signs credentials with. Credentials point to it with their `kid` header.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VerifierKey {
+	/// The Ed25519 public key verifying the signature of the credentials.
+	pub public_key: [u8; 32],
+	/// The `iss` claim of the credentials signed with the key.
+	pub issuer: Issuer,
+	/// First moment (in milliseconds) the key is valid.
+	pub valid_from: u64,
+	/// Moment (in milliseconds) the key stops being valid, if any.
+	pub valid_until: Option<u64>,
+}
+
+impl VerifierKey {
+	#[must_use]
+	pub fn is_valid_at(&self, now: u64) -> bool {
+		self.valid_from <= now && self.valid_until.map_or(true, |valid_until| now < valid_until)
+	}
+}
+
+/// The keys trusted to sign credentials, besides the `verifying_key` given to
+/// [`EnsureOriginWithCredentials::try_origin`].
+pub trait VerifierKeys {
+	/// The key with the id `key_id`, if it is trusted right now.
+	fn trusted_key(key_id: &[u8]) -> Option<VerifierKey>;
+
+	/// Whether credentials without a `kid` header can still be verified with the `verifying_key`.
+	fn accepts_default_key() -> bool;
+}
+
+impl VerifierKeys for () {
+	fn trusted_key(_key_id: &[u8]) -> Option<VerifierKey> {
+		None
+	}
+
+	fn accepts_default_key() -> bool {
+		true
+	}
+}
+
+/// The nonces of the single-use tokens already used. [`EnsureInvestor`] rejects the tokens whose nonce was used, and
+/// the calls consuming a token mark its nonce as used. Nonces are kept per DID, so that the tokens of different
+/// investors cannot collide.
+pub trait CredentialNonces {
+	/// Whether `nonce` was already used by `did`.
+	fn is_used(did: &Did, nonce: &Nonce) -> bool;
+
+	/// Marks `nonce` as used by `did`, until the token expires at `expires_at` (in milliseconds). Returns `false` if
+	/// it was already used.
+	fn use_nonce(did: &Did, nonce: &Nonce, expires_at: u64) -> bool;
+}
+
+/// Does not keep track of nonces, so tokens are never single-use.
+impl CredentialNonces for () {
+	fn is_used(_did: &Did, _nonce: &Nonce) -> bool {
+		false
+	}
+
+	fn use_nonce(_did: &Did, _nonce: &Nonce, _expires_at: u64) -> bool {
+		true
+	}
+}
+
+pub struct EnsureInvestor<T, Revocations = (), Keys = (), Nonces = ()>(
+	sp_std::marker::PhantomData<(T, Revocations, Keys, Nonces)>,
+);
+impl<T, Revocations, Keys, Nonces> EnsureOriginWithCredentials<T::RuntimeOrigin>
+	for EnsureInvestor<T, Revocations, Keys, Nonces>
 where
 	T: frame_system::Config + pallet_timestamp::Config,
+	Revocations: CredentialRevocations,
+	Keys: VerifierKeys,
+	Nonces: CredentialNonces,
 {
 	type Claims = SampleClaims<T::AccountId>;
-	type Success = (T::AccountId, Did, InvestorType, Cid);
+	type Success = (T::AccountId, Did, InvestorType, Cid, Option<Jurisdiction>, CredentialScope);
+	type VerifierKeys = Keys;
+	type Nonces = Nonces;
 
 	fn try_origin(
 		origin: T::RuntimeOrigin,
@@ -82,13 +216,23 @@ where
 		verifying_key: [u8; 32],
 	) -> Result<Self::Success, T::RuntimeOrigin> {
 		let Some(who) = origin.clone().into_signer() else { return Err(origin) };
-		let Ok(token) = Self::verify_token(token, verifying_key) else { return Err(origin) };
+		let Ok((token, issuer)) = Self::verify_token(token, verifying_key) else { return Err(origin) };
 		let claims = token.claims();
+		if issuer.is_some_and(|issuer| claims.custom.issuer.as_bytes() != issuer.as_slice()) {
+			return Err(origin);
+		}
 		// Get the current timestamp from the pallet_timestamp. It is in milliseconds.
 		let Ok(now) = Now::<T>::get().try_into() else { return Err(origin) };
 		let Some(date_time) = claims.expiration else { return Err(origin) };
 
 		let timestamp: u64 = date_time.timestamp_millis().try_into().map_err(|_| origin.clone())?;
+		let issued_at = claims.issued_at.and_then(|date_time| date_time.timestamp_millis().try_into().ok());
+		if Revocations::is_revoked(&claims.custom.did, claims.custom.token_id.as_ref(), issued_at) {
+			return Err(origin);
+		}
+		if claims.custom.nonce.as_ref().is_some_and(|nonce| Nonces::is_used(&claims.custom.did, nonce)) {
+			return Err(origin);
+		}
 
 		if claims.custom.subject == who && timestamp >= now {
 			return Ok((
@@ -96,6 +240,13 @@ where
 				claims.custom.did.clone(),
 				claims.custom.investor_type.clone(),
 				claims.custom.ipfs_cid.clone(),
+				claims.custom.jurisdiction.clone(),
+				CredentialScope {
+					nonce: claims.custom.nonce.clone(),
+					expires_at: timestamp,
+					project_id: claims.custom.project_id,
+					max_usd: claims.custom.max_usd,
+				},
 			));
 		}
 
@@ -110,6 +261,8 @@ where
 {
 	type Success;
 	type Claims: Clone + Encode + Decode + Eq + PartialEq + Ord + PartialOrd + TypeInfo + DeserializeOwned;
+	type VerifierKeys: VerifierKeys;
+	type Nonces: CredentialNonces;
 
 	fn try_origin(
 		origin: OuterOrigin,
@@ -125,13 +278,25 @@ where
 		Self::try_origin(origin, token, verifying_key).map_err(|_| BadOrigin)
 	}
 
+	/// Verifies the token with the trusted key its `kid` header points to, or with `verifying_key` if it has none.
+	/// Returns the token, and the `iss` claim it must have if it was signed with a trusted key.
 	fn verify_token(
 		token: &jwt_compact::UntrustedToken,
 		verifying_key: [u8; 32],
-	) -> Result<jwt_compact::Token<Self::Claims>, ValidationError> {
-		let signing_key =
-			<<Ed25519 as Algorithm>::VerifyingKey>::from_slice(&verifying_key).expect("The Key is always valid");
-		Ed25519.validator::<Self::Claims>(&signing_key).validate(token)
+	) -> Result<(jwt_compact::Token<Self::Claims>, Option<Issuer>), ValidationError> {
+		// A token signed with an unknown, expired or retired key cannot have a valid signature.
+		let (verifying_key, issuer) = match token.header().key_id.as_deref() {
+			Some(key_id) => {
+				let key =
+					Self::VerifierKeys::trusted_key(key_id.as_bytes()).ok_or(ValidationError::InvalidSignature)?;
+				(key.public_key, Some(key.issuer))
+			},
+			None if Self::VerifierKeys::accepts_default_key() => (verifying_key, None),
+			None => return Err(ValidationError::InvalidSignature),
+		};
+		let signing_key = <<Ed25519 as Algorithm>::VerifyingKey>::from_slice(&verifying_key)
+			.map_err(|_| ValidationError::InvalidSignature)?;
+		Ed25519.validator::<Self::Claims>(&signing_key).validate(token).map(|token| (token, issuer))
 	}
 }
 
@@ -153,6 +318,39 @@ where
 		.and_then(|vec| vec.try_into().map_err(|_| Error::custom("failed to deserialize")))
 }
 
+pub fn from_optional_bounded_token_id<'de, D>(deserializer: D) -> Result<Option<TokenId>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	Option::<String>::deserialize(deserializer).and_then(|maybe_string| {
+		maybe_string
+			.map(|string| string.as_bytes().to_vec().try_into().map_err(|_| Error::custom("failed to deserialize")))
+			.transpose()
+	})
+}
+
+pub fn from_optional_bounded_nonce<'de, D>(deserializer: D) -> Result<Option<Nonce>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	Option::<String>::deserialize(deserializer).and_then(|maybe_string| {
+		maybe_string
+			.map(|string| string.as_bytes().to_vec().try_into().map_err(|_| Error::custom("failed to deserialize")))
+			.transpose()
+	})
+}
+
+pub fn from_optional_bounded_jurisdiction<'de, D>(deserializer: D) -> Result<Option<Jurisdiction>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	Option::<String>::deserialize(deserializer).and_then(|maybe_string| {
+		maybe_string
+			.map(|string| string.as_bytes().to_vec().try_into().map_err(|_| Error::custom("failed to deserialize")))
+			.transpose()
+	})
+}
+
 impl<AccountId> Serialize for SampleClaims<AccountId>
 where
 	AccountId: Serialize, // Ensure AccountId can be serialized
@@ -162,7 +360,7 @@ where
 		S: Serializer,
 	{
 		// Define how many fields we are serializing.
-		let mut state = serializer.serialize_struct("SampleClaims", 5)?;
+		let mut state = serializer.serialize_struct("SampleClaims", 10)?;
 
 		// Serialize each field.
 		// Fields like `subject`, `issuer`, and `investor_type` can be serialized directly.
@@ -182,6 +380,28 @@ where
 		let did_string = String::from_utf8_lossy(&did_bytes); // Convert Vec<u8> to String
 		state.serialize_field("did", &did_string)?;
 
+		// The `jti` is optional, so tokens without it are serialized without the field.
+		match &self.token_id {
+			Some(token_id) => state.serialize_field("jti", &String::from_utf8_lossy(token_id))?,
+			None => state.skip_field("jti")?,
+		}
+		match &self.jurisdiction {
+			Some(jurisdiction) => state.serialize_field("jurisdiction", &String::from_utf8_lossy(jurisdiction))?,
+			None => state.skip_field("jurisdiction")?,
+		}
+		match &self.nonce {
+			Some(nonce) => state.serialize_field("nonce", &String::from_utf8_lossy(nonce))?,
+			None => state.skip_field("nonce")?,
+		}
+		match &self.project_id {
+			Some(project_id) => state.serialize_field("project_id", project_id)?,
+			None => state.skip_field("project_id")?,
+		}
+		match &self.max_usd {
+			Some(max_usd) => state.serialize_field("max_usd", max_usd)?,
+			None => state.skip_field("max_usd")?,
+		}
+
 		// End the serialization
 		state.end()
 	}
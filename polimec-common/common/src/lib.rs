@@ -104,6 +104,8 @@ pub trait ReleaseSchedule<AccountId, Reason> {
 pub mod migration_types {
 	#[allow(clippy::wildcard_imports)]
 	use super::*;
+	use serde::{Deserialize, Serialize};
+	use sp_runtime::Percent;
 
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct MigrationOrigin {
@@ -131,16 +133,64 @@ pub mod migration_types {
 		Evaluation,
 		Bid,
 		Contribution,
+		PrivateContribution,
+		/// Share of the long-term-holder CT pot claimed by a participant.
+		LongTermHolderReward,
+		/// The liquidity-pool CT pot of a project.
+		LiquidityPools,
+	}
+
+	/// How the contribution tokens of a project are released after the TGE, as chosen by its issuer.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+	)]
+	pub struct CtReleasePolicy {
+		/// Blocks after the TGE during which nothing besides the `tge_unlock` is released.
+		pub cliff: u64,
+		/// Blocks over which the locked tokens are released linearly once the cliff is over.
+		pub duration: u64,
+		/// Part of the tokens that is free right at the TGE.
+		pub tge_unlock: Option<Percent>,
+	}
+
+	impl CtReleasePolicy {
+		pub fn is_valid(&self) -> bool {
+			self.duration > 0
+		}
+
+		/// Splits `amount` into the tokens free at the TGE, and the ones to be vested.
+		pub fn split(&self, amount: u128) -> (u128, u128) {
+			let unlocked = self.tge_unlock.unwrap_or_default() * amount;
+			(unlocked, amount.saturating_sub(unlocked))
+		}
 	}
 
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct MigrationInfo {
 		pub contribution_token_amount: u128,
 		pub vesting_time: u64,
+		/// If set, replaces the `vesting_time` of the participation's multiplier.
+		pub release_policy: Option<CtReleasePolicy>,
 	}
 	impl From<(u128, u64)> for MigrationInfo {
 		fn from((contribution_token_amount, vesting_time): (u128, u64)) -> Self {
-			Self { contribution_token_amount, vesting_time }
+			Self { contribution_token_amount, vesting_time, release_policy: None }
+		}
+	}
+	impl From<(u128, u64, Option<CtReleasePolicy>)> for MigrationInfo {
+		fn from(
+			(contribution_token_amount, vesting_time, release_policy): (u128, u64, Option<CtReleasePolicy>),
+		) -> Self {
+			Self { contribution_token_amount, vesting_time, release_policy }
+		}
+	}
+	impl MigrationInfo {
+		/// Blocks after the TGE until all the contribution tokens are released.
+		pub fn vesting_end(&self) -> u64 {
+			match self.release_policy {
+				Some(policy) => policy.cliff.saturating_add(policy.duration),
+				None => self.vesting_time,
+			}
 		}
 	}
 
@@ -214,7 +264,92 @@ pub mod migration_types {
 		}
 
 		pub fn biggest_vesting_time(&self) -> u64 {
-			self.0.iter().map(|migration| migration.info.vesting_time).max().unwrap_or(0)
+			self.0.iter().map(|migration| migration.info.vesting_end()).max().unwrap_or(0)
+		}
+	}
+
+	/// The first layout of the migrations message, from before CT release policies, the participation types added
+	/// since, and users with a non-`AccountId32` location. Receivers that were not upgraded only decode this one, so it
+	/// is still sent whenever the migrations fit in it.
+	pub mod v1 {
+		#[allow(clippy::wildcard_imports)]
+		use super::*;
+
+		#[derive(
+			Clone, Copy, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo, MaxEncodedLen,
+		)]
+		pub enum ParticipationType {
+			Evaluation,
+			Bid,
+			Contribution,
+		}
+
+		#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+		pub struct MigrationOrigin {
+			pub user: Location,
+			pub id: u32,
+			pub participation_type: ParticipationType,
+		}
+
+		#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+		pub struct MigrationInfo {
+			pub contribution_token_amount: u128,
+			pub vesting_time: u64,
+		}
+
+		#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+		pub struct Migration {
+			pub origin: MigrationOrigin,
+			pub info: MigrationInfo,
+		}
+
+		#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, Default)]
+		pub struct Migrations(pub Vec<Migration>);
+
+		impl TryFrom<super::Migration> for Migration {
+			type Error = ();
+
+			fn try_from(migration: super::Migration) -> Result<Self, Self::Error> {
+				let super::Migration { origin, info } = migration;
+				let participation_type = match origin.participation_type {
+					super::ParticipationType::Evaluation => ParticipationType::Evaluation,
+					super::ParticipationType::Bid => ParticipationType::Bid,
+					super::ParticipationType::Contribution => ParticipationType::Contribution,
+					_ => return Err(()),
+				};
+				if info.release_policy.is_some() ||
+					!matches!(origin.user.unpack(), (0, [xcm::v4::Junction::AccountId32 { .. }]))
+				{
+					return Err(());
+				}
+				Ok(Self {
+					origin: MigrationOrigin { user: origin.user, id: origin.id, participation_type },
+					info: MigrationInfo {
+						contribution_token_amount: info.contribution_token_amount,
+						vesting_time: info.vesting_time,
+					},
+				})
+			}
+		}
+
+		impl From<Migration> for super::Migration {
+			fn from(migration: Migration) -> Self {
+				let participation_type = match migration.origin.participation_type {
+					ParticipationType::Evaluation => super::ParticipationType::Evaluation,
+					ParticipationType::Bid => super::ParticipationType::Bid,
+					ParticipationType::Contribution => super::ParticipationType::Contribution,
+				};
+				let origin =
+					super::MigrationOrigin { user: migration.origin.user, id: migration.origin.id, participation_type };
+				let info = (migration.info.contribution_token_amount, migration.info.vesting_time).into();
+				Self::new(origin, info)
+			}
+		}
+
+		impl From<Migrations> for super::Migrations {
+			fn from(migrations: Migrations) -> Self {
+				migrations.0.into_iter().map(Into::into).collect()
+			}
 		}
 	}
 }
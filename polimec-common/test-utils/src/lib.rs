@@ -18,8 +18,8 @@
 
 use frame_support::{sp_runtime::app_crypto::sp_core::bytes::to_hex, traits::ConstU32, BoundedVec, Parameter};
 use jwt_compact::{alg::Ed25519, AlgorithmExt, Header};
-use parity_scale_codec::alloc::string::ToString;
-use polimec_common::credentials::{Did, InvestorType, SampleClaims, UntrustedToken};
+use parity_scale_codec::alloc::string::{String, ToString};
+use polimec_common::credentials::{Did, InvestorType, SampleClaims, TokenId, UntrustedToken};
 
 /// Fetches a JWT from a dummy Polimec JWT producer that will return a JWT with the specified investor type
 #[cfg(feature = "std")]
@@ -50,46 +50,96 @@ pub fn get_test_jwt<AccountId: core::fmt::Display>(
 	UntrustedToken::new(&jwt).expect("Failed to parse the JWT")
 }
 
+/// The signing key of the mock JWTs, unless [`MockJwtOptions::signing_key`] is set.
+const MOCK_SIGNING_KEY: [u8; 32] = [
+	80, 168, 164, 18, 76, 133, 92, 116, 50, 20, 155, 28, 33, 89, 151, 207, 199, 247, 113, 185, 127, 156, 2, 132, 65,
+	58, 76, 156, 143, 109, 29, 251,
+];
+
+/// The optional parts of a mock JWT. Tests set the ones they need, and keep the rest with `..Default::default()`.
+#[derive(Clone, Default)]
+pub struct MockJwtOptions {
+	/// The `aud` claim. Empty by default.
+	pub ipfs_cid: Option<BoundedVec<u8, ConstU32<96>>>,
+	/// The `jti` claim.
+	pub token_id: Option<TokenId>,
+	/// The `iat` claim, in seconds.
+	pub issued_at: Option<i64>,
+	/// The `iss` claim. `"verifier"` by default.
+	pub issuer: Option<String>,
+	/// The `jurisdiction` claim, e.g. `"CH"` or `"US-NY"`.
+	pub jurisdiction: Option<String>,
+	/// The `nonce` claim, making the JWT single-use.
+	pub nonce: Option<String>,
+	/// The `project_id` claim, binding the JWT to a project.
+	pub project_id: Option<u32>,
+	/// The `max_usd` claim, with `USD_DECIMALS` decimals.
+	pub max_usd: Option<u128>,
+	/// The `kid` header, pointing to the key the JWT is signed with.
+	pub key_id: Option<String>,
+	/// The Ed25519 secret key signing the JWT. Its public key can be derived with [`mock_verifying_key`].
+	pub signing_key: Option<[u8; 32]>,
+}
+
 fn create_jwt<AccountId: frame_support::Serialize>(
 	account_id: AccountId,
 	investor_type: InvestorType,
 	did: BoundedVec<u8, ConstU32<57>>,
-	ipfs_cid: Option<BoundedVec<u8, ConstU32<96>>>,
+	options: MockJwtOptions,
 ) -> UntrustedToken {
 	use chrono::{TimeZone, Utc};
 	use jwt_compact::{alg::SigningKey, Claims};
 
 	// Create a signing key from raw bytes.
-	let key = SigningKey::from_slice(
-		[
-			80, 168, 164, 18, 76, 133, 92, 116, 50, 20, 155, 28, 33, 89, 151, 207, 199, 247, 113, 185, 127, 156, 2,
-			132, 65, 58, 76, 156, 143, 109, 29, 251,
-		]
-		.as_ref(),
-	)
-	.unwrap();
+	let key = SigningKey::from_slice(options.signing_key.unwrap_or(MOCK_SIGNING_KEY).as_ref()).unwrap();
 
-	let header: Header = Header::empty();
+	let mut header: Header = Header::empty();
+	if let Some(key_id) = options.key_id {
+		header = header.with_key_id(key_id);
+	}
 
 	// Handle optional IPFS CID
-	let ipfs_cid = ipfs_cid.unwrap_or_else(|| BoundedVec::with_bounded_capacity(96));
-	let custom_claims =
-		SampleClaims { subject: account_id, investor_type, issuer: "verifier".to_string(), did, ipfs_cid };
+	let ipfs_cid = options.ipfs_cid.unwrap_or_else(|| BoundedVec::with_bounded_capacity(96));
+	let issuer = options.issuer.unwrap_or_else(|| "verifier".to_string());
+	let jurisdiction = options.jurisdiction.map(|jurisdiction| jurisdiction.as_bytes().to_vec().try_into().unwrap());
+	let custom_claims = SampleClaims {
+		subject: account_id,
+		investor_type,
+		issuer,
+		did,
+		ipfs_cid,
+		token_id: options.token_id,
+		jurisdiction,
+		nonce: options.nonce.map(|nonce| nonce.as_bytes().to_vec().try_into().unwrap()),
+		project_id: options.project_id,
+		max_usd: options.max_usd,
+	};
 
 	let mut claims = Claims::new(custom_claims);
 	claims.expiration = Some(Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap());
+	claims.issued_at = options.issued_at.map(|issued_at| Utc.timestamp_opt(issued_at, 0).unwrap());
 
 	let token_string = Ed25519.token(&header, &claims, &key).unwrap();
 	UntrustedToken::new(&token_string).expect("Failed to parse the JWT")
 }
 
+/// The Ed25519 public key of a secret key signing mock JWTs.
+pub fn mock_verifying_key(signing_key: [u8; 32]) -> [u8; 32] {
+	use jwt_compact::{
+		alg::{SigningKey, VerifyingKey},
+		Algorithm,
+	};
+	let key = <<Ed25519 as Algorithm>::SigningKey as SigningKey<Ed25519>>::from_slice(signing_key.as_ref()).unwrap();
+	VerifyingKey::<Ed25519>::as_bytes(&key.to_verifying_key())[..].try_into().unwrap()
+}
+
 // The `Serialize` trait is needed to serialize the `account_id` into a  `SampleClaims` struct.
 pub fn get_mock_jwt<AccountId: frame_support::Serialize>(
 	account_id: AccountId,
 	investor_type: InvestorType,
 	did: BoundedVec<u8, ConstU32<57>>,
 ) -> UntrustedToken {
-	create_jwt(account_id, investor_type, did, None)
+	create_jwt(account_id, investor_type, did, MockJwtOptions::default())
 }
 
 // The `Serialize` trait is needed to serialize the `account_id` into a  `SampleClaims` struct.
@@ -99,7 +149,44 @@ pub fn get_mock_jwt_with_cid<AccountId: frame_support::Serialize>(
 	did: BoundedVec<u8, ConstU32<57>>,
 	ipfs_cid: BoundedVec<u8, ConstU32<96>>,
 ) -> UntrustedToken {
-	create_jwt(account_id, investor_type, did, Some(ipfs_cid))
+	create_jwt(account_id, investor_type, did, MockJwtOptions { ipfs_cid: Some(ipfs_cid), ..Default::default() })
+}
+
+/// Creates a JWT with a `jti` claim, issued at `issued_at` (in seconds, like the `iat` claim). Useful to test
+/// credential revocations.
+// The `Serialize` trait is needed to serialize the `account_id` into a  `SampleClaims` struct.
+pub fn get_mock_jwt_with_token_id<AccountId: frame_support::Serialize>(
+	account_id: AccountId,
+	investor_type: InvestorType,
+	did: BoundedVec<u8, ConstU32<57>>,
+	token_id: TokenId,
+	issued_at: i64,
+) -> UntrustedToken {
+	let options = MockJwtOptions { token_id: Some(token_id), issued_at: Some(issued_at), ..Default::default() };
+	create_jwt(account_id, investor_type, did, options)
+}
+
+/// Creates a JWT with a `jurisdiction` claim, e.g. `"CH"` or `"US-NY"`.
+// The `Serialize` trait is needed to serialize the `account_id` into a  `SampleClaims` struct.
+pub fn get_mock_jwt_with_jurisdiction<AccountId: frame_support::Serialize>(
+	account_id: AccountId,
+	investor_type: InvestorType,
+	did: BoundedVec<u8, ConstU32<57>>,
+	jurisdiction: &str,
+) -> UntrustedToken {
+	let options = MockJwtOptions { jurisdiction: Some(jurisdiction.to_string()), ..Default::default() };
+	create_jwt(account_id, investor_type, did, options)
+}
+
+/// Creates a JWT with any of the optional claims and headers of [`MockJwtOptions`].
+// The `Serialize` trait is needed to serialize the `account_id` into a  `SampleClaims` struct.
+pub fn get_mock_jwt_with_options<AccountId: frame_support::Serialize>(
+	account_id: AccountId,
+	investor_type: InvestorType,
+	did: BoundedVec<u8, ConstU32<57>>,
+	options: MockJwtOptions,
+) -> UntrustedToken {
+	create_jwt(account_id, investor_type, did, options)
 }
 
 /// Fetches a JWT from a dummy Polimec JWT producer that will return a JWT with the specified
@@ -149,7 +236,10 @@ pub fn do_request(url: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-	use crate::{generate_did_from_account, get_mock_jwt, get_mock_jwt_with_cid};
+	use crate::{
+		generate_did_from_account, get_mock_jwt, get_mock_jwt_with_cid, get_mock_jwt_with_jurisdiction,
+		get_mock_jwt_with_options, get_mock_jwt_with_token_id, mock_verifying_key, MockJwtOptions, MOCK_SIGNING_KEY,
+	};
 	use jwt_compact::{
 		alg::{Ed25519, VerifyingKey},
 		AlgorithmExt,
@@ -197,4 +287,85 @@ mod tests {
 		let cid_from_token = std::str::from_utf8(&claims.custom.ipfs_cid).unwrap();
 		assert_eq!(cid_from_token, cid);
 	}
+
+	#[test]
+	fn test_get_test_jwt_with_token_id() {
+		let verifying_key = VerifyingKey::from_slice(
+			[
+				32, 118, 30, 171, 58, 212, 197, 27, 146, 122, 255, 243, 34, 245, 90, 244, 221, 37, 253, 195, 18, 202,
+				111, 55, 39, 48, 123, 17, 101, 78, 215, 94,
+			]
+			.as_ref(),
+		)
+		.unwrap();
+		let token_id = frame_support::BoundedVec::try_from(b"token-1".to_vec()).unwrap();
+		let token = get_mock_jwt_with_token_id(
+			"0x1234",
+			InvestorType::Retail,
+			generate_did_from_account(40u64),
+			token_id.clone(),
+			1_700_000_000,
+		);
+		let res = Ed25519.validator::<SampleClaims<String>>(&verifying_key).validate(&token);
+		assert!(res.is_ok());
+		let validated_token = res.unwrap();
+		let claims = validated_token.claims();
+		assert_eq!(claims.custom.token_id, Some(token_id));
+		assert_eq!(claims.issued_at.unwrap().timestamp(), 1_700_000_000);
+
+		// Tokens without a `jti` are still accepted
+		let token = get_mock_jwt("0x1234", InvestorType::Retail, generate_did_from_account(40u64));
+		let validated_token = Ed25519.validator::<SampleClaims<String>>(&verifying_key).validate(&token).unwrap();
+		assert_eq!(validated_token.claims().custom.token_id, None);
+	}
+
+	#[test]
+	fn test_get_test_jwt_with_key_id() {
+		let signing_key = [7u8; 32];
+		let verifying_key = VerifyingKey::from_slice(mock_verifying_key(signing_key).as_ref()).unwrap();
+		let options = MockJwtOptions {
+			issuer: Some("kyc-provider".to_string()),
+			key_id: Some("kyc-provider-2024".to_string()),
+			signing_key: Some(signing_key),
+			..Default::default()
+		};
+		let token =
+			get_mock_jwt_with_options("0x1234", InvestorType::Retail, generate_did_from_account(40u64), options);
+		assert_eq!(token.header().key_id.as_deref(), Some("kyc-provider-2024"));
+
+		let validated_token = Ed25519.validator::<SampleClaims<String>>(&verifying_key).validate(&token).unwrap();
+		assert_eq!(validated_token.claims().custom.issuer, "kyc-provider");
+	}
+
+	#[test]
+	fn test_get_test_jwt_with_jurisdiction() {
+		let verifying_key = VerifyingKey::from_slice(mock_verifying_key(MOCK_SIGNING_KEY).as_ref()).unwrap();
+		let token =
+			get_mock_jwt_with_jurisdiction("0x1234", InvestorType::Retail, generate_did_from_account(40u64), "US-NY");
+		let validated_token = Ed25519.validator::<SampleClaims<String>>(&verifying_key).validate(&token).unwrap();
+		assert_eq!(validated_token.claims().custom.jurisdiction.as_deref(), Some(b"US-NY".as_slice()));
+
+		// Tokens without a `jurisdiction` are still accepted
+		let token = get_mock_jwt("0x1234", InvestorType::Retail, generate_did_from_account(40u64));
+		let validated_token = Ed25519.validator::<SampleClaims<String>>(&verifying_key).validate(&token).unwrap();
+		assert_eq!(validated_token.claims().custom.jurisdiction, None);
+	}
+
+	#[test]
+	fn test_get_test_jwt_with_scope() {
+		let verifying_key = VerifyingKey::from_slice(mock_verifying_key(MOCK_SIGNING_KEY).as_ref()).unwrap();
+		let options = MockJwtOptions {
+			nonce: Some("nonce-1".to_string()),
+			project_id: Some(7),
+			max_usd: Some(250_000_000_000),
+			..Default::default()
+		};
+		let token =
+			get_mock_jwt_with_options("0x1234", InvestorType::Institutional, generate_did_from_account(40u64), options);
+		let validated_token = Ed25519.validator::<SampleClaims<String>>(&verifying_key).validate(&token).unwrap();
+		let claims = &validated_token.claims().custom;
+		assert_eq!(claims.nonce.as_deref(), Some(b"nonce-1".as_slice()));
+		assert_eq!(claims.project_id, Some(7));
+		assert_eq!(claims.max_usd, Some(250_000_000_000));
+	}
 }
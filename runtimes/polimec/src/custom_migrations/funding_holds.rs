@@ -1,6 +1,6 @@
 use crate::{Balance, Funding, Runtime, RuntimeHoldReason};
 use alloc::vec::Vec;
-use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, VariantCount, VariantCountOf};
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion, VariantCount, VariantCountOf};
 use pallet_balances::IdAmount;
 use pallet_funding::ProjectId;
 use parity_scale_codec::{Decode, Encode};
@@ -82,6 +82,7 @@ impl OnRuntimeUpgrade for FromFundingV4Migration {
 		};
 
 		pallet_balances::Holds::<Runtime>::translate(|key, object: OldHoldsItem| translate(key, object));
+		StorageVersion::new(5).put::<Funding>();
 
 		log::info!("Number of users migrated: {}", items);
 		let weight = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(items, items);
@@ -0,0 +1,793 @@
+//! Migrations of the funding pallet storage from version 5, one storage version per change.
+//! Each step only runs if the on-chain version is the one it migrates from, and then bumps it, so that they can all be
+//! applied in a single runtime upgrade by listing them in order.
+use crate::{xcm_config::default_funding_assets, AccountId, Balance, BlockNumber, Funding, Runtime};
+use alloc::{vec, vec::Vec};
+use frame_support::{
+	traits::{ConstU32, Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use pallet_funding::{
+	AcceptedFundingAsset, BidInfoOf, BidStatus, BiddingTicketSizes, ContributingTicketSizes, ContributionInfoOf,
+	CurrencyMetadata, DefaultFeeParameters, FeeMode, MaxParticipationsPerUser, MilestonePlan, PricingModel,
+	PrivateRoundConfig, ProjectId, ProjectMetadataOf, ProjectStatus, RoundDurations, SettlementCursor,
+	MAX_PARTICIPATION_CURRENCIES,
+};
+use parity_scale_codec::{Decode, Encode};
+use polimec_common::{
+	credentials::{Cid, Did},
+	migration_types::{CtReleasePolicy, Migration, MigrationInfo, MigrationOrigin, MigrationStatus},
+};
+use scale_info::TypeInfo;
+use sp_core::{MaxEncodedLen, RuntimeDebug};
+use sp_runtime::{BoundedVec, FixedU128, Percent, Perquintill};
+use xcm::v4::Location;
+
+type Price = FixedU128;
+type StringLimit = <Runtime as pallet_funding::Config>::StringLimit;
+type Multiplier = <Runtime as pallet_funding::Config>::Multiplier;
+type ParticipationCurrencies = BoundedVec<AcceptedFundingAsset, ConstU32<MAX_PARTICIPATION_CURRENCIES>>;
+
+/// Runs `migrate` if the funding storage is at version `from`, and bumps it to the next version.
+/// `migrate` returns the number of items it read and wrote.
+fn migrate_from(from: u16, name: &str, migrate: impl FnOnce() -> u64) -> Weight {
+	let on_chain_version = Funding::on_chain_storage_version();
+	if on_chain_version != from {
+		log::warn!("Funding {} migration can be removed. Skipping it now...", name);
+		return <Runtime as frame_system::Config>::DbWeight::get().reads(1)
+	}
+
+	let items = migrate();
+	StorageVersion::new(from + 1).put::<Funding>();
+
+	log::info!("Number of funding {} items migrated: {}", name, items);
+	let weight = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(items + 1, items + 1);
+	log::info!("funding {} weight: {:?}", name, weight);
+	weight
+}
+
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum OldAcceptedFundingAsset {
+	#[codec(index = 0)]
+	USDT,
+	#[codec(index = 1)]
+	USDC,
+	#[codec(index = 2)]
+	DOT,
+}
+impl From<OldAcceptedFundingAsset> for AcceptedFundingAsset {
+	fn from(old: OldAcceptedFundingAsset) -> Self {
+		match old {
+			OldAcceptedFundingAsset::USDT => AcceptedFundingAsset::USDT,
+			OldAcceptedFundingAsset::USDC => AcceptedFundingAsset::USDC,
+			OldAcceptedFundingAsset::DOT => AcceptedFundingAsset::DOT,
+		}
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProjectMetadataV5 {
+	pub token_information: CurrencyMetadata<BoundedVec<u8, StringLimit>>,
+	pub mainnet_token_max_supply: Balance,
+	pub total_allocation_size: Balance,
+	pub auction_round_allocation_percentage: Percent,
+	pub minimum_price: Price,
+	pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+	pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
+	pub participation_currencies: BoundedVec<OldAcceptedFundingAsset, ConstU32<3>>,
+	pub funding_destination_account: AccountId,
+	pub policy_ipfs_cid: Option<Cid>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProjectMetadataV6 {
+	pub token_information: CurrencyMetadata<BoundedVec<u8, StringLimit>>,
+	pub mainnet_token_max_supply: Balance,
+	pub total_allocation_size: Balance,
+	pub auction_round_allocation_percentage: Percent,
+	pub minimum_price: Price,
+	pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+	pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
+	pub participation_currencies: ParticipationCurrencies,
+	pub funding_destination_account: AccountId,
+	pub policy_ipfs_cid: Option<Cid>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OldBidInfo {
+	pub id: u32,
+	pub project_id: ProjectId,
+	pub bidder: AccountId,
+	pub did: Did,
+	pub status: BidStatus,
+	#[codec(compact)]
+	pub original_ct_amount: Balance,
+	pub original_ct_usd_price: Price,
+	pub funding_asset: OldAcceptedFundingAsset,
+	pub funding_asset_amount_locked: Balance,
+	pub multiplier: Multiplier,
+	pub plmc_bond: Balance,
+	pub when: BlockNumber,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OldContributionInfo {
+	pub id: u32,
+	pub did: Did,
+	pub project_id: ProjectId,
+	pub contributor: AccountId,
+	pub ct_amount: Balance,
+	pub usd_contribution_amount: Balance,
+	pub multiplier: Multiplier,
+	pub funding_asset: OldAcceptedFundingAsset,
+	pub funding_asset_amount: Balance,
+	pub plmc_bond: Balance,
+	pub when: BlockNumber,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ContributionInfoV6 {
+	pub id: u32,
+	pub did: Did,
+	pub project_id: ProjectId,
+	pub contributor: AccountId,
+	pub ct_amount: Balance,
+	pub usd_contribution_amount: Balance,
+	pub multiplier: Multiplier,
+	pub funding_asset: AcceptedFundingAsset,
+	pub funding_asset_amount: Balance,
+	pub plmc_bond: Balance,
+	pub when: BlockNumber,
+}
+
+/// Moves the accepted funding assets from a hardcoded enum to the `FundingAssets` registry.
+pub struct FundingAssetsMigration;
+impl OnRuntimeUpgrade for FundingAssetsMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(5, "assets", || {
+			let mut items = 0u64;
+			for (asset, info) in default_funding_assets() {
+				items += 2;
+				pallet_funding::FundingAssetLocations::<Runtime>::insert(info.location.clone(), asset);
+				pallet_funding::FundingAssets::<Runtime>::insert(asset, info);
+			}
+
+			pallet_funding::ProjectsMetadata::<Runtime>::translate(|_key, old: ProjectMetadataV5| {
+				items += 1;
+				let participation_currencies =
+					old.participation_currencies.into_iter().map(Into::into).collect::<Vec<_>>().try_into().ok()?;
+				Some(ProjectMetadataV6 {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					participation_currencies,
+					funding_destination_account: old.funding_destination_account,
+					policy_ipfs_cid: old.policy_ipfs_cid,
+				})
+			});
+
+			pallet_funding::Bids::<Runtime>::translate(|_key, old: OldBidInfo| {
+				items += 1;
+				Some(BidInfoOf::<Runtime> {
+					id: old.id,
+					project_id: old.project_id,
+					bidder: old.bidder,
+					did: old.did,
+					status: old.status,
+					original_ct_amount: old.original_ct_amount,
+					original_ct_usd_price: old.original_ct_usd_price,
+					funding_asset: old.funding_asset.into(),
+					funding_asset_amount_locked: old.funding_asset_amount_locked,
+					multiplier: old.multiplier,
+					plmc_bond: old.plmc_bond,
+					when: old.when,
+				})
+			});
+
+			pallet_funding::Contributions::<Runtime>::translate(|_key, old: OldContributionInfo| {
+				items += 1;
+				Some(ContributionInfoV6 {
+					id: old.id,
+					did: old.did,
+					project_id: old.project_id,
+					contributor: old.contributor,
+					ct_amount: old.ct_amount,
+					usd_contribution_amount: old.usd_contribution_amount,
+					multiplier: old.multiplier,
+					funding_asset: old.funding_asset.into(),
+					funding_asset_amount: old.funding_asset_amount,
+					plmc_bond: old.plmc_bond,
+					when: old.when,
+				})
+			});
+			items
+		})
+	}
+}
+
+/// Projects that started settling before settlement was automatic get a settlement cursor, so that they are settled
+/// automatically.
+pub struct SettlementCursorsMigration;
+impl OnRuntimeUpgrade for SettlementCursorsMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(6, "settlement cursors", || {
+			let mut items = 0u64;
+			for (project_id, details) in pallet_funding::ProjectsDetails::<Runtime>::iter() {
+				items += 1;
+				if matches!(details.status, ProjectStatus::SettlementStarted(..)) {
+					pallet_funding::SettlementCursors::<Runtime>::insert(project_id, SettlementCursor::default());
+					items += 1;
+				}
+			}
+			items
+		})
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProjectMetadataV8 {
+	pub token_information: CurrencyMetadata<BoundedVec<u8, StringLimit>>,
+	pub mainnet_token_max_supply: Balance,
+	pub total_allocation_size: Balance,
+	pub auction_round_allocation_percentage: Percent,
+	pub minimum_price: Price,
+	pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+	pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
+	pub participation_currencies: ParticipationCurrencies,
+	pub funding_destination_account: AccountId,
+	pub policy_ipfs_cid: Option<Cid>,
+	pub round_durations: RoundDurations<BlockNumber>,
+}
+
+/// Existing projects keep the default round durations.
+pub struct RoundDurationsMigration;
+impl OnRuntimeUpgrade for RoundDurationsMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(7, "round durations", || {
+			let mut items = 0u64;
+			pallet_funding::ProjectsMetadata::<Runtime>::translate(|_key, old: ProjectMetadataV6| {
+				items += 1;
+				Some(ProjectMetadataV8 {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					participation_currencies: old.participation_currencies,
+					funding_destination_account: old.funding_destination_account,
+					policy_ipfs_cid: old.policy_ipfs_cid,
+					round_durations: Default::default(),
+				})
+			});
+			items
+		})
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProjectMetadataV9 {
+	pub token_information: CurrencyMetadata<BoundedVec<u8, StringLimit>>,
+	pub mainnet_token_max_supply: Balance,
+	pub total_allocation_size: Balance,
+	pub auction_round_allocation_percentage: Percent,
+	pub minimum_price: Price,
+	pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+	pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
+	pub participation_currencies: ParticipationCurrencies,
+	pub funding_destination_account: AccountId,
+	pub policy_ipfs_cid: Option<Cid>,
+	pub round_durations: RoundDurations<BlockNumber>,
+	pub ct_release_policy: Option<CtReleasePolicy>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OldMigrationInfo {
+	pub contribution_token_amount: u128,
+	pub vesting_time: u64,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OldMigration {
+	pub origin: MigrationOrigin,
+	pub info: OldMigrationInfo,
+}
+
+type OldUserMigrations = (MigrationStatus, BoundedVec<OldMigration, MaxParticipationsPerUser<Runtime>>);
+
+/// Existing projects and pending CT migrations have no release policy, so they keep the multiplier's vesting.
+pub struct CtReleasePolicyMigration;
+impl OnRuntimeUpgrade for CtReleasePolicyMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(8, "CT release policy", || {
+			let mut items = 0u64;
+			pallet_funding::ProjectsMetadata::<Runtime>::translate(|_key, old: ProjectMetadataV8| {
+				items += 1;
+				Some(ProjectMetadataV9 {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					participation_currencies: old.participation_currencies,
+					funding_destination_account: old.funding_destination_account,
+					policy_ipfs_cid: old.policy_ipfs_cid,
+					round_durations: old.round_durations,
+					ct_release_policy: None,
+				})
+			});
+
+			pallet_funding::UserMigrations::<Runtime>::translate(
+				|_key, (status, old_migrations): OldUserMigrations| {
+					items += 1;
+					let migrations = old_migrations
+						.into_iter()
+						.map(|old| {
+							let OldMigrationInfo { contribution_token_amount, vesting_time } = old.info;
+							Migration::new(old.origin, MigrationInfo::from((contribution_token_amount, vesting_time)))
+						})
+						.collect::<Vec<_>>()
+						.try_into()
+						.ok()?;
+					Some((status, migrations))
+				},
+			);
+			items
+		})
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProjectMetadataV10 {
+	pub token_information: CurrencyMetadata<BoundedVec<u8, StringLimit>>,
+	pub mainnet_token_max_supply: Balance,
+	pub total_allocation_size: Balance,
+	pub auction_round_allocation_percentage: Percent,
+	pub minimum_price: Price,
+	pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+	pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
+	pub participation_currencies: ParticipationCurrencies,
+	pub funding_destination_account: AccountId,
+	pub policy_ipfs_cid: Option<Cid>,
+	pub round_durations: RoundDurations<BlockNumber>,
+	pub ct_release_policy: Option<CtReleasePolicy>,
+	pub soft_cap: Option<Perquintill>,
+}
+
+/// Existing projects keep the runtime's funding success threshold as their soft cap.
+pub struct SoftCapMigration;
+impl OnRuntimeUpgrade for SoftCapMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(9, "soft cap", || {
+			let mut items = 0u64;
+			pallet_funding::ProjectsMetadata::<Runtime>::translate(|_key, old: ProjectMetadataV9| {
+				items += 1;
+				Some(ProjectMetadataV10 {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					participation_currencies: old.participation_currencies,
+					funding_destination_account: old.funding_destination_account,
+					policy_ipfs_cid: old.policy_ipfs_cid,
+					round_durations: old.round_durations,
+					ct_release_policy: old.ct_release_policy,
+					soft_cap: None,
+				})
+			});
+			items
+		})
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProjectMetadataV11 {
+	pub token_information: CurrencyMetadata<BoundedVec<u8, StringLimit>>,
+	pub mainnet_token_max_supply: Balance,
+	pub total_allocation_size: Balance,
+	pub auction_round_allocation_percentage: Percent,
+	pub minimum_price: Price,
+	pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+	pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
+	pub participation_currencies: ParticipationCurrencies,
+	pub funding_destination_account: AccountId,
+	pub policy_ipfs_cid: Option<Cid>,
+	pub round_durations: RoundDurations<BlockNumber>,
+	pub ct_release_policy: Option<CtReleasePolicy>,
+	pub soft_cap: Option<Perquintill>,
+	pub private_round: Option<PrivateRoundConfig<Price>>,
+}
+
+/// Existing projects have no private round, and none of the existing contributions were made in one.
+pub struct PrivateRoundMigration;
+impl OnRuntimeUpgrade for PrivateRoundMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(10, "private round", || {
+			let mut items = 0u64;
+			pallet_funding::ProjectsMetadata::<Runtime>::translate(|_key, old: ProjectMetadataV10| {
+				items += 1;
+				Some(ProjectMetadataV11 {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					participation_currencies: old.participation_currencies,
+					funding_destination_account: old.funding_destination_account,
+					policy_ipfs_cid: old.policy_ipfs_cid,
+					round_durations: old.round_durations,
+					ct_release_policy: old.ct_release_policy,
+					soft_cap: old.soft_cap,
+					private_round: None,
+				})
+			});
+
+			pallet_funding::Contributions::<Runtime>::translate(|_key, old: ContributionInfoV6| {
+				items += 1;
+				Some(ContributionInfoOf::<Runtime> {
+					id: old.id,
+					did: old.did,
+					project_id: old.project_id,
+					contributor: old.contributor,
+					ct_amount: old.ct_amount,
+					usd_contribution_amount: old.usd_contribution_amount,
+					multiplier: old.multiplier,
+					funding_asset: old.funding_asset,
+					funding_asset_amount: old.funding_asset_amount,
+					plmc_bond: old.plmc_bond,
+					when: old.when,
+					private_round: false,
+				})
+			});
+			items
+		})
+	}
+}
+
+/// Projects past their application snapshot the fee parameters that were hardcoded when they started, so that later
+/// governance changes do not apply to them.
+pub struct FeeParametersMigration;
+impl OnRuntimeUpgrade for FeeParametersMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(11, "fee parameters", || {
+			let mut items = 0u64;
+			for (project_id, details) in pallet_funding::ProjectsDetails::<Runtime>::iter() {
+				items += 1;
+				if details.status != ProjectStatus::Application &&
+					!pallet_funding::ProjectsFeeParameters::<Runtime>::contains_key(project_id)
+				{
+					pallet_funding::ProjectsFeeParameters::<Runtime>::insert(
+						project_id,
+						DefaultFeeParameters::<Runtime>::get(),
+					);
+					items += 1;
+				}
+			}
+			items
+		})
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProjectMetadataV13 {
+	pub token_information: CurrencyMetadata<BoundedVec<u8, StringLimit>>,
+	pub mainnet_token_max_supply: Balance,
+	pub total_allocation_size: Balance,
+	pub auction_round_allocation_percentage: Percent,
+	pub minimum_price: Price,
+	pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+	pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
+	pub participation_currencies: ParticipationCurrencies,
+	pub funding_destination_account: AccountId,
+	pub policy_ipfs_cid: Option<Cid>,
+	pub round_durations: RoundDurations<BlockNumber>,
+	pub ct_release_policy: Option<CtReleasePolicy>,
+	pub soft_cap: Option<Perquintill>,
+	pub private_round: Option<PrivateRoundConfig<Price>>,
+	pub fee_mode: FeeMode,
+}
+
+/// Existing projects pay their fee in CTs.
+pub struct FeeModeMigration;
+impl OnRuntimeUpgrade for FeeModeMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(12, "fee mode", || {
+			let mut items = 0u64;
+			pallet_funding::ProjectsMetadata::<Runtime>::translate(|_key, old: ProjectMetadataV11| {
+				items += 1;
+				Some(ProjectMetadataV13 {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					participation_currencies: old.participation_currencies,
+					funding_destination_account: old.funding_destination_account,
+					policy_ipfs_cid: old.policy_ipfs_cid,
+					round_durations: old.round_durations,
+					ct_release_policy: old.ct_release_policy,
+					soft_cap: old.soft_cap,
+					private_round: old.private_round,
+					fee_mode: FeeMode::ContributionTokens,
+				})
+			});
+			items
+		})
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProjectMetadataV14 {
+	pub token_information: CurrencyMetadata<BoundedVec<u8, StringLimit>>,
+	pub mainnet_token_max_supply: Balance,
+	pub total_allocation_size: Balance,
+	pub auction_round_allocation_percentage: Percent,
+	pub minimum_price: Price,
+	pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+	pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
+	pub participation_currencies: ParticipationCurrencies,
+	pub funding_destination_account: AccountId,
+	pub policy_ipfs_cid: Option<Cid>,
+	pub round_durations: RoundDurations<BlockNumber>,
+	pub ct_release_policy: Option<CtReleasePolicy>,
+	pub soft_cap: Option<Perquintill>,
+	pub private_round: Option<PrivateRoundConfig<Price>>,
+	pub fee_mode: FeeMode,
+	pub milestones: Option<MilestonePlan<BlockNumber>>,
+}
+
+/// Existing projects have no milestones, so their funds are paid out at settlement.
+pub struct MilestonesMigration;
+impl OnRuntimeUpgrade for MilestonesMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(13, "milestones", || {
+			let mut items = 0u64;
+			pallet_funding::ProjectsMetadata::<Runtime>::translate(|_key, old: ProjectMetadataV13| {
+				items += 1;
+				Some(ProjectMetadataV14 {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					participation_currencies: old.participation_currencies,
+					funding_destination_account: old.funding_destination_account,
+					policy_ipfs_cid: old.policy_ipfs_cid,
+					round_durations: old.round_durations,
+					ct_release_policy: old.ct_release_policy,
+					soft_cap: old.soft_cap,
+					private_round: old.private_round,
+					fee_mode: old.fee_mode,
+					milestones: None,
+				})
+			});
+			items
+		})
+	}
+}
+
+/// Each issuer DID keeps its single active project in the new set.
+pub struct IssuerProjectsMigration;
+impl OnRuntimeUpgrade for IssuerProjectsMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(14, "issuer projects", || {
+			let mut items = 0u64;
+			pallet_funding::DidWithActiveProjects::<Runtime>::translate(|_key, old: ProjectId| {
+				items += 1;
+				Some(BoundedVec::truncate_from(vec![old]))
+			});
+			items
+		})
+	}
+}
+
+/// Bids of ongoing auctions are indexed by price bucket, in the order they were placed.
+pub struct BidsByBucketMigration;
+impl OnRuntimeUpgrade for BidsByBucketMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(15, "bids by bucket", || {
+			let mut items = 0u64;
+			let mut unresolved_bids = Vec::new();
+			for bid in pallet_funding::Bids::<Runtime>::iter_values() {
+				items += 1;
+				if bid.status == BidStatus::YetUnknown {
+					unresolved_bids.push((bid.id, bid.project_id, bid.bidder, bid.original_ct_usd_price));
+				}
+			}
+
+			unresolved_bids.sort_by_key(|(id, ..)| *id);
+			for (id, project_id, bidder, price) in unresolved_bids {
+				let Some(bucket) = pallet_funding::Buckets::<Runtime>::get(project_id) else { continue };
+				let bucket_index = bucket.index_of(price);
+				let position = pallet_funding::BucketBidCounts::<Runtime>::get(project_id, bucket_index);
+				let next_position = position.saturating_add(1);
+				pallet_funding::BidsByBucket::<Runtime>::insert((project_id, bucket_index, position), (bidder, id));
+				pallet_funding::BucketBidCounts::<Runtime>::insert(project_id, bucket_index, next_position);
+				pallet_funding::HighestBidBuckets::<Runtime>::mutate(project_id, |highest| {
+					*highest = (*highest).max(bucket_index)
+				});
+				items += 4;
+			}
+			items
+		})
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProjectMetadataV17 {
+	pub token_information: CurrencyMetadata<BoundedVec<u8, StringLimit>>,
+	pub mainnet_token_max_supply: Balance,
+	pub total_allocation_size: Balance,
+	pub auction_round_allocation_percentage: Percent,
+	pub minimum_price: Price,
+	pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+	pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
+	pub participation_currencies: ParticipationCurrencies,
+	pub funding_destination_account: AccountId,
+	pub policy_ipfs_cid: Option<Cid>,
+	pub round_durations: RoundDurations<BlockNumber>,
+	pub ct_release_policy: Option<CtReleasePolicy>,
+	pub soft_cap: Option<Perquintill>,
+	pub private_round: Option<PrivateRoundConfig<Price>>,
+	pub fee_mode: FeeMode,
+	pub milestones: Option<MilestonePlan<BlockNumber>>,
+	pub pricing_model: PricingModel,
+}
+
+/// Existing projects keep the bucket auction.
+pub struct PricingModelMigration;
+impl OnRuntimeUpgrade for PricingModelMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(16, "pricing model", || {
+			let mut items = 0u64;
+			pallet_funding::ProjectsMetadata::<Runtime>::translate(|_key, old: ProjectMetadataV14| {
+				items += 1;
+				Some(ProjectMetadataV17 {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					participation_currencies: old.participation_currencies,
+					funding_destination_account: old.funding_destination_account,
+					policy_ipfs_cid: old.policy_ipfs_cid,
+					round_durations: old.round_durations,
+					ct_release_policy: old.ct_release_policy,
+					soft_cap: old.soft_cap,
+					private_round: old.private_round,
+					fee_mode: old.fee_mode,
+					milestones: old.milestones,
+					pricing_model: PricingModel::Buckets,
+				})
+			});
+			items
+		})
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProjectMetadataV18 {
+	pub token_information: CurrencyMetadata<BoundedVec<u8, StringLimit>>,
+	pub mainnet_token_max_supply: Balance,
+	pub total_allocation_size: Balance,
+	pub auction_round_allocation_percentage: Percent,
+	pub minimum_price: Price,
+	pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+	pub contributing_ticket_sizes: ContributingTicketSizes<Price>,
+	pub participation_currencies: ParticipationCurrencies,
+	pub funding_destination_account: AccountId,
+	pub funding_destination_location: Option<Location>,
+	pub policy_ipfs_cid: Option<Cid>,
+	pub round_durations: RoundDurations<BlockNumber>,
+	pub ct_release_policy: Option<CtReleasePolicy>,
+	pub soft_cap: Option<Perquintill>,
+	pub private_round: Option<PrivateRoundConfig<Price>>,
+	pub fee_mode: FeeMode,
+	pub milestones: Option<MilestonePlan<BlockNumber>>,
+	pub pricing_model: PricingModel,
+}
+
+/// Existing projects are paid out on Polimec, to their funding destination account.
+pub struct FundingDestinationLocationMigration;
+impl OnRuntimeUpgrade for FundingDestinationLocationMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(17, "funding destination location", || {
+			let mut items = 0u64;
+			pallet_funding::ProjectsMetadata::<Runtime>::translate(|_key, old: ProjectMetadataV17| {
+				items += 1;
+				Some(ProjectMetadataV18 {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					participation_currencies: old.participation_currencies,
+					funding_destination_account: old.funding_destination_account,
+					funding_destination_location: None,
+					policy_ipfs_cid: old.policy_ipfs_cid,
+					round_durations: old.round_durations,
+					ct_release_policy: old.ct_release_policy,
+					soft_cap: old.soft_cap,
+					private_round: old.private_round,
+					fee_mode: old.fee_mode,
+					milestones: old.milestones,
+					pricing_model: old.pricing_model,
+				})
+			});
+			items
+		})
+	}
+}
+
+/// Existing projects have no jurisdiction restriction.
+pub struct JurisdictionRestrictionMigration;
+impl OnRuntimeUpgrade for JurisdictionRestrictionMigration {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_from(18, "jurisdiction restriction", || {
+			let mut items = 0u64;
+			pallet_funding::ProjectsMetadata::<Runtime>::translate(|_key, old: ProjectMetadataV18| {
+				items += 1;
+				Some(ProjectMetadataOf::<Runtime> {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					participation_currencies: old.participation_currencies,
+					funding_destination_account: old.funding_destination_account,
+					funding_destination_location: old.funding_destination_location,
+					policy_ipfs_cid: old.policy_ipfs_cid,
+					round_durations: old.round_durations,
+					ct_release_policy: old.ct_release_policy,
+					soft_cap: old.soft_cap,
+					private_round: old.private_round,
+					fee_mode: old.fee_mode,
+					milestones: old.milestones,
+					pricing_model: old.pricing_model,
+					jurisdiction_restriction: None,
+				})
+			});
+			items
+		})
+	}
+}
+
+/// All the funding migrations from storage version 5, in order.
+pub type FromFundingV5Migrations = (
+	FundingAssetsMigration,
+	SettlementCursorsMigration,
+	RoundDurationsMigration,
+	CtReleasePolicyMigration,
+	SoftCapMigration,
+	PrivateRoundMigration,
+	FeeParametersMigration,
+	FeeModeMigration,
+	MilestonesMigration,
+	IssuerProjectsMigration,
+	BidsByBucketMigration,
+	PricingModelMigration,
+	FundingDestinationLocationMigration,
+	JurisdictionRestrictionMigration,
+);
@@ -18,3 +18,4 @@
 #![allow(clippy::all)]
 
 pub mod funding_holds;
+pub mod funding_versions;
@@ -59,13 +59,13 @@ use sp_runtime::{
 		IdentifyAccount, IdentityLookup, OpaqueKeys, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, FixedU128, MultiSignature, SaturatedConversion,
+	ApplyExtrinsicResult, FixedU128, MultiSignature, Perquintill, SaturatedConversion,
 };
 use sp_std::{cmp::Ordering, prelude::*};
 use sp_version::RuntimeVersion;
 
 // XCM Imports
-use xcm_config::XcmOriginToTransactDispatchOrigin;
+use xcm_config::{AssetHubAccounts, LocationToAccountId, XcmOriginToTransactDispatchOrigin};
 
 #[cfg(not(feature = "runtime-benchmarks"))]
 use xcm_config::XcmConfig;
@@ -170,6 +170,7 @@ pub mod migrations {
 	pub type Unreleased = (
 		cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
 		crate::custom_migrations::funding_holds::FromFundingV4Migration,
+		crate::custom_migrations::funding_versions::FromFundingV5Migrations,
 	);
 }
 
@@ -249,15 +250,41 @@ impl Contains<RuntimeCall> for BaseCallFilter {
 							pallet_funding::Call::edit_project { .. } |
 							pallet_funding::Call::start_evaluation { .. } |
 							pallet_funding::Call::evaluate { .. } |
+							pallet_funding::Call::increase_evaluation { .. } |
+							pallet_funding::Call::withdraw_evaluation { .. } |
 							pallet_funding::Call::end_evaluation { .. } |
+							pallet_funding::Call::set_private_round_access { .. } |
+							pallet_funding::Call::private_contribute { .. } |
+							pallet_funding::Call::end_private_round { .. } |
 							pallet_funding::Call::bid { .. } |
+							pallet_funding::Call::cancel_bid { .. } |
+							pallet_funding::Call::reduce_bid { .. } |
 							pallet_funding::Call::end_auction { .. } |
 							pallet_funding::Call::contribute { .. } |
+							pallet_funding::Call::withdraw_contribution { .. } |
 							pallet_funding::Call::end_funding { .. } |
 							pallet_funding::Call::start_settlement { .. } |
 							pallet_funding::Call::settle_evaluation { .. } |
 							pallet_funding::Call::settle_bid { .. } |
-							pallet_funding::Call::settle_contribution { .. }
+							pallet_funding::Call::settle_contribution { .. } |
+							pallet_funding::Call::mark_project_as_settled { .. } |
+							pallet_funding::Call::claim_long_term_holder_reward { .. } |
+							pallet_funding::Call::forfeit_long_term_holder_reward { .. } |
+							pallet_funding::Call::approve_milestone { .. } |
+							pallet_funding::Call::fail_milestone { .. } |
+							pallet_funding::Call::claim_escrow_refund { .. } |
+							pallet_funding::Call::pay_out_issuer { .. } |
+							pallet_funding::Call::set_funding_destination_location { .. } |
+							pallet_funding::Call::register_funding_asset { .. } |
+							pallet_funding::Call::set_funding_asset_enabled { .. } |
+							pallet_funding::Call::set_round_duration_bounds { .. } |
+							pallet_funding::Call::pause_project { .. } |
+							pallet_funding::Call::resume_project { .. } |
+							pallet_funding::Call::cancel_project { .. } |
+							pallet_funding::Call::set_soft_cap_bounds { .. } |
+							pallet_funding::Call::set_fee_parameters { .. } |
+							pallet_funding::Call::set_active_projects_limit { .. } |
+							pallet_funding::Call::resolve_auction { .. }
 					)
 				},
 			_ => true,
@@ -996,7 +1023,7 @@ impl pallet_assets::Config<ContributionTokensInstance> for Runtime {
 	type Currency = Balances;
 	type Extra = ();
 	type ForceOrigin = EnsureRoot<AccountId>;
-	type Freezer = ();
+	type Freezer = Funding;
 	type MetadataDepositBase = ZeroDeposit;
 	type MetadataDepositPerByte = ZeroDeposit;
 	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
@@ -1032,6 +1059,7 @@ impl ConvertBack<AccountId, [u8; 32]> for ConvertSelf {
 
 impl pallet_funding::Config for Runtime {
 	type AccountId32Conversion = ConvertSelf;
+	type AdminOrigin = EnsureRoot<AccountId>;
 	#[cfg(any(test, feature = "runtime-benchmarks", feature = "std"))]
 	type AllPalletsWithoutSystem =
 		(Balances, ContributionTokens, ForeignAssets, Oracle, Funding, LinearRelease, Random);
@@ -1039,6 +1067,7 @@ impl pallet_funding::Config for Runtime {
 	type BlockNumber = BlockNumber;
 	type BlockchainOperationTreasury = BlockchainOperationTreasury;
 	type CommunityRoundDuration = CommunityRoundDuration;
+	type ContributionCoolingOffPeriod = ContributionCoolingOffPeriod;
 	type ContributionTokenCurrency = ContributionTokens;
 	type ContributionTreasury = ContributionTreasuryAccount;
 	type DaysToBlocks = DaysToBlocks;
@@ -1048,8 +1077,12 @@ impl pallet_funding::Config for Runtime {
 	type FeeBrackets = FeeBrackets;
 	type FundingCurrency = ForeignAssets;
 	type FundingSuccessThreshold = FundingSuccessThreshold;
-	type InvestorOrigin = EnsureInvestor<Runtime>;
-	type MaxBidsPerProject = ConstU32<512>;
+	type InvestorOrigin = EnsureInvestor<Runtime, Credentials, Credentials, Credentials>;
+	// The protocol treasury provides the liquidity pools until a dedicated account is set up.
+	type LiquidityPoolsAccount = BlockchainOperationTreasury;
+	type LongTermHolderPeriod = LongTermHolderPeriod;
+	type MaxActiveProjectsPerDid = ConstU32<8>;
+	type MaxBidsPerProject = ConstU32<50_000>;
 	type MaxBidsPerUser = ConstU32<16>;
 	type MaxCapacityThresholds = MaxCapacityThresholds;
 	type MaxContributionsPerUser = ConstU32<16>;
@@ -1063,6 +1096,7 @@ impl pallet_funding::Config for Runtime {
 	type PalletId = FundingPalletId;
 	type Price = Price;
 	type PriceProvider = OraclePriceProvider<AssetId, Price, Oracle>;
+	type PrivateRoundDuration = PrivateRoundDuration;
 	type Randomness = Random;
 	type RemainderRoundDuration = RemainderRoundDuration;
 	type RequiredMaxCapacity = RequiredMaxCapacity;
@@ -1076,6 +1110,8 @@ impl pallet_funding::Config for Runtime {
 	type StringLimit = ConstU32<64>;
 	type VerifierPublicKey = VerifierPublicKey;
 	type WeightInfo = weights::pallet_funding::WeightInfo<Runtime>;
+	type XcmParticipantAccount = LocationToAccountId;
+	type XcmParticipantOrigin = pallet_xcm::EnsureXcm<AssetHubAccounts>;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -1117,7 +1153,7 @@ impl pallet_dispenser::Config for Runtime {
 	type BlockNumberToBalance = ConvertInto;
 	type FreeDispenseAmount = FreeDispenseAmount;
 	type InitialDispenseAmount = InitialDispenseAmount;
-	type InvestorOrigin = EnsureInvestor<Runtime>;
+	type InvestorOrigin = EnsureInvestor<Runtime, Credentials, Credentials, Credentials>;
 	type LockPeriod = DispenserLockPeriod;
 	type PalletId = DispenserId;
 	type RuntimeEvent = RuntimeEvent;
@@ -1128,6 +1164,14 @@ impl pallet_dispenser::Config for Runtime {
 	type WhitelistedPolicy = DispenserWhitelistedPolicy;
 }
 
+impl pallet_credentials::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// Revocations go through governance until the verifier has an on-chain account.
+	type VerifierOrigin = EnsureRoot<AccountId>;
+	type GovernanceOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = pallet_credentials::weights::SubstrateWeight<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime
@@ -1150,6 +1194,7 @@ construct_runtime!(
 		ContributionTokens: pallet_assets::<Instance1> = 13,
 		ForeignAssets: pallet_assets::<Instance2> = 14,
 		Dispenser: pallet_dispenser = 15,
+		Credentials: pallet_credentials = 16,
 
 		// Collator support. the order of these 5 are important and shall not change.
 		Authorship: pallet_authorship::{Pallet, Storage} = 20,
@@ -1204,6 +1249,7 @@ mod benches {
 		[pallet_assets, ForeignAssets]
 		[pallet_assets, ContributionTokens]
 		[pallet_dispenser, Dispenser]
+		[pallet_credentials, Credentials]
 
 		// Collator support.
 		[pallet_session, SessionBench::<Runtime>]
@@ -1426,6 +1472,10 @@ impl_runtime_apis! {
 		fn projects_by_did(did: Did) -> Vec<ProjectId> {
 			Funding::projects_by_did(did)
 		}
+
+		fn funding_success_threshold(project_id: ProjectId) -> Perquintill {
+			Funding::funding_success_threshold(project_id)
+		}
 	}
 
 	impl pallet_funding::runtime_api::ExtrinsicHelpers<Block, Runtime> for Runtime {
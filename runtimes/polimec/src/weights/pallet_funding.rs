@@ -139,6 +139,52 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(5))
 			.saturating_add(Weight::from_parts(0, 2729).saturating_mul(x.into()))
 	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:1 w:1)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(254), added: 2729, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Values` (r:1 w:0)
+	/// Proof: `Oracle::Values` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1149), added: 3624, mode: `MaxEncodedLen`)
+	fn increase_evaluation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1422`
+		//  Estimated: `4614`
+		// Minimum execution time: 54_310_000 picoseconds.
+		Weight::from_parts(55_720_000, 0)
+			.saturating_add(Weight::from_parts(0, 4614))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:2 w:2)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(254), added: 2729, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1149), added: 3624, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 512]`.
+	fn withdraw_evaluation(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1388 + x * (254 ±0)`
+		//  Estimated: `4614 + x * (2729 ±0)`
+		// Minimum execution time: 48_230_000 picoseconds.
+		Weight::from_parts(44_820_519, 0)
+			.saturating_add(Weight::from_parts(0, 4614))
+			// Standard Error: 9_512
+			.saturating_add(Weight::from_parts(6_318_745, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2729).saturating_mul(x.into()))
+	}
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
@@ -200,34 +246,81 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(y.into())))
 			.saturating_add(Weight::from_parts(0, 2748).saturating_mul(x.into()))
 	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Bids` (r:1 w:1)
+	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:1 w:1)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1149), added: 3624, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::BidCounts` (r:1 w:1)
+	/// Proof: `Funding::BidCounts` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	fn cancel_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1941`
+		//  Estimated: `4614`
+		// Minimum execution time: 98_410_000 picoseconds.
+		Weight::from_parts(100_330_000, 0)
+			.saturating_add(Weight::from_parts(0, 4614))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
 	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::Buckets` (r:1 w:0)
-	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::Bids` (r:34 w:33)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Bids` (r:1 w:1)
 	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::DidWithWinningBids` (r:15 w:15)
-	/// Proof: `Funding::DidWithWinningBids` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:1 w:1)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1149), added: 3624, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	fn reduce_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2131`
+		//  Estimated: `4614`
+		// Minimum execution time: 101_230_000 picoseconds.
+		Weight::from_parts(103_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 4614))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[10, 25]`.
-	/// The range of component `y` is `[0, 8]`.
-	fn end_auction(x: u32, y: u32, ) -> Weight {
+	/// Storage: `Funding::Buckets` (r:1 w:0)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::HighestBidBuckets` (r:1 w:1)
+	/// Proof: `Funding::HighestBidBuckets` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionResolutions` (r:0 w:1)
+	/// Proof: `Funding::AuctionResolutions` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn end_auction() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1112 + x * (212 ±0) + y * (192 ±0)`
-		//  Estimated: `3967 + x * (2748 ±13) + y * (2748 ±0)`
-		// Minimum execution time: 184_100_000 picoseconds.
-		Weight::from_parts(28_884_206, 0)
+		//  Measured:  `1112`
+		//  Estimated: `3967`
+		// Minimum execution time: 38_210_000 picoseconds.
+		Weight::from_parts(39_480_000, 0)
 			.saturating_add(Weight::from_parts(0, 3967))
-			// Standard Error: 13_889
-			.saturating_add(Weight::from_parts(10_019_424, 0).saturating_mul(x.into()))
-			// Standard Error: 25_145
-			.saturating_add(Weight::from_parts(7_838_626, 0).saturating_mul(y.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
-			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
-			.saturating_add(Weight::from_parts(0, 2748).saturating_mul(x.into()))
-			.saturating_add(Weight::from_parts(0, 2748).saturating_mul(y.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
@@ -270,6 +363,30 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(8))
 			.saturating_add(Weight::from_parts(0, 2731).saturating_mul(x.into()))
 	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Contributions` (r:1 w:1)
+	/// Proof: `Funding::Contributions` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(1149), added: 3624, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ContributionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::ContributionBoughtUSD` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	fn withdraw_contribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1768`
+		//  Estimated: `4614`
+		// Minimum execution time: 86_120_000 picoseconds.
+		Weight::from_parts(88_040_000, 0)
+			.saturating_add(Weight::from_parts(0, 4614))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
@@ -310,6 +427,8 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(254), added: 2729, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
 	/// Storage: `ContributionTokens::Account` (r:1 w:1)
 	/// Proof: `ContributionTokens::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `ContributionTokens::Asset` (r:1 w:1)
@@ -327,7 +446,7 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 83_331_000 picoseconds.
 		Weight::from_parts(85_130_000, 0)
 			.saturating_add(Weight::from_parts(0, 33831))
-			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `Funding::Bids` (r:1 w:1)
@@ -608,4 +727,380 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Funding::FundingAssets` (r:1 w:1)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(564), added: 3039, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FundingAssetLocations` (r:1 w:2)
+	/// Proof: `Funding::FundingAssetLocations` (`max_values`: None, `max_size`: Some(558), added: 3033, mode: `MaxEncodedLen`)
+	fn register_funding_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `4029`
+		// Minimum execution time: 17_230_000 picoseconds.
+		Weight::from_parts(17_910_000, 0)
+			.saturating_add(Weight::from_parts(0, 4029))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Funding::FundingAssets` (r:1 w:1)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(564), added: 3039, mode: `MaxEncodedLen`)
+	fn set_funding_asset_enabled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `4029`
+		// Minimum execution time: 11_040_000 picoseconds.
+		Weight::from_parts(11_520_000, 0)
+			.saturating_add(Weight::from_parts(0, 4029))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::RoundDurationLimits` (r:0 w:1)
+	/// Proof: `Funding::RoundDurationLimits` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_round_duration_bounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_410_000 picoseconds.
+		Weight::from_parts(6_730_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PausedProjects` (r:0 w:1)
+	/// Proof: `Funding::PausedProjects` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn pause_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `426`
+		//  Estimated: `3807`
+		// Minimum execution time: 14_220_000 picoseconds.
+		Weight::from_parts(14_810_000, 0)
+			.saturating_add(Weight::from_parts(0, 3807))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::PausedProjects` (r:1 w:1)
+	/// Proof: `Funding::PausedProjects` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn resume_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `3485`
+		// Minimum execution time: 10_530_000 picoseconds.
+		Weight::from_parts(11_050_000, 0)
+			.saturating_add(Weight::from_parts(0, 3485))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:2 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithActiveProjects` (r:1 w:1)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(78), added: 2553, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PausedProjects` (r:0 w:1)
+	/// Proof: `Funding::PausedProjects` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::SettlementCursors` (r:0 w:1)
+	/// Proof: `Funding::SettlementCursors` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn cancel_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1029`
+		//  Estimated: `3967`
+		// Minimum execution time: 48_370_000 picoseconds.
+		Weight::from_parts(50_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 3967))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Funding::SoftCapLimits` (r:0 w:1)
+	/// Proof: `Funding::SoftCapLimits` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_soft_cap_bounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_280_000 picoseconds.
+		Weight::from_parts(6_590_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PrivateRoundAllowList` (r:1 w:0)
+	/// Proof: `Funding::PrivateRoundAllowList` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Contributions` (r:16 w:1)
+	/// Proof: `Funding::Contributions` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ContributionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::ContributionBoughtUSD` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Values` (r:2 w:0)
+	/// Proof: `Oracle::Values` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Metadata` (r:1 w:0)
+	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::NextContributionId` (r:1 w:1)
+	/// Proof: `Funding::NextContributionId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:1 w:0)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(254), added: 2729, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 15]`.
+	fn private_contribute(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3006 + x * (196 ±0)`
+		//  Estimated: `6208 + x * (2731 ±0)`
+		// Minimum execution time: 251_306_000 picoseconds.
+		Weight::from_parts(257_904_112, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			// Standard Error: 30_448
+			.saturating_add(Weight::from_parts(3_910_300, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(Weight::from_parts(0, 2731).saturating_mul(x.into()))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PrivateRoundAllowList` (r:0 w:1)
+	/// Proof: `Funding::PrivateRoundAllowList` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	fn set_private_round_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `964`
+		//  Estimated: `3967`
+		// Minimum execution time: 18_940_000 picoseconds.
+		Weight::from_parts(19_620_000, 0)
+			.saturating_add(Weight::from_parts(0, 3967))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	fn end_private_round() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `964`
+		//  Estimated: `3967`
+		// Minimum execution time: 20_310_000 picoseconds.
+		Weight::from_parts(21_050_000, 0)
+			.saturating_add(Weight::from_parts(0, 3967))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::LongTermHoldings` (r:1 w:1)
+	/// Proof: `Funding::LongTermHoldings` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::LongTermHolderPots` (r:1 w:0)
+	/// Proof: `Funding::LongTermHolderPots` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Account` (r:2 w:2)
+	/// Proof: `ContributionTokens::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:1)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(30366), added: 32841, mode: `MaxEncodedLen`)
+	fn claim_long_term_holder_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1850`
+		//  Estimated: `33831`
+		// Minimum execution time: 61_240_000 picoseconds.
+		Weight::from_parts(62_910_000, 0)
+			.saturating_add(Weight::from_parts(0, 33831))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Funding::LongTermHoldings` (r:1 w:1)
+	/// Proof: `Funding::LongTermHoldings` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn forfeit_long_term_holder_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3565`
+		// Minimum execution time: 14_870_000 picoseconds.
+		Weight::from_parts(15_410_000, 0)
+			.saturating_add(Weight::from_parts(0, 3565))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProtocolFeeParameters` (r:0 w:1)
+	/// Proof: `Funding::ProtocolFeeParameters` (`max_values`: Some(1), `max_size`: Some(203), added: 698, mode: `MaxEncodedLen`)
+	fn set_fee_parameters() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_410_000 picoseconds.
+		Weight::from_parts(6_730_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestonesProgress` (r:1 w:1)
+	/// Proof: `Funding::MilestonesProgress` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneVotingPower` (r:1 w:0)
+	/// Proof: `Funding::MilestoneVotingPower` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneApprovals` (r:1 w:1)
+	/// Proof: `Funding::MilestoneApprovals` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Escrows` (r:3 w:3)
+	/// Proof: `Funding::Escrows` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:3 w:3)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:6 w:6)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn approve_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2113`
+		//  Estimated: `16644`
+		// Minimum execution time: 148_310_000 picoseconds.
+		Weight::from_parts(152_960_000, 0)
+			.saturating_add(Weight::from_parts(0, 16644))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestonesProgress` (r:1 w:1)
+	/// Proof: `Funding::MilestonesProgress` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn fail_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021`
+		//  Estimated: `3967`
+		// Minimum execution time: 21_870_000 picoseconds.
+		Weight::from_parts(22_540_000, 0)
+			.saturating_add(Weight::from_parts(0, 3967))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestonesProgress` (r:1 w:0)
+	/// Proof: `Funding::MilestonesProgress` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EscrowedParticipations` (r:3 w:3)
+	/// Proof: `Funding::EscrowedParticipations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Escrows` (r:3 w:0)
+	/// Proof: `Funding::Escrows` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:3 w:3)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:6 w:6)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn claim_escrow_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1876`
+		//  Estimated: `16644`
+		// Minimum execution time: 129_450_000 picoseconds.
+		Weight::from_parts(133_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 16644))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	/// Storage: `Funding::ActiveProjectsLimit` (r:0 w:1)
+	/// Proof: `Funding::ActiveProjectsLimit` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_active_projects_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_120_000 picoseconds.
+		Weight::from_parts(6_390_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionResolutions` (r:1 w:1)
+	/// Proof: `Funding::AuctionResolutions` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::BucketBidCounts` (r:1 w:0)
+	/// Proof: `Funding::BucketBidCounts` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::BidsByBucket` (r:1 w:1)
+	/// Proof: `Funding::BidsByBucket` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Bids` (r:1 w:1)
+	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithWinningBids` (r:0 w:1)
+	/// Proof: `Funding::DidWithWinningBids` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	fn resolve_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1287`
+		//  Estimated: `3738`
+		// Minimum execution time: 41_870_000 picoseconds.
+		Weight::from_parts(43_020_000, 0)
+			.saturating_add(Weight::from_parts(0, 3738))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::BucketBidCounts` (r:1 w:1)
+	/// Proof: `Funding::BucketBidCounts` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:0 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionResolutions` (r:0 w:1)
+	/// Proof: `Funding::AuctionResolutions` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn finish_auction_resolution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1094`
+		//  Estimated: `3967`
+		// Minimum execution time: 27_940_000 picoseconds.
+		Weight::from_parts(28_760_000, 0)
+			.saturating_add(Weight::from_parts(0, 3967))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingIssuerPayouts` (r:3 w:3)
+	/// Proof: `Funding::PendingIssuerPayouts` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FundingAssets` (r:3 w:0)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(564), added: 3039, mode: `MaxEncodedLen`)
+	fn pay_out_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1502`
+		//  Estimated: `11199`
+		// Minimum execution time: 108_920_000 picoseconds.
+		Weight::from_parts(112_430_000, 0)
+			.saturating_add(Weight::from_parts(0, 11199))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:1)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(502), added: 2977, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PausedProjects` (r:1 w:0)
+	/// Proof: `Funding::PausedProjects` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingIssuerPayouts` (r:3 w:3)
+	/// Proof: `Funding::PendingIssuerPayouts` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn set_funding_destination_location() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1873`
+		//  Estimated: `11199`
+		// Minimum execution time: 89_410_000 picoseconds.
+		Weight::from_parts(92_150_000, 0)
+			.saturating_add(Weight::from_parts(0, 11199))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(30366), added: 32841, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationDestinations` (r:0 w:1)
+	/// Proof: `Funding::MigrationDestinations` (`max_values`: None, `max_size`: Some(739), added: 3214, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 48]`.
+	fn set_migration_destination(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1025 + x * (65 ±0)`
+		//  Estimated: `33831`
+		// Minimum execution time: 59_870_000 picoseconds.
+		Weight::from_parts(60_412_950, 0)
+			.saturating_add(Weight::from_parts(0, 33831))
+			// Standard Error: 1_288
+			.saturating_add(Weight::from_parts(297_614, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -26,6 +26,7 @@ use frame_support::{
 	traits::{ConstU32, Contains, ContainsPair, Everything, Nothing, ProcessMessageError},
 	weights::Weight,
 };
+use pallet_funding::{AcceptedFundingAsset, FundingAssetInfo};
 use pallet_xcm::XcmPassthrough;
 #[cfg(feature = "runtime-benchmarks")]
 use polimec_common::DummyXcmSender;
@@ -35,11 +36,12 @@ use sp_runtime::traits::MaybeEquivalence;
 use xcm::v4::prelude::*;
 use xcm_builder::{
 	AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses, AllowSubscriptionsFrom,
-	AllowTopLevelPaidExecutionFrom, CreateMatcher, DenyReserveTransferToRelayChain, DenyThenTry, EnsureXcmOrigin,
-	FixedRateOfFungible, FixedWeightBounds, FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter, IsConcrete,
-	MatchXcm, MatchedConvertedConcreteId, MintLocation, NoChecking, ParentIsPreset, RelayChainAsNative,
-	SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
-	SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents, WithComputedOrigin,
+	AllowTopLevelPaidExecutionFrom, CreateMatcher, DenyReserveTransferToRelayChain, DenyThenTry, DescribeAllTerminal,
+	DescribeFamily, EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds, FrameTransactionalProcessor,
+	FungibleAdapter, FungiblesAdapter, HashedDescription, IsConcrete, MatchXcm, MatchedConvertedConcreteId,
+	MintLocation, NoChecking, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative,
+	SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation,
+	TakeWeightCredit, TrailingSetTopicAsId, UsingComponents, WithComputedOrigin,
 };
 use xcm_executor::{
 	traits::{JustTry, Properties, ShouldExecute},
@@ -53,12 +55,10 @@ const DOT_PER_MB_PROOF: u128 = 0_2_000_000_000; // 0.0000001 DOT per Megabyte of
 // USDT from Polkadot Asset Hub
 const USDT_PER_SECOND_EXECUTION: u128 = 1_000_000; // 1 USDT per second of execution time
 const USDT_PER_MB_PROOF: u128 = 1_000_000; // 1 USDT per Megabyte of proof size
-const USDT_JUNCTION: &[Junction] = &[Parachain(1000), PalletInstance(50), GeneralIndex(1984)];
 
 // USDC from Polkadot Asset Hub
 const USDC_PER_SECOND_EXECUTION: u128 = 1_000_000; // 1 USDC per second of execution time
 const USDC_PER_MB_PROOF: u128 = 1_000_000; // 1 USDC per Megabyte of proof size
-const USDC_JUNCTION: &[Junction] = &[Parachain(1000), PalletInstance(50), GeneralIndex(1337)];
 
 parameter_types! {
 	pub const RelayLocation: Location = Location::parent();
@@ -83,6 +83,21 @@ parameter_types! {
 	pub UsdcTraderParams: (AssetId, u128, u128) = (UsdcLocation::get().into(), USDC_PER_SECOND_EXECUTION, USDC_PER_MB_PROOF);
 }
 
+/// The funding assets accepted by Polimec before the registry was introduced. Used to populate the
+/// `FundingAssets` registry at genesis and on migration.
+pub fn default_funding_assets() -> Vec<(AcceptedFundingAsset, FundingAssetInfo)> {
+	[
+		(AcceptedFundingAsset::USDT, UsdtLocation::get(), 6),
+		(AcceptedFundingAsset::USDC, UsdcLocation::get(), 6),
+		(AcceptedFundingAsset::DOT, DotLocation::get(), 10),
+	]
+	.into_iter()
+	.map(|(asset, location, decimals)| {
+		(asset, FundingAssetInfo { location, decimals, oracle_key: asset.id(), enabled: true })
+	})
+	.collect()
+}
+
 /// Type for specifying how a `Location` can be converted into an `AccountId`. This is used
 /// when determining ownership of accounts for asset transacting and when attempting to use XCM
 /// `Transact` in order to determine the dispatch Origin.
@@ -93,6 +108,9 @@ pub type LocationToAccountId = (
 	SiblingParachainConvertsVia<Sibling, AccountId>,
 	// Straight up local `AccountId32` origins just alias directly to `AccountId`.
 	AccountId32Aliases<RelayNetwork, AccountId>,
+	// Accounts of other chains, e.g. AssetHub users participating through XCM, get an account derived from the
+	// hash of their location.
+	HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
 );
 
 /// Means for transacting assets on this chain.
@@ -109,37 +127,27 @@ pub type FungibleTransactor = FungibleAdapter<
 	LocalCheckAccount,
 >;
 
-// The `AssetIdPalletAssets` ids that are supported by this chain.
-// Currently, we only support DOT (10), USDT (1984) and USDC (1337).
+// The `AssetIdPalletAssets` ids that are supported by this chain, read from the funding asset registry.
+// Disabled funding assets are still matched, so that refunds and payouts keep working.
 pub struct SupportedAssets;
 impl frame_support::traits::Contains<Location> for SupportedAssets {
 	fn contains(l: &Location) -> bool {
-		matches!(l.unpack(), (1, []) | (1, USDC_JUNCTION) | (1, USDT_JUNCTION))
+		pallet_funding::FundingAssetLocations::<Runtime>::contains_key(l)
 	}
 }
 
 impl MaybeEquivalence<Location, AssetIdPalletAssets> for SupportedAssets {
 	fn convert(asset_id: &Location) -> Option<AssetIdPalletAssets> {
-		match asset_id.unpack() {
-			(1, []) => Some(10),
-			(1, USDC_JUNCTION) => Some(1337),
-			(1, USDT_JUNCTION) => Some(1984),
-			_ => None,
-		}
+		pallet_funding::FundingAssetLocations::<Runtime>::get(asset_id).map(|asset| asset.id())
 	}
 
 	fn convert_back(asset_id: &AssetIdPalletAssets) -> Option<Location> {
-		match asset_id {
-			10 => Some(DotLocation::get()),
-			1337 => Some(UsdcLocation::get()),
-			1984 => Some(UsdtLocation::get()),
-			_ => None,
-		}
+		pallet_funding::FundingAssets::<Runtime>::get(AcceptedFundingAsset(*asset_id)).map(|info| info.location)
 	}
 }
 
-/// Foreign assets adapter for supporting assets from other chains. Currently the only
-/// supported assets are DOT, USDT, and USDC.
+/// Foreign assets adapter for supporting assets from other chains. The supported assets are
+/// the ones registered in the funding asset registry.
 pub type ForeignAssetsAdapter = FungiblesAdapter<
 	// Use this fungibles implementation:
 	ForeignAssets,
@@ -178,6 +186,9 @@ impl Contains<(Location, Vec<Asset>)> for AssetHubAssetsAsReserve {
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
 /// biases the kind of local `Origin` it will become.
+/// Users of AssetHub participating in projects should use `OriginKind::Xcm`, so that the funding pallet
+/// keeps their location and sends their refunds back there. With `OriginKind::SovereignAccount` they
+/// participate as a plain `Signed` origin, and their refunds stay on their local account.
 pub type XcmOriginToTransactDispatchOrigin = (
 	// Sovereign account converter; this attempts to derive an `AccountId` from the origin location
 	// using `LocationToAccountId` and then turn that into the usual `Signed` origin. Useful for
@@ -192,10 +203,40 @@ pub type XcmOriginToTransactDispatchOrigin = (
 	// Native signed account converter; this just converts an `AccountId32` origin into a normal
 	// `RuntimeOrigin::Signed` origin of the same 32-byte value.
 	SignedAccountId32AsNative<RelayNetwork, RuntimeOrigin>,
-	// Xcm origins can be represented natively under the Xcm pallet's Xcm origin.
+	// Xcm origins can be represented natively under the Xcm pallet's Xcm origin. Used with `OriginKind::Xcm`
+	// by the users of AssetHub participating in projects.
 	XcmPassthrough<RuntimeOrigin>,
 );
 
+/// Accounts of AssetHub users, who can evaluate, bid and contribute through an XCM `Transact`.
+pub struct AssetHubAccounts;
+impl Contains<Location> for AssetHubAccounts {
+	fn contains(location: &Location) -> bool {
+		matches!(location.unpack(), (1, [Parachain(1000), AccountId32 { .. }]))
+	}
+}
+
+/// The calls that can be dispatched with an XCM `Transact`: only the participations in funding projects, and
+/// the calls changing them.
+pub struct FundingParticipationCalls;
+impl Contains<RuntimeCall> for FundingParticipationCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Funding(
+				pallet_funding::Call::evaluate { .. } |
+					pallet_funding::Call::increase_evaluation { .. } |
+					pallet_funding::Call::withdraw_evaluation { .. } |
+					pallet_funding::Call::bid { .. } |
+					pallet_funding::Call::cancel_bid { .. } |
+					pallet_funding::Call::reduce_bid { .. } |
+					pallet_funding::Call::contribute { .. } |
+					pallet_funding::Call::withdraw_contribution { .. }
+			)
+		)
+	}
+}
+
 parameter_types! {
 	// One XCM operation is 1_000_000_000 weight - almost certainly a conservative estimate.
 	pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
@@ -292,8 +333,8 @@ impl xcm_executor::Config for XcmConfig {
 	type PalletInstancesInfo = AllPalletsWithSystem;
 	type ResponseHandler = PolkadotXcm;
 	type RuntimeCall = RuntimeCall;
-	// Do not allow any Transact instructions to be executed on our chain.
-	type SafeCallFilter = Nothing;
+	// Only allow Transact instructions participating in funding projects.
+	type SafeCallFilter = FundingParticipationCalls;
 	type SubscriptionService = PolkadotXcm;
 	type Trader = (
 		// TODO: `WeightToFee` has to be carefully considered. For now use default
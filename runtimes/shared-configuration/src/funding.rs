@@ -30,6 +30,13 @@ pub const EVALUATION_ROUND_DURATION: BlockNumber = 7 * crate::MINUTES;
 #[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
 pub const EVALUATION_ROUND_DURATION: BlockNumber = 7 * crate::DAYS;
 
+#[cfg(feature = "instant-mode")]
+pub const PRIVATE_ROUND_DURATION: BlockNumber = 3;
+#[cfg(feature = "fast-mode")]
+pub const PRIVATE_ROUND_DURATION: BlockNumber = 3 * crate::MINUTES;
+#[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
+pub const PRIVATE_ROUND_DURATION: BlockNumber = 3 * crate::DAYS;
+
 #[cfg(feature = "instant-mode")]
 pub const AUCTION_ROUND_DURATION: BlockNumber = 7;
 #[cfg(feature = "fast-mode")]
@@ -51,13 +58,30 @@ pub const REMAINDER_ROUND_DURATION: BlockNumber = 2 * crate::MINUTES;
 #[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
 pub const REMAINDER_ROUND_DURATION: BlockNumber = 2 * crate::DAYS;
 
+#[cfg(feature = "instant-mode")]
+pub const CONTRIBUTION_COOLING_OFF_PERIOD: BlockNumber = 2;
+#[cfg(feature = "fast-mode")]
+pub const CONTRIBUTION_COOLING_OFF_PERIOD: BlockNumber = 2 * crate::MINUTES;
+#[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
+pub const CONTRIBUTION_COOLING_OFF_PERIOD: BlockNumber = 2 * crate::DAYS;
+
+#[cfg(feature = "instant-mode")]
+pub const LONG_TERM_HOLDER_PERIOD: BlockNumber = 10;
+#[cfg(feature = "fast-mode")]
+pub const LONG_TERM_HOLDER_PERIOD: BlockNumber = 10 * crate::MINUTES;
+#[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
+pub const LONG_TERM_HOLDER_PERIOD: BlockNumber = 90 * crate::DAYS;
+
 pub type ProjectIdentifier = u32;
 
 parameter_types! {
 	pub const EvaluationRoundDuration: BlockNumber = EVALUATION_ROUND_DURATION;
+	pub const PrivateRoundDuration: BlockNumber = PRIVATE_ROUND_DURATION;
 	pub const AuctionRoundDuration: BlockNumber = AUCTION_ROUND_DURATION;
 	pub const CommunityRoundDuration: BlockNumber = COMMUNITY_ROUND_DURATION;
 	pub const RemainderRoundDuration: BlockNumber = REMAINDER_ROUND_DURATION;
+	pub const ContributionCoolingOffPeriod: BlockNumber = CONTRIBUTION_COOLING_OFF_PERIOD;
+	pub const LongTermHolderPeriod: BlockNumber = LONG_TERM_HOLDER_PERIOD;
 	pub const FundingPalletId: PalletId = PalletId(*b"plmc/fun");
 	pub PriceMap: BTreeMap<AssetIdForTrustBackedAssets, FixedU128> = BTreeMap::from_iter(vec![
 		(AcceptedFundingAsset::DOT.id(), FixedU128::from_rational(69, 1)), // DOT